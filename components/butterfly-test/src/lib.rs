@@ -20,6 +20,8 @@ extern crate time;
 extern crate habitat_butterfly;
 extern crate habitat_core;
 
+pub mod sim;
+
 use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 use std::thread;
 use std::ops::{Deref, DerefMut, Range};
@@ -53,6 +55,15 @@ impl Suitability for NSuitability {
 }
 
 pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> Server {
+    let mut server = new_server(name, ring_key, suitability);
+    server.start(Timing::default()).expect(
+        "Cannot start server",
+    );
+    server
+}
+
+/// Creates a server on the next free pair of ports, without starting it.
+pub fn new_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> Server {
     SERVER_PORT.compare_and_swap(0, 6666, Ordering::Relaxed);
     let swim_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
     let gossip_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
@@ -61,7 +72,7 @@ pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> S
     let mut member = Member::default();
     member.set_swim_port(swim_port as i32);
     member.set_gossip_port(gossip_port as i32);
-    let mut server = Server::new(
+    Server::new(
         &listen_swim[..],
         &listen_gossip[..],
         member,
@@ -70,11 +81,7 @@ pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> S
         Some(String::from(name)),
         None::<PathBuf>,
         Box::new(NSuitability(suitability)),
-    ).unwrap();
-    server.start(Timing::default()).expect(
-        "Cannot start server",
-    );
-    server
+    ).unwrap()
 }
 
 pub fn member_from_server(server: &Server) -> Member {
@@ -200,6 +207,22 @@ impl SwimNet {
         from.member_list.health_of(&to_member)
     }
 
+    /// How many members other than the first suspect confirm `from_entry`'s suspicion of
+    /// `to_entry`, if it suspects it.
+    pub fn suspicion_confirmations_of(&self, from_entry: usize, to_entry: usize) -> Option<usize> {
+        let from = self.members.get(from_entry).expect(
+            "Asked for a network member who is out of bounds",
+        );
+        let to = self.members.get(to_entry).expect(
+            "Asked for a network member who is out of bounds",
+        );
+        let mut confirmations = None;
+        from.member_list.with_suspects(|(id, suspicion)| if id == to.member_id() {
+            confirmations = Some(suspicion.confirmations());
+        });
+        confirmations
+    }
+
     pub fn network_health_of(&self, to_check: usize) -> Vec<Option<Health>> {
         let mut health_summary = Vec::with_capacity(self.members.len() - 1);
         let length = self.members.len();
@@ -415,6 +438,47 @@ impl SwimNet {
         }
     }
 
+    pub fn wait_for_suspicion_confirmations(
+        &self,
+        from_entry: usize,
+        to_check: usize,
+        confirmations: usize,
+    ) -> bool {
+        let rounds_in = self.rounds_in(self.max_rounds());
+        loop {
+            if let Some(real) = self.suspicion_confirmations_of(from_entry, to_check) {
+                if real >= confirmations {
+                    return true;
+                }
+            }
+            if self.check_rounds(&rounds_in) {
+                println!(
+                    "Failed suspicion confirmation check for\n***FROM***{:#?}\n***TO***\n{:#?}",
+                    self.members[from_entry],
+                    self.members[to_check]
+                );
+                return false;
+            }
+        }
+    }
+
+    pub fn wait_for_local_health_of(&self, member: usize, at_least: usize) -> bool {
+        let rounds_in = self.rounds_in(self.max_rounds());
+        loop {
+            if self.members[member].local_health() >= at_least {
+                return true;
+            }
+            if self.check_rounds(&rounds_in) {
+                println!(
+                    "Failed local health check for {}: {}",
+                    member,
+                    self.members[member].local_health()
+                );
+                return false;
+            }
+        }
+    }
+
     pub fn wait_for_network_health_of(&self, to_check: usize, health: Health) -> bool {
         let rounds_in = self.rounds_in(self.max_rounds());
         loop {
@@ -454,7 +518,7 @@ impl SwimNet {
     #[allow(dead_code)]
     pub fn wait_protocol_period(&self) {
         let timing = Timing::default();
-        let next_period = timing.next_protocol_period();
+        let next_period = timing.next_protocol_period(SteadyTime::now());
         loop {
             if SteadyTime::now() <= next_period {
                 thread::sleep(Duration::from_millis(100));
@@ -508,6 +572,14 @@ impl SwimNet {
     pub fn add_election(&mut self, member: usize, service: &str) {
         self[member].start_election(ServiceGroup::new(None, service, "prod", None).unwrap(), 0);
     }
+
+    pub fn add_election_with_quorum(&mut self, member: usize, service: &str, quorum: u32) {
+        self[member].start_election_with_quorum(
+            ServiceGroup::new(None, service, "prod", None).unwrap(),
+            0,
+            quorum,
+        );
+    }
 }
 
 #[macro_export]
@@ -0,0 +1,222 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rings of servers on a simulated network, in virtual time.
+//!
+//! Each `SimNet` runs its servers on a `Simulation`, so SWIM messages between them can be lost,
+//! delayed, reordered or blocked, gossip can be delayed or blocked, and the waits below advance
+//! virtual time rather than sleeping. The servers' threads only run while time advances, so a
+//! test can inspect or change them in between without racing them.
+
+use std::net::SocketAddr;
+use std::ops::{Deref, DerefMut, Range};
+use std::sync::Arc;
+use std::time::Duration;
+
+use habitat_butterfly::member::{Member, Health};
+use habitat_butterfly::network::sim::Simulation;
+use habitat_butterfly::server::Server;
+use habitat_butterfly::server::timing::Timing;
+
+use super::{member_from_server, new_server};
+
+/// How far to advance virtual time between checks while waiting for a condition.
+const STEP_MS: u64 = 100;
+/// How much virtual time a wait may take before it fails; enough to confirm a suspect that nobody
+/// else confirms, with time to spare.
+const WAIT_MS: u64 = 120_000;
+
+#[derive(Debug)]
+pub struct SimNet {
+    pub members: Vec<Server>,
+    pub simulation: Simulation,
+}
+
+impl Deref for SimNet {
+    type Target = Vec<Server>;
+
+    fn deref(&self) -> &Vec<Server> {
+        &self.members
+    }
+}
+
+impl DerefMut for SimNet {
+    fn deref_mut(&mut self) -> &mut Vec<Server> {
+        &mut self.members
+    }
+}
+
+impl SimNet {
+    /// Starts `count` servers on a simulation whose message fates follow from `seed`.
+    pub fn new(count: usize, seed: u64) -> SimNet {
+        let simulation = Simulation::new(seed);
+        let mut members = Vec::with_capacity(count);
+        for x in 0..count {
+            let mut server = new_server(&format!("{}", x), None, 0);
+            server.set_network(Arc::new(simulation.clone()));
+            server.set_clock(Arc::new(simulation.clone()));
+            server.start(Timing::default()).expect(
+                "Cannot start server",
+            );
+            members.push(server);
+        }
+        SimNet {
+            members: members,
+            simulation: simulation,
+        }
+    }
+
+    /// The SWIM address of a member.
+    pub fn address(&self, entry: usize) -> SocketAddr {
+        format!("127.0.0.1:{}", self.members[entry].swim_port())
+            .parse()
+            .expect("Member has an invalid SWIM address")
+    }
+
+    /// The address a member pulls gossip from.
+    pub fn gossip_address(&self, entry: usize) -> SocketAddr {
+        format!("127.0.0.1:{}", self.members[entry].gossip_port())
+            .parse()
+            .expect("Member has an invalid gossip address")
+    }
+
+    pub fn connect(&mut self, from_entry: usize, to_entry: usize) {
+        let to = member_from_server(&self.members[to_entry]);
+        self.members[from_entry].insert_member(to, Health::Alive);
+    }
+
+    // Fully mesh the network
+    pub fn mesh(&mut self) {
+        for pos in 0..self.members.len() {
+            let to_mesh: Vec<Member> = self.members
+                .iter()
+                .enumerate()
+                .filter(|&(x_pos, _)| x_pos != pos)
+                .map(|(_, server)| member_from_server(server))
+                .collect();
+            for member in to_mesh.into_iter() {
+                self.members[pos].insert_member(member, Health::Alive);
+            }
+        }
+    }
+
+    /// Drops every message and rumor from one member to another, but not the other way.
+    pub fn block(&self, from_entry: usize, to_entry: usize) {
+        self.simulation.block(
+            self.address(from_entry),
+            self.address(to_entry),
+        );
+        self.simulation.block(
+            self.gossip_address(from_entry),
+            self.gossip_address(to_entry),
+        );
+    }
+
+    pub fn unblock(&self, from_entry: usize, to_entry: usize) {
+        self.simulation.unblock(
+            self.address(from_entry),
+            self.address(to_entry),
+        );
+        self.simulation.unblock(
+            self.gossip_address(from_entry),
+            self.gossip_address(to_entry),
+        );
+    }
+
+    pub fn partition(&self, left_range: Range<usize>, right_range: Range<usize>) {
+        let right: Vec<usize> = right_range.collect();
+        for l in left_range {
+            for r in right.iter() {
+                self.block(l, *r);
+                self.block(*r, l);
+            }
+        }
+    }
+
+    pub fn unpartition(&self, left_range: Range<usize>, right_range: Range<usize>) {
+        let right: Vec<usize> = right_range.collect();
+        for l in left_range {
+            for r in right.iter() {
+                self.unblock(l, *r);
+                self.unblock(*r, l);
+            }
+        }
+    }
+
+    pub fn health_of(&self, from_entry: usize, to_entry: usize) -> Option<Health> {
+        let to_member = self.members[to_entry].member.read().expect(
+            "Member lock is poisoned",
+        );
+        self.members[from_entry].member_list.health_of(&to_member)
+    }
+
+    pub fn network_health_of(&self, to_check: usize) -> Vec<Option<Health>> {
+        (0..self.members.len())
+            .filter(|x| *x != to_check)
+            .map(|x| self.health_of(x, to_check))
+            .collect()
+    }
+
+    /// Advances virtual time by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.simulation.advance(duration);
+    }
+
+    /// Advances virtual time until `done` holds, returning false if it still doesn't after
+    /// `WAIT_MS`.
+    pub fn advance_until<F>(&self, mut done: F) -> bool
+    where
+        F: FnMut(&SimNet) -> bool,
+    {
+        let mut waited = 0;
+        loop {
+            if done(self) {
+                return true;
+            }
+            if waited >= WAIT_MS {
+                return false;
+            }
+            self.simulation.advance(Duration::from_millis(STEP_MS));
+            waited += STEP_MS;
+        }
+    }
+
+    pub fn wait_for_health_of(&self, from_entry: usize, to_check: usize, health: Health) -> bool {
+        let found = self.advance_until(|net| {
+            net.health_of(from_entry, to_check) == Some(health)
+        });
+        if !found {
+            println!(
+                "Failed health check for\n***FROM***{:#?}\n***TO***\n{:#?}",
+                self.members[from_entry],
+                self.members[to_check]
+            );
+        }
+        found
+    }
+
+    pub fn wait_for_network_health_of(&self, to_check: usize, health: Health) -> bool {
+        let found = self.advance_until(|net| {
+            net.network_health_of(to_check).into_iter().all(
+                |x| x == Some(health),
+            )
+        });
+        if !found {
+            for (i, some_health) in self.network_health_of(to_check).iter().enumerate() {
+                println!("{}: {:?}", i, some_health);
+            }
+        }
+        found
+    }
+}
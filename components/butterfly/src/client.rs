@@ -24,6 +24,7 @@ use ZMQ_CONTEXT;
 use message;
 use rumor::Rumor;
 use rumor::departure::Departure;
+use rumor::ring_key::RingKey;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
 use error::{Result, Error};
@@ -104,6 +105,32 @@ impl Client {
         self.send(sf)
     }
 
+    /// Create a service file tombstone and send it to the server. The incarnation must be higher
+    /// than the file's for the ring to accept it and remove the file.
+    pub fn send_service_file_tombstone<S: Into<String>>(
+        &mut self,
+        service_group: ServiceGroup,
+        filename: S,
+        incarnation: u64,
+    ) -> Result<()> {
+        let mut sf = ServiceFile::new_tombstone("butterflyclient", service_group, filename);
+        sf.set_incarnation(incarnation);
+        self.send(sf)
+    }
+
+    /// Create a ring key rumor for a new revision of the ring key and send it to the server. The
+    /// revision is used from `activate_at` and older revisions are retired at `retire_at`, both
+    /// in seconds since the Unix epoch.
+    pub fn send_ring_key(
+        &mut self,
+        ring_key: &SymKey,
+        activate_at: u64,
+        retire_at: u64,
+    ) -> Result<()> {
+        let rk = RingKey::new(ring_key, activate_at, retire_at)?;
+        self.send(rk)
+    }
+
     /// Send any `Rumor` to the server.
     pub fn send<T: Rumor>(&mut self, rumor: T) -> Result<()> {
         let bytes = rumor.write_to_bytes()?;
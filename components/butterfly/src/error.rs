@@ -34,6 +34,7 @@ pub enum Error {
     CannotBind(io::Error),
     DatFileIO(PathBuf, io::Error),
     HabitatCore(habitat_core::error::Error),
+    NoRingKey(String),
     NonExistentRumor(String, String),
     ProtobufError(protobuf::ProtobufError),
     ServiceConfigDecode(String, toml::de::Error),
@@ -41,7 +42,9 @@ pub enum Error {
     SocketSetReadTimeout(io::Error),
     SocketSetWriteTimeout(io::Error),
     SocketCloneError,
+    ZmqBindError(zmq::Error),
     ZmqConnectError(zmq::Error),
+    ZmqRecvError(zmq::Error),
     ZmqSendError(zmq::Error),
 }
 
@@ -72,6 +75,12 @@ impl fmt::Display for Error {
                 )
             }
             Error::HabitatCore(ref err) => format!("{}", err),
+            Error::NoRingKey(ref revision) => {
+                format!(
+                    "No ring key to decrypt a message encrypted with revision '{}'",
+                    revision
+                )
+            }
             Error::NonExistentRumor(ref member_id, ref rumor_id) => {
                 format!(
                     "Non existent rumor asked to be written to bytes: {} {}",
@@ -93,7 +102,11 @@ impl fmt::Display for Error {
                 format!("Cannot set UDP socket write timeout: {}", err)
             }
             Error::SocketCloneError => format!("Cannot clone the underlying UDP socket"),
+            Error::ZmqBindError(ref err) => format!("Cannot bind ZMQ socket: {}", err),
             Error::ZmqConnectError(ref err) => format!("Cannot connect ZMQ socket: {}", err),
+            Error::ZmqRecvError(ref err) => {
+                format!("Cannot receive message from ZMQ socket: {}", err)
+            }
             Error::ZmqSendError(ref err) => {
                 format!("Cannot send message through ZMQ socket: {}", err)
             }
@@ -112,6 +125,7 @@ impl error::Error for Error {
             Error::CannotBind(_) => "Cannot bind to port",
             Error::DatFileIO(_, _) => "Error reading or writing to DatFile",
             Error::HabitatCore(_) => "Habitat core error",
+            Error::NoRingKey(_) => "No ring key to decrypt a message",
             Error::NonExistentRumor(_, _) => {
                 "Cannot write rumor to bytes because it does not exist"
             }
@@ -121,7 +135,9 @@ impl error::Error for Error {
             Error::SocketSetReadTimeout(_) => "Cannot set UDP socket read timeout",
             Error::SocketSetWriteTimeout(_) => "Cannot set UDP socket write timeout",
             Error::SocketCloneError => "Cannot clone the underlying UDP socket",
+            Error::ZmqBindError(_) => "Cannot bind ZMQ socket",
             Error::ZmqConnectError(_) => "Cannot connect ZMQ socket",
+            Error::ZmqRecvError(_) => "Cannot receive message from ZMQ socket",
             Error::ZmqSendError(_) => "Cannot send message through ZMQ socket",
         }
     }
@@ -38,6 +38,10 @@
 //!    than one second).
 //! 1. A 'pull' thread, which takes messages from any push source and applies them locally.
 //!
+//! Every thread is started by the server's `network::Clock` and keeps time with it, and takes its
+//! SWIM and gossip sockets from the server's `network::Network`, so that tests can run whole rings
+//! on a simulated network in virtual time, one thread at a time.
+//!
 //! Start exploring the code base by following the thread of execution in the `server` module.
 
 #![cfg_attr(feature="clippy", feature(plugin))]
@@ -65,6 +69,8 @@ pub mod client;
 pub mod error;
 pub mod member;
 pub mod message;
+pub mod network;
+pub mod ring_keys;
 pub mod rumor;
 pub mod server;
 
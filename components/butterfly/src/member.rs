@@ -14,7 +14,7 @@
 
 //! Tracks membership. Contains both the `Member` struct and the `MemberList`.
 
-use std::collections::{hash_map, HashMap};
+use std::collections::{hash_map, BTreeMap, HashMap};
 use std::fmt;
 use std::iter::IntoIterator;
 use std::net::SocketAddr;
@@ -24,7 +24,7 @@ use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
-use protobuf::ProtobufEnum;
+use protobuf::{ProtobufEnum, RepeatedField};
 use rand::{thread_rng, Rng};
 use time::SteadyTime;
 use uuid::Uuid;
@@ -32,8 +32,10 @@ use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 
 use error::Error;
-use message::swim::{Member as ProtoMember, Membership as ProtoMembership,
-                    Membership_Health as ProtoMembership_Health, Rumor_Type};
+use message::swim::{Member as ProtoMember, MemberLabel as ProtoMemberLabel,
+                    Membership as ProtoMembership, Membership_Health as ProtoMembership_Health,
+                    Rumor_Type};
+use network::{Clock, SystemClock};
 use rumor::RumorKey;
 
 /// How many nodes do we target when we need to run PingReq.
@@ -146,6 +148,43 @@ impl Member {
             }
         }
     }
+
+    /// Returns the address this member pulls gossip from.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the address is un-parseable, as `swim_socket_address` does.
+    pub fn gossip_socket_address(&self) -> SocketAddr {
+        let address_str = format!("{}:{}", self.get_address(), self.get_gossip_port());
+        match address_str.parse() {
+            Ok(addr) => addr,
+            Err(e) => {
+                panic!("Cannot parse member {:?} address: {}", self, e);
+            }
+        }
+    }
+
+    /// Returns the operator-defined labels this member advertises, such as its datacenter or rack.
+    pub fn labels(&self) -> BTreeMap<String, String> {
+        self.get_labels()
+            .iter()
+            .map(|l| (l.get_key().to_string(), l.get_value().to_string()))
+            .collect()
+    }
+
+    /// Replaces the labels this member advertises.
+    pub fn set_label_map(&mut self, labels: &BTreeMap<String, String>) {
+        let labels = labels
+            .iter()
+            .map(|(key, value)| {
+                let mut label = ProtoMemberLabel::new();
+                label.set_key(key.clone());
+                label.set_value(value.clone());
+                label
+            })
+            .collect();
+        self.set_labels(labels);
+    }
 }
 
 impl Default for Member {
@@ -204,15 +243,43 @@ impl<'a> From<&'a &'a Member> for RumorKey {
 // This is a Uuid type turned to a string
 pub type UuidSimple = String;
 
+/// When a member became suspect, and the members who independently suspect it. The first of them
+/// started the suspicion; the rest confirm it.
+#[derive(Debug, Clone)]
+pub struct Suspicion {
+    pub started: SteadyTime,
+    suspected_by: Vec<UuidSimple>,
+}
+
+impl Suspicion {
+    fn new(started: SteadyTime) -> Suspicion {
+        Suspicion {
+            started: started,
+            suspected_by: Vec::new(),
+        }
+    }
+
+    /// The member who started the suspicion, if we know who it was.
+    pub fn suspected_by(&self) -> Option<&str> {
+        self.suspected_by.first().map(|id| id.as_str())
+    }
+
+    /// How many members other than the one who started the suspicion have confirmed it.
+    pub fn confirmations(&self) -> usize {
+        self.suspected_by.len().saturating_sub(1)
+    }
+}
+
 /// Tracks lists of members, their health, and how long they have been suspect.
 #[derive(Debug, Clone)]
 pub struct MemberList {
     pub members: Arc<RwLock<HashMap<UuidSimple, Member>>>,
     pub health: Arc<RwLock<HashMap<UuidSimple, Health>>>,
-    suspect: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
+    suspect: Arc<RwLock<HashMap<UuidSimple, Suspicion>>>,
     depart: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
     initial_members: Arc<RwLock<Vec<Member>>>,
     update_counter: Arc<AtomicUsize>,
+    clock: Arc<Clock>,
 }
 
 impl Serialize for MemberList {
@@ -247,9 +314,15 @@ impl MemberList {
             depart: Arc::new(RwLock::new(HashMap::new())),
             initial_members: Arc::new(RwLock::new(Vec::new())),
             update_counter: Arc::new(AtomicUsize::new(0)),
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Sets the clock that times suspicions and departures.
+    pub fn set_clock(&mut self, clock: Arc<Clock>) {
+        self.clock = clock;
+    }
+
     /// Increment the update counter for this store.
     ///
     /// We don't care if this repeats - it just needs to be unique for any given two states, which
@@ -310,11 +383,15 @@ impl MemberList {
             // to prefer it.
             } else if member.get_incarnation() > current_member.get_incarnation() {
                 share_rumor = true;
-                if health == Health::Confirmed {
+                // A newer incarnation replaces any suspicion of an older one; a suspect member
+                // has refuted it by coming back alive, and a suspicion of the new incarnation
+                // starts over.
+                if health == Health::Suspect {
+                    start_suspicion = true;
+                } else {
                     stop_suspicion = true;
                 }
-                if health == Health::Departed {
-                    stop_suspicion = true;
+                if health != Health::Confirmed {
                     stop_departure = true;
                 }
             } else {
@@ -365,6 +442,9 @@ impl MemberList {
             }
         } else {
             share_rumor = true;
+            if health == Health::Suspect {
+                start_suspicion = true;
+            }
         }
 
         if share_rumor == true {
@@ -377,7 +457,7 @@ impl MemberList {
                 self.suspect
                     .write()
                     .expect("Suspect lock is poisoned")
-                    .insert(String::from(member.get_id()), SteadyTime::now());
+                    .insert(String::from(member.get_id()), Suspicion::new(self.clock.now()));
             }
             if stop_suspicion == true {
                 self.suspect
@@ -483,7 +563,7 @@ impl MemberList {
         }
         if health == Health::Suspect {
             let mut sl = self.suspect.write().expect("Suspect lock is poisoned");
-            sl.insert(String::from(member_id), SteadyTime::now());
+            sl.insert(String::from(member_id), Suspicion::new(self.clock.now()));
         }
         self.health
             .write()
@@ -498,7 +578,8 @@ impl MemberList {
         self.insert_health_by_id(member.get_id(), health)
     }
 
-    /// Returns a protobuf membership record for the given member id.
+    /// Returns a protobuf membership record for the given member id. The record of a suspect
+    /// member carries every member who suspects it, the one who started the suspicion first.
     pub fn membership_for(&self, member_id: &str) -> Option<ProtoMembership> {
         let mut pm = ProtoMembership::new();
         let mhealth: ProtoMembership_Health = match self.health
@@ -513,6 +594,13 @@ impl MemberList {
             Some(member) => {
                 pm.set_health(mhealth);
                 pm.set_member(member.proto.clone());
+                if let Some(suspicion) = self.suspect
+                    .read()
+                    .expect("Suspect lock is poisoned")
+                    .get(member_id)
+                {
+                    pm.set_suspected_by(RepeatedField::from_vec(suspicion.suspected_by.clone()));
+                }
                 Some(pm)
             }
             None => None,
@@ -621,10 +709,30 @@ impl MemberList {
         }
     }
 
+    /// Records that a member independently suspects a member who is suspect. Returns true if
+    /// this is news, that is the member hadn't suspected it before.
+    pub fn confirm_suspicion(&self, member_id: &str, suspected_by: &str) -> bool {
+        if suspected_by.is_empty() || suspected_by == member_id {
+            return false;
+        }
+        let mut sl = self.suspect.write().expect("Suspect lock is poisoned");
+        match sl.get_mut(member_id) {
+            Some(suspicion) => {
+                if suspicion.suspected_by.iter().any(|id| id == suspected_by) {
+                    false
+                } else {
+                    suspicion.suspected_by.push(String::from(suspected_by));
+                    true
+                }
+            }
+            None => false,
+        }
+    }
+
     /// Iterates over every suspected membership entry, calling the given closure.
     pub fn with_suspects<F>(&self, mut with_closure: F) -> ()
     where
-        F: FnMut((&str, &SteadyTime)) -> (),
+        F: FnMut((&str, &Suspicion)) -> (),
     {
         for (id, suspect) in self.suspect
             .read()
@@ -661,7 +769,7 @@ impl MemberList {
         let mut depart = self.depart.write().expect(
             "Departure list lock is poisoned",
         );
-        depart.insert(member_id.to_string(), SteadyTime::now());
+        depart.insert(member_id.to_string(), self.clock.now());
     }
 
     /// Removes a member from the departure list
@@ -683,6 +791,8 @@ impl MemberList {
 #[cfg(test)]
 mod tests {
     mod member {
+        use std::collections::BTreeMap;
+
         use uuid::Uuid;
         use message::swim;
         use member::Member;
@@ -706,6 +816,18 @@ mod tests {
             let member: Member = proto.into();
             assert_eq!(proto2, member.proto);
         }
+
+        #[test]
+        fn labels() {
+            let mut labels = BTreeMap::new();
+            labels.insert("datacenter".to_string(), "east".to_string());
+            labels.insert("rack".to_string(), "r12".to_string());
+            let mut member = Member::default();
+            assert!(member.labels().is_empty());
+            member.set_label_map(&labels);
+            assert_eq!(member.get_labels().len(), 2);
+            assert_eq!(member.labels(), labels);
+        }
     }
 
     mod member_list {
@@ -1026,5 +1148,48 @@ mod tests {
             assert!(ml.check_health_of(&mcheck_two, Health::Departed));
         }
 
+        #[test]
+        fn insert_higher_incarnation_alive_refutes_suspicion() {
+            let ml = MemberList::new();
+            let member_one = Member::default();
+            let mut member_two = member_one.clone();
+            let mcheck = member_one.clone();
+
+            assert_eq!(ml.insert(member_one, Health::Suspect), true);
+            let mut suspects = 0;
+            ml.with_suspects(|_| suspects += 1);
+            assert_eq!(suspects, 1);
+
+            member_two.set_incarnation(1);
+            assert_eq!(ml.insert(member_two, Health::Alive), true);
+            assert!(ml.check_health_of(&mcheck, Health::Alive));
+            let mut suspects = 0;
+            ml.with_suspects(|_| suspects += 1);
+            assert_eq!(suspects, 0);
+        }
+
+        #[test]
+        fn confirm_suspicion() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let mcheck = member.clone();
+
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "a"), false);
+            assert_eq!(ml.insert(member, Health::Suspect), true);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "a"), true);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "a"), false);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), ""), false);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), mcheck.get_id()), false);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), "b"), true);
+            ml.with_suspects(|(_, suspicion)| {
+                assert_eq!(suspicion.suspected_by(), Some("a"));
+                assert_eq!(suspicion.confirmations(), 1);
+            });
+            let membership = ml.membership_for(mcheck.get_id()).unwrap();
+            assert_eq!(
+                membership.get_suspected_by(),
+                &[String::from("a"), String::from("b")][..]
+            );
+        }
     }
 }
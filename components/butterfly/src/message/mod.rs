@@ -14,6 +14,7 @@
 
 pub mod swim;
 
+use std::collections::BTreeMap;
 use std::result;
 use std::str;
 
@@ -24,6 +25,7 @@ use toml;
 
 use error::Result;
 use message::swim::Wire;
+use ring_keys::RingKeys;
 use protobuf::{self, Message};
 
 pub fn generate_wire(payload: Vec<u8>, ring_key: Option<&SymKey>) -> Result<Vec<u8>> {
@@ -31,6 +33,7 @@ pub fn generate_wire(payload: Vec<u8>, ring_key: Option<&SymKey>) -> Result<Vec<
     if let Some(ring_key) = ring_key {
         wire.set_encrypted(true);
         let (nonce, encrypted_payload) = ring_key.encrypt(&payload)?;
+        wire.set_key_revision(ring_key.rev.clone());
         wire.set_nonce(nonce);
         wire.set_payload(encrypted_payload);
     } else {
@@ -39,10 +42,14 @@ pub fn generate_wire(payload: Vec<u8>, ring_key: Option<&SymKey>) -> Result<Vec<
     Ok(wire.write_to_bytes()?)
 }
 
-pub fn unwrap_wire(payload: &[u8], ring_key: Option<&SymKey>) -> Result<Vec<u8>> {
+pub fn unwrap_wire(payload: &[u8], ring_keys: &RingKeys) -> Result<Vec<u8>> {
     let mut wire: Wire = protobuf::parse_from_bytes(payload)?;
-    if let Some(ring_key) = ring_key {
-        Ok(ring_key.decrypt(wire.get_nonce(), wire.get_payload())?)
+    if ring_keys.is_encrypted() {
+        ring_keys.decrypt(
+            wire.get_key_revision(),
+            wire.get_nonce(),
+            wire.get_payload(),
+        )
     } else {
         Ok(wire.take_payload())
     }
@@ -75,7 +82,7 @@ impl Serialize for swim::Member {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("member", 6)?;
+        let mut strukt = serializer.serialize_struct("member", 7)?;
         strukt.serialize_field("id", self.get_id())?;
         strukt.serialize_field(
             "incarnation",
@@ -88,6 +95,11 @@ impl Serialize for swim::Member {
             &self.get_gossip_port(),
         )?;
         strukt.serialize_field("persistent", &self.get_persistent())?;
+        let labels: BTreeMap<&str, &str> = self.get_labels()
+            .iter()
+            .map(|l| (l.get_key(), l.get_value()))
+            .collect();
+        strukt.serialize_field("labels", &labels)?;
         strukt.end()
     }
 }
@@ -97,9 +109,10 @@ impl Serialize for swim::Membership {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("membership", 2)?;
+        let mut strukt = serializer.serialize_struct("membership", 3)?;
         strukt.serialize_field("member", self.get_member())?;
         strukt.serialize_field("health", &self.get_health())?;
+        strukt.serialize_field("suspected_by", self.get_suspected_by())?;
         strukt.end()
     }
 }
@@ -134,6 +147,26 @@ impl Serialize for swim::Rumor {
         if self.has_election() {
             strukt.serialize_field("election", self.get_election())?;
         }
+        if self.has_ring_key() {
+            strukt.serialize_field("ring_key", self.get_ring_key())?;
+        }
+        strukt.end()
+    }
+}
+
+// The secret is left out, as this is shown in the Supervisor's debug output.
+impl Serialize for swim::RingKey {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("ring_key", 3)?;
+        strukt.serialize_field("name_with_rev", self.get_name_with_rev())?;
+        strukt.serialize_field(
+            "activate_at",
+            &self.get_activate_at(),
+        )?;
+        strukt.serialize_field("retire_at", &self.get_retire_at())?;
         strukt.end()
     }
 }
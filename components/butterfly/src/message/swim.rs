@@ -31,6 +31,7 @@ pub struct Member {
     gossip_port: ::std::option::Option<i32>,
     persistent: ::std::option::Option<bool>,
     departed: ::std::option::Option<bool>,
+    labels: ::protobuf::RepeatedField<MemberLabel>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -276,10 +277,48 @@ impl Member {
     fn mut_departed_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
         &mut self.departed
     }
+
+    // repeated .MemberLabel labels = 8;
+
+    pub fn clear_labels(&mut self) {
+        self.labels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_labels(&mut self, v: ::protobuf::RepeatedField<MemberLabel>) {
+        self.labels = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_labels(&mut self) -> &mut ::protobuf::RepeatedField<MemberLabel> {
+        &mut self.labels
+    }
+
+    // Take field
+    pub fn take_labels(&mut self) -> ::protobuf::RepeatedField<MemberLabel> {
+        ::std::mem::replace(&mut self.labels, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_labels(&self) -> &[MemberLabel] {
+        &self.labels
+    }
+
+    fn get_labels_for_reflect(&self) -> &::protobuf::RepeatedField<MemberLabel> {
+        &self.labels
+    }
+
+    fn mut_labels_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<MemberLabel> {
+        &mut self.labels
+    }
 }
 
 impl ::protobuf::Message for Member {
     fn is_initialized(&self) -> bool {
+        for v in &self.labels {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -328,6 +367,9 @@ impl ::protobuf::Message for Member {
                     let tmp = is.read_bool()?;
                     self.departed = ::std::option::Option::Some(tmp);
                 },
+                8 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.labels)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -361,6 +403,10 @@ impl ::protobuf::Message for Member {
         if let Some(v) = self.departed {
             my_size += 2;
         }
+        for value in &self.labels {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -388,6 +434,11 @@ impl ::protobuf::Message for Member {
         if let Some(v) = self.departed {
             os.write_bool(7, v)?;
         }
+        for v in &self.labels {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -467,6 +518,11 @@ impl ::protobuf::MessageStatic for Member {
                     Member::get_departed_for_reflect,
                     Member::mut_departed_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MemberLabel>>(
+                    "labels",
+                    Member::get_labels_for_reflect,
+                    Member::mut_labels_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Member>(
                     "Member",
                     fields,
@@ -486,6 +542,7 @@ impl ::protobuf::Clear for Member {
         self.clear_gossip_port();
         self.clear_persistent();
         self.clear_departed();
+        self.clear_labels();
         self.unknown_fields.clear();
     }
 }
@@ -502,6 +559,252 @@ impl ::protobuf::reflect::ProtobufValue for Member {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct MemberLabel {
+    // message fields
+    key: ::protobuf::SingularField<::std::string::String>,
+    value: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for MemberLabel {}
+
+impl MemberLabel {
+    pub fn new() -> MemberLabel {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static MemberLabel {
+        static mut instance: ::protobuf::lazy::Lazy<MemberLabel> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MemberLabel,
+        };
+        unsafe {
+            instance.get(MemberLabel::new)
+        }
+    }
+
+    // optional string key = 1;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        if self.key.is_none() {
+            self.key.set_default();
+        }
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        self.key.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key(&self) -> &str {
+        match self.key.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_key_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.key
+    }
+
+    fn mut_key_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.key
+    }
+
+    // optional string value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        if self.value.is_none() {
+            self.value.set_default();
+        }
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        self.value.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_value(&self) -> &str {
+        match self.value.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_value_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.value
+    }
+
+    fn mut_value_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.value
+    }
+}
+
+impl ::protobuf::Message for MemberLabel {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.key.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.key.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for MemberLabel {
+    fn new() -> MemberLabel {
+        MemberLabel::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<MemberLabel>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    MemberLabel::get_key_for_reflect,
+                    MemberLabel::mut_key_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    MemberLabel::get_value_for_reflect,
+                    MemberLabel::mut_value_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MemberLabel>(
+                    "MemberLabel",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for MemberLabel {
+    fn clear(&mut self) {
+        self.clear_key();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MemberLabel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MemberLabel {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Ping {
     // message fields
@@ -1275,6 +1578,7 @@ pub struct Membership {
     // message fields
     member: ::protobuf::SingularPtrField<Member>,
     health: ::std::option::Option<Membership_Health>,
+    suspected_by: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -1365,6 +1669,39 @@ impl Membership {
     fn mut_health_for_reflect(&mut self) -> &mut ::std::option::Option<Membership_Health> {
         &mut self.health
     }
+
+    // repeated string suspected_by = 3;
+
+    pub fn clear_suspected_by(&mut self) {
+        self.suspected_by.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suspected_by(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.suspected_by = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_suspected_by(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.suspected_by
+    }
+
+    // Take field
+    pub fn take_suspected_by(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.suspected_by, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_suspected_by(&self) -> &[::std::string::String] {
+        &self.suspected_by
+    }
+
+    fn get_suspected_by_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.suspected_by
+    }
+
+    fn mut_suspected_by_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.suspected_by
+    }
 }
 
 impl ::protobuf::Message for Membership {
@@ -1391,6 +1728,9 @@ impl ::protobuf::Message for Membership {
                     let tmp = is.read_enum()?;
                     self.health = ::std::option::Option::Some(tmp);
                 },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.suspected_by)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1410,6 +1750,9 @@ impl ::protobuf::Message for Membership {
         if let Some(v) = self.health {
             my_size += ::protobuf::rt::enum_size(2, v);
         }
+        for value in &self.suspected_by {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1424,6 +1767,9 @@ impl ::protobuf::Message for Membership {
         if let Some(v) = self.health {
             os.write_enum(2, v.value())?;
         }
+        for v in &self.suspected_by {
+            os.write_string(3, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1478,6 +1824,11 @@ impl ::protobuf::MessageStatic for Membership {
                     Membership::get_health_for_reflect,
                     Membership::mut_health_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "suspected_by",
+                    Membership::get_suspected_by_for_reflect,
+                    Membership::mut_suspected_by_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Membership>(
                     "Membership",
                     fields,
@@ -1492,6 +1843,7 @@ impl ::protobuf::Clear for Membership {
     fn clear(&mut self) {
         self.clear_member();
         self.clear_health();
+        self.clear_suspected_by();
         self.unknown_fields.clear();
     }
 }
@@ -1564,34 +1916,30 @@ impl ::protobuf::reflect::ProtobufValue for Membership_Health {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Election {
+pub struct ElectionCandidate {
     // message fields
     member_id: ::protobuf::SingularField<::std::string::String>,
-    service_group: ::protobuf::SingularField<::std::string::String>,
-    term: ::std::option::Option<u64>,
     suitability: ::std::option::Option<u64>,
-    status: ::std::option::Option<Election_Status>,
-    votes: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Election {}
+unsafe impl ::std::marker::Sync for ElectionCandidate {}
 
-impl Election {
-    pub fn new() -> Election {
+impl ElectionCandidate {
+    pub fn new() -> ElectionCandidate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Election {
-        static mut instance: ::protobuf::lazy::Lazy<Election> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ElectionCandidate {
+        static mut instance: ::protobuf::lazy::Lazy<ElectionCandidate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Election,
+            ptr: 0 as *const ElectionCandidate,
         };
         unsafe {
-            instance.get(Election::new)
+            instance.get(ElectionCandidate::new)
         }
     }
 
@@ -1639,22 +1987,261 @@ impl Election {
         &mut self.member_id
     }
 
-    // optional string service_group = 2;
+    // optional uint64 suitability = 2;
 
-    pub fn clear_service_group(&mut self) {
-        self.service_group.clear();
+    pub fn clear_suitability(&mut self) {
+        self.suitability = ::std::option::Option::None;
     }
 
-    pub fn has_service_group(&self) -> bool {
-        self.service_group.is_some()
+    pub fn has_suitability(&self) -> bool {
+        self.suitability.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_service_group(&mut self, v: ::std::string::String) {
-        self.service_group = ::protobuf::SingularField::some(v);
+    pub fn set_suitability(&mut self, v: u64) {
+        self.suitability = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
+    pub fn get_suitability(&self) -> u64 {
+        self.suitability.unwrap_or(0)
+    }
+
+    fn get_suitability_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.suitability
+    }
+
+    fn mut_suitability_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.suitability
+    }
+}
+
+impl ::protobuf::Message for ElectionCandidate {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.suitability = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.member_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.suitability {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.member_id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.suitability {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ElectionCandidate {
+    fn new() -> ElectionCandidate {
+        ElectionCandidate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ElectionCandidate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_id",
+                    ElectionCandidate::get_member_id_for_reflect,
+                    ElectionCandidate::mut_member_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "suitability",
+                    ElectionCandidate::get_suitability_for_reflect,
+                    ElectionCandidate::mut_suitability_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ElectionCandidate>(
+                    "ElectionCandidate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ElectionCandidate {
+    fn clear(&mut self) {
+        self.clear_member_id();
+        self.clear_suitability();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ElectionCandidate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ElectionCandidate {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Election {
+    // message fields
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    term: ::std::option::Option<u64>,
+    suitability: ::std::option::Option<u64>,
+    status: ::std::option::Option<Election_Status>,
+    votes: ::protobuf::RepeatedField<::std::string::String>,
+    quorum: ::std::option::Option<u32>,
+    candidates: ::protobuf::RepeatedField<ElectionCandidate>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Election {}
+
+impl Election {
+    pub fn new() -> Election {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Election {
+        static mut instance: ::protobuf::lazy::Lazy<Election> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Election,
+        };
+        unsafe {
+            instance.get(Election::new)
+        }
+    }
+
+    // optional string member_id = 1;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        }
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
+    }
+
+    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
+    }
+
+    // optional string service_group = 2;
+
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
+    }
+
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
     pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
         if self.service_group.is_none() {
@@ -1796,10 +2383,75 @@ impl Election {
     fn mut_votes_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
         &mut self.votes
     }
+
+    // optional uint32 quorum = 7;
+
+    pub fn clear_quorum(&mut self) {
+        self.quorum = ::std::option::Option::None;
+    }
+
+    pub fn has_quorum(&self) -> bool {
+        self.quorum.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quorum(&mut self, v: u32) {
+        self.quorum = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_quorum(&self) -> u32 {
+        self.quorum.unwrap_or(0)
+    }
+
+    fn get_quorum_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.quorum
+    }
+
+    fn mut_quorum_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.quorum
+    }
+
+    // repeated .ElectionCandidate candidates = 8;
+
+    pub fn clear_candidates(&mut self) {
+        self.candidates.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidates(&mut self, v: ::protobuf::RepeatedField<ElectionCandidate>) {
+        self.candidates = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_candidates(&mut self) -> &mut ::protobuf::RepeatedField<ElectionCandidate> {
+        &mut self.candidates
+    }
+
+    // Take field
+    pub fn take_candidates(&mut self) -> ::protobuf::RepeatedField<ElectionCandidate> {
+        ::std::mem::replace(&mut self.candidates, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_candidates(&self) -> &[ElectionCandidate] {
+        &self.candidates
+    }
+
+    fn get_candidates_for_reflect(&self) -> &::protobuf::RepeatedField<ElectionCandidate> {
+        &self.candidates
+    }
+
+    fn mut_candidates_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<ElectionCandidate> {
+        &mut self.candidates
+    }
 }
 
 impl ::protobuf::Message for Election {
     fn is_initialized(&self) -> bool {
+        for v in &self.candidates {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1837,6 +2489,16 @@ impl ::protobuf::Message for Election {
                 6 => {
                     ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.votes)?;
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.quorum = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.candidates)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1867,6 +2529,13 @@ impl ::protobuf::Message for Election {
         for value in &self.votes {
             my_size += ::protobuf::rt::string_size(6, &value);
         };
+        if let Some(v) = self.quorum {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.candidates {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1891,6 +2560,14 @@ impl ::protobuf::Message for Election {
         for v in &self.votes {
             os.write_string(6, &v)?;
         };
+        if let Some(v) = self.quorum {
+            os.write_uint32(7, v)?;
+        }
+        for v in &self.candidates {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1965,6 +2642,16 @@ impl ::protobuf::MessageStatic for Election {
                     Election::get_votes_for_reflect,
                     Election::mut_votes_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "quorum",
+                    Election::get_quorum_for_reflect,
+                    Election::mut_quorum_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ElectionCandidate>>(
+                    "candidates",
+                    Election::get_candidates_for_reflect,
+                    Election::mut_candidates_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Election>(
                     "Election",
                     fields,
@@ -1983,6 +2670,8 @@ impl ::protobuf::Clear for Election {
         self.clear_suitability();
         self.clear_status();
         self.clear_votes();
+        self.clear_quorum();
+        self.clear_candidates();
         self.unknown_fields.clear();
     }
 }
@@ -2061,6 +2750,10 @@ pub struct Service {
     pkg: ::protobuf::SingularField<::std::string::String>,
     cfg: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     sys: ::protobuf::SingularPtrField<SysInfo>,
+    health_restarts: ::std::option::Option<u64>,
+    last_health_restart: ::std::option::Option<i64>,
+    health: ::std::option::Option<i32>,
+    tombstone: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -2338,21 +3031,129 @@ impl Service {
         self.sys.as_mut().unwrap()
     }
 
-    // Take field
-    pub fn take_sys(&mut self) -> SysInfo {
-        self.sys.take().unwrap_or_else(|| SysInfo::new())
+    // Take field
+    pub fn take_sys(&mut self) -> SysInfo {
+        self.sys.take().unwrap_or_else(|| SysInfo::new())
+    }
+
+    pub fn get_sys(&self) -> &SysInfo {
+        self.sys.as_ref().unwrap_or_else(|| SysInfo::default_instance())
+    }
+
+    fn get_sys_for_reflect(&self) -> &::protobuf::SingularPtrField<SysInfo> {
+        &self.sys
+    }
+
+    fn mut_sys_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<SysInfo> {
+        &mut self.sys
+    }
+
+    // optional uint64 health_restarts = 13;
+
+    pub fn clear_health_restarts(&mut self) {
+        self.health_restarts = ::std::option::Option::None;
+    }
+
+    pub fn has_health_restarts(&self) -> bool {
+        self.health_restarts.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health_restarts(&mut self, v: u64) {
+        self.health_restarts = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_health_restarts(&self) -> u64 {
+        self.health_restarts.unwrap_or(0)
+    }
+
+    fn get_health_restarts_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.health_restarts
+    }
+
+    fn mut_health_restarts_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.health_restarts
+    }
+
+    // optional int64 last_health_restart = 14;
+
+    pub fn clear_last_health_restart(&mut self) {
+        self.last_health_restart = ::std::option::Option::None;
+    }
+
+    pub fn has_last_health_restart(&self) -> bool {
+        self.last_health_restart.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_health_restart(&mut self, v: i64) {
+        self.last_health_restart = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_last_health_restart(&self) -> i64 {
+        self.last_health_restart.unwrap_or(0)
+    }
+
+    fn get_last_health_restart_for_reflect(&self) -> &::std::option::Option<i64> {
+        &self.last_health_restart
+    }
+
+    fn mut_last_health_restart_for_reflect(&mut self) -> &mut ::std::option::Option<i64> {
+        &mut self.last_health_restart
+    }
+
+    // optional int32 health = 15;
+
+    pub fn clear_health(&mut self) {
+        self.health = ::std::option::Option::None;
+    }
+
+    pub fn has_health(&self) -> bool {
+        self.health.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health(&mut self, v: i32) {
+        self.health = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_health(&self) -> i32 {
+        self.health.unwrap_or(0)
+    }
+
+    fn get_health_for_reflect(&self) -> &::std::option::Option<i32> {
+        &self.health
+    }
+
+    fn mut_health_for_reflect(&mut self) -> &mut ::std::option::Option<i32> {
+        &mut self.health
+    }
+
+    // optional bool tombstone = 16;
+
+    pub fn clear_tombstone(&mut self) {
+        self.tombstone = ::std::option::Option::None;
+    }
+
+    pub fn has_tombstone(&self) -> bool {
+        self.tombstone.is_some()
     }
 
-    pub fn get_sys(&self) -> &SysInfo {
-        self.sys.as_ref().unwrap_or_else(|| SysInfo::default_instance())
+    // Param is passed by value, moved
+    pub fn set_tombstone(&mut self, v: bool) {
+        self.tombstone = ::std::option::Option::Some(v);
     }
 
-    fn get_sys_for_reflect(&self) -> &::protobuf::SingularPtrField<SysInfo> {
-        &self.sys
+    pub fn get_tombstone(&self) -> bool {
+        self.tombstone.unwrap_or(false)
     }
 
-    fn mut_sys_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<SysInfo> {
-        &mut self.sys
+    fn get_tombstone_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.tombstone
+    }
+
+    fn mut_tombstone_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.tombstone
     }
 }
 
@@ -2399,6 +3200,34 @@ impl ::protobuf::Message for Service {
                 12 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.sys)?;
                 },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.health_restarts = ::std::option::Option::Some(tmp);
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_health_restart = ::std::option::Option::Some(tmp);
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.health = ::std::option::Option::Some(tmp);
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.tombstone = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2433,6 +3262,18 @@ impl ::protobuf::Message for Service {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let Some(v) = self.health_restarts {
+            my_size += ::protobuf::rt::value_size(13, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.last_health_restart {
+            my_size += ::protobuf::rt::value_size(14, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.health {
+            my_size += ::protobuf::rt::value_size(15, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.tombstone {
+            my_size += 3;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2462,6 +3303,18 @@ impl ::protobuf::Message for Service {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let Some(v) = self.health_restarts {
+            os.write_uint64(13, v)?;
+        }
+        if let Some(v) = self.last_health_restart {
+            os.write_int64(14, v)?;
+        }
+        if let Some(v) = self.health {
+            os.write_int32(15, v)?;
+        }
+        if let Some(v) = self.tombstone {
+            os.write_bool(16, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2541,6 +3394,26 @@ impl ::protobuf::MessageStatic for Service {
                     Service::get_sys_for_reflect,
                     Service::mut_sys_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "health_restarts",
+                    Service::get_health_restarts_for_reflect,
+                    Service::mut_health_restarts_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "last_health_restart",
+                    Service::get_last_health_restart_for_reflect,
+                    Service::mut_last_health_restart_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "health",
+                    Service::get_health_for_reflect,
+                    Service::mut_health_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "tombstone",
+                    Service::get_tombstone_for_reflect,
+                    Service::mut_tombstone_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Service>(
                     "Service",
                     fields,
@@ -2560,6 +3433,10 @@ impl ::protobuf::Clear for Service {
         self.clear_pkg();
         self.clear_cfg();
         self.clear_sys();
+        self.clear_health_restarts();
+        self.clear_last_health_restart();
+        self.clear_health();
+        self.clear_tombstone();
         self.unknown_fields.clear();
     }
 }
@@ -2924,6 +3801,7 @@ pub struct ServiceFile {
     encrypted: ::std::option::Option<bool>,
     filename: ::protobuf::SingularField<::std::string::String>,
     body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    tombstone: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -3132,6 +4010,33 @@ impl ServiceFile {
     fn mut_body_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.body
     }
+
+    // optional bool tombstone = 6;
+
+    pub fn clear_tombstone(&mut self) {
+        self.tombstone = ::std::option::Option::None;
+    }
+
+    pub fn has_tombstone(&self) -> bool {
+        self.tombstone.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tombstone(&mut self, v: bool) {
+        self.tombstone = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_tombstone(&self) -> bool {
+        self.tombstone.unwrap_or(false)
+    }
+
+    fn get_tombstone_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.tombstone
+    }
+
+    fn mut_tombstone_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.tombstone
+    }
 }
 
 impl ::protobuf::Message for ServiceFile {
@@ -3166,6 +4071,13 @@ impl ::protobuf::Message for ServiceFile {
                 5 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body)?;
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.tombstone = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3193,6 +4105,9 @@ impl ::protobuf::Message for ServiceFile {
         if let Some(ref v) = self.body.as_ref() {
             my_size += ::protobuf::rt::bytes_size(5, &v);
         }
+        if let Some(v) = self.tombstone {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3214,6 +4129,9 @@ impl ::protobuf::Message for ServiceFile {
         if let Some(ref v) = self.body.as_ref() {
             os.write_bytes(5, &v)?;
         }
+        if let Some(v) = self.tombstone {
+            os.write_bool(6, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3283,6 +4201,11 @@ impl ::protobuf::MessageStatic for ServiceFile {
                     ServiceFile::get_body_for_reflect,
                     ServiceFile::mut_body_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "tombstone",
+                    ServiceFile::get_tombstone_for_reflect,
+                    ServiceFile::mut_tombstone_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ServiceFile>(
                     "ServiceFile",
                     fields,
@@ -3300,6 +4223,7 @@ impl ::protobuf::Clear for ServiceFile {
         self.clear_encrypted();
         self.clear_filename();
         self.clear_body();
+        self.clear_tombstone();
         self.unknown_fields.clear();
     }
 }
@@ -3733,17 +4657,208 @@ impl ::protobuf::MessageStatic for SysInfo {
                     SysInfo::mut_gossip_port_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "http_gateway_ip",
-                    SysInfo::get_http_gateway_ip_for_reflect,
-                    SysInfo::mut_http_gateway_ip_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "http_gateway_port",
-                    SysInfo::get_http_gateway_port_for_reflect,
-                    SysInfo::mut_http_gateway_port_for_reflect,
+                    "http_gateway_ip",
+                    SysInfo::get_http_gateway_ip_for_reflect,
+                    SysInfo::mut_http_gateway_ip_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "http_gateway_port",
+                    SysInfo::get_http_gateway_port_for_reflect,
+                    SysInfo::mut_http_gateway_port_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SysInfo>(
+                    "SysInfo",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SysInfo {
+    fn clear(&mut self) {
+        self.clear_ip();
+        self.clear_hostname();
+        self.clear_gossip_ip();
+        self.clear_gossip_port();
+        self.clear_http_gateway_ip();
+        self.clear_http_gateway_port();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SysInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SysInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Departure {
+    // message fields
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Departure {}
+
+impl Departure {
+    pub fn new() -> Departure {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Departure {
+        static mut instance: ::protobuf::lazy::Lazy<Departure> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Departure,
+        };
+        unsafe {
+            instance.get(Departure::new)
+        }
+    }
+
+    // optional string member_id = 1;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        }
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.member_id
+    }
+
+    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.member_id
+    }
+}
+
+impl ::protobuf::Message for Departure {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.member_id.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.member_id.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Departure {
+    fn new() -> Departure {
+        Departure::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Departure>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "member_id",
+                    Departure::get_member_id_for_reflect,
+                    Departure::mut_member_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SysInfo>(
-                    "SysInfo",
+                ::protobuf::reflect::MessageDescriptor::new::<Departure>(
+                    "Departure",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3752,103 +4867,199 @@ impl ::protobuf::MessageStatic for SysInfo {
     }
 }
 
-impl ::protobuf::Clear for SysInfo {
+impl ::protobuf::Clear for Departure {
     fn clear(&mut self) {
-        self.clear_ip();
-        self.clear_hostname();
-        self.clear_gossip_ip();
-        self.clear_gossip_port();
-        self.clear_http_gateway_ip();
-        self.clear_http_gateway_port();
+        self.clear_member_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SysInfo {
+impl ::std::fmt::Debug for Departure {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SysInfo {
+impl ::protobuf::reflect::ProtobufValue for Departure {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Departure {
+pub struct RingKey {
     // message fields
-    member_id: ::protobuf::SingularField<::std::string::String>,
+    name_with_rev: ::protobuf::SingularField<::std::string::String>,
+    secret: ::protobuf::SingularField<::std::string::String>,
+    activate_at: ::std::option::Option<u64>,
+    retire_at: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Departure {}
+unsafe impl ::std::marker::Sync for RingKey {}
 
-impl Departure {
-    pub fn new() -> Departure {
+impl RingKey {
+    pub fn new() -> RingKey {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Departure {
-        static mut instance: ::protobuf::lazy::Lazy<Departure> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static RingKey {
+        static mut instance: ::protobuf::lazy::Lazy<RingKey> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Departure,
+            ptr: 0 as *const RingKey,
         };
         unsafe {
-            instance.get(Departure::new)
+            instance.get(RingKey::new)
         }
     }
 
-    // optional string member_id = 1;
+    // optional string name_with_rev = 1;
 
-    pub fn clear_member_id(&mut self) {
-        self.member_id.clear();
+    pub fn clear_name_with_rev(&mut self) {
+        self.name_with_rev.clear();
     }
 
-    pub fn has_member_id(&self) -> bool {
-        self.member_id.is_some()
+    pub fn has_name_with_rev(&self) -> bool {
+        self.name_with_rev.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_member_id(&mut self, v: ::std::string::String) {
-        self.member_id = ::protobuf::SingularField::some(v);
+    pub fn set_name_with_rev(&mut self, v: ::std::string::String) {
+        self.name_with_rev = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
-        if self.member_id.is_none() {
-            self.member_id.set_default();
+    pub fn mut_name_with_rev(&mut self) -> &mut ::std::string::String {
+        if self.name_with_rev.is_none() {
+            self.name_with_rev.set_default();
         }
-        self.member_id.as_mut().unwrap()
+        self.name_with_rev.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_member_id(&mut self) -> ::std::string::String {
-        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_name_with_rev(&mut self) -> ::std::string::String {
+        self.name_with_rev.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_member_id(&self) -> &str {
-        match self.member_id.as_ref() {
+    pub fn get_name_with_rev(&self) -> &str {
+        match self.name_with_rev.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_member_id_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.member_id
+    fn get_name_with_rev_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name_with_rev
     }
 
-    fn mut_member_id_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.member_id
+    fn mut_name_with_rev_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name_with_rev
+    }
+
+    // optional string secret = 2;
+
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    pub fn has_secret(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        if self.secret.is_none() {
+            self.secret.set_default();
+        }
+        self.secret.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        self.secret.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_secret(&self) -> &str {
+        match self.secret.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_secret_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.secret
+    }
+
+    fn mut_secret_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.secret
+    }
+
+    // optional uint64 activate_at = 3;
+
+    pub fn clear_activate_at(&mut self) {
+        self.activate_at = ::std::option::Option::None;
+    }
+
+    pub fn has_activate_at(&self) -> bool {
+        self.activate_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_activate_at(&mut self, v: u64) {
+        self.activate_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_activate_at(&self) -> u64 {
+        self.activate_at.unwrap_or(0)
+    }
+
+    fn get_activate_at_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.activate_at
+    }
+
+    fn mut_activate_at_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.activate_at
+    }
+
+    // optional uint64 retire_at = 4;
+
+    pub fn clear_retire_at(&mut self) {
+        self.retire_at = ::std::option::Option::None;
+    }
+
+    pub fn has_retire_at(&self) -> bool {
+        self.retire_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retire_at(&mut self, v: u64) {
+        self.retire_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_retire_at(&self) -> u64 {
+        self.retire_at.unwrap_or(0)
+    }
+
+    fn get_retire_at_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.retire_at
+    }
+
+    fn mut_retire_at_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.retire_at
     }
 }
 
-impl ::protobuf::Message for Departure {
+impl ::protobuf::Message for RingKey {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3858,7 +5069,24 @@ impl ::protobuf::Message for Departure {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id)?;
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name_with_rev)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.secret)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.activate_at = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.retire_at = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3872,18 +5100,36 @@ impl ::protobuf::Message for Departure {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.member_id.as_ref() {
+        if let Some(ref v) = self.name_with_rev.as_ref() {
             my_size += ::protobuf::rt::string_size(1, &v);
         }
+        if let Some(ref v) = self.secret.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.activate_at {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.retire_at {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.member_id.as_ref() {
+        if let Some(ref v) = self.name_with_rev.as_ref() {
             os.write_string(1, &v)?;
         }
+        if let Some(ref v) = self.secret.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.activate_at {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.retire_at {
+            os.write_uint64(4, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3915,12 +5161,12 @@ impl ::protobuf::Message for Departure {
     }
 }
 
-impl ::protobuf::MessageStatic for Departure {
-    fn new() -> Departure {
-        Departure::new()
+impl ::protobuf::MessageStatic for RingKey {
+    fn new() -> RingKey {
+        RingKey::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Departure>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<RingKey>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3929,12 +5175,27 @@ impl ::protobuf::MessageStatic for Departure {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "member_id",
-                    Departure::get_member_id_for_reflect,
-                    Departure::mut_member_id_for_reflect,
+                    "name_with_rev",
+                    RingKey::get_name_with_rev_for_reflect,
+                    RingKey::mut_name_with_rev_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Departure>(
-                    "Departure",
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "secret",
+                    RingKey::get_secret_for_reflect,
+                    RingKey::mut_secret_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "activate_at",
+                    RingKey::get_activate_at_for_reflect,
+                    RingKey::mut_activate_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "retire_at",
+                    RingKey::get_retire_at_for_reflect,
+                    RingKey::mut_retire_at_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RingKey>(
+                    "RingKey",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3943,20 +5204,23 @@ impl ::protobuf::MessageStatic for Departure {
     }
 }
 
-impl ::protobuf::Clear for Departure {
+impl ::protobuf::Clear for RingKey {
     fn clear(&mut self) {
-        self.clear_member_id();
+        self.clear_name_with_rev();
+        self.clear_secret();
+        self.clear_activate_at();
+        self.clear_retire_at();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Departure {
+impl ::std::fmt::Debug for RingKey {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Departure {
+impl ::protobuf::reflect::ProtobufValue for RingKey {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -4513,6 +5777,7 @@ pub enum Rumor_oneof_payload {
     service_file(ServiceFile),
     election(Election),
     departure(Departure),
+    ring_key(RingKey),
 }
 
 impl Rumor {
@@ -4927,6 +6192,55 @@ impl Rumor {
             _ => Departure::default_instance(),
         }
     }
+
+    // optional .RingKey ring_key = 10;
+
+    pub fn clear_ring_key(&mut self) {
+        self.payload = ::std::option::Option::None;
+    }
+
+    pub fn has_ring_key(&self) -> bool {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_key(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ring_key(&mut self, v: RingKey) {
+        self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_key(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ring_key(&mut self) -> &mut RingKey {
+        if let ::std::option::Option::Some(Rumor_oneof_payload::ring_key(_)) = self.payload {
+        } else {
+            self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_key(RingKey::new()));
+        }
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_key(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ring_key(&mut self) -> RingKey {
+        if self.has_ring_key() {
+            match self.payload.take() {
+                ::std::option::Option::Some(Rumor_oneof_payload::ring_key(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RingKey::new()
+        }
+    }
+
+    pub fn get_ring_key(&self) -> &RingKey {
+        match self.payload {
+            ::std::option::Option::Some(Rumor_oneof_payload::ring_key(ref v)) => v,
+            _ => RingKey::default_instance(),
+        }
+    }
 }
 
 impl ::protobuf::Message for Rumor {
@@ -4964,6 +6278,11 @@ impl ::protobuf::Message for Rumor {
                 return false;
             }
         }
+        if let Some(Rumor_oneof_payload::ring_key(ref v)) = self.payload {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -5020,6 +6339,12 @@ impl ::protobuf::Message for Rumor {
                     }
                     self.payload = ::std::option::Option::Some(Rumor_oneof_payload::departure(is.read_message()?));
                 },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.payload = ::std::option::Option::Some(Rumor_oneof_payload::ring_key(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5067,6 +6392,10 @@ impl ::protobuf::Message for Rumor {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &Rumor_oneof_payload::ring_key(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -5116,6 +6445,11 @@ impl ::protobuf::Message for Rumor {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &Rumor_oneof_payload::ring_key(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -5207,6 +6541,11 @@ impl ::protobuf::MessageStatic for Rumor {
                     Rumor::has_departure,
                     Rumor::get_departure,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, RingKey>(
+                    "ring_key",
+                    Rumor::has_ring_key,
+                    Rumor::get_ring_key,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Rumor>(
                     "Rumor",
                     fields,
@@ -5228,6 +6567,7 @@ impl ::protobuf::Clear for Rumor {
         self.clear_service_file();
         self.clear_election();
         self.clear_departure();
+        self.clear_ring_key();
         self.unknown_fields.clear();
     }
 }
@@ -5255,6 +6595,7 @@ pub enum Rumor_Type {
     Fake2 = 7,
     ElectionUpdate = 8,
     Departure = 9,
+    RingKey = 10,
 }
 
 impl ::protobuf::ProtobufEnum for Rumor_Type {
@@ -5273,6 +6614,7 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             7 => ::std::option::Option::Some(Rumor_Type::Fake2),
             8 => ::std::option::Option::Some(Rumor_Type::ElectionUpdate),
             9 => ::std::option::Option::Some(Rumor_Type::Departure),
+            10 => ::std::option::Option::Some(Rumor_Type::RingKey),
             _ => ::std::option::Option::None
         }
     }
@@ -5288,6 +6630,7 @@ impl ::protobuf::ProtobufEnum for Rumor_Type {
             Rumor_Type::Fake2,
             Rumor_Type::ElectionUpdate,
             Rumor_Type::Departure,
+            Rumor_Type::RingKey,
         ];
         values
     }
@@ -5320,6 +6663,7 @@ pub struct Wire {
     encrypted: ::std::option::Option<bool>,
     nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    key_revision: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -5457,6 +6801,50 @@ impl Wire {
     fn mut_payload_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.payload
     }
+
+    // optional string key_revision = 4;
+
+    pub fn clear_key_revision(&mut self) {
+        self.key_revision.clear();
+    }
+
+    pub fn has_key_revision(&self) -> bool {
+        self.key_revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_revision(&mut self, v: ::std::string::String) {
+        self.key_revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_revision(&mut self) -> &mut ::std::string::String {
+        if self.key_revision.is_none() {
+            self.key_revision.set_default();
+        }
+        self.key_revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_revision(&mut self) -> ::std::string::String {
+        self.key_revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key_revision(&self) -> &str {
+        match self.key_revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_key_revision_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.key_revision
+    }
+
+    fn mut_key_revision_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.key_revision
+    }
 }
 
 impl ::protobuf::Message for Wire {
@@ -5481,6 +6869,9 @@ impl ::protobuf::Message for Wire {
                 3 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key_revision)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5502,6 +6893,9 @@ impl ::protobuf::Message for Wire {
         if let Some(ref v) = self.payload.as_ref() {
             my_size += ::protobuf::rt::bytes_size(3, &v);
         }
+        if let Some(ref v) = self.key_revision.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -5517,6 +6911,9 @@ impl ::protobuf::Message for Wire {
         if let Some(ref v) = self.payload.as_ref() {
             os.write_bytes(3, &v)?;
         }
+        if let Some(ref v) = self.key_revision.as_ref() {
+            os.write_string(4, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5576,6 +6973,11 @@ impl ::protobuf::MessageStatic for Wire {
                     Wire::get_payload_for_reflect,
                     Wire::mut_payload_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key_revision",
+                    Wire::get_key_revision_for_reflect,
+                    Wire::mut_key_revision_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Wire>(
                     "Wire",
                     fields,
@@ -5591,6 +6993,7 @@ impl ::protobuf::Clear for Wire {
         self.clear_encrypted();
         self.clear_nonce();
         self.clear_payload();
+        self.clear_key_revision();
         self.unknown_fields.clear();
     }
 }
@@ -5608,340 +7011,431 @@ impl ::protobuf::reflect::ProtobufValue for Wire {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x14protocols/swim.proto\"\xdc\x01\n\x06Member\x12\x0e\n\x02id\x18\x01\
+    \n\x14protocols/swim.proto\"\x82\x02\n\x06Member\x12\x0e\n\x02id\x18\x01\
     \x20\x01(\tR\x02id\x12\x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\x0binc\
     arnation\x12\x18\n\x07address\x18\x03\x20\x01(\tR\x07address\x12\x1b\n\t\
     swim_port\x18\x04\x20\x01(\x05R\x08swimPort\x12\x1f\n\x0bgossip_port\x18\
     \x05\x20\x01(\x05R\ngossipPort\x12%\n\npersistent\x18\x06\x20\x01(\x08:\
     \x05falseR\npersistent\x12!\n\x08departed\x18\x07\x20\x01(\x08:\x05false\
-    R\x08departed\"K\n\x04Ping\x12\x1b\n\x04from\x18\x01\x20\x01(\x0b2\x07.M\
-    emberR\x04from\x12&\n\nforward_to\x18\x02\x20\x01(\x0b2\x07.MemberR\tfor\
-    wardTo\"J\n\x03Ack\x12\x1b\n\x04from\x18\x01\x20\x01(\x0b2\x07.MemberR\
-    \x04from\x12&\n\nforward_to\x18\x02\x20\x01(\x0b2\x07.MemberR\tforwardTo\
-    \"G\n\x07PingReq\x12\x1b\n\x04from\x18\x01\x20\x01(\x0b2\x07.MemberR\x04\
-    from\x12\x1f\n\x06target\x18\x02\x20\x01(\x0b2\x07.MemberR\x06target\"\
-    \x98\x01\n\nMembership\x12\x1f\n\x06member\x18\x01\x20\x01(\x0b2\x07.Mem\
-    berR\x06member\x12*\n\x06health\x18\x02\x20\x01(\x0e2\x12.Membership.Hea\
-    lthR\x06health\"=\n\x06Health\x12\t\n\x05ALIVE\x10\x01\x12\x0b\n\x07SUSP\
-    ECT\x10\x02\x12\r\n\tCONFIRMED\x10\x03\x12\x0c\n\x08DEPARTED\x10\x04\"\
-    \xf5\x01\n\x08Election\x12\x1b\n\tmember_id\x18\x01\x20\x01(\tR\x08membe\
-    rId\x12#\n\rservice_group\x18\x02\x20\x01(\tR\x0cserviceGroup\x12\x12\n\
-    \x04term\x18\x03\x20\x01(\x04R\x04term\x12\x20\n\x0bsuitability\x18\x04\
-    \x20\x01(\x04R\x0bsuitability\x12(\n\x06status\x18\x05\x20\x01(\x0e2\x10\
-    .Election.StatusR\x06status\x12\x14\n\x05votes\x18\x06\x20\x03(\tR\x05vo\
-    tes\"1\n\x06Status\x12\x0b\n\x07Running\x10\x01\x12\x0c\n\x08NoQuorum\
-    \x10\x02\x12\x0c\n\x08Finished\x10\x03\"\xcf\x01\n\x07Service\x12\x1b\n\
-    \tmember_id\x18\x01\x20\x01(\tR\x08memberId\x12#\n\rservice_group\x18\
-    \x02\x20\x01(\tR\x0cserviceGroup\x12\x20\n\x0bincarnation\x18\x03\x20\
-    \x01(\x04R\x0bincarnation\x12\x20\n\x0binitialized\x18\x08\x20\x01(\x08R\
-    \x0binitialized\x12\x10\n\x03pkg\x18\t\x20\x01(\tR\x03pkg\x12\x10\n\x03c\
-    fg\x18\n\x20\x01(\x0cR\x03cfg\x12\x1a\n\x03sys\x18\x0c\x20\x01(\x0b2\x08\
-    .SysInfoR\x03sys\"\x8c\x01\n\rServiceConfig\x12#\n\rservice_group\x18\
+    R\x08departed\x12$\n\x06labels\x18\x08\x20\x03(\x0b2\x0c.MemberLabelR\
+    \x06labels\"5\n\x0bMemberLabel\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03k\
+    ey\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value\"K\n\x04Ping\x12\x1b\
+    \n\x04from\x18\x01\x20\x01(\x0b2\x07.MemberR\x04from\x12&\n\nforward_to\
+    \x18\x02\x20\x01(\x0b2\x07.MemberR\tforwardTo\"J\n\x03Ack\x12\x1b\n\x04f\
+    rom\x18\x01\x20\x01(\x0b2\x07.MemberR\x04from\x12&\n\nforward_to\x18\x02\
+    \x20\x01(\x0b2\x07.MemberR\tforwardTo\"G\n\x07PingReq\x12\x1b\n\x04from\
+    \x18\x01\x20\x01(\x0b2\x07.MemberR\x04from\x12\x1f\n\x06target\x18\x02\
+    \x20\x01(\x0b2\x07.MemberR\x06target\"\xbb\x01\n\nMembership\x12\x1f\n\
+    \x06member\x18\x01\x20\x01(\x0b2\x07.MemberR\x06member\x12*\n\x06health\
+    \x18\x02\x20\x01(\x0e2\x12.Membership.HealthR\x06health\x12!\n\x0csuspec\
+    ted_by\x18\x03\x20\x03(\tR\x0bsuspectedBy\"=\n\x06Health\x12\t\n\x05ALIV\
+    E\x10\x01\x12\x0b\n\x07SUSPECT\x10\x02\x12\r\n\tCONFIRMED\x10\x03\x12\
+    \x0c\n\x08DEPARTED\x10\x04\"R\n\x11ElectionCandidate\x12\x1b\n\tmember_i\
+    d\x18\x01\x20\x01(\tR\x08memberId\x12\x20\n\x0bsuitability\x18\x02\x20\
+    \x01(\x04R\x0bsuitability\"\xc1\x02\n\x08Election\x12\x1b\n\tmember_id\
+    \x18\x01\x20\x01(\tR\x08memberId\x12#\n\rservice_group\x18\x02\x20\x01(\
+    \tR\x0cserviceGroup\x12\x12\n\x04term\x18\x03\x20\x01(\x04R\x04term\x12\
+    \x20\n\x0bsuitability\x18\x04\x20\x01(\x04R\x0bsuitability\x12(\n\x06sta\
+    tus\x18\x05\x20\x01(\x0e2\x10.Election.StatusR\x06status\x12\x14\n\x05vo\
+    tes\x18\x06\x20\x03(\tR\x05votes\x12\x16\n\x06quorum\x18\x07\x20\x01(\rR\
+    \x06quorum\x122\n\ncandidates\x18\x08\x20\x03(\x0b2\x12.ElectionCandidat\
+    eR\ncandidates\"1\n\x06Status\x12\x0b\n\x07Running\x10\x01\x12\x0c\n\x08\
+    NoQuorum\x10\x02\x12\x0c\n\x08Finished\x10\x03\"\xde\x02\n\x07Service\
+    \x12\x1b\n\tmember_id\x18\x01\x20\x01(\tR\x08memberId\x12#\n\rservice_gr\
+    oup\x18\x02\x20\x01(\tR\x0cserviceGroup\x12\x20\n\x0bincarnation\x18\x03\
+    \x20\x01(\x04R\x0bincarnation\x12\x20\n\x0binitialized\x18\x08\x20\x01(\
+    \x08R\x0binitialized\x12\x10\n\x03pkg\x18\t\x20\x01(\tR\x03pkg\x12\x10\n\
+    \x03cfg\x18\n\x20\x01(\x0cR\x03cfg\x12\x1a\n\x03sys\x18\x0c\x20\x01(\x0b\
+    2\x08.SysInfoR\x03sys\x12'\n\x0fhealth_restarts\x18\r\x20\x01(\x04R\x0eh\
+    ealthRestarts\x12.\n\x13last_health_restart\x18\x0e\x20\x01(\x03R\x11las\
+    tHealthRestart\x12\x16\n\x06health\x18\x0f\x20\x01(\x05R\x06health\x12\
+    \x1c\n\ttombstone\x18\x10\x20\x01(\x08R\ttombstone\"\x8c\x01\n\rServiceC\
+    onfig\x12#\n\rservice_group\x18\x01\x20\x01(\tR\x0cserviceGroup\x12\x20\
+    \n\x0bincarnation\x18\x02\x20\x01(\x04R\x0bincarnation\x12\x1c\n\tencryp\
+    ted\x18\x03\x20\x01(\x08R\tencrypted\x12\x16\n\x06config\x18\x04\x20\x01\
+    (\x0cR\x06config\"\xc0\x01\n\x0bServiceFile\x12#\n\rservice_group\x18\
     \x01\x20\x01(\tR\x0cserviceGroup\x12\x20\n\x0bincarnation\x18\x02\x20\
     \x01(\x04R\x0bincarnation\x12\x1c\n\tencrypted\x18\x03\x20\x01(\x08R\ten\
-    crypted\x12\x16\n\x06config\x18\x04\x20\x01(\x0cR\x06config\"\xa2\x01\n\
-    \x0bServiceFile\x12#\n\rservice_group\x18\x01\x20\x01(\tR\x0cserviceGrou\
-    p\x12\x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\x0bincarnation\x12\x1c\
-    \n\tencrypted\x18\x03\x20\x01(\x08R\tencrypted\x12\x1a\n\x08filename\x18\
-    \x04\x20\x01(\tR\x08filename\x12\x12\n\x04body\x18\x05\x20\x01(\x0cR\x04\
-    body\"\xf3\x01\n\x07SysInfo\x12\x19\n\x02ip\x18\x01\x20\x01(\t:\t127.0.0\
-    .1R\x02ip\x12%\n\x08hostname\x18\x02\x20\x01(\t:\tlocalhostR\x08hostname\
-    \x12&\n\tgossip_ip\x18\x03\x20\x01(\t:\t127.0.0.1R\x08gossipIp\x12\x1f\n\
-    \x0bgossip_port\x18\x04\x20\x01(\rR\ngossipPort\x121\n\x0fhttp_gateway_i\
-    p\x18\x05\x20\x01(\t:\t127.0.0.1R\rhttpGatewayIp\x12*\n\x11http_gateway_\
-    port\x18\x06\x20\x01(\rR\x0fhttpGatewayPort\"(\n\tDeparture\x12\x1b\n\tm\
-    ember_id\x18\x01\x20\x01(\tR\x08memberId\"\xe3\x01\n\x04Swim\x12\x1e\n\
-    \x04type\x18\x01\x20\x02(\x0e2\n.Swim.TypeR\x04type\x12\x1b\n\x04ping\
-    \x18\x02\x20\x01(\x0b2\x05.PingH\0R\x04ping\x12\x18\n\x03ack\x18\x03\x20\
-    \x01(\x0b2\x04.AckH\0R\x03ack\x12$\n\x07pingreq\x18\x04\x20\x01(\x0b2\
-    \x08.PingReqH\0R\x07pingreq\x12+\n\nmembership\x18\x05\x20\x03(\x0b2\x0b\
-    .MembershipR\nmembership\"&\n\x04Type\x12\x08\n\x04PING\x10\x01\x12\x07\
-    \n\x03ACK\x10\x02\x12\x0b\n\x07PINGREQ\x10\x03B\t\n\x07payload\"\xf8\x03\
-    \n\x05Rumor\x12\x1f\n\x04type\x18\x01\x20\x02(\x0e2\x0b.Rumor.TypeR\x04t\
-    ype\x12\x10\n\x03tag\x18\x02\x20\x03(\tR\x03tag\x12\x17\n\x07from_id\x18\
-    \x03\x20\x01(\tR\x06fromId\x12%\n\x06member\x18\x04\x20\x01(\x0b2\x0b.Me\
-    mbershipH\0R\x06member\x12$\n\x07service\x18\x05\x20\x01(\x0b2\x08.Servi\
-    ceH\0R\x07service\x127\n\x0eservice_config\x18\x06\x20\x01(\x0b2\x0e.Ser\
-    viceConfigH\0R\rserviceConfig\x121\n\x0cservice_file\x18\x07\x20\x01(\
-    \x0b2\x0c.ServiceFileH\0R\x0bserviceFile\x12'\n\x08election\x18\x08\x20\
-    \x01(\x0b2\t.ElectionH\0R\x08election\x12*\n\tdeparture\x18\t\x20\x01(\
-    \x0b2\n.DepartureH\0R\tdeparture\"\x89\x01\n\x04Type\x12\n\n\x06Member\
-    \x10\x01\x12\x0b\n\x07Service\x10\x02\x12\x0c\n\x08Election\x10\x03\x12\
-    \x11\n\rServiceConfig\x10\x04\x12\x0f\n\x0bServiceFile\x10\x05\x12\x08\n\
-    \x04Fake\x10\x06\x12\t\n\x05Fake2\x10\x07\x12\x12\n\x0eElectionUpdate\
-    \x10\x08\x12\r\n\tDeparture\x10\tB\t\n\x07payload\"T\n\x04Wire\x12\x1c\n\
-    \tencrypted\x18\x01\x20\x01(\x08R\tencrypted\x12\x14\n\x05nonce\x18\x02\
-    \x20\x01(\x0cR\x05nonce\x12\x18\n\x07payload\x18\x03\x20\x01(\x0cR\x07pa\
-    yloadJ\xc7,\n\x06\x12\x04\0\0~\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
-    \x02\x04\0\x12\x04\x02\0\n\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x0e\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03\x03\x02\x19\n\x0c\n\x05\x04\0\x02\0\x04\
-    \x12\x03\x03\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x0b\x11\n\x0c\
-    \n\x05\x04\0\x02\0\x01\x12\x03\x03\x12\x14\n\x0c\n\x05\x04\0\x02\0\x03\
-    \x12\x03\x03\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x02\"\n\x0c\n\
-    \x05\x04\0\x02\x01\x04\x12\x03\x04\x02\n\n\x0c\n\x05\x04\0\x02\x01\x05\
-    \x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x12\x1d\n\
-    \x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x20!\n\x0b\n\x04\x04\0\x02\x02\
-    \x12\x03\x05\x02\x1e\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x03\x05\x02\n\n\
-    \x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\
-    \x02\x01\x12\x03\x05\x12\x19\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\
-    \x1c\x1d\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x02\x1f\n\x0c\n\x05\x04\0\
-    \x02\x03\x04\x12\x03\x06\x02\n\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\
-    \x0b\x10\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\x11\x1a\n\x0c\n\x05\
-    \x04\0\x02\x03\x03\x12\x03\x06\x1d\x1e\n\x0b\n\x04\x04\0\x02\x04\x12\x03\
-    \x07\x02!\n\x0c\n\x05\x04\0\x02\x04\x04\x12\x03\x07\x02\n\n\x0c\n\x05\
-    \x04\0\x02\x04\x05\x12\x03\x07\x0b\x10\n\x0c\n\x05\x04\0\x02\x04\x01\x12\
-    \x03\x07\x11\x1c\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x1f\x20\n\x0b\
-    \n\x04\x04\0\x02\x05\x12\x03\x08\x021\n\x0c\n\x05\x04\0\x02\x05\x04\x12\
-    \x03\x08\x02\n\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x0b\x0f\n\x0c\n\
-    \x05\x04\0\x02\x05\x01\x12\x03\x08\x10\x1a\n\x0c\n\x05\x04\0\x02\x05\x03\
-    \x12\x03\x08\x1d\x1e\n\x0c\n\x05\x04\0\x02\x05\x08\x12\x03\x08\x1f0\n\
-    \x0c\n\x05\x04\0\x02\x05\x07\x12\x03\x08*/\n\x0b\n\x04\x04\0\x02\x06\x12\
-    \x03\t\x02/\n\x0c\n\x05\x04\0\x02\x06\x04\x12\x03\t\x02\n\n\x0c\n\x05\
-    \x04\0\x02\x06\x05\x12\x03\t\x0b\x0f\n\x0c\n\x05\x04\0\x02\x06\x01\x12\
-    \x03\t\x10\x18\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t\x1b\x1c\n\x0c\n\
-    \x05\x04\0\x02\x06\x08\x12\x03\t\x1d.\n\x0c\n\x05\x04\0\x02\x06\x07\x12\
-    \x03\t(-\n\n\n\x02\x04\x01\x12\x04\x0c\0\x0f\x01\n\n\n\x03\x04\x01\x01\
-    \x12\x03\x0c\x08\x0c\n\x0b\n\x04\x04\x01\x02\0\x12\x03\r\x02\x1b\n\x0c\n\
-    \x05\x04\x01\x02\0\x04\x12\x03\r\x02\n\n\x0c\n\x05\x04\x01\x02\0\x06\x12\
-    \x03\r\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\r\x12\x16\n\x0c\n\
-    \x05\x04\x01\x02\0\x03\x12\x03\r\x19\x1a\n\x0b\n\x04\x04\x01\x02\x01\x12\
-    \x03\x0e\x02!\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\x0e\x02\n\n\x0c\n\
-    \x05\x04\x01\x02\x01\x06\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03\x0e\x12\x1c\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0e\x1f\
-    \x20\n\n\n\x02\x04\x02\x12\x04\x11\0\x14\x01\n\n\n\x03\x04\x02\x01\x12\
-    \x03\x11\x08\x0b\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x12\x02\x1b\n\x0c\n\
-    \x05\x04\x02\x02\0\x04\x12\x03\x12\x02\n\n\x0c\n\x05\x04\x02\x02\0\x06\
-    \x12\x03\x12\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x12\x12\x16\n\
-    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x12\x19\x1a\n\x0b\n\x04\x04\x02\x02\
-    \x01\x12\x03\x13\x02!\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\x13\x02\n\
-    \n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x13\x0b\x11\n\x0c\n\x05\x04\x02\
-    \x02\x01\x01\x12\x03\x13\x12\x1c\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\
-    \x13\x1f\x20\n\n\n\x02\x04\x03\x12\x04\x16\0\x19\x01\n\n\n\x03\x04\x03\
-    \x01\x12\x03\x16\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x17\x02\x1b\n\
-    \x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x17\x02\n\n\x0c\n\x05\x04\x03\x02\0\
-    \x06\x12\x03\x17\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x17\x12\
-    \x16\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x17\x19\x1a\n\x0b\n\x04\x04\
-    \x03\x02\x01\x12\x03\x18\x02\x1d\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03\
-    \x18\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x06\x12\x03\x18\x0b\x11\n\x0c\n\
-    \x05\x04\x03\x02\x01\x01\x12\x03\x18\x12\x18\n\x0c\n\x05\x04\x03\x02\x01\
-    \x03\x12\x03\x18\x1b\x1c\n\n\n\x02\x04\x04\x12\x04\x1b\0\x20\x01\n\n\n\
-    \x03\x04\x04\x01\x12\x03\x1b\x08\x12\n\x0b\n\x04\x04\x04\x04\0\x12\x03\
-    \x1c\x02F\n\x0c\n\x05\x04\x04\x04\0\x01\x12\x03\x1c\x07\r\n\r\n\x06\x04\
-    \x04\x04\0\x02\0\x12\x03\x1c\x10\x1a\n\x0e\n\x07\x04\x04\x04\0\x02\0\x01\
-    \x12\x03\x1c\x10\x15\n\x0e\n\x07\x04\x04\x04\0\x02\0\x02\x12\x03\x1c\x18\
-    \x19\n\r\n\x06\x04\x04\x04\0\x02\x01\x12\x03\x1c\x1b'\n\x0e\n\x07\x04\
-    \x04\x04\0\x02\x01\x01\x12\x03\x1c\x1b\"\n\x0e\n\x07\x04\x04\x04\0\x02\
-    \x01\x02\x12\x03\x1c%&\n\r\n\x06\x04\x04\x04\0\x02\x02\x12\x03\x1c(6\n\
-    \x0e\n\x07\x04\x04\x04\0\x02\x02\x01\x12\x03\x1c(1\n\x0e\n\x07\x04\x04\
-    \x04\0\x02\x02\x02\x12\x03\x1c45\n\r\n\x06\x04\x04\x04\0\x02\x03\x12\x03\
-    \x1c7D\n\x0e\n\x07\x04\x04\x04\0\x02\x03\x01\x12\x03\x1c7?\n\x0e\n\x07\
-    \x04\x04\x04\0\x02\x03\x02\x12\x03\x1cBC\n\x0b\n\x04\x04\x04\x02\0\x12\
-    \x03\x1e\x02\x1d\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1e\x02\n\n\x0c\n\
-    \x05\x04\x04\x02\0\x06\x12\x03\x1e\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\
-    \x12\x03\x1e\x12\x18\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1e\x1b\x1c\n\
-    \x0b\n\x04\x04\x04\x02\x01\x12\x03\x1f\x02\x1d\n\x0c\n\x05\x04\x04\x02\
-    \x01\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x04\x02\x01\x06\x12\x03\x1f\
-    \x0b\x11\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x1f\x12\x18\n\x0c\n\x05\
-    \x04\x04\x02\x01\x03\x12\x03\x1f\x1b\x1c\n\n\n\x02\x04\x05\x12\x04\"\0+\
-    \x01\n\n\n\x03\x04\x05\x01\x12\x03\"\x08\x10\n\x0b\n\x04\x04\x05\x04\0\
-    \x12\x03#\x02:\n\x0c\n\x05\x04\x05\x04\0\x01\x12\x03#\x07\r\n\r\n\x06\
-    \x04\x05\x04\0\x02\0\x12\x03#\x10\x1c\n\x0e\n\x07\x04\x05\x04\0\x02\0\
-    \x01\x12\x03#\x10\x17\n\x0e\n\x07\x04\x05\x04\0\x02\0\x02\x12\x03#\x1a\
-    \x1b\n\r\n\x06\x04\x05\x04\0\x02\x01\x12\x03#\x1d*\n\x0e\n\x07\x04\x05\
-    \x04\0\x02\x01\x01\x12\x03#\x1d%\n\x0e\n\x07\x04\x05\x04\0\x02\x01\x02\
-    \x12\x03#()\n\r\n\x06\x04\x05\x04\0\x02\x02\x12\x03#+8\n\x0e\n\x07\x04\
-    \x05\x04\0\x02\x02\x01\x12\x03#+3\n\x0e\n\x07\x04\x05\x04\0\x02\x02\x02\
-    \x12\x03#67\n\x0b\n\x04\x04\x05\x02\0\x12\x03%\x02\x20\n\x0c\n\x05\x04\
-    \x05\x02\0\x04\x12\x03%\x02\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03%\x0b\
-    \x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03%\x12\x1b\n\x0c\n\x05\x04\x05\
-    \x02\0\x03\x12\x03%\x1e\x1f\n\x0b\n\x04\x04\x05\x02\x01\x12\x03&\x02$\n\
-    \x0c\n\x05\x04\x05\x02\x01\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\x05\x02\
-    \x01\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03&\x12\
-    \x1f\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03&\"#\n\x0b\n\x04\x04\x05\x02\
-    \x02\x12\x03'\x02\x1b\n\x0c\n\x05\x04\x05\x02\x02\x04\x12\x03'\x02\n\n\
-    \x0c\n\x05\x04\x05\x02\x02\x05\x12\x03'\x0b\x11\n\x0c\n\x05\x04\x05\x02\
-    \x02\x01\x12\x03'\x12\x16\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03'\x19\
-    \x1a\n\x0b\n\x04\x04\x05\x02\x03\x12\x03(\x02\"\n\x0c\n\x05\x04\x05\x02\
-    \x03\x04\x12\x03(\x02\n\n\x0c\n\x05\x04\x05\x02\x03\x05\x12\x03(\x0b\x11\
-    \n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x03(\x12\x1d\n\x0c\n\x05\x04\x05\
-    \x02\x03\x03\x12\x03(\x20!\n\x0b\n\x04\x04\x05\x02\x04\x12\x03)\x02\x1d\
-    \n\x0c\n\x05\x04\x05\x02\x04\x04\x12\x03)\x02\n\n\x0c\n\x05\x04\x05\x02\
-    \x04\x06\x12\x03)\x0b\x11\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03)\x12\
-    \x18\n\x0c\n\x05\x04\x05\x02\x04\x03\x12\x03)\x1b\x1c\n\x0b\n\x04\x04\
-    \x05\x02\x05\x12\x03*\x02\x1c\n\x0c\n\x05\x04\x05\x02\x05\x04\x12\x03*\
-    \x02\n\n\x0c\n\x05\x04\x05\x02\x05\x05\x12\x03*\x0b\x11\n\x0c\n\x05\x04\
-    \x05\x02\x05\x01\x12\x03*\x12\x17\n\x0c\n\x05\x04\x05\x02\x05\x03\x12\
-    \x03*\x1a\x1b\n\n\n\x02\x04\x06\x12\x04-\05\x01\n\n\n\x03\x04\x06\x01\
-    \x12\x03-\x08\x0f\n\x0b\n\x04\x04\x06\x02\0\x12\x03.\x02\x20\n\x0c\n\x05\
-    \x04\x06\x02\0\x04\x12\x03.\x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03.\
-    \x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03.\x12\x1b\n\x0c\n\x05\x04\
-    \x06\x02\0\x03\x12\x03.\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x01\x12\x03/\x02\
-    $\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\x03/\x02\n\n\x0c\n\x05\x04\x06\x02\
-    \x01\x05\x12\x03/\x0b\x11\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03/\x12\
-    \x1f\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03/\"#\n\x0b\n\x04\x04\x06\x02\
-    \x02\x12\x030\x02\"\n\x0c\n\x05\x04\x06\x02\x02\x04\x12\x030\x02\n\n\x0c\
-    \n\x05\x04\x06\x02\x02\x05\x12\x030\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\
-    \x01\x12\x030\x12\x1d\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x030\x20!\n\
-    \x0b\n\x04\x04\x06\x02\x03\x12\x031\x02\x20\n\x0c\n\x05\x04\x06\x02\x03\
-    \x04\x12\x031\x02\n\n\x0c\n\x05\x04\x06\x02\x03\x05\x12\x031\x0b\x0f\n\
-    \x0c\n\x05\x04\x06\x02\x03\x01\x12\x031\x10\x1b\n\x0c\n\x05\x04\x06\x02\
-    \x03\x03\x12\x031\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x04\x12\x032\x02\x1a\n\
-    \x0c\n\x05\x04\x06\x02\x04\x04\x12\x032\x02\n\n\x0c\n\x05\x04\x06\x02\
-    \x04\x05\x12\x032\x0b\x11\n\x0c\n\x05\x04\x06\x02\x04\x01\x12\x032\x12\
-    \x15\n\x0c\n\x05\x04\x06\x02\x04\x03\x12\x032\x18\x19\n\x0b\n\x04\x04\
-    \x06\x02\x05\x12\x033\x02\x1a\n\x0c\n\x05\x04\x06\x02\x05\x04\x12\x033\
-    \x02\n\n\x0c\n\x05\x04\x06\x02\x05\x05\x12\x033\x0b\x10\n\x0c\n\x05\x04\
-    \x06\x02\x05\x01\x12\x033\x11\x14\n\x0c\n\x05\x04\x06\x02\x05\x03\x12\
-    \x033\x17\x19\n\x0b\n\x04\x04\x06\x02\x06\x12\x034\x02\x1c\n\x0c\n\x05\
-    \x04\x06\x02\x06\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x06\x02\x06\x06\x12\
-    \x034\x0b\x12\n\x0c\n\x05\x04\x06\x02\x06\x01\x12\x034\x13\x16\n\x0c\n\
-    \x05\x04\x06\x02\x06\x03\x12\x034\x19\x1b\n\n\n\x02\x04\x07\x12\x047\0<\
-    \x01\n\n\n\x03\x04\x07\x01\x12\x037\x08\x15\n\x0b\n\x04\x04\x07\x02\0\
-    \x12\x038\x02$\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x038\x02\n\n\x0c\n\x05\
-    \x04\x07\x02\0\x05\x12\x038\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\
-    \x038\x12\x1f\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x038\"#\n\x0b\n\x04\x04\
-    \x07\x02\x01\x12\x039\x02\"\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x039\x02\
-    \n\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x039\x0b\x11\n\x0c\n\x05\x04\x07\
-    \x02\x01\x01\x12\x039\x12\x1d\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x039\
-    \x20!\n\x0b\n\x04\x04\x07\x02\x02\x12\x03:\x02\x1e\n\x0c\n\x05\x04\x07\
-    \x02\x02\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03:\x0b\
-    \x0f\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03:\x10\x19\n\x0c\n\x05\x04\
-    \x07\x02\x02\x03\x12\x03:\x1c\x1d\n\x0b\n\x04\x04\x07\x02\x03\x12\x03;\
-    \x02\x1c\n\x0c\n\x05\x04\x07\x02\x03\x04\x12\x03;\x02\n\n\x0c\n\x05\x04\
-    \x07\x02\x03\x05\x12\x03;\x0b\x10\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\
-    \x03;\x11\x17\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03;\x1a\x1b\n\n\n\x02\
-    \x04\x08\x12\x04>\0D\x01\n\n\n\x03\x04\x08\x01\x12\x03>\x08\x13\n\x0b\n\
-    \x04\x04\x08\x02\0\x12\x03?\x02$\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x03?\
-    \x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\
-    \x08\x02\0\x01\x12\x03?\x12\x1f\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03?\"\
-    #\n\x0b\n\x04\x04\x08\x02\x01\x12\x03@\x02\"\n\x0c\n\x05\x04\x08\x02\x01\
-    \x04\x12\x03@\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03@\x0b\x11\n\
-    \x0c\n\x05\x04\x08\x02\x01\x01\x12\x03@\x12\x1d\n\x0c\n\x05\x04\x08\x02\
-    \x01\x03\x12\x03@\x20!\n\x0b\n\x04\x04\x08\x02\x02\x12\x03A\x02\x1e\n\
-    \x0c\n\x05\x04\x08\x02\x02\x04\x12\x03A\x02\n\n\x0c\n\x05\x04\x08\x02\
-    \x02\x05\x12\x03A\x0b\x0f\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03A\x10\
-    \x19\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x03A\x1c\x1d\n\x0b\n\x04\x04\
-    \x08\x02\x03\x12\x03B\x02\x1f\n\x0c\n\x05\x04\x08\x02\x03\x04\x12\x03B\
-    \x02\n\n\x0c\n\x05\x04\x08\x02\x03\x05\x12\x03B\x0b\x11\n\x0c\n\x05\x04\
-    \x08\x02\x03\x01\x12\x03B\x12\x1a\n\x0c\n\x05\x04\x08\x02\x03\x03\x12\
-    \x03B\x1d\x1e\n\x0b\n\x04\x04\x08\x02\x04\x12\x03C\x02\x1a\n\x0c\n\x05\
-    \x04\x08\x02\x04\x04\x12\x03C\x02\n\n\x0c\n\x05\x04\x08\x02\x04\x05\x12\
-    \x03C\x0b\x10\n\x0c\n\x05\x04\x08\x02\x04\x01\x12\x03C\x11\x15\n\x0c\n\
-    \x05\x04\x08\x02\x04\x03\x12\x03C\x18\x19\n\n\n\x02\x04\t\x12\x04F\0M\
-    \x01\n\n\n\x03\x04\t\x01\x12\x03F\x08\x0f\n\x0b\n\x04\x04\t\x02\0\x12\
-    \x03G\x021\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03G\x02\n\n\x0c\n\x05\x04\t\
-    \x02\0\x05\x12\x03G\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03G\x12\x14\
-    \n\x0c\n\x05\x04\t\x02\0\x03\x12\x03G\x17\x18\n\x0c\n\x05\x04\t\x02\0\
-    \x08\x12\x03G\x190\n\x0c\n\x05\x04\t\x02\0\x07\x12\x03G$/\n\x0b\n\x04\
-    \x04\t\x02\x01\x12\x03H\x027\n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03H\x02\
-    \n\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03H\x0b\x11\n\x0c\n\x05\x04\t\x02\
-    \x01\x01\x12\x03H\x12\x1a\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03H\x1d\x1e\
-    \n\x0c\n\x05\x04\t\x02\x01\x08\x12\x03H\x1f6\n\x0c\n\x05\x04\t\x02\x01\
-    \x07\x12\x03H*5\n\x0b\n\x04\x04\t\x02\x02\x12\x03I\x028\n\x0c\n\x05\x04\
-    \t\x02\x02\x04\x12\x03I\x02\n\n\x0c\n\x05\x04\t\x02\x02\x05\x12\x03I\x0b\
-    \x11\n\x0c\n\x05\x04\t\x02\x02\x01\x12\x03I\x12\x1b\n\x0c\n\x05\x04\t\
-    \x02\x02\x03\x12\x03I\x1e\x1f\n\x0c\n\x05\x04\t\x02\x02\x08\x12\x03I\x20\
-    7\n\x0c\n\x05\x04\t\x02\x02\x07\x12\x03I+6\n\x0b\n\x04\x04\t\x02\x03\x12\
-    \x03J\x02\"\n\x0c\n\x05\x04\t\x02\x03\x04\x12\x03J\x02\n\n\x0c\n\x05\x04\
-    \t\x02\x03\x05\x12\x03J\x0b\x11\n\x0c\n\x05\x04\t\x02\x03\x01\x12\x03J\
-    \x12\x1d\n\x0c\n\x05\x04\t\x02\x03\x03\x12\x03J\x20!\n\x0b\n\x04\x04\t\
-    \x02\x04\x12\x03K\x02>\n\x0c\n\x05\x04\t\x02\x04\x04\x12\x03K\x02\n\n\
-    \x0c\n\x05\x04\t\x02\x04\x05\x12\x03K\x0b\x11\n\x0c\n\x05\x04\t\x02\x04\
-    \x01\x12\x03K\x12!\n\x0c\n\x05\x04\t\x02\x04\x03\x12\x03K$%\n\x0c\n\x05\
-    \x04\t\x02\x04\x08\x12\x03K&=\n\x0c\n\x05\x04\t\x02\x04\x07\x12\x03K1<\n\
-    \x0b\n\x04\x04\t\x02\x05\x12\x03L\x02(\n\x0c\n\x05\x04\t\x02\x05\x04\x12\
-    \x03L\x02\n\n\x0c\n\x05\x04\t\x02\x05\x05\x12\x03L\x0b\x11\n\x0c\n\x05\
-    \x04\t\x02\x05\x01\x12\x03L\x12#\n\x0c\n\x05\x04\t\x02\x05\x03\x12\x03L&\
-    '\n\n\n\x02\x04\n\x12\x04O\0Q\x01\n\n\n\x03\x04\n\x01\x12\x03O\x08\x11\n\
-    \x0b\n\x04\x04\n\x02\0\x12\x03P\x02\x20\n\x0c\n\x05\x04\n\x02\0\x04\x12\
-    \x03P\x02\n\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03P\x0b\x11\n\x0c\n\x05\x04\
-    \n\x02\0\x01\x12\x03P\x12\x1b\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03P\x1e\
-    \x1f\n\n\n\x02\x04\x0b\x12\x04S\0^\x01\n\n\n\x03\x04\x0b\x01\x12\x03S\
-    \x08\x0c\n\x0b\n\x04\x04\x0b\x04\0\x12\x03T\x02/\n\x0c\n\x05\x04\x0b\x04\
-    \0\x01\x12\x03T\x07\x0b\n\r\n\x06\x04\x0b\x04\0\x02\0\x12\x03T\x0e\x17\n\
-    \x0e\n\x07\x04\x0b\x04\0\x02\0\x01\x12\x03T\x0e\x12\n\x0e\n\x07\x04\x0b\
-    \x04\0\x02\0\x02\x12\x03T\x15\x16\n\r\n\x06\x04\x0b\x04\0\x02\x01\x12\
-    \x03T\x18\x20\n\x0e\n\x07\x04\x0b\x04\0\x02\x01\x01\x12\x03T\x18\x1b\n\
-    \x0e\n\x07\x04\x0b\x04\0\x02\x01\x02\x12\x03T\x1e\x1f\n\r\n\x06\x04\x0b\
-    \x04\0\x02\x02\x12\x03T!-\n\x0e\n\x07\x04\x0b\x04\0\x02\x02\x01\x12\x03T\
-    !(\n\x0e\n\x07\x04\x0b\x04\0\x02\x02\x02\x12\x03T+,\n3\n\x04\x04\x0b\x02\
-    \0\x12\x03W\x02\x19\x1a&\x20Identifies\x20which\x20field\x20is\x20filled\
-    \x20in.\n\n\x0c\n\x05\x04\x0b\x02\0\x04\x12\x03W\x02\n\n\x0c\n\x05\x04\
-    \x0b\x02\0\x06\x12\x03W\x0b\x0f\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03W\
-    \x10\x14\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03W\x17\x18\n\x0c\n\x04\x04\
-    \x0b\x08\0\x12\x04X\x02\\\x03\n\x0c\n\x05\x04\x0b\x08\0\x01\x12\x03X\x08\
-    \x0f\n\x0b\n\x04\x04\x0b\x02\x01\x12\x03Y\x04\x12\n\x0c\n\x05\x04\x0b\
-    \x02\x01\x06\x12\x03Y\x04\x08\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03Y\t\
-    \r\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03Y\x10\x11\n\x0b\n\x04\x04\x0b\
-    \x02\x02\x12\x03Z\x04\x10\n\x0c\n\x05\x04\x0b\x02\x02\x06\x12\x03Z\x04\
-    \x07\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\x03Z\x08\x0b\n\x0c\n\x05\x04\
-    \x0b\x02\x02\x03\x12\x03Z\x0e\x0f\n\x0b\n\x04\x04\x0b\x02\x03\x12\x03[\
-    \x04\x18\n\x0c\n\x05\x04\x0b\x02\x03\x06\x12\x03[\x04\x0b\n\x0c\n\x05\
-    \x04\x0b\x02\x03\x01\x12\x03[\x0c\x13\n\x0c\n\x05\x04\x0b\x02\x03\x03\
-    \x12\x03[\x16\x17\n\x0b\n\x04\x04\x0b\x02\x04\x12\x03]\x02%\n\x0c\n\x05\
-    \x04\x0b\x02\x04\x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x0b\x02\x04\x06\x12\
-    \x03]\x0b\x15\n\x0c\n\x05\x04\x0b\x02\x04\x01\x12\x03]\x16\x20\n\x0c\n\
-    \x05\x04\x0b\x02\x04\x03\x12\x03]#$\n\n\n\x02\x04\x0c\x12\x04`\0x\x01\n\
-    \n\n\x03\x04\x0c\x01\x12\x03`\x08\r\n\x0c\n\x04\x04\x0c\x04\0\x12\x04a\
-    \x02k\x03\n\x0c\n\x05\x04\x0c\x04\0\x01\x12\x03a\x07\x0b\n\r\n\x06\x04\
-    \x0c\x04\0\x02\0\x12\x03b\x04\x0f\n\x0e\n\x07\x04\x0c\x04\0\x02\0\x01\
-    \x12\x03b\x04\n\n\x0e\n\x07\x04\x0c\x04\0\x02\0\x02\x12\x03b\r\x0e\n\r\n\
-    \x06\x04\x0c\x04\0\x02\x01\x12\x03c\x04\x10\n\x0e\n\x07\x04\x0c\x04\0\
-    \x02\x01\x01\x12\x03c\x04\x0b\n\x0e\n\x07\x04\x0c\x04\0\x02\x01\x02\x12\
-    \x03c\x0e\x0f\n\r\n\x06\x04\x0c\x04\0\x02\x02\x12\x03d\x04\x11\n\x0e\n\
-    \x07\x04\x0c\x04\0\x02\x02\x01\x12\x03d\x04\x0c\n\x0e\n\x07\x04\x0c\x04\
-    \0\x02\x02\x02\x12\x03d\x0f\x10\n\r\n\x06\x04\x0c\x04\0\x02\x03\x12\x03e\
-    \x04\x16\n\x0e\n\x07\x04\x0c\x04\0\x02\x03\x01\x12\x03e\x04\x11\n\x0e\n\
-    \x07\x04\x0c\x04\0\x02\x03\x02\x12\x03e\x14\x15\n\r\n\x06\x04\x0c\x04\0\
-    \x02\x04\x12\x03f\x04\x14\n\x0e\n\x07\x04\x0c\x04\0\x02\x04\x01\x12\x03f\
-    \x04\x0f\n\x0e\n\x07\x04\x0c\x04\0\x02\x04\x02\x12\x03f\x12\x13\n\r\n\
-    \x06\x04\x0c\x04\0\x02\x05\x12\x03g\x04\r\n\x0e\n\x07\x04\x0c\x04\0\x02\
-    \x05\x01\x12\x03g\x04\x08\n\x0e\n\x07\x04\x0c\x04\0\x02\x05\x02\x12\x03g\
-    \x0b\x0c\n\r\n\x06\x04\x0c\x04\0\x02\x06\x12\x03h\x04\x0e\n\x0e\n\x07\
-    \x04\x0c\x04\0\x02\x06\x01\x12\x03h\x04\t\n\x0e\n\x07\x04\x0c\x04\0\x02\
-    \x06\x02\x12\x03h\x0c\r\n\r\n\x06\x04\x0c\x04\0\x02\x07\x12\x03i\x04\x17\
-    \n\x0e\n\x07\x04\x0c\x04\0\x02\x07\x01\x12\x03i\x04\x12\n\x0e\n\x07\x04\
-    \x0c\x04\0\x02\x07\x02\x12\x03i\x15\x16\n\r\n\x06\x04\x0c\x04\0\x02\x08\
-    \x12\x03j\x04\x12\n\x0e\n\x07\x04\x0c\x04\0\x02\x08\x01\x12\x03j\x04\r\n\
-    \x0e\n\x07\x04\x0c\x04\0\x02\x08\x02\x12\x03j\x10\x11\n\x0b\n\x04\x04\
-    \x0c\x02\0\x12\x03m\x02\x19\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03m\x02\n\
-    \n\x0c\n\x05\x04\x0c\x02\0\x06\x12\x03m\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\
-    \0\x01\x12\x03m\x10\x14\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03m\x17\x18\n\
-    \x0b\n\x04\x04\x0c\x02\x01\x12\x03n\x02\x1a\n\x0c\n\x05\x04\x0c\x02\x01\
-    \x04\x12\x03n\x02\n\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03n\x0b\x11\n\
-    \x0c\n\x05\x04\x0c\x02\x01\x01\x12\x03n\x12\x15\n\x0c\n\x05\x04\x0c\x02\
-    \x01\x03\x12\x03n\x18\x19\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03o\x02\x1e\n\
-    \x0c\n\x05\x04\x0c\x02\x02\x04\x12\x03o\x02\n\n\x0c\n\x05\x04\x0c\x02\
-    \x02\x05\x12\x03o\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03o\x12\
-    \x19\n\x0c\n\x05\x04\x0c\x02\x02\x03\x12\x03o\x1c\x1d\n\x0c\n\x04\x04\
-    \x0c\x08\0\x12\x04p\x02w\x03\n\x0c\n\x05\x04\x0c\x08\0\x01\x12\x03p\x08\
-    \x0f\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03q\x04\x1a\n\x0c\n\x05\x04\x0c\
-    \x02\x03\x06\x12\x03q\x04\x0e\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\x03q\
-    \x0f\x15\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03q\x18\x19\n\x0b\n\x04\
-    \x04\x0c\x02\x04\x12\x03r\x04\x18\n\x0c\n\x05\x04\x0c\x02\x04\x06\x12\
-    \x03r\x04\x0b\n\x0c\n\x05\x04\x0c\x02\x04\x01\x12\x03r\x0c\x13\n\x0c\n\
-    \x05\x04\x0c\x02\x04\x03\x12\x03r\x16\x17\n\x0b\n\x04\x04\x0c\x02\x05\
-    \x12\x03s\x04%\n\x0c\n\x05\x04\x0c\x02\x05\x06\x12\x03s\x04\x11\n\x0c\n\
-    \x05\x04\x0c\x02\x05\x01\x12\x03s\x12\x20\n\x0c\n\x05\x04\x0c\x02\x05\
-    \x03\x12\x03s#$\n\x0b\n\x04\x04\x0c\x02\x06\x12\x03t\x04!\n\x0c\n\x05\
-    \x04\x0c\x02\x06\x06\x12\x03t\x04\x0f\n\x0c\n\x05\x04\x0c\x02\x06\x01\
-    \x12\x03t\x10\x1c\n\x0c\n\x05\x04\x0c\x02\x06\x03\x12\x03t\x1f\x20\n\x0b\
-    \n\x04\x04\x0c\x02\x07\x12\x03u\x04\x1a\n\x0c\n\x05\x04\x0c\x02\x07\x06\
-    \x12\x03u\x04\x0c\n\x0c\n\x05\x04\x0c\x02\x07\x01\x12\x03u\r\x15\n\x0c\n\
-    \x05\x04\x0c\x02\x07\x03\x12\x03u\x18\x19\n\x0b\n\x04\x04\x0c\x02\x08\
-    \x12\x03v\x04\x1c\n\x0c\n\x05\x04\x0c\x02\x08\x06\x12\x03v\x04\r\n\x0c\n\
-    \x05\x04\x0c\x02\x08\x01\x12\x03v\x0e\x17\n\x0c\n\x05\x04\x0c\x02\x08\
-    \x03\x12\x03v\x1a\x1b\n\n\n\x02\x04\r\x12\x04z\0~\x01\n\n\n\x03\x04\r\
-    \x01\x12\x03z\x08\x0c\n\x0b\n\x04\x04\r\x02\0\x12\x03{\x02\x1e\n\x0c\n\
-    \x05\x04\r\x02\0\x04\x12\x03{\x02\n\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03{\
-    \x0b\x0f\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03{\x10\x19\n\x0c\n\x05\x04\r\
-    \x02\0\x03\x12\x03{\x1c\x1d\n\x0b\n\x04\x04\r\x02\x01\x12\x03|\x02\x1b\n\
-    \x0c\n\x05\x04\r\x02\x01\x04\x12\x03|\x02\n\n\x0c\n\x05\x04\r\x02\x01\
-    \x05\x12\x03|\x0b\x10\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03|\x11\x16\n\
-    \x0c\n\x05\x04\r\x02\x01\x03\x12\x03|\x19\x1a\n\x0b\n\x04\x04\r\x02\x02\
-    \x12\x03}\x02\x1d\n\x0c\n\x05\x04\r\x02\x02\x04\x12\x03}\x02\n\n\x0c\n\
-    \x05\x04\r\x02\x02\x05\x12\x03}\x0b\x10\n\x0c\n\x05\x04\r\x02\x02\x01\
-    \x12\x03}\x11\x18\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03}\x1b\x1c\
+    crypted\x12\x1a\n\x08filename\x18\x04\x20\x01(\tR\x08filename\x12\x12\n\
+    \x04body\x18\x05\x20\x01(\x0cR\x04body\x12\x1c\n\ttombstone\x18\x06\x20\
+    \x01(\x08R\ttombstone\"\xf3\x01\n\x07SysInfo\x12\x19\n\x02ip\x18\x01\x20\
+    \x01(\t:\t127.0.0.1R\x02ip\x12%\n\x08hostname\x18\x02\x20\x01(\t:\tlocal\
+    hostR\x08hostname\x12&\n\tgossip_ip\x18\x03\x20\x01(\t:\t127.0.0.1R\x08g\
+    ossipIp\x12\x1f\n\x0bgossip_port\x18\x04\x20\x01(\rR\ngossipPort\x121\n\
+    \x0fhttp_gateway_ip\x18\x05\x20\x01(\t:\t127.0.0.1R\rhttpGatewayIp\x12*\
+    \n\x11http_gateway_port\x18\x06\x20\x01(\rR\x0fhttpGatewayPort\"(\n\tDep\
+    arture\x12\x1b\n\tmember_id\x18\x01\x20\x01(\tR\x08memberId\"\x83\x01\n\
+    \x07RingKey\x12\"\n\rname_with_rev\x18\x01\x20\x01(\tR\x0bnameWithRev\
+    \x12\x16\n\x06secret\x18\x02\x20\x01(\tR\x06secret\x12\x1f\n\x0bactivate\
+    _at\x18\x03\x20\x01(\x04R\nactivateAt\x12\x1b\n\tretire_at\x18\x04\x20\
+    \x01(\x04R\x08retireAt\"\xe3\x01\n\x04Swim\x12\x1e\n\x04type\x18\x01\x20\
+    \x02(\x0e2\n.Swim.TypeR\x04type\x12\x1b\n\x04ping\x18\x02\x20\x01(\x0b2\
+    \x05.PingH\0R\x04ping\x12\x18\n\x03ack\x18\x03\x20\x01(\x0b2\x04.AckH\0R\
+    \x03ack\x12$\n\x07pingreq\x18\x04\x20\x01(\x0b2\x08.PingReqH\0R\x07pingr\
+    eq\x12+\n\nmembership\x18\x05\x20\x03(\x0b2\x0b.MembershipR\nmembership\
+    \"&\n\x04Type\x12\x08\n\x04PING\x10\x01\x12\x07\n\x03ACK\x10\x02\x12\x0b\
+    \n\x07PINGREQ\x10\x03B\t\n\x07payload\"\xac\x04\n\x05Rumor\x12\x1f\n\x04\
+    type\x18\x01\x20\x02(\x0e2\x0b.Rumor.TypeR\x04type\x12\x10\n\x03tag\x18\
+    \x02\x20\x03(\tR\x03tag\x12\x17\n\x07from_id\x18\x03\x20\x01(\tR\x06from\
+    Id\x12%\n\x06member\x18\x04\x20\x01(\x0b2\x0b.MembershipH\0R\x06member\
+    \x12$\n\x07service\x18\x05\x20\x01(\x0b2\x08.ServiceH\0R\x07service\x127\
+    \n\x0eservice_config\x18\x06\x20\x01(\x0b2\x0e.ServiceConfigH\0R\rservic\
+    eConfig\x121\n\x0cservice_file\x18\x07\x20\x01(\x0b2\x0c.ServiceFileH\0R\
+    \x0bserviceFile\x12'\n\x08election\x18\x08\x20\x01(\x0b2\t.ElectionH\0R\
+    \x08election\x12*\n\tdeparture\x18\t\x20\x01(\x0b2\n.DepartureH\0R\tdepa\
+    rture\x12%\n\x08ring_key\x18\n\x20\x01(\x0b2\x08.RingKeyH\0R\x07ringKey\
+    \"\x96\x01\n\x04Type\x12\n\n\x06Member\x10\x01\x12\x0b\n\x07Service\x10\
+    \x02\x12\x0c\n\x08Election\x10\x03\x12\x11\n\rServiceConfig\x10\x04\x12\
+    \x0f\n\x0bServiceFile\x10\x05\x12\x08\n\x04Fake\x10\x06\x12\t\n\x05Fake2\
+    \x10\x07\x12\x12\n\x0eElectionUpdate\x10\x08\x12\r\n\tDeparture\x10\t\
+    \x12\x0b\n\x07RingKey\x10\nB\t\n\x07payload\"w\n\x04Wire\x12\x1c\n\tencr\
+    ypted\x18\x01\x20\x01(\x08R\tencrypted\x12\x14\n\x05nonce\x18\x02\x20\
+    \x01(\x0cR\x05nonce\x12\x18\n\x07payload\x18\x03\x20\x01(\x0cR\x07payloa\
+    d\x12!\n\x0ckey_revision\x18\x04\x20\x01(\tR\x0bkeyRevisionJ\xa98\n\x07\
+    \x12\x05\0\0\x9b\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\
+    \x12\x04\x02\0\x0b\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x0e\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x03\x02\x19\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\
+    \x03\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x0b\x11\n\x0c\n\x05\
+    \x04\0\x02\0\x01\x12\x03\x03\x12\x14\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\
+    \x03\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x02\"\n\x0c\n\x05\x04\
+    \0\x02\x01\x04\x12\x03\x04\x02\n\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\
+    \x04\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x12\x1d\n\x0c\n\
+    \x05\x04\0\x02\x01\x03\x12\x03\x04\x20!\n\x0b\n\x04\x04\0\x02\x02\x12\
+    \x03\x05\x02\x1e\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x03\x05\x02\n\n\x0c\n\
+    \x05\x04\0\x02\x02\x05\x12\x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\x02\x01\
+    \x12\x03\x05\x12\x19\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x1c\x1d\n\
+    \x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x02\x1f\n\x0c\n\x05\x04\0\x02\x03\
+    \x04\x12\x03\x06\x02\n\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x0b\x10\
+    \n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\x11\x1a\n\x0c\n\x05\x04\0\x02\
+    \x03\x03\x12\x03\x06\x1d\x1e\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x02!\
+    \n\x0c\n\x05\x04\0\x02\x04\x04\x12\x03\x07\x02\n\n\x0c\n\x05\x04\0\x02\
+    \x04\x05\x12\x03\x07\x0b\x10\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\
+    \x11\x1c\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x1f\x20\n\x0b\n\x04\
+    \x04\0\x02\x05\x12\x03\x08\x021\n\x0c\n\x05\x04\0\x02\x05\x04\x12\x03\
+    \x08\x02\n\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x0b\x0f\n\x0c\n\x05\
+    \x04\0\x02\x05\x01\x12\x03\x08\x10\x1a\n\x0c\n\x05\x04\0\x02\x05\x03\x12\
+    \x03\x08\x1d\x1e\n\x0c\n\x05\x04\0\x02\x05\x08\x12\x03\x08\x1f0\n\x0c\n\
+    \x05\x04\0\x02\x05\x07\x12\x03\x08*/\n\x0b\n\x04\x04\0\x02\x06\x12\x03\t\
+    \x02/\n\x0c\n\x05\x04\0\x02\x06\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\0\
+    \x02\x06\x05\x12\x03\t\x0b\x0f\n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03\t\
+    \x10\x18\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t\x1b\x1c\n\x0c\n\x05\x04\
+    \0\x02\x06\x08\x12\x03\t\x1d.\n\x0c\n\x05\x04\0\x02\x06\x07\x12\x03\t(-\
+    \n\x0b\n\x04\x04\0\x02\x07\x12\x03\n\x02\"\n\x0c\n\x05\x04\0\x02\x07\x04\
+    \x12\x03\n\x02\n\n\x0c\n\x05\x04\0\x02\x07\x06\x12\x03\n\x0b\x16\n\x0c\n\
+    \x05\x04\0\x02\x07\x01\x12\x03\n\x17\x1d\n\x0c\n\x05\x04\0\x02\x07\x03\
+    \x12\x03\n\x20!\n\n\n\x02\x04\x01\x12\x04\r\0\x10\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\r\x08\x13\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0e\x02\x1a\n\
+    \x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x0e\x02\n\n\x0c\n\x05\x04\x01\x02\0\
+    \x05\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0e\x12\
+    \x15\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0e\x18\x19\n\x0b\n\x04\x04\
+    \x01\x02\x01\x12\x03\x0f\x02\x1c\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\
+    \x0f\x02\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x0f\x0b\x11\n\x0c\n\
+    \x05\x04\x01\x02\x01\x01\x12\x03\x0f\x12\x17\n\x0c\n\x05\x04\x01\x02\x01\
+    \x03\x12\x03\x0f\x1a\x1b\n\n\n\x02\x04\x02\x12\x04\x12\0\x15\x01\n\n\n\
+    \x03\x04\x02\x01\x12\x03\x12\x08\x0c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\
+    \x13\x02\x1b\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\x13\x02\n\n\x0c\n\x05\
+    \x04\x02\x02\0\x06\x12\x03\x13\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
+    \x03\x13\x12\x16\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x13\x19\x1a\n\x0b\
+    \n\x04\x04\x02\x02\x01\x12\x03\x14\x02!\n\x0c\n\x05\x04\x02\x02\x01\x04\
+    \x12\x03\x14\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x14\x0b\x11\n\
+    \x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x14\x12\x1c\n\x0c\n\x05\x04\x02\
+    \x02\x01\x03\x12\x03\x14\x1f\x20\n\n\n\x02\x04\x03\x12\x04\x17\0\x1a\x01\
+    \n\n\n\x03\x04\x03\x01\x12\x03\x17\x08\x0b\n\x0b\n\x04\x04\x03\x02\0\x12\
+    \x03\x18\x02\x1b\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x18\x02\n\n\x0c\n\
+    \x05\x04\x03\x02\0\x06\x12\x03\x18\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\
+    \x12\x03\x18\x12\x16\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x18\x19\x1a\n\
+    \x0b\n\x04\x04\x03\x02\x01\x12\x03\x19\x02!\n\x0c\n\x05\x04\x03\x02\x01\
+    \x04\x12\x03\x19\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x06\x12\x03\x19\x0b\
+    \x11\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x19\x12\x1c\n\x0c\n\x05\x04\
+    \x03\x02\x01\x03\x12\x03\x19\x1f\x20\n\n\n\x02\x04\x04\x12\x04\x1c\0\x1f\
+    \x01\n\n\n\x03\x04\x04\x01\x12\x03\x1c\x08\x0f\n\x0b\n\x04\x04\x04\x02\0\
+    \x12\x03\x1d\x02\x1b\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1d\x02\n\n\
+    \x0c\n\x05\x04\x04\x02\0\x06\x12\x03\x1d\x0b\x11\n\x0c\n\x05\x04\x04\x02\
+    \0\x01\x12\x03\x1d\x12\x16\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1d\x19\
+    \x1a\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x1e\x02\x1d\n\x0c\n\x05\x04\x04\
+    \x02\x01\x04\x12\x03\x1e\x02\n\n\x0c\n\x05\x04\x04\x02\x01\x06\x12\x03\
+    \x1e\x0b\x11\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x1e\x12\x18\n\x0c\n\
+    \x05\x04\x04\x02\x01\x03\x12\x03\x1e\x1b\x1c\n\n\n\x02\x04\x05\x12\x04!\
+    \0'\x01\n\n\n\x03\x04\x05\x01\x12\x03!\x08\x12\n\x0b\n\x04\x04\x05\x04\0\
+    \x12\x03\"\x02F\n\x0c\n\x05\x04\x05\x04\0\x01\x12\x03\"\x07\r\n\r\n\x06\
+    \x04\x05\x04\0\x02\0\x12\x03\"\x10\x1a\n\x0e\n\x07\x04\x05\x04\0\x02\0\
+    \x01\x12\x03\"\x10\x15\n\x0e\n\x07\x04\x05\x04\0\x02\0\x02\x12\x03\"\x18\
+    \x19\n\r\n\x06\x04\x05\x04\0\x02\x01\x12\x03\"\x1b'\n\x0e\n\x07\x04\x05\
+    \x04\0\x02\x01\x01\x12\x03\"\x1b\"\n\x0e\n\x07\x04\x05\x04\0\x02\x01\x02\
+    \x12\x03\"%&\n\r\n\x06\x04\x05\x04\0\x02\x02\x12\x03\"(6\n\x0e\n\x07\x04\
+    \x05\x04\0\x02\x02\x01\x12\x03\"(1\n\x0e\n\x07\x04\x05\x04\0\x02\x02\x02\
+    \x12\x03\"45\n\r\n\x06\x04\x05\x04\0\x02\x03\x12\x03\"7D\n\x0e\n\x07\x04\
+    \x05\x04\0\x02\x03\x01\x12\x03\"7?\n\x0e\n\x07\x04\x05\x04\0\x02\x03\x02\
+    \x12\x03\"BC\n\x0b\n\x04\x04\x05\x02\0\x12\x03$\x02\x1d\n\x0c\n\x05\x04\
+    \x05\x02\0\x04\x12\x03$\x02\n\n\x0c\n\x05\x04\x05\x02\0\x06\x12\x03$\x0b\
+    \x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03$\x12\x18\n\x0c\n\x05\x04\x05\
+    \x02\0\x03\x12\x03$\x1b\x1c\n\x0b\n\x04\x04\x05\x02\x01\x12\x03%\x02\x1d\
+    \n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03%\x02\n\n\x0c\n\x05\x04\x05\x02\
+    \x01\x06\x12\x03%\x0b\x11\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03%\x12\
+    \x18\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03%\x1b\x1c\n\x0b\n\x04\x04\
+    \x05\x02\x02\x12\x03&\x02#\n\x0c\n\x05\x04\x05\x02\x02\x04\x12\x03&\x02\
+    \n\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\x05\
+    \x02\x02\x01\x12\x03&\x12\x1e\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03&!\
+    \"\n\n\n\x02\x04\x06\x12\x04)\0,\x01\n\n\n\x03\x04\x06\x01\x12\x03)\x08\
+    \x19\n\x0b\n\x04\x04\x06\x02\0\x12\x03*\x02\x20\n\x0c\n\x05\x04\x06\x02\
+    \0\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03*\x0b\x11\n\
+    \x0c\n\x05\x04\x06\x02\0\x01\x12\x03*\x12\x1b\n\x0c\n\x05\x04\x06\x02\0\
+    \x03\x12\x03*\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x01\x12\x03+\x02\"\n\x0c\n\
+    \x05\x04\x06\x02\x01\x04\x12\x03+\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x05\
+    \x12\x03+\x0b\x11\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03+\x12\x1d\n\x0c\
+    \n\x05\x04\x06\x02\x01\x03\x12\x03+\x20!\n\n\n\x02\x04\x07\x12\x04.\09\
+    \x01\n\n\n\x03\x04\x07\x01\x12\x03.\x08\x10\n\x0b\n\x04\x04\x07\x04\0\
+    \x12\x03/\x02:\n\x0c\n\x05\x04\x07\x04\0\x01\x12\x03/\x07\r\n\r\n\x06\
+    \x04\x07\x04\0\x02\0\x12\x03/\x10\x1c\n\x0e\n\x07\x04\x07\x04\0\x02\0\
+    \x01\x12\x03/\x10\x17\n\x0e\n\x07\x04\x07\x04\0\x02\0\x02\x12\x03/\x1a\
+    \x1b\n\r\n\x06\x04\x07\x04\0\x02\x01\x12\x03/\x1d*\n\x0e\n\x07\x04\x07\
+    \x04\0\x02\x01\x01\x12\x03/\x1d%\n\x0e\n\x07\x04\x07\x04\0\x02\x01\x02\
+    \x12\x03/()\n\r\n\x06\x04\x07\x04\0\x02\x02\x12\x03/+8\n\x0e\n\x07\x04\
+    \x07\x04\0\x02\x02\x01\x12\x03/+3\n\x0e\n\x07\x04\x07\x04\0\x02\x02\x02\
+    \x12\x03/67\n\x0b\n\x04\x04\x07\x02\0\x12\x031\x02\x20\n\x0c\n\x05\x04\
+    \x07\x02\0\x04\x12\x031\x02\n\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x031\x0b\
+    \x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x031\x12\x1b\n\x0c\n\x05\x04\x07\
+    \x02\0\x03\x12\x031\x1e\x1f\n\x0b\n\x04\x04\x07\x02\x01\x12\x032\x02$\n\
+    \x0c\n\x05\x04\x07\x02\x01\x04\x12\x032\x02\n\n\x0c\n\x05\x04\x07\x02\
+    \x01\x05\x12\x032\x0b\x11\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x032\x12\
+    \x1f\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x032\"#\n\x0b\n\x04\x04\x07\x02\
+    \x02\x12\x033\x02\x1b\n\x0c\n\x05\x04\x07\x02\x02\x04\x12\x033\x02\n\n\
+    \x0c\n\x05\x04\x07\x02\x02\x05\x12\x033\x0b\x11\n\x0c\n\x05\x04\x07\x02\
+    \x02\x01\x12\x033\x12\x16\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x033\x19\
+    \x1a\n\x0b\n\x04\x04\x07\x02\x03\x12\x034\x02\"\n\x0c\n\x05\x04\x07\x02\
+    \x03\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x07\x02\x03\x05\x12\x034\x0b\x11\
+    \n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x034\x12\x1d\n\x0c\n\x05\x04\x07\
+    \x02\x03\x03\x12\x034\x20!\n\x0b\n\x04\x04\x07\x02\x04\x12\x035\x02\x1d\
+    \n\x0c\n\x05\x04\x07\x02\x04\x04\x12\x035\x02\n\n\x0c\n\x05\x04\x07\x02\
+    \x04\x06\x12\x035\x0b\x11\n\x0c\n\x05\x04\x07\x02\x04\x01\x12\x035\x12\
+    \x18\n\x0c\n\x05\x04\x07\x02\x04\x03\x12\x035\x1b\x1c\n\x0b\n\x04\x04\
+    \x07\x02\x05\x12\x036\x02\x1c\n\x0c\n\x05\x04\x07\x02\x05\x04\x12\x036\
+    \x02\n\n\x0c\n\x05\x04\x07\x02\x05\x05\x12\x036\x0b\x11\n\x0c\n\x05\x04\
+    \x07\x02\x05\x01\x12\x036\x12\x17\n\x0c\n\x05\x04\x07\x02\x05\x03\x12\
+    \x036\x1a\x1b\n\x0b\n\x04\x04\x07\x02\x06\x12\x037\x02\x1d\n\x0c\n\x05\
+    \x04\x07\x02\x06\x04\x12\x037\x02\n\n\x0c\n\x05\x04\x07\x02\x06\x05\x12\
+    \x037\x0b\x11\n\x0c\n\x05\x04\x07\x02\x06\x01\x12\x037\x12\x18\n\x0c\n\
+    \x05\x04\x07\x02\x06\x03\x12\x037\x1b\x1c\n\x0b\n\x04\x04\x07\x02\x07\
+    \x12\x038\x02,\n\x0c\n\x05\x04\x07\x02\x07\x04\x12\x038\x02\n\n\x0c\n\
+    \x05\x04\x07\x02\x07\x06\x12\x038\x0b\x1c\n\x0c\n\x05\x04\x07\x02\x07\
+    \x01\x12\x038\x1d'\n\x0c\n\x05\x04\x07\x02\x07\x03\x12\x038*+\n\n\n\x02\
+    \x04\x08\x12\x04;\0G\x01\n\n\n\x03\x04\x08\x01\x12\x03;\x08\x0f\n\x0b\n\
+    \x04\x04\x08\x02\0\x12\x03<\x02\x20\n\x0c\n\x05\x04\x08\x02\0\x04\x12\
+    \x03<\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03<\x0b\x11\n\x0c\n\x05\
+    \x04\x08\x02\0\x01\x12\x03<\x12\x1b\n\x0c\n\x05\x04\x08\x02\0\x03\x12\
+    \x03<\x1e\x1f\n\x0b\n\x04\x04\x08\x02\x01\x12\x03=\x02$\n\x0c\n\x05\x04\
+    \x08\x02\x01\x04\x12\x03=\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03=\
+    \x0b\x11\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03=\x12\x1f\n\x0c\n\x05\
+    \x04\x08\x02\x01\x03\x12\x03=\"#\n\x0b\n\x04\x04\x08\x02\x02\x12\x03>\
+    \x02\"\n\x0c\n\x05\x04\x08\x02\x02\x04\x12\x03>\x02\n\n\x0c\n\x05\x04\
+    \x08\x02\x02\x05\x12\x03>\x0b\x11\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\
+    \x03>\x12\x1d\n\x0c\n\x05\x04\x08\x02\x02\x03\x12\x03>\x20!\n\x0b\n\x04\
+    \x04\x08\x02\x03\x12\x03?\x02\x20\n\x0c\n\x05\x04\x08\x02\x03\x04\x12\
+    \x03?\x02\n\n\x0c\n\x05\x04\x08\x02\x03\x05\x12\x03?\x0b\x0f\n\x0c\n\x05\
+    \x04\x08\x02\x03\x01\x12\x03?\x10\x1b\n\x0c\n\x05\x04\x08\x02\x03\x03\
+    \x12\x03?\x1e\x1f\n\x0b\n\x04\x04\x08\x02\x04\x12\x03@\x02\x1a\n\x0c\n\
+    \x05\x04\x08\x02\x04\x04\x12\x03@\x02\n\n\x0c\n\x05\x04\x08\x02\x04\x05\
+    \x12\x03@\x0b\x11\n\x0c\n\x05\x04\x08\x02\x04\x01\x12\x03@\x12\x15\n\x0c\
+    \n\x05\x04\x08\x02\x04\x03\x12\x03@\x18\x19\n\x0b\n\x04\x04\x08\x02\x05\
+    \x12\x03A\x02\x1a\n\x0c\n\x05\x04\x08\x02\x05\x04\x12\x03A\x02\n\n\x0c\n\
+    \x05\x04\x08\x02\x05\x05\x12\x03A\x0b\x10\n\x0c\n\x05\x04\x08\x02\x05\
+    \x01\x12\x03A\x11\x14\n\x0c\n\x05\x04\x08\x02\x05\x03\x12\x03A\x17\x19\n\
+    \x0b\n\x04\x04\x08\x02\x06\x12\x03B\x02\x1c\n\x0c\n\x05\x04\x08\x02\x06\
+    \x04\x12\x03B\x02\n\n\x0c\n\x05\x04\x08\x02\x06\x06\x12\x03B\x0b\x12\n\
+    \x0c\n\x05\x04\x08\x02\x06\x01\x12\x03B\x13\x16\n\x0c\n\x05\x04\x08\x02\
+    \x06\x03\x12\x03B\x19\x1b\n\x0b\n\x04\x04\x08\x02\x07\x12\x03C\x02'\n\
+    \x0c\n\x05\x04\x08\x02\x07\x04\x12\x03C\x02\n\n\x0c\n\x05\x04\x08\x02\
+    \x07\x05\x12\x03C\x0b\x11\n\x0c\n\x05\x04\x08\x02\x07\x01\x12\x03C\x12!\
+    \n\x0c\n\x05\x04\x08\x02\x07\x03\x12\x03C$&\n\x0b\n\x04\x04\x08\x02\x08\
+    \x12\x03D\x02*\n\x0c\n\x05\x04\x08\x02\x08\x04\x12\x03D\x02\n\n\x0c\n\
+    \x05\x04\x08\x02\x08\x05\x12\x03D\x0b\x10\n\x0c\n\x05\x04\x08\x02\x08\
+    \x01\x12\x03D\x11$\n\x0c\n\x05\x04\x08\x02\x08\x03\x12\x03D')\n\x0b\n\
+    \x04\x04\x08\x02\t\x12\x03E\x02\x1d\n\x0c\n\x05\x04\x08\x02\t\x04\x12\
+    \x03E\x02\n\n\x0c\n\x05\x04\x08\x02\t\x05\x12\x03E\x0b\x10\n\x0c\n\x05\
+    \x04\x08\x02\t\x01\x12\x03E\x11\x17\n\x0c\n\x05\x04\x08\x02\t\x03\x12\
+    \x03E\x1a\x1c\n\x0b\n\x04\x04\x08\x02\n\x12\x03F\x02\x1f\n\x0c\n\x05\x04\
+    \x08\x02\n\x04\x12\x03F\x02\n\n\x0c\n\x05\x04\x08\x02\n\x05\x12\x03F\x0b\
+    \x0f\n\x0c\n\x05\x04\x08\x02\n\x01\x12\x03F\x10\x19\n\x0c\n\x05\x04\x08\
+    \x02\n\x03\x12\x03F\x1c\x1e\n\n\n\x02\x04\t\x12\x04I\0N\x01\n\n\n\x03\
+    \x04\t\x01\x12\x03I\x08\x15\n\x0b\n\x04\x04\t\x02\0\x12\x03J\x02$\n\x0c\
+    \n\x05\x04\t\x02\0\x04\x12\x03J\x02\n\n\x0c\n\x05\x04\t\x02\0\x05\x12\
+    \x03J\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03J\x12\x1f\n\x0c\n\x05\
+    \x04\t\x02\0\x03\x12\x03J\"#\n\x0b\n\x04\x04\t\x02\x01\x12\x03K\x02\"\n\
+    \x0c\n\x05\x04\t\x02\x01\x04\x12\x03K\x02\n\n\x0c\n\x05\x04\t\x02\x01\
+    \x05\x12\x03K\x0b\x11\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03K\x12\x1d\n\
+    \x0c\n\x05\x04\t\x02\x01\x03\x12\x03K\x20!\n\x0b\n\x04\x04\t\x02\x02\x12\
+    \x03L\x02\x1e\n\x0c\n\x05\x04\t\x02\x02\x04\x12\x03L\x02\n\n\x0c\n\x05\
+    \x04\t\x02\x02\x05\x12\x03L\x0b\x0f\n\x0c\n\x05\x04\t\x02\x02\x01\x12\
+    \x03L\x10\x19\n\x0c\n\x05\x04\t\x02\x02\x03\x12\x03L\x1c\x1d\n\x0b\n\x04\
+    \x04\t\x02\x03\x12\x03M\x02\x1c\n\x0c\n\x05\x04\t\x02\x03\x04\x12\x03M\
+    \x02\n\n\x0c\n\x05\x04\t\x02\x03\x05\x12\x03M\x0b\x10\n\x0c\n\x05\x04\t\
+    \x02\x03\x01\x12\x03M\x11\x17\n\x0c\n\x05\x04\t\x02\x03\x03\x12\x03M\x1a\
+    \x1b\n\n\n\x02\x04\n\x12\x04P\0W\x01\n\n\n\x03\x04\n\x01\x12\x03P\x08\
+    \x13\n\x0b\n\x04\x04\n\x02\0\x12\x03Q\x02$\n\x0c\n\x05\x04\n\x02\0\x04\
+    \x12\x03Q\x02\n\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03Q\x0b\x11\n\x0c\n\x05\
+    \x04\n\x02\0\x01\x12\x03Q\x12\x1f\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03Q\"\
+    #\n\x0b\n\x04\x04\n\x02\x01\x12\x03R\x02\"\n\x0c\n\x05\x04\n\x02\x01\x04\
+    \x12\x03R\x02\n\n\x0c\n\x05\x04\n\x02\x01\x05\x12\x03R\x0b\x11\n\x0c\n\
+    \x05\x04\n\x02\x01\x01\x12\x03R\x12\x1d\n\x0c\n\x05\x04\n\x02\x01\x03\
+    \x12\x03R\x20!\n\x0b\n\x04\x04\n\x02\x02\x12\x03S\x02\x1e\n\x0c\n\x05\
+    \x04\n\x02\x02\x04\x12\x03S\x02\n\n\x0c\n\x05\x04\n\x02\x02\x05\x12\x03S\
+    \x0b\x0f\n\x0c\n\x05\x04\n\x02\x02\x01\x12\x03S\x10\x19\n\x0c\n\x05\x04\
+    \n\x02\x02\x03\x12\x03S\x1c\x1d\n\x0b\n\x04\x04\n\x02\x03\x12\x03T\x02\
+    \x1f\n\x0c\n\x05\x04\n\x02\x03\x04\x12\x03T\x02\n\n\x0c\n\x05\x04\n\x02\
+    \x03\x05\x12\x03T\x0b\x11\n\x0c\n\x05\x04\n\x02\x03\x01\x12\x03T\x12\x1a\
+    \n\x0c\n\x05\x04\n\x02\x03\x03\x12\x03T\x1d\x1e\n\x0b\n\x04\x04\n\x02\
+    \x04\x12\x03U\x02\x1a\n\x0c\n\x05\x04\n\x02\x04\x04\x12\x03U\x02\n\n\x0c\
+    \n\x05\x04\n\x02\x04\x05\x12\x03U\x0b\x10\n\x0c\n\x05\x04\n\x02\x04\x01\
+    \x12\x03U\x11\x15\n\x0c\n\x05\x04\n\x02\x04\x03\x12\x03U\x18\x19\n\x0b\n\
+    \x04\x04\n\x02\x05\x12\x03V\x02\x1e\n\x0c\n\x05\x04\n\x02\x05\x04\x12\
+    \x03V\x02\n\n\x0c\n\x05\x04\n\x02\x05\x05\x12\x03V\x0b\x0f\n\x0c\n\x05\
+    \x04\n\x02\x05\x01\x12\x03V\x10\x19\n\x0c\n\x05\x04\n\x02\x05\x03\x12\
+    \x03V\x1c\x1d\n\n\n\x02\x04\x0b\x12\x04Y\0`\x01\n\n\n\x03\x04\x0b\x01\
+    \x12\x03Y\x08\x0f\n\x0b\n\x04\x04\x0b\x02\0\x12\x03Z\x021\n\x0c\n\x05\
+    \x04\x0b\x02\0\x04\x12\x03Z\x02\n\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03Z\
+    \x0b\x11\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03Z\x12\x14\n\x0c\n\x05\x04\
+    \x0b\x02\0\x03\x12\x03Z\x17\x18\n\x0c\n\x05\x04\x0b\x02\0\x08\x12\x03Z\
+    \x190\n\x0c\n\x05\x04\x0b\x02\0\x07\x12\x03Z$/\n\x0b\n\x04\x04\x0b\x02\
+    \x01\x12\x03[\x027\n\x0c\n\x05\x04\x0b\x02\x01\x04\x12\x03[\x02\n\n\x0c\
+    \n\x05\x04\x0b\x02\x01\x05\x12\x03[\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x01\
+    \x01\x12\x03[\x12\x1a\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03[\x1d\x1e\n\
+    \x0c\n\x05\x04\x0b\x02\x01\x08\x12\x03[\x1f6\n\x0c\n\x05\x04\x0b\x02\x01\
+    \x07\x12\x03[*5\n\x0b\n\x04\x04\x0b\x02\x02\x12\x03\\\x028\n\x0c\n\x05\
+    \x04\x0b\x02\x02\x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\x0b\x02\x02\x05\x12\
+    \x03\\\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\x03\\\x12\x1b\n\x0c\n\
+    \x05\x04\x0b\x02\x02\x03\x12\x03\\\x1e\x1f\n\x0c\n\x05\x04\x0b\x02\x02\
+    \x08\x12\x03\\\x207\n\x0c\n\x05\x04\x0b\x02\x02\x07\x12\x03\\+6\n\x0b\n\
+    \x04\x04\x0b\x02\x03\x12\x03]\x02\"\n\x0c\n\x05\x04\x0b\x02\x03\x04\x12\
+    \x03]\x02\n\n\x0c\n\x05\x04\x0b\x02\x03\x05\x12\x03]\x0b\x11\n\x0c\n\x05\
+    \x04\x0b\x02\x03\x01\x12\x03]\x12\x1d\n\x0c\n\x05\x04\x0b\x02\x03\x03\
+    \x12\x03]\x20!\n\x0b\n\x04\x04\x0b\x02\x04\x12\x03^\x02>\n\x0c\n\x05\x04\
+    \x0b\x02\x04\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x0b\x02\x04\x05\x12\x03^\
+    \x0b\x11\n\x0c\n\x05\x04\x0b\x02\x04\x01\x12\x03^\x12!\n\x0c\n\x05\x04\
+    \x0b\x02\x04\x03\x12\x03^$%\n\x0c\n\x05\x04\x0b\x02\x04\x08\x12\x03^&=\n\
+    \x0c\n\x05\x04\x0b\x02\x04\x07\x12\x03^1<\n\x0b\n\x04\x04\x0b\x02\x05\
+    \x12\x03_\x02(\n\x0c\n\x05\x04\x0b\x02\x05\x04\x12\x03_\x02\n\n\x0c\n\
+    \x05\x04\x0b\x02\x05\x05\x12\x03_\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x05\
+    \x01\x12\x03_\x12#\n\x0c\n\x05\x04\x0b\x02\x05\x03\x12\x03_&'\n\n\n\x02\
+    \x04\x0c\x12\x04b\0d\x01\n\n\n\x03\x04\x0c\x01\x12\x03b\x08\x11\n\x0b\n\
+    \x04\x04\x0c\x02\0\x12\x03c\x02\x20\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\
+    \x03c\x02\n\n\x0c\n\x05\x04\x0c\x02\0\x05\x12\x03c\x0b\x11\n\x0c\n\x05\
+    \x04\x0c\x02\0\x01\x12\x03c\x12\x1b\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\
+    \x03c\x1e\x1f\n\n\n\x02\x04\r\x12\x04f\0k\x01\n\n\n\x03\x04\r\x01\x12\
+    \x03f\x08\x0f\n\x0b\n\x04\x04\r\x02\0\x12\x03g\x02$\n\x0c\n\x05\x04\r\
+    \x02\0\x04\x12\x03g\x02\n\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03g\x0b\x11\n\
+    \x0c\n\x05\x04\r\x02\0\x01\x12\x03g\x12\x1f\n\x0c\n\x05\x04\r\x02\0\x03\
+    \x12\x03g\"#\n\x0b\n\x04\x04\r\x02\x01\x12\x03h\x02\x1d\n\x0c\n\x05\x04\
+    \r\x02\x01\x04\x12\x03h\x02\n\n\x0c\n\x05\x04\r\x02\x01\x05\x12\x03h\x0b\
+    \x11\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03h\x12\x18\n\x0c\n\x05\x04\r\
+    \x02\x01\x03\x12\x03h\x1b\x1c\n\x0b\n\x04\x04\r\x02\x02\x12\x03i\x02\"\n\
+    \x0c\n\x05\x04\r\x02\x02\x04\x12\x03i\x02\n\n\x0c\n\x05\x04\r\x02\x02\
+    \x05\x12\x03i\x0b\x11\n\x0c\n\x05\x04\r\x02\x02\x01\x12\x03i\x12\x1d\n\
+    \x0c\n\x05\x04\r\x02\x02\x03\x12\x03i\x20!\n\x0b\n\x04\x04\r\x02\x03\x12\
+    \x03j\x02\x20\n\x0c\n\x05\x04\r\x02\x03\x04\x12\x03j\x02\n\n\x0c\n\x05\
+    \x04\r\x02\x03\x05\x12\x03j\x0b\x11\n\x0c\n\x05\x04\r\x02\x03\x01\x12\
+    \x03j\x12\x1b\n\x0c\n\x05\x04\r\x02\x03\x03\x12\x03j\x1e\x1f\n\n\n\x02\
+    \x04\x0e\x12\x04m\0x\x01\n\n\n\x03\x04\x0e\x01\x12\x03m\x08\x0c\n\x0b\n\
+    \x04\x04\x0e\x04\0\x12\x03n\x02/\n\x0c\n\x05\x04\x0e\x04\0\x01\x12\x03n\
+    \x07\x0b\n\r\n\x06\x04\x0e\x04\0\x02\0\x12\x03n\x0e\x17\n\x0e\n\x07\x04\
+    \x0e\x04\0\x02\0\x01\x12\x03n\x0e\x12\n\x0e\n\x07\x04\x0e\x04\0\x02\0\
+    \x02\x12\x03n\x15\x16\n\r\n\x06\x04\x0e\x04\0\x02\x01\x12\x03n\x18\x20\n\
+    \x0e\n\x07\x04\x0e\x04\0\x02\x01\x01\x12\x03n\x18\x1b\n\x0e\n\x07\x04\
+    \x0e\x04\0\x02\x01\x02\x12\x03n\x1e\x1f\n\r\n\x06\x04\x0e\x04\0\x02\x02\
+    \x12\x03n!-\n\x0e\n\x07\x04\x0e\x04\0\x02\x02\x01\x12\x03n!(\n\x0e\n\x07\
+    \x04\x0e\x04\0\x02\x02\x02\x12\x03n+,\n3\n\x04\x04\x0e\x02\0\x12\x03q\
+    \x02\x19\x1a&\x20Identifies\x20which\x20field\x20is\x20filled\x20in.\n\n\
+    \x0c\n\x05\x04\x0e\x02\0\x04\x12\x03q\x02\n\n\x0c\n\x05\x04\x0e\x02\0\
+    \x06\x12\x03q\x0b\x0f\n\x0c\n\x05\x04\x0e\x02\0\x01\x12\x03q\x10\x14\n\
+    \x0c\n\x05\x04\x0e\x02\0\x03\x12\x03q\x17\x18\n\x0c\n\x04\x04\x0e\x08\0\
+    \x12\x04r\x02v\x03\n\x0c\n\x05\x04\x0e\x08\0\x01\x12\x03r\x08\x0f\n\x0b\
+    \n\x04\x04\x0e\x02\x01\x12\x03s\x04\x12\n\x0c\n\x05\x04\x0e\x02\x01\x06\
+    \x12\x03s\x04\x08\n\x0c\n\x05\x04\x0e\x02\x01\x01\x12\x03s\t\r\n\x0c\n\
+    \x05\x04\x0e\x02\x01\x03\x12\x03s\x10\x11\n\x0b\n\x04\x04\x0e\x02\x02\
+    \x12\x03t\x04\x10\n\x0c\n\x05\x04\x0e\x02\x02\x06\x12\x03t\x04\x07\n\x0c\
+    \n\x05\x04\x0e\x02\x02\x01\x12\x03t\x08\x0b\n\x0c\n\x05\x04\x0e\x02\x02\
+    \x03\x12\x03t\x0e\x0f\n\x0b\n\x04\x04\x0e\x02\x03\x12\x03u\x04\x18\n\x0c\
+    \n\x05\x04\x0e\x02\x03\x06\x12\x03u\x04\x0b\n\x0c\n\x05\x04\x0e\x02\x03\
+    \x01\x12\x03u\x0c\x13\n\x0c\n\x05\x04\x0e\x02\x03\x03\x12\x03u\x16\x17\n\
+    \x0b\n\x04\x04\x0e\x02\x04\x12\x03w\x02%\n\x0c\n\x05\x04\x0e\x02\x04\x04\
+    \x12\x03w\x02\n\n\x0c\n\x05\x04\x0e\x02\x04\x06\x12\x03w\x0b\x15\n\x0c\n\
+    \x05\x04\x0e\x02\x04\x01\x12\x03w\x16\x20\n\x0c\n\x05\x04\x0e\x02\x04\
+    \x03\x12\x03w#$\n\x0b\n\x02\x04\x0f\x12\x05z\0\x94\x01\x01\n\n\n\x03\x04\
+    \x0f\x01\x12\x03z\x08\r\n\r\n\x04\x04\x0f\x04\0\x12\x05{\x02\x86\x01\x03\
+    \n\x0c\n\x05\x04\x0f\x04\0\x01\x12\x03{\x07\x0b\n\r\n\x06\x04\x0f\x04\0\
+    \x02\0\x12\x03|\x04\x0f\n\x0e\n\x07\x04\x0f\x04\0\x02\0\x01\x12\x03|\x04\
+    \n\n\x0e\n\x07\x04\x0f\x04\0\x02\0\x02\x12\x03|\r\x0e\n\r\n\x06\x04\x0f\
+    \x04\0\x02\x01\x12\x03}\x04\x10\n\x0e\n\x07\x04\x0f\x04\0\x02\x01\x01\
+    \x12\x03}\x04\x0b\n\x0e\n\x07\x04\x0f\x04\0\x02\x01\x02\x12\x03}\x0e\x0f\
+    \n\r\n\x06\x04\x0f\x04\0\x02\x02\x12\x03~\x04\x11\n\x0e\n\x07\x04\x0f\
+    \x04\0\x02\x02\x01\x12\x03~\x04\x0c\n\x0e\n\x07\x04\x0f\x04\0\x02\x02\
+    \x02\x12\x03~\x0f\x10\n\r\n\x06\x04\x0f\x04\0\x02\x03\x12\x03\x7f\x04\
+    \x16\n\x0e\n\x07\x04\x0f\x04\0\x02\x03\x01\x12\x03\x7f\x04\x11\n\x0e\n\
+    \x07\x04\x0f\x04\0\x02\x03\x02\x12\x03\x7f\x14\x15\n\x0e\n\x06\x04\x0f\
+    \x04\0\x02\x04\x12\x04\x80\x01\x04\x14\n\x0f\n\x07\x04\x0f\x04\0\x02\x04\
+    \x01\x12\x04\x80\x01\x04\x0f\n\x0f\n\x07\x04\x0f\x04\0\x02\x04\x02\x12\
+    \x04\x80\x01\x12\x13\n\x0e\n\x06\x04\x0f\x04\0\x02\x05\x12\x04\x81\x01\
+    \x04\r\n\x0f\n\x07\x04\x0f\x04\0\x02\x05\x01\x12\x04\x81\x01\x04\x08\n\
+    \x0f\n\x07\x04\x0f\x04\0\x02\x05\x02\x12\x04\x81\x01\x0b\x0c\n\x0e\n\x06\
+    \x04\x0f\x04\0\x02\x06\x12\x04\x82\x01\x04\x0e\n\x0f\n\x07\x04\x0f\x04\0\
+    \x02\x06\x01\x12\x04\x82\x01\x04\t\n\x0f\n\x07\x04\x0f\x04\0\x02\x06\x02\
+    \x12\x04\x82\x01\x0c\r\n\x0e\n\x06\x04\x0f\x04\0\x02\x07\x12\x04\x83\x01\
+    \x04\x17\n\x0f\n\x07\x04\x0f\x04\0\x02\x07\x01\x12\x04\x83\x01\x04\x12\n\
+    \x0f\n\x07\x04\x0f\x04\0\x02\x07\x02\x12\x04\x83\x01\x15\x16\n\x0e\n\x06\
+    \x04\x0f\x04\0\x02\x08\x12\x04\x84\x01\x04\x12\n\x0f\n\x07\x04\x0f\x04\0\
+    \x02\x08\x01\x12\x04\x84\x01\x04\r\n\x0f\n\x07\x04\x0f\x04\0\x02\x08\x02\
+    \x12\x04\x84\x01\x10\x11\n\x0e\n\x06\x04\x0f\x04\0\x02\t\x12\x04\x85\x01\
+    \x04\x11\n\x0f\n\x07\x04\x0f\x04\0\x02\t\x01\x12\x04\x85\x01\x04\x0b\n\
+    \x0f\n\x07\x04\x0f\x04\0\x02\t\x02\x12\x04\x85\x01\x0e\x10\n\x0c\n\x04\
+    \x04\x0f\x02\0\x12\x04\x88\x01\x02\x19\n\r\n\x05\x04\x0f\x02\0\x04\x12\
+    \x04\x88\x01\x02\n\n\r\n\x05\x04\x0f\x02\0\x06\x12\x04\x88\x01\x0b\x0f\n\
+    \r\n\x05\x04\x0f\x02\0\x01\x12\x04\x88\x01\x10\x14\n\r\n\x05\x04\x0f\x02\
+    \0\x03\x12\x04\x88\x01\x17\x18\n\x0c\n\x04\x04\x0f\x02\x01\x12\x04\x89\
+    \x01\x02\x1a\n\r\n\x05\x04\x0f\x02\x01\x04\x12\x04\x89\x01\x02\n\n\r\n\
+    \x05\x04\x0f\x02\x01\x05\x12\x04\x89\x01\x0b\x11\n\r\n\x05\x04\x0f\x02\
+    \x01\x01\x12\x04\x89\x01\x12\x15\n\r\n\x05\x04\x0f\x02\x01\x03\x12\x04\
+    \x89\x01\x18\x19\n\x0c\n\x04\x04\x0f\x02\x02\x12\x04\x8a\x01\x02\x1e\n\r\
+    \n\x05\x04\x0f\x02\x02\x04\x12\x04\x8a\x01\x02\n\n\r\n\x05\x04\x0f\x02\
+    \x02\x05\x12\x04\x8a\x01\x0b\x11\n\r\n\x05\x04\x0f\x02\x02\x01\x12\x04\
+    \x8a\x01\x12\x19\n\r\n\x05\x04\x0f\x02\x02\x03\x12\x04\x8a\x01\x1c\x1d\n\
+    \x0e\n\x04\x04\x0f\x08\0\x12\x06\x8b\x01\x02\x93\x01\x03\n\r\n\x05\x04\
+    \x0f\x08\0\x01\x12\x04\x8b\x01\x08\x0f\n\x0c\n\x04\x04\x0f\x02\x03\x12\
+    \x04\x8c\x01\x04\x1a\n\r\n\x05\x04\x0f\x02\x03\x06\x12\x04\x8c\x01\x04\
+    \x0e\n\r\n\x05\x04\x0f\x02\x03\x01\x12\x04\x8c\x01\x0f\x15\n\r\n\x05\x04\
+    \x0f\x02\x03\x03\x12\x04\x8c\x01\x18\x19\n\x0c\n\x04\x04\x0f\x02\x04\x12\
+    \x04\x8d\x01\x04\x18\n\r\n\x05\x04\x0f\x02\x04\x06\x12\x04\x8d\x01\x04\
+    \x0b\n\r\n\x05\x04\x0f\x02\x04\x01\x12\x04\x8d\x01\x0c\x13\n\r\n\x05\x04\
+    \x0f\x02\x04\x03\x12\x04\x8d\x01\x16\x17\n\x0c\n\x04\x04\x0f\x02\x05\x12\
+    \x04\x8e\x01\x04%\n\r\n\x05\x04\x0f\x02\x05\x06\x12\x04\x8e\x01\x04\x11\
+    \n\r\n\x05\x04\x0f\x02\x05\x01\x12\x04\x8e\x01\x12\x20\n\r\n\x05\x04\x0f\
+    \x02\x05\x03\x12\x04\x8e\x01#$\n\x0c\n\x04\x04\x0f\x02\x06\x12\x04\x8f\
+    \x01\x04!\n\r\n\x05\x04\x0f\x02\x06\x06\x12\x04\x8f\x01\x04\x0f\n\r\n\
+    \x05\x04\x0f\x02\x06\x01\x12\x04\x8f\x01\x10\x1c\n\r\n\x05\x04\x0f\x02\
+    \x06\x03\x12\x04\x8f\x01\x1f\x20\n\x0c\n\x04\x04\x0f\x02\x07\x12\x04\x90\
+    \x01\x04\x1a\n\r\n\x05\x04\x0f\x02\x07\x06\x12\x04\x90\x01\x04\x0c\n\r\n\
+    \x05\x04\x0f\x02\x07\x01\x12\x04\x90\x01\r\x15\n\r\n\x05\x04\x0f\x02\x07\
+    \x03\x12\x04\x90\x01\x18\x19\n\x0c\n\x04\x04\x0f\x02\x08\x12\x04\x91\x01\
+    \x04\x1c\n\r\n\x05\x04\x0f\x02\x08\x06\x12\x04\x91\x01\x04\r\n\r\n\x05\
+    \x04\x0f\x02\x08\x01\x12\x04\x91\x01\x0e\x17\n\r\n\x05\x04\x0f\x02\x08\
+    \x03\x12\x04\x91\x01\x1a\x1b\n\x0c\n\x04\x04\x0f\x02\t\x12\x04\x92\x01\
+    \x04\x1a\n\r\n\x05\x04\x0f\x02\t\x06\x12\x04\x92\x01\x04\x0b\n\r\n\x05\
+    \x04\x0f\x02\t\x01\x12\x04\x92\x01\x0c\x14\n\r\n\x05\x04\x0f\x02\t\x03\
+    \x12\x04\x92\x01\x17\x19\n\x0c\n\x02\x04\x10\x12\x06\x96\x01\0\x9b\x01\
+    \x01\n\x0b\n\x03\x04\x10\x01\x12\x04\x96\x01\x08\x0c\n\x0c\n\x04\x04\x10\
+    \x02\0\x12\x04\x97\x01\x02\x1e\n\r\n\x05\x04\x10\x02\0\x04\x12\x04\x97\
+    \x01\x02\n\n\r\n\x05\x04\x10\x02\0\x05\x12\x04\x97\x01\x0b\x0f\n\r\n\x05\
+    \x04\x10\x02\0\x01\x12\x04\x97\x01\x10\x19\n\r\n\x05\x04\x10\x02\0\x03\
+    \x12\x04\x97\x01\x1c\x1d\n\x0c\n\x04\x04\x10\x02\x01\x12\x04\x98\x01\x02\
+    \x1b\n\r\n\x05\x04\x10\x02\x01\x04\x12\x04\x98\x01\x02\n\n\r\n\x05\x04\
+    \x10\x02\x01\x05\x12\x04\x98\x01\x0b\x10\n\r\n\x05\x04\x10\x02\x01\x01\
+    \x12\x04\x98\x01\x11\x16\n\r\n\x05\x04\x10\x02\x01\x03\x12\x04\x98\x01\
+    \x19\x1a\n\x0c\n\x04\x04\x10\x02\x02\x12\x04\x99\x01\x02\x1d\n\r\n\x05\
+    \x04\x10\x02\x02\x04\x12\x04\x99\x01\x02\n\n\r\n\x05\x04\x10\x02\x02\x05\
+    \x12\x04\x99\x01\x0b\x10\n\r\n\x05\x04\x10\x02\x02\x01\x12\x04\x99\x01\
+    \x11\x18\n\r\n\x05\x04\x10\x02\x02\x03\x12\x04\x99\x01\x1b\x1c\n\x0c\n\
+    \x04\x04\x10\x02\x03\x12\x04\x9a\x01\x02#\n\r\n\x05\x04\x10\x02\x03\x04\
+    \x12\x04\x9a\x01\x02\n\n\r\n\x05\x04\x10\x02\x03\x05\x12\x04\x9a\x01\x0b\
+    \x11\n\r\n\x05\x04\x10\x02\x03\x01\x12\x04\x9a\x01\x12\x1e\n\r\n\x05\x04\
+    \x10\x02\x03\x03\x12\x04\x9a\x01!\"\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -0,0 +1,222 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The network and the clock a `Server` runs on.
+//!
+//! Supervisors send SWIM messages over UDP, push gossip over ZeroMQ, and run their threads on the
+//! system's scheduler and clock. Tests can run servers on a `sim::Simulation` instead, which
+//! delivers both kinds of message in memory, with injected loss, latency and partitions, and runs
+//! the servers' threads one at a time in virtual time.
+
+pub mod sim;
+
+use std::fmt::Debug;
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use time::SteadyTime;
+use zmq;
+
+use ZMQ_CONTEXT;
+use error::{Error, Result};
+
+/// How long a `SwimSocket` waits to receive a message before giving up.
+pub const SWIM_READ_TIMEOUT_MS: u64 = 1000;
+/// How long a `SwimSocket` may block sending a message.
+pub const SWIM_WRITE_TIMEOUT_MS: u64 = 1000;
+
+/// A socket for SWIM messages.
+pub trait SwimSocket: Debug + Send + Sync {
+    /// Sends a message to an address. Like UDP, a message may be lost without an error.
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> io::Result<usize>;
+
+    /// Receives a message, returning its length and sender. Returns an error of kind `WouldBlock`
+    /// or `TimedOut` if no message arrives within `SWIM_READ_TIMEOUT_MS`.
+    fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)>;
+}
+
+impl SwimSocket for UdpSocket {
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> io::Result<usize> {
+        UdpSocket::send_to(self, buf, addr)
+    }
+
+    fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        UdpSocket::recv_from(self, buf)
+    }
+}
+
+/// The socket a server pulls the gossip pushed to it from.
+pub trait GossipReceiver: Send {
+    /// Receives a message, blocking until one arrives.
+    fn recv(&self) -> Result<Vec<u8>>;
+}
+
+/// A connection a server pushes gossip to another member over.
+pub trait GossipSender: Send {
+    /// Sends a message. A message to a member that can't be reached may be lost.
+    fn send(&self, payload: &[u8]) -> Result<()>;
+}
+
+/// Where a server's SWIM and gossip sockets come from.
+pub trait Network: Debug + Send + Sync {
+    /// Binds a SWIM socket to an address.
+    fn bind(&self, addr: SocketAddr) -> Result<Arc<SwimSocket>>;
+
+    /// Binds the socket a server pulls gossip from to its gossip address.
+    fn bind_gossip(&self, addr: SocketAddr) -> Result<Box<GossipReceiver>>;
+
+    /// Connects a server's gossip address to another member's, to push gossip to it.
+    fn connect_gossip(&self, from: SocketAddr, to: SocketAddr) -> Result<Box<GossipSender>>;
+}
+
+/// The real network; SWIM messages are UDP datagrams, and gossip is pushed over ZeroMQ.
+#[derive(Debug, Default)]
+pub struct UdpNetwork;
+
+impl Network for UdpNetwork {
+    fn bind(&self, addr: SocketAddr) -> Result<Arc<SwimSocket>> {
+        let socket = UdpSocket::bind(addr).map_err(|e| Error::CannotBind(e))?;
+        socket
+            .set_read_timeout(Some(Duration::from_millis(SWIM_READ_TIMEOUT_MS)))
+            .map_err(|e| Error::SocketSetReadTimeout(e))?;
+        socket
+            .set_write_timeout(Some(Duration::from_millis(SWIM_WRITE_TIMEOUT_MS)))
+            .map_err(|e| Error::SocketSetWriteTimeout(e))?;
+        Ok(Arc::new(socket))
+    }
+
+    fn bind_gossip(&self, addr: SocketAddr) -> Result<Box<GossipReceiver>> {
+        let socket = (**ZMQ_CONTEXT).as_mut().socket(zmq::PULL).expect(
+            "Failure to create the ZMQ pull socket",
+        );
+        socket.set_linger(0).expect(
+            "Failure to set the ZMQ Pull socket to not linger",
+        );
+        socket.set_tcp_keepalive(0).expect(
+            "Failure to set the ZMQ Pull socket to not use keepalive",
+        );
+        socket.bind(&format!("tcp://{}", addr)).map_err(
+            |e| Error::ZmqBindError(e),
+        )?;
+        Ok(Box::new(ZmqGossipSocket(socket)))
+    }
+
+    /// Connects a push socket. ZeroMQ may choose to keep the connection open for 1 second after
+    /// the socket is dropped - so it is possible, but unlikely, that messages are lost.
+    fn connect_gossip(&self, _from: SocketAddr, to: SocketAddr) -> Result<Box<GossipSender>> {
+        let socket = (**ZMQ_CONTEXT).as_mut().socket(zmq::PUSH).expect(
+            "Failure to create the ZMQ push socket",
+        );
+        socket.set_linger(1000).expect(
+            "Failure to set the ZMQ push socket to not linger",
+        );
+        socket.set_tcp_keepalive(0).expect(
+            "Failure to set the ZMQ push socket to not use keepalive",
+        );
+        socket.set_immediate(true).expect(
+            "Failure to set the ZMQ push socket to immediate",
+        );
+        socket.set_sndhwm(1000).expect(
+            "Failure to set the ZMQ push socket hwm",
+        );
+        socket.set_sndtimeo(500).expect(
+            "Failure to set the ZMQ send timeout",
+        );
+        socket.connect(&format!("tcp://{}", to)).map_err(
+            |e| Error::ZmqConnectError(e),
+        )?;
+        Ok(Box::new(ZmqGossipSocket(socket)))
+    }
+}
+
+/// A ZeroMQ socket gossip is pulled from or pushed to.
+struct ZmqGossipSocket(zmq::Socket);
+
+impl GossipReceiver for ZmqGossipSocket {
+    fn recv(&self) -> Result<Vec<u8>> {
+        let msg = self.0.recv_msg(0).map_err(|e| Error::ZmqRecvError(e))?;
+        Ok(msg.to_vec())
+    }
+}
+
+impl GossipSender for ZmqGossipSocket {
+    fn send(&self, payload: &[u8]) -> Result<()> {
+        self.0.send(payload, 0).map_err(|e| Error::ZmqSendError(e))
+    }
+}
+
+/// A function for a thread of its own to run.
+pub trait Task: Send {
+    fn run(self: Box<Self>);
+}
+
+impl<F: FnOnce() + Send> Task for F {
+    fn run(self: Box<Self>) {
+        (*self)()
+    }
+}
+
+/// A thread started by a `Clock`.
+pub trait Join: Send {
+    /// Waits for the thread to finish, returning an error if it panicked.
+    fn join(self: Box<Self>) -> thread::Result<()>;
+}
+
+impl Join for thread::JoinHandle<()> {
+    fn join(self: Box<Self>) -> thread::Result<()> {
+        thread::JoinHandle::join(*self)
+    }
+}
+
+/// Runs a server's threads, and keeps time for them.
+pub trait Clock: Debug + Send + Sync {
+    /// Starts a thread, named for debugging, which keeps time with this clock.
+    fn spawn(&self, name: String, task: Box<Task>) -> io::Result<Box<Join>>;
+
+    /// The current time.
+    fn now(&self) -> SteadyTime;
+
+    /// Blocks the calling thread for a duration.
+    fn sleep(&self, duration: Duration);
+
+    /// Blocks the calling thread until a time, if it is still to come.
+    fn sleep_until(&self, deadline: SteadyTime) {
+        let wait_time = (deadline - self.now()).num_milliseconds();
+        if wait_time > 0 {
+            self.sleep(Duration::from_millis(wait_time as u64));
+        }
+    }
+}
+
+/// The system's clock.
+#[derive(Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn spawn(&self, name: String, task: Box<Task>) -> io::Result<Box<Join>> {
+        let handle = thread::Builder::new().name(name).spawn(move || task.run())?;
+        Ok(Box::new(handle))
+    }
+
+    fn now(&self) -> SteadyTime {
+        SteadyTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
@@ -0,0 +1,752 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A simulated network and virtual clock for testing servers.
+//!
+//! Messages between servers on a `Simulation` never touch a real socket. Whether a message is
+//! lost, and if not how long it takes to arrive, follows from hashing the simulation's seed with
+//! the sending and receiving addresses and the message's position on that link, so a seed always
+//! gives the messages on a link the same fates. Latencies vary between messages within the
+//! configured bounds, which lets later SWIM messages overtake earlier ones. Gossip is never lost
+//! and arrives in order, as over the TCP connections it travels on in a real ring, but is delayed
+//! and blocked like SWIM messages. Links can be blocked in one direction only, for asymmetric
+//! partitions.
+//!
+//! The servers' threads are started by the simulation, which runs them one at a time. A thread
+//! runs until it sleeps, waits for a message or waits for another thread, and then hands over to
+//! the thread that became ready to run first, so the order in which threads run never depends on
+//! the operating system's scheduler. Time stands still until `advance` is called. It then jumps
+//! from one event to the next (a sleep ending, a message arriving, or a receive timing out), and
+//! runs every thread the event readied, in the order the threads were started, until none is left
+//! to run before it jumps to the next. Which member a server probes next is still chosen at
+//! random.
+
+use std::any::Any;
+use std::cell::Cell;
+use std::cmp;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::net::SocketAddr;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+use time::{SteadyTime, Duration as TimeDuration};
+
+use error::{Error, Result};
+use super::{Clock, GossipReceiver, GossipSender, Join, Network, SwimSocket, Task,
+            SWIM_READ_TIMEOUT_MS};
+
+thread_local! {
+    /// The simulated thread running on this thread, if it is one.
+    static CURRENT: Cell<Option<usize>> = Cell::new(None);
+}
+
+/// A simulated network and virtual clock, shared by every server that runs on it.
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    seed: u64,
+    epoch: SteadyTime,
+    state: Mutex<State>,
+    /// Signalled when no thread is left to run, for `advance`.
+    idle: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    now_ms: u64,
+    loss: f64,
+    min_latency_ms: u64,
+    max_latency_ms: u64,
+    bound: HashSet<SocketAddr>,
+    blocked: HashSet<(SocketAddr, SocketAddr)>,
+    /// How many messages have been sent over each link.
+    links: HashMap<(SocketAddr, SocketAddr), u64>,
+    /// When the latest gossip sent over each link arrives.
+    gossip_arrivals: HashMap<(SocketAddr, SocketAddr), u64>,
+    /// The messages on their way to each address.
+    in_flight: HashMap<SocketAddr, Vec<Packet>>,
+    next_packet: u64,
+    /// The times at which a message arrives or a waiting thread wants to wake. Some may have
+    /// passed, or belong to threads which have woken since.
+    alarms: BTreeSet<u64>,
+    /// The threads the simulation started, in the order it started them.
+    threads: Vec<SimThread>,
+    /// The thread which is running, if any.
+    running: Option<usize>,
+    /// The threads ready to run, in the order they became ready.
+    ready: VecDeque<usize>,
+    delivered: u64,
+    dropped: u64,
+}
+
+#[derive(Debug)]
+struct Packet {
+    deliver_at: u64,
+    id: u64,
+    from: SocketAddr,
+    payload: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct SimThread {
+    /// Signalled when it is the thread's turn to run.
+    turn: Arc<Condvar>,
+    wait: Option<Wait>,
+    finished: bool,
+    panicked: bool,
+}
+
+/// What a thread is waiting for.
+#[derive(Clone, Copy, Debug)]
+enum Wait {
+    /// A time.
+    Until(u64),
+    /// A message to arrive at an address, or a time, whichever comes first.
+    Message(SocketAddr, Option<u64>),
+    /// Another thread to finish.
+    Join(usize),
+}
+
+impl State {
+    /// The soonest time after now at which a message arrives or a thread wants to wake.
+    fn next_event(&mut self) -> Option<u64> {
+        loop {
+            let at = match self.alarms.iter().next() {
+                Some(at) => *at,
+                None => return None,
+            };
+            if at > self.now_ms {
+                return Some(at);
+            }
+            self.alarms.remove(&at);
+        }
+    }
+
+    fn arrived(&self, addr: &SocketAddr) -> bool {
+        let now = self.now_ms;
+        self.in_flight.get(addr).map_or(false, |packets| {
+            packets.iter().any(|p| p.deliver_at <= now)
+        })
+    }
+
+    /// Removes the earliest message which has arrived at an address.
+    fn take_arrived(&mut self, addr: &SocketAddr) -> Option<Packet> {
+        let now = self.now_ms;
+        match self.in_flight.get_mut(addr) {
+            Some(packets) => {
+                let earliest = packets
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, p)| p.deliver_at <= now)
+                    .min_by_key(|&(_, p)| (p.deliver_at, p.id))
+                    .map(|(i, _)| i);
+                earliest.map(|i| packets.swap_remove(i))
+            }
+            None => None,
+        }
+    }
+
+    fn is_over(&self, wait: Wait) -> bool {
+        match wait {
+            Wait::Until(at) => at <= self.now_ms,
+            Wait::Message(addr, deadline) => {
+                self.arrived(&addr) || deadline.map_or(false, |at| at <= self.now_ms)
+            }
+            Wait::Join(id) => self.threads[id].finished,
+        }
+    }
+
+    /// Readies every thread whose wait is over, in the order the threads were started.
+    fn wake_due(&mut self) {
+        for id in 0..self.threads.len() {
+            let over = match self.threads[id].wait {
+                Some(wait) => self.is_over(wait),
+                None => false,
+            };
+            if over {
+                self.threads[id].wait = None;
+                self.ready.push_back(id);
+            }
+        }
+    }
+
+    /// Lets the thread which became ready first run, or tells `advance` that none is ready.
+    fn run_next(&mut self, idle: &Condvar) {
+        match self.ready.pop_front() {
+            Some(id) => {
+                self.running = Some(id);
+                self.threads[id].turn.notify_one();
+            }
+            None => {
+                self.running = None;
+                idle.notify_one();
+            }
+        }
+    }
+}
+
+impl Simulation {
+    /// Creates a simulation whose message fates follow from `seed`. Until told otherwise, it
+    /// delivers every message at once.
+    pub fn new(seed: u64) -> Simulation {
+        Simulation {
+            inner: Arc::new(Inner {
+                seed: seed,
+                epoch: SteadyTime::now(),
+                state: Mutex::new(State::default()),
+                idle: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Loses each SWIM message with a probability from 0.0 to 1.0.
+    pub fn set_loss(&self, loss: f64) {
+        self.lock().loss = loss;
+    }
+
+    /// Delays each message by at least `min` and at most `max`.
+    pub fn set_latency(&self, min: Duration, max: Duration) {
+        let mut state = self.lock();
+        state.min_latency_ms = millis(min);
+        state.max_latency_ms = cmp::max(millis(min), millis(max));
+    }
+
+    /// Drops every message from one address to another. Messages the other way still arrive.
+    pub fn block(&self, from: SocketAddr, to: SocketAddr) {
+        self.lock().blocked.insert((from, to));
+    }
+
+    /// Lets messages from one address to another arrive again.
+    pub fn unblock(&self, from: SocketAddr, to: SocketAddr) {
+        self.lock().blocked.remove(&(from, to));
+    }
+
+    /// How much virtual time has passed since the simulation was created.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_millis(self.lock().now_ms)
+    }
+
+    /// How many messages have been received.
+    pub fn delivered(&self) -> u64 {
+        self.lock().delivered
+    }
+
+    /// How many messages were lost, blocked, or sent to an address nothing is bound to.
+    pub fn dropped(&self) -> u64 {
+        self.lock().dropped
+    }
+
+    /// Moves virtual time forward, an event at a time, running the threads each event readies.
+    /// Only a thread the simulation didn't start can advance it.
+    pub fn advance(&self, duration: Duration) {
+        assert!(
+            current_thread().is_none(),
+            "A simulated thread cannot advance its own simulation"
+        );
+        let mut state = self.lock();
+        let target = state.now_ms + millis(duration);
+        loop {
+            state.wake_due();
+            if state.ready.is_empty() {
+                if state.now_ms >= target {
+                    return;
+                }
+                state.now_ms = state.next_event().map_or(target, |at| cmp::min(at, target));
+                continue;
+            }
+            state.run_next(&self.inner.idle);
+            while state.running.is_some() {
+                state = self.inner.idle.wait(state).expect(
+                    "Simulation lock is poisoned",
+                );
+            }
+        }
+    }
+
+    fn bind_addr(&self, addr: SocketAddr) -> Result<()> {
+        if !self.lock().bound.insert(addr) {
+            return Err(Error::CannotBind(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                "Address is already bound in the simulation",
+            )));
+        }
+        Ok(())
+    }
+
+    fn send(&self, from: SocketAddr, to: SocketAddr, buf: &[u8], gossip: bool) {
+        let mut state = self.lock();
+        let link = (from, to);
+        let sequence = {
+            let sent = state.links.entry(link).or_insert(0);
+            *sent += 1;
+            *sent
+        };
+        let fate = fate(self.inner.seed, &link, sequence);
+        // The top 53 bits make an evenly distributed fraction.
+        let fraction = (fate >> 11) as f64 / (1u64 << 53) as f64;
+        let lost = !gossip && fraction < state.loss;
+        if lost || state.blocked.contains(&link) || !state.bound.contains(&to) {
+            state.dropped += 1;
+            return;
+        }
+        let spread = state.max_latency_ms - state.min_latency_ms;
+        let mut deliver_at = state.now_ms + state.min_latency_ms + splitmix(fate) % (spread + 1);
+        if gossip {
+            let latest = state.gossip_arrivals.entry(link).or_insert(0);
+            deliver_at = cmp::max(deliver_at, *latest);
+            *latest = deliver_at;
+        }
+        let packet = Packet {
+            deliver_at: deliver_at,
+            id: state.next_packet,
+            from: from,
+            payload: buf.to_vec(),
+        };
+        state.next_packet += 1;
+        state.alarms.insert(deliver_at);
+        state.in_flight.entry(to).or_insert_with(Vec::new).push(packet);
+    }
+
+    /// Receives a message sent to an address, waiting at most `timeout_ms` if given. Only a
+    /// simulated thread can wait; any other gets an error of kind `WouldBlock` instead.
+    fn recv(&self, addr: SocketAddr, timeout_ms: Option<u64>) -> io::Result<Packet> {
+        let mut state = self.lock();
+        let deadline = timeout_ms.map(|timeout| state.now_ms + timeout);
+        loop {
+            if let Some(packet) = state.take_arrived(&addr) {
+                state.delivered += 1;
+                return Ok(packet);
+            }
+            if deadline.map_or(false, |at| state.now_ms >= at) {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "No simulated message arrived",
+                ));
+            }
+            if current_thread().is_none() {
+                return Err(io::Error::new(
+                    io::ErrorKind::WouldBlock,
+                    "No simulated message has arrived",
+                ));
+            }
+            state = self.wait(state, Wait::Message(addr, deadline));
+        }
+    }
+
+    /// Blocks the calling thread until its wait is over, running other threads meanwhile.
+    fn wait<'a>(&'a self, mut state: MutexGuard<'a, State>, wait: Wait) -> MutexGuard<'a, State> {
+        let id = current_thread().expect("Only a simulated thread can wait on its simulation");
+        match wait {
+            Wait::Until(at) |
+            Wait::Message(_, Some(at)) => {
+                state.alarms.insert(at);
+            }
+            Wait::Message(_, None) |
+            Wait::Join(_) => (),
+        }
+        state.threads[id].wait = Some(wait);
+        state.run_next(&self.inner.idle);
+        let turn = state.threads[id].turn.clone();
+        while state.running != Some(id) {
+            state = turn.wait(state).expect("Simulation lock is poisoned");
+        }
+        state
+    }
+
+    /// The body of every simulated thread; waits for its turn, runs the task, and hands over to
+    /// the next thread when it's done.
+    fn run_thread(&self, id: usize, task: Box<Task>) {
+        CURRENT.with(|current| current.set(Some(id)));
+        {
+            let mut state = self.lock();
+            let turn = state.threads[id].turn.clone();
+            while state.running != Some(id) {
+                state = turn.wait(state).expect("Simulation lock is poisoned");
+            }
+        }
+        let result = panic::catch_unwind(AssertUnwindSafe(move || task.run()));
+        {
+            let mut state = self.lock();
+            state.threads[id].finished = true;
+            state.threads[id].panicked = result.is_err();
+            state.run_next(&self.inner.idle);
+        }
+        if let Err(payload) = result {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<State> {
+        self.inner.state.lock().expect("Simulation lock is poisoned")
+    }
+}
+
+impl Clock for Simulation {
+    /// Starts a simulated thread. It is ready to run at once, but only runs once the threads
+    /// ready before it have waited, and never before `advance` is called.
+    fn spawn(&self, name: String, task: Box<Task>) -> io::Result<Box<Join>> {
+        let id = {
+            let mut state = self.lock();
+            let id = state.threads.len();
+            state.threads.push(SimThread {
+                turn: Arc::new(Condvar::new()),
+                wait: None,
+                finished: false,
+                panicked: false,
+            });
+            state.ready.push_back(id);
+            id
+        };
+        let simulation = self.clone();
+        let spawned = thread::Builder::new().name(name).spawn(move || {
+            simulation.run_thread(id, task)
+        });
+        if let Err(err) = spawned {
+            let mut state = self.lock();
+            state.ready.retain(|ready| *ready != id);
+            state.threads[id].finished = true;
+            state.threads[id].panicked = true;
+            return Err(err);
+        }
+        Ok(Box::new(SimJoin {
+            id: id,
+            simulation: self.clone(),
+        }))
+    }
+
+    fn now(&self) -> SteadyTime {
+        self.inner.epoch + TimeDuration::milliseconds(self.lock().now_ms as i64)
+    }
+
+    fn sleep(&self, duration: Duration) {
+        let mut state = self.lock();
+        let deadline = state.now_ms + millis(duration);
+        while state.now_ms < deadline {
+            state = self.wait(state, Wait::Until(deadline));
+        }
+    }
+}
+
+impl Network for Simulation {
+    fn bind(&self, addr: SocketAddr) -> Result<Arc<SwimSocket>> {
+        self.bind_addr(addr)?;
+        Ok(Arc::new(SimSocket {
+            addr: addr,
+            simulation: self.clone(),
+        }))
+    }
+
+    fn bind_gossip(&self, addr: SocketAddr) -> Result<Box<GossipReceiver>> {
+        self.bind_addr(addr)?;
+        Ok(Box::new(SimSocket {
+            addr: addr,
+            simulation: self.clone(),
+        }))
+    }
+
+    fn connect_gossip(&self, from: SocketAddr, to: SocketAddr) -> Result<Box<GossipSender>> {
+        Ok(Box::new(SimLink {
+            from: from,
+            to: to,
+            simulation: self.clone(),
+        }))
+    }
+}
+
+/// A socket bound to an address in a simulation.
+#[derive(Debug)]
+struct SimSocket {
+    addr: SocketAddr,
+    simulation: Simulation,
+}
+
+impl SwimSocket for SimSocket {
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> io::Result<usize> {
+        self.simulation.send(self.addr, addr, buf, false);
+        Ok(buf.len())
+    }
+
+    fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        let packet = self.simulation.recv(self.addr, Some(SWIM_READ_TIMEOUT_MS))?;
+        let len = cmp::min(buf.len(), packet.payload.len());
+        buf[..len].copy_from_slice(&packet.payload[..len]);
+        Ok((len, packet.from))
+    }
+}
+
+impl GossipReceiver for SimSocket {
+    /// Receives gossip. Without a timeout, only a thread which can't wait gets an error, and
+    /// that is a bug in the test.
+    fn recv(&self) -> Result<Vec<u8>> {
+        let packet = self.simulation.recv(self.addr, None).expect(
+            "Only a simulated thread can wait for gossip",
+        );
+        Ok(packet.payload)
+    }
+}
+
+/// A gossip connection from one address to another in a simulation.
+#[derive(Debug)]
+struct SimLink {
+    from: SocketAddr,
+    to: SocketAddr,
+    simulation: Simulation,
+}
+
+impl GossipSender for SimLink {
+    fn send(&self, payload: &[u8]) -> Result<()> {
+        self.simulation.send(self.from, self.to, payload, true);
+        Ok(())
+    }
+}
+
+/// A thread started by a simulation.
+#[derive(Debug)]
+struct SimJoin {
+    id: usize,
+    simulation: Simulation,
+}
+
+impl Join for SimJoin {
+    fn join(self: Box<Self>) -> thread::Result<()> {
+        let mut state = self.simulation.lock();
+        if !state.threads[self.id].finished {
+            state = self.simulation.wait(state, Wait::Join(self.id));
+        }
+        if state.threads[self.id].panicked {
+            let payload: Box<Any + Send> = Box::new("Simulated thread panicked");
+            return Err(payload);
+        }
+        Ok(())
+    }
+}
+
+fn current_thread() -> Option<usize> {
+    CURRENT.with(|current| current.get())
+}
+
+fn millis(duration: Duration) -> u64 {
+    duration.as_secs() * 1000 + (duration.subsec_nanos() / 1_000_000) as u64
+}
+
+/// The fate of the message with the given position on a link, as a well mixed number.
+fn fate(seed: u64, link: &(SocketAddr, SocketAddr), sequence: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    link.hash(&mut hasher);
+    sequence.hash(&mut hasher);
+    splitmix(hasher.finish())
+}
+
+/// The SplitMix64 finalizer.
+fn splitmix(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::net::SocketAddr;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use network::{Clock, Network, SwimSocket, SWIM_READ_TIMEOUT_MS};
+    use super::Simulation;
+
+    fn addr(port: u16) -> SocketAddr {
+        format!("127.0.0.1:{}", port).parse().unwrap()
+    }
+
+    /// Sends numbered messages from one address to another, and returns the numbers of those
+    /// which arrive, in the order they arrive.
+    fn arrivals(seed: u64, count: u8) -> Vec<u8> {
+        let sim = Simulation::new(seed);
+        sim.set_loss(0.3);
+        sim.set_latency(Duration::from_millis(10), Duration::from_millis(200));
+        let from = sim.bind(addr(1)).unwrap();
+        let to = sim.bind(addr(2)).unwrap();
+        for n in 0..count {
+            from.send_to(&[n], addr(2)).unwrap();
+        }
+        sim.advance(Duration::from_millis(200));
+        let arrived = count as u64 - sim.dropped();
+        let mut buf = [0; 1];
+        (0..arrived)
+            .map(|_| {
+                let (_, sender) = to.recv_from(&mut buf).unwrap();
+                assert_eq!(sender, addr(1));
+                buf[0]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_same_fates() {
+        let first = arrivals(42, 200);
+        assert_eq!(first, arrivals(42, 200));
+        assert!(first != arrivals(43, 200));
+
+        // Some are lost, and some overtake others.
+        assert!(first.len() > 100 && first.len() < 180);
+        let mut sorted = first.clone();
+        sorted.sort();
+        assert!(first != sorted);
+    }
+
+    #[test]
+    fn gossip_is_never_lost_and_arrives_in_order() {
+        let sim = Simulation::new(0);
+        sim.set_loss(1.0);
+        sim.set_latency(Duration::from_millis(10), Duration::from_millis(200));
+        let receiver = sim.bind_gossip(addr(2)).unwrap();
+        let sender = sim.connect_gossip(addr(1), addr(2)).unwrap();
+        for n in 0..50 {
+            sender.send(&[n]).unwrap();
+        }
+        sim.advance(Duration::from_millis(200));
+        let arrived: Vec<u8> = (0..50).map(|_| receiver.recv().unwrap()[0]).collect();
+        assert_eq!(arrived, (0..50).collect::<Vec<u8>>());
+        assert_eq!(sim.dropped(), 0);
+    }
+
+    #[test]
+    fn blocks_are_one_way() {
+        let sim = Simulation::new(0);
+        let a = sim.bind(addr(1)).unwrap();
+        let b = sim.bind(addr(2)).unwrap();
+        let _gossip = sim.bind_gossip(addr(3)).unwrap();
+        sim.block(addr(1), addr(2));
+        sim.block(addr(1), addr(3));
+
+        a.send_to(b"ping", addr(2)).unwrap();
+        b.send_to(b"ack", addr(1)).unwrap();
+        sim.connect_gossip(addr(1), addr(3))
+            .unwrap()
+            .send(b"rumor")
+            .unwrap();
+        assert_eq!(sim.dropped(), 2);
+        let mut buf = [0; 8];
+        assert_eq!(a.recv_from(&mut buf).unwrap(), (3, addr(2)));
+        assert_eq!(sim.delivered(), 1);
+        assert_eq!(
+            b.recv_from(&mut buf).unwrap_err().kind(),
+            io::ErrorKind::WouldBlock
+        );
+    }
+
+    #[test]
+    fn bind_twice_fails() {
+        let sim = Simulation::new(0);
+        assert!(sim.bind(addr(1)).is_ok());
+        assert!(sim.bind(addr(1)).is_err());
+        assert!(sim.bind_gossip(addr(1)).is_err());
+    }
+
+    #[test]
+    fn threads_take_turns_in_the_order_they_were_started() {
+        let sim = Simulation::new(0);
+        let turns = Arc::new(Mutex::new(Vec::<(u32, u32)>::new()));
+        for thread in 0..3 {
+            let clock = sim.clone();
+            let turns = turns.clone();
+            sim.spawn(
+                format!("sleeper-{}", thread),
+                Box::new(move || for round in 0..3 {
+                    turns.lock().unwrap().push((round, thread));
+                    clock.sleep(Duration::from_millis(10));
+                }),
+            ).unwrap();
+        }
+        sim.advance(Duration::from_millis(100));
+        let expected: Vec<(u32, u32)> = (0..3)
+            .flat_map(|round| (0..3).map(move |thread| (round, thread)))
+            .collect();
+        assert_eq!(*turns.lock().unwrap(), expected);
+    }
+
+    #[test]
+    fn sleep_ends_when_virtual_time_passes() {
+        let sim = Simulation::new(0);
+        let woke = Arc::new(Mutex::new(None));
+        let sleeper = sim.clone();
+        let sleeper_woke = woke.clone();
+        sim.spawn(
+            String::from("sleeper"),
+            Box::new(move || {
+                sleeper.sleep(Duration::from_millis(1000));
+                *sleeper_woke.lock().unwrap() = Some(sleeper.elapsed());
+            }),
+        ).unwrap();
+        sim.advance(Duration::from_millis(999));
+        assert_eq!(*woke.lock().unwrap(), None);
+        sim.advance(Duration::from_millis(1));
+        assert_eq!(*woke.lock().unwrap(), Some(Duration::from_millis(1000)));
+    }
+
+    #[test]
+    fn recv_times_out_in_virtual_time() {
+        let sim = Simulation::new(0);
+        let socket = sim.bind(addr(1)).unwrap();
+        let error = Arc::new(Mutex::new(None));
+        let receiver_error = error.clone();
+        sim.spawn(
+            String::from("receiver"),
+            Box::new(move || {
+                let mut buf = [0; 8];
+                let kind = socket.recv_from(&mut buf).unwrap_err().kind();
+                *receiver_error.lock().unwrap() = Some(kind);
+            }),
+        ).unwrap();
+        sim.advance(Duration::from_millis(SWIM_READ_TIMEOUT_MS - 1));
+        assert_eq!(*error.lock().unwrap(), None);
+        sim.advance(Duration::from_millis(1));
+        assert_eq!(*error.lock().unwrap(), Some(io::ErrorKind::TimedOut));
+    }
+
+    #[test]
+    fn join_waits_for_the_thread_to_finish() {
+        let sim = Simulation::new(0);
+        let joined = Arc::new(Mutex::new(None));
+        let parent = sim.clone();
+        let parent_joined = joined.clone();
+        sim.spawn(
+            String::from("parent"),
+            Box::new(move || {
+                let child = parent.clone();
+                let handle = parent
+                    .spawn(
+                        String::from("child"),
+                        Box::new(move || child.sleep(Duration::from_millis(50))),
+                    )
+                    .unwrap();
+                handle.join().unwrap();
+                *parent_joined.lock().unwrap() = Some(parent.elapsed());
+            }),
+        ).unwrap();
+        sim.advance(Duration::from_millis(100));
+        assert_eq!(*joined.lock().unwrap(), Some(Duration::from_millis(50)));
+    }
+}
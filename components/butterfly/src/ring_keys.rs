@@ -0,0 +1,225 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The revisions of the ring key a server encrypts and decrypts its traffic with.
+//!
+//! A ring may know several revisions of its key at once while the key is being rotated. Traffic
+//! is encrypted with the newest active revision and decrypted with whichever known revision it
+//! was encrypted with, so members which have and have not yet switched to a new revision can
+//! still talk to one another.
+
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use habitat_core::crypto::SymKey;
+
+use error::{Error, Result};
+
+#[derive(Clone, Debug)]
+struct Revision {
+    key: SymKey,
+    activate_at: u64,
+    retire_at: Option<u64>,
+}
+
+impl Revision {
+    fn is_active(&self, now: u64) -> bool {
+        self.activate_at <= now && !self.is_retired(now)
+    }
+
+    fn is_retired(&self, now: u64) -> bool {
+        self.retire_at.map_or(false, |retire_at| retire_at <= now)
+    }
+}
+
+/// The known revisions of a ring key, newest first. Empty for a ring without encryption.
+#[derive(Clone, Debug, Default)]
+pub struct RingKeys {
+    revisions: Arc<RwLock<Vec<Revision>>>,
+}
+
+impl RingKeys {
+    /// Creates the ring keys from revisions which are active immediately.
+    pub fn new(keys: Vec<SymKey>) -> RingKeys {
+        let ring_keys = RingKeys::default();
+        for key in keys {
+            ring_keys.insert(key, 0, None);
+        }
+        ring_keys
+    }
+
+    /// Returns true if the ring encrypts its traffic.
+    pub fn is_encrypted(&self) -> bool {
+        !self.revisions
+            .read()
+            .expect("Ring keys lock poisoned")
+            .is_empty()
+    }
+
+    /// Returns the name of the ring, if it encrypts its traffic.
+    pub fn name(&self) -> Option<String> {
+        self.revisions
+            .read()
+            .expect("Ring keys lock poisoned")
+            .first()
+            .map(|r| r.key.name.clone())
+    }
+
+    /// Adds a revision of the ring key which becomes active at the given time, in seconds since
+    /// the Unix epoch. If a retirement time is given, every older revision is retired then.
+    ///
+    /// A revision which is already known keeps its key; the later of the two activation times
+    /// and the earlier of the two retirement times are kept. Returns false if the key belongs to
+    /// another ring.
+    pub fn insert(&self, key: SymKey, activate_at: u64, retire_at: Option<u64>) -> bool {
+        let mut revisions = self.revisions.write().expect("Ring keys lock poisoned");
+        if revisions.first().map_or(false, |r| r.key.name != key.name) {
+            return false;
+        }
+        if let Some(retire_at) = retire_at {
+            for revision in revisions.iter_mut().filter(|r| r.key.rev < key.rev) {
+                revision.retire_at = Some(revision.retire_at.map_or(retire_at, |current| {
+                    current.min(retire_at)
+                }));
+            }
+        }
+        match revisions.iter().position(|r| r.key.rev <= key.rev) {
+            Some(i) if revisions[i].key.rev == key.rev => {
+                if revisions[i].activate_at < activate_at {
+                    revisions[i].activate_at = activate_at;
+                }
+            }
+            position => {
+                let retire_at = revisions
+                    .iter()
+                    .filter(|r| r.key.rev > key.rev)
+                    .filter_map(|r| r.retire_at)
+                    .min();
+                let revision = Revision {
+                    key: key,
+                    activate_at: activate_at,
+                    retire_at: retire_at,
+                };
+                match position {
+                    Some(i) => revisions.insert(i, revision),
+                    None => revisions.push(revision),
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns the revision traffic is encrypted with: the newest one which is active.
+    pub fn encryption_key(&self) -> Option<SymKey> {
+        let now = now();
+        self.revisions
+            .read()
+            .expect("Ring keys lock poisoned")
+            .iter()
+            .find(|r| r.is_active(now))
+            .map(|r| r.key.clone())
+    }
+
+    /// Returns the revisions which have not been retired, newest first.
+    pub fn revisions(&self) -> Vec<String> {
+        let now = now();
+        self.revisions
+            .read()
+            .expect("Ring keys lock poisoned")
+            .iter()
+            .filter(|r| !r.is_retired(now))
+            .map(|r| r.key.rev.clone())
+            .collect()
+    }
+
+    /// Decrypts a payload with the given revision of the ring key. When the revision isn't known,
+    /// as for traffic from members which don't say which revision they used, each revision which
+    /// hasn't been retired is tried in turn.
+    pub fn decrypt(&self, revision: &str, nonce: &[u8], payload: &[u8]) -> Result<Vec<u8>> {
+        let now = now();
+        let revisions = self.revisions.read().expect("Ring keys lock poisoned");
+        let usable: Vec<&Revision> = revisions.iter().filter(|r| !r.is_retired(now)).collect();
+        if let Some(r) = usable.iter().find(|r| r.key.rev == revision) {
+            return Ok(r.key.decrypt(nonce, payload)?);
+        }
+        let mut last_err = None;
+        for r in usable {
+            match r.key.decrypt(nonce, payload) {
+                Ok(decrypted) => return Ok(decrypted),
+                Err(err) => last_err = Some(err),
+            }
+        }
+        match last_err {
+            Some(err) => Err(Error::HabitatCore(err)),
+            None => Err(Error::NoRingKey(revision.to_string())),
+        }
+    }
+}
+
+/// Returns the current time in seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::SymKey;
+
+    use super::{now, RingKeys};
+
+    fn revision(name: &str, rev: &str) -> SymKey {
+        let key = SymKey::generate_in_memory(name).unwrap();
+        SymKey::new(key.name, rev.to_string(), key.public, key.secret)
+    }
+
+    #[test]
+    fn encrypts_with_the_newest_active_revision() {
+        let old = revision("beyonce", "20170101000000");
+        let new = revision("beyonce", "20170601000000");
+        let ring_keys = RingKeys::new(vec![old.clone()]);
+        assert!(ring_keys.insert(new.clone(), now() + 3600, None));
+        assert_eq!(ring_keys.encryption_key().unwrap().rev, old.rev);
+
+        assert!(ring_keys.insert(new.clone(), 0, None));
+        assert_eq!(ring_keys.encryption_key().unwrap().rev, old.rev);
+
+        let newer = revision("beyonce", "20171201000000");
+        assert!(ring_keys.insert(newer.clone(), 0, None));
+        assert_eq!(ring_keys.encryption_key().unwrap().rev, newer.rev);
+        assert!(!ring_keys.insert(revision("jayz", "20171201000000"), 0, None));
+    }
+
+    #[test]
+    fn decrypts_with_any_revision_until_retired() {
+        let old = revision("beyonce", "20170101000000");
+        let new = revision("beyonce", "20170601000000");
+        let ring_keys = RingKeys::new(vec![old.clone()]);
+        ring_keys.insert(new.clone(), 0, Some(now() + 3600));
+
+        let (nonce, payload) = old.encrypt(b"Guess who?").unwrap();
+        assert_eq!(ring_keys.decrypt(&old.rev, &nonce, &payload).unwrap(), b"Guess who?");
+        assert_eq!(ring_keys.decrypt("", &nonce, &payload).unwrap(), b"Guess who?");
+        let (nonce, payload) = new.encrypt(b"Guess who?").unwrap();
+        assert_eq!(ring_keys.decrypt(&new.rev, &nonce, &payload).unwrap(), b"Guess who?");
+        assert_eq!(ring_keys.revisions(), vec![new.rev.clone(), old.rev.clone()]);
+
+        ring_keys.insert(new.clone(), 0, Some(now() - 1));
+        let (nonce, payload) = old.encrypt(b"Guess who?").unwrap();
+        assert!(ring_keys.decrypt(&old.rev, &nonce, &payload).is_err());
+        assert_eq!(ring_keys.revisions(), vec![new.rev.clone()]);
+    }
+}
@@ -25,10 +25,10 @@ use error::{Result, Error};
 use member::{Health, Member, MemberList};
 use message::swim::Membership as ProtoMembership;
 use rumor::{Election, ElectionUpdate, Rumor, RumorStore, Service, ServiceConfig, ServiceFile,
-            Departure};
+            Departure, RingKey};
 use server::Server;
 
-const HEADER_VERSION: u8 = 2;
+const HEADER_VERSION: u8 = 3;
 
 /// A versioned binary file containing rumors exchanged by the butterfly server which have
 /// been periodically persisted to disk.
@@ -235,9 +235,32 @@ impl DatFile {
             }
         }
 
+        if version[0] >= 3 {
+            debug!("Reading ring key rumors list from {}", self.path().display());
+            bytes_read = 0;
+            loop {
+                if bytes_read >= self.header.ring_key_len {
+                    break;
+                }
+                reader.read_exact(&mut size_buf).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })?;
+                let rumor_size = LittleEndian::read_u64(&size_buf);
+                rumor_buf.resize(rumor_size as usize, 0);
+                reader.read_exact(&mut rumor_buf).map_err(|err| {
+                    Error::DatFileIO(self.path.clone(), err)
+                })?;
+                let rumor = RingKey::from_bytes(&rumor_buf)?;
+                server.insert_ring_key(rumor);
+                bytes_read += size_buf.len() as u64 + rumor_size;
+            }
+        }
+
         Ok(())
     }
 
+    /// Rewrites the whole file from the server's rumor stores, so rumors that have been expired
+    /// from the stores are dropped from the file too.
     pub fn write(&self, server: &Server) -> Result<usize> {
         let mut header = Header::default();
         let tmp_path = self.path.with_extension(
@@ -268,6 +291,7 @@ impl DatFile {
             header.election_len = self.write_rumor_store(&mut writer, &server.election_store)?;
             header.update_len = self.write_rumor_store(&mut writer, &server.update_store)?;
             header.departure_len = self.write_rumor_store(&mut writer, &server.departure_store)?;
+            header.ring_key_len = self.write_rumor_store(&mut writer, &server.ring_key_store)?;
             writer.seek(SeekFrom::Start(1)).map_err(|err| {
                 Error::DatFileIO(self.path.clone(), err)
             })?;
@@ -331,6 +355,11 @@ impl DatFile {
         self.update_offset() + self.header.update_len
     }
 
+    #[allow(dead_code)]
+    fn ring_key_offset(&self) -> u64 {
+        self.departure_offset() + self.header.departure_len
+    }
+
     fn write_header<W>(&self, writer: &mut W, header: &Header) -> Result<usize>
     where
         W: Write,
@@ -426,6 +455,7 @@ pub struct Header {
     pub election_len: u64,
     pub update_len: u64,
     pub departure_len: u64,
+    pub ring_key_len: u64,
 }
 
 impl Header {
@@ -434,7 +464,8 @@ impl Header {
         R: Read,
     {
         let mut bytes = match version {
-            1 => vec![0; mem::size_of::<Self>()],
+            1 => vec![0; 48],
+            2 => vec![0; 64],
             _ => vec![0; mem::size_of::<Self>() + 8],
         };
         reader.read_exact(&mut bytes)?;
@@ -458,6 +489,22 @@ impl Header {
                         election_len: LittleEndian::read_u64(&bytes[32..40]),
                         update_len: LittleEndian::read_u64(&bytes[40..48]),
                         departure_len: 0,
+                        ring_key_len: 0,
+                    },
+                )
+            }
+            2 => {
+                (
+                    LittleEndian::read_u64(&bytes[0..8]),
+                    Header {
+                        member_len: LittleEndian::read_u64(&bytes[8..16]),
+                        service_len: LittleEndian::read_u64(&bytes[16..24]),
+                        service_config_len: LittleEndian::read_u64(&bytes[24..32]),
+                        service_file_len: LittleEndian::read_u64(&bytes[32..40]),
+                        election_len: LittleEndian::read_u64(&bytes[40..48]),
+                        update_len: LittleEndian::read_u64(&bytes[48..56]),
+                        departure_len: LittleEndian::read_u64(&bytes[56..64]),
+                        ring_key_len: 0,
                     },
                 )
             }
@@ -480,6 +527,7 @@ impl Header {
                         election_len: LittleEndian::read_u64(&bytes[40..48]),
                         update_len: LittleEndian::read_u64(&bytes[48..56]),
                         departure_len: LittleEndian::read_u64(&bytes[56..64]),
+                        ring_key_len: LittleEndian::read_u64(&bytes[64..72]),
                     },
                 )
             }
@@ -498,6 +546,7 @@ impl Header {
         LittleEndian::write_u64(&mut bytes[40..48], self.election_len);
         LittleEndian::write_u64(&mut bytes[48..56], self.update_len);
         LittleEndian::write_u64(&mut bytes[56..64], self.departure_len);
+        LittleEndian::write_u64(&mut bytes[64..72], self.ring_key_len);
         Ok(bytes)
     }
 }
@@ -517,6 +566,8 @@ mod tests {
         original.service_file_len = rand::random::<u64>();
         original.election_len = rand::random::<u64>();
         original.update_len = rand::random::<u64>();
+        original.departure_len = rand::random::<u64>();
+        original.ring_key_len = rand::random::<u64>();
         let bytes = original.write_to_bytes().unwrap();
         let (_size_of_header, restored) = Header::from_bytes(&bytes, HEADER_VERSION);
         assert_eq!(bytes.len(), mem::size_of::<Header>() + 8);
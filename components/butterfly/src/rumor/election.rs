@@ -21,6 +21,11 @@
 //! It uses a particular variant I think of as the "highlander" model. A given election will
 //! devolve to a single, universal rumor, which when it is received by the winner will result in
 //! the election finishing. There can, in the end, be only one.
+//!
+//! Every member that takes part in an election is also recorded as a candidate, along with its
+//! suitability, so that once the election has finished the members which did not win can be ranked
+//! as standbys. An election may also carry an explicit quorum size, which replaces the default
+//! majority rule when deciding if the election can complete.
 
 use std::ops::{Deref, DerefMut};
 
@@ -29,7 +34,8 @@ use protobuf::{self, Message, RepeatedField};
 
 pub use message::swim::Election_Status;
 use error::Result;
-use message::swim::{Election as ProtoElection, Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type};
+use message::swim::{Election as ProtoElection, ElectionCandidate, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
 use rumor::Rumor;
 
 #[derive(Debug, Clone, Serialize)]
@@ -73,6 +79,9 @@ impl Election {
         let from_id = member_id.into();
         let real_member_id = from_id.clone();
         let vote_member_id = from_id.clone();
+        let mut candidate = ElectionCandidate::new();
+        candidate.set_member_id(from_id.clone());
+        candidate.set_suitability(suitability);
         rumor.set_from_id(from_id);
         rumor.set_field_type(ProtoRumor_Type::Election);
 
@@ -83,6 +92,7 @@ impl Election {
         proto.set_suitability(suitability);
         proto.set_status(Election_Status::Running);
         proto.set_votes(RepeatedField::from_vec(vec![vote_member_id]));
+        proto.set_candidates(RepeatedField::from_vec(vec![candidate]));
 
         rumor.set_election(proto);
         Election(rumor)
@@ -95,11 +105,43 @@ impl Election {
         }
     }
 
+    /// Record a candidate of the election, unless it is already known.
+    pub fn insert_candidate(&mut self, candidate: &ElectionCandidate) {
+        if !self.get_candidates().iter().any(|c| {
+            c.get_member_id() == candidate.get_member_id()
+        })
+        {
+            self.mut_candidates().push(candidate.clone());
+        }
+    }
+
     /// Steal all the votes from another election for ourselves.
     pub fn steal_votes(&mut self, other: &mut Election) {
         for x in other.mut_votes().iter() {
             self.insert_vote(x);
         }
+        for x in other.mut_candidates().iter() {
+            self.insert_candidate(x);
+        }
+    }
+
+    /// Returns the member ids of every candidate but the one this election is voting for, ordered
+    /// the same way the election orders them: most suitable first, and by member id when equally
+    /// suitable.
+    pub fn standbys(&self) -> Vec<String> {
+        let mut candidates: Vec<&ElectionCandidate> = self.get_candidates()
+            .iter()
+            .filter(|c| c.get_member_id() != self.get_member_id())
+            .collect();
+        candidates.sort_by(|a, b| {
+            b.get_suitability().cmp(&a.get_suitability()).then_with(|| {
+                b.get_member_id().cmp(a.get_member_id())
+            })
+        });
+        candidates
+            .into_iter()
+            .map(|c| c.get_member_id().to_string())
+            .collect()
     }
 
     /// Sets the status of the election to "running".
@@ -130,6 +172,8 @@ impl PartialEq for Election {
             self.get_member_id() == other.get_member_id() &&
             self.get_suitability() == other.get_suitability() &&
             self.get_votes() == other.get_votes() &&
+            self.get_candidates() == other.get_candidates() &&
+            self.get_quorum() == other.get_quorum() &&
             self.get_status() == other.get_status() && self.get_term() == other.get_term()
     }
 }
@@ -331,4 +375,17 @@ mod tests {
         assert_eq!(e1.get_member_id(), "d");
         assert_eq!(e1.get_votes().len(), 4);
     }
+
+    #[test]
+    fn standbys_are_ranked_by_suitability_then_member_id() {
+        let mut e1 = create_election("a", 1);
+        let e2 = create_election("b", 3);
+        let e3 = create_election("c", 1);
+        let e4 = create_election("d", 0);
+        assert_eq!(e1.merge(e2), true);
+        assert_eq!(e1.merge(e3), true);
+        assert_eq!(e1.merge(e4), true);
+        assert_eq!(e1.get_member_id(), "b");
+        assert_eq!(e1.standbys(), vec!["c", "a", "d"]);
+    }
 }
@@ -64,6 +64,13 @@ impl RumorHeat {
         rumors.insert(rk, HashMap::new());
     }
 
+    /// Stop tracking a rumor, such as one that has been garbage
+    /// collected; it will no longer be shared with anyone.
+    pub fn stop_rumor(&self, rumor: &RumorKey) {
+        let mut rumors = self.0.write().expect("RumorHeat lock poisoned");
+        rumors.remove(rumor);
+    }
+
     /// Return a list of currently "hot" rumors for the specified
     /// member. This will be the subset of all rumors being tracked
     /// which have not already been sent to the member more than
@@ -19,11 +19,16 @@
 //!
 //! New rumors need to implement the `From` trait for `RumorKey`, and then can track the arrival of
 //! new rumors, and dispatch them according to their `kind`.
+//!
+//! Rumors that are no longer needed, such as tombstones, are garbage collected by `expire` once
+//! they have been garbage for a while. The store remembers the incarnation of each rumor it
+//! expires, so a member that never heard of a tombstone can't bring back what it removed.
 
 pub mod dat_file;
 pub mod departure;
 pub mod heat;
 pub mod election;
+pub mod ring_key;
 pub mod service;
 pub mod service_config;
 pub mod service_file;
@@ -33,6 +38,7 @@ pub use self::service::Service;
 pub use self::service_config::ServiceConfig;
 pub use self::service_file::ServiceFile;
 pub use self::departure::Departure;
+pub use self::ring_key::RingKey;
 
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
@@ -44,6 +50,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
+use time::{Duration, SteadyTime};
 
 use message::swim::Rumor_Type;
 use error::{Result, Error};
@@ -87,6 +94,11 @@ pub trait Rumor: Serialize + Sized {
     fn id(&self) -> &str;
     fn merge(&mut self, other: Self) -> bool;
     fn write_to_bytes(&self) -> Result<Vec<u8>>;
+
+    /// The incarnation of the rumor, for rumors which are replaced by a higher incarnation.
+    fn incarnation(&self) -> Option<u64> {
+        None
+    }
 }
 
 impl<'a, T: Rumor> From<&'a T> for RumorKey {
@@ -103,6 +115,10 @@ impl<'a, T: Rumor> From<&'a T> for RumorKey {
 pub struct RumorStore<T: Rumor> {
     pub list: Arc<RwLock<HashMap<String, HashMap<String, T>>>>,
     update_counter: Arc<AtomicUsize>,
+    /// When each rumor found to be garbage was first found so, by key and id.
+    garbage: Arc<RwLock<HashMap<(String, String), SteadyTime>>>,
+    /// The incarnation of each expired rumor, by key and id; rumors at or below it are stale.
+    floors: Arc<RwLock<HashMap<(String, String), u64>>>,
 }
 
 impl<T: Rumor> Default for RumorStore<T> {
@@ -110,6 +126,8 @@ impl<T: Rumor> Default for RumorStore<T> {
         RumorStore {
             list: Arc::new(RwLock::new(HashMap::new())),
             update_counter: Arc::new(AtomicUsize::new(0)),
+            garbage: Arc::new(RwLock::new(HashMap::new())),
+            floors: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
@@ -154,6 +172,14 @@ impl<T: Rumor> RumorStore<T> {
     pub fn clear(&self) -> usize {
         let mut list = self.list.write().expect("Rumor store lock poisoned");
         list.clear();
+        self.garbage
+            .write()
+            .expect("Rumor store garbage lock poisoned")
+            .clear();
+        self.floors
+            .write()
+            .expect("Rumor store floors lock poisoned")
+            .clear();
         self.update_counter.swap(0, Ordering::Relaxed)
     }
 
@@ -177,9 +203,24 @@ impl<T: Rumor> RumorStore<T> {
         list.get(key).map_or(0, |r| r.len())
     }
 
+    /// Whether a rumor is no newer than one we have already expired, and so shouldn't be stored.
+    pub fn is_stale(&self, rumor: &T) -> bool {
+        let incarnation = match rumor.incarnation() {
+            Some(incarnation) => incarnation,
+            None => return false,
+        };
+        let floors = self.floors.read().expect("Rumor store floors lock poisoned");
+        floors
+            .get(&(String::from(rumor.key()), String::from(rumor.id())))
+            .map_or(false, |floor| incarnation <= *floor)
+    }
+
     /// Insert a rumor into the Rumor Store. Returns true if the value didn't exist or if it was
-    /// mutated; if nothing changed, returns false.
+    /// mutated; if nothing changed, or the rumor is stale, returns false.
     pub fn insert(&self, rumor: T) -> bool {
+        if self.is_stale(&rumor) {
+            return false;
+        }
         let mut list = self.list.write().expect("Rumor store lock poisoned");
         let mut rumors = list.entry(String::from(rumor.key())).or_insert(
             HashMap::new(),
@@ -203,6 +244,78 @@ impl<T: Rumor> RumorStore<T> {
         list.get_mut(key).and_then(|mut r| r.remove(id));
     }
 
+    /// Removes the rumors that have been garbage for at least `ttl`, and returns their keys.
+    ///
+    /// A rumor is garbage while `is_garbage` holds for it, counting from the first call which
+    /// found it so; if a later call finds it isn't, it starts over. Keys left without rumors are
+    /// removed as well. The incarnation of each removed rumor is kept, so that copies of it which
+    /// are no newer are refused from then on.
+    pub fn expire<F>(&self, now: SteadyTime, ttl: Duration, mut is_garbage: F) -> Vec<RumorKey>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut expired = Vec::new();
+        {
+            let list = self.list.read().expect("Rumor store lock poisoned");
+            let mut garbage = self.garbage.write().expect(
+                "Rumor store garbage lock poisoned",
+            );
+            let mut still_garbage = HashMap::new();
+            for (key, rumors) in list.iter() {
+                for (id, rumor) in rumors.iter() {
+                    if !is_garbage(rumor) {
+                        continue;
+                    }
+                    let entry = (key.clone(), id.clone());
+                    let since = garbage.get(&entry).cloned().unwrap_or(now);
+                    if now - since >= ttl {
+                        expired.push(RumorKey::from(rumor));
+                    }
+                    still_garbage.insert(entry, since);
+                }
+            }
+            *garbage = still_garbage;
+        }
+        if expired.is_empty() {
+            return expired;
+        }
+
+        let mut list = self.list.write().expect("Rumor store lock poisoned");
+        let mut garbage = self.garbage.write().expect(
+            "Rumor store garbage lock poisoned",
+        );
+        let mut floors = self.floors.write().expect(
+            "Rumor store floors lock poisoned",
+        );
+        // A rumor may have been replaced since we looked; only remove it if it's still garbage.
+        expired.retain(|rk| {
+            list.get(&rk.key).and_then(|r| r.get(&rk.id)).map_or(
+                false,
+                |rumor| is_garbage(rumor),
+            )
+        });
+        for rk in expired.iter() {
+            let now_empty = match list.get_mut(&rk.key) {
+                Some(rumors) => {
+                    let incarnation = rumors.remove(&rk.id).and_then(|r| r.incarnation());
+                    if let Some(incarnation) = incarnation {
+                        floors.insert((rk.key.clone(), rk.id.clone()), incarnation);
+                    }
+                    rumors.is_empty()
+                }
+                None => false,
+            };
+            if now_empty {
+                list.remove(&rk.key);
+            }
+            garbage.remove(&(rk.key.clone(), rk.id.clone()));
+        }
+        if !expired.is_empty() {
+            self.increment_update_counter();
+        }
+        expired
+    }
+
     pub fn with_keys<F>(&self, mut with_closure: F)
     where
         F: FnMut((&String, &HashMap<String, T>)),
@@ -356,6 +469,7 @@ mod tests {
         use rumor::RumorStore;
         use rumor::Rumor;
         use std::usize;
+        use time::{Duration, SteadyTime};
 
         fn create_rumor_store() -> RumorStore<FakeRumor> {
             RumorStore::default()
@@ -454,5 +568,37 @@ mod tests {
             let rs = create_rumor_store();
             rs.with_rumor("bar", "foo", |o| assert!(o.is_none()));
         }
+
+        #[test]
+        fn expire_removes_rumors_once_garbage_for_the_ttl() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let f1_id = f1.id.clone();
+            rs.insert(f1);
+            let start = SteadyTime::now();
+            let ttl = Duration::seconds(60);
+            assert!(rs.expire(start, ttl, |_| true).is_empty());
+            assert!(
+                rs.expire(start + Duration::seconds(59), ttl, |_| true)
+                    .is_empty()
+            );
+            let expired = rs.expire(start + ttl, ttl, |_| true);
+            assert_eq!(expired.len(), 1);
+            assert_eq!(expired[0].id, f1_id);
+            assert_eq!(rs.len(), 0);
+            assert!(rs.list.read().unwrap().is_empty());
+        }
+
+        #[test]
+        fn expire_starts_over_for_rumors_that_stop_being_garbage() {
+            let rs = create_rumor_store();
+            rs.insert(FakeRumor::default());
+            let start = SteadyTime::now();
+            let ttl = Duration::seconds(60);
+            rs.expire(start, ttl, |_| true);
+            rs.expire(start + Duration::seconds(30), ttl, |_| false);
+            assert!(rs.expire(start + ttl, ttl, |_| true).is_empty());
+            assert_eq!(rs.expire(start + ttl * 2, ttl, |_| true).len(), 1);
+        }
     }
 }
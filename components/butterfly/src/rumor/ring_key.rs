@@ -0,0 +1,156 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The RingKey rumor.
+//!
+//! RingKey rumors distribute a new revision of the ring key to every member of the ring. Members
+//! decrypt with the new revision as soon as they learn it, start encrypting with it once it
+//! activates, and stop accepting older revisions once they are retired. Both times are chosen by
+//! whoever rotates the key, far enough apart for the rumor to reach the whole ring first.
+
+use std::cmp::Ordering;
+use std::ops::{Deref, DerefMut};
+
+use habitat_core::crypto::SymKey;
+use protobuf::{self, Message};
+
+use error::Result;
+use message::swim::{RingKey as ProtoRingKey, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RingKey(ProtoRumor);
+
+impl PartialOrd for RingKey {
+    fn partial_cmp(&self, other: &RingKey) -> Option<Ordering> {
+        if self.get_name_with_rev() != other.get_name_with_rev() {
+            None
+        } else {
+            Some(self.get_name_with_rev().cmp(&other.get_name_with_rev()))
+        }
+    }
+}
+
+impl PartialEq for RingKey {
+    fn eq(&self, other: &RingKey) -> bool {
+        self.get_name_with_rev() == other.get_name_with_rev()
+    }
+}
+
+impl From<ProtoRumor> for RingKey {
+    fn from(pr: ProtoRumor) -> RingKey {
+        RingKey(pr)
+    }
+}
+
+impl From<RingKey> for ProtoRumor {
+    fn from(ring_key: RingKey) -> ProtoRumor {
+        ring_key.0
+    }
+}
+
+impl Deref for RingKey {
+    type Target = ProtoRingKey;
+
+    fn deref(&self) -> &ProtoRingKey {
+        self.0.get_ring_key()
+    }
+}
+
+impl DerefMut for RingKey {
+    fn deref_mut(&mut self) -> &mut ProtoRingKey {
+        self.0.mut_ring_key()
+    }
+}
+
+impl RingKey {
+    /// Creates a rumor for a ring key revision which activates and retires the revisions before
+    /// it at the given times, in seconds since the Unix epoch.
+    pub fn new(key: &SymKey, activate_at: u64, retire_at: u64) -> Result<Self> {
+        let mut rumor = ProtoRumor::new();
+        rumor.set_from_id(String::from("butterflyclient"));
+        rumor.set_field_type(ProtoRumor_Type::RingKey);
+
+        let mut proto = ProtoRingKey::new();
+        proto.set_name_with_rev(key.name_with_rev());
+        proto.set_secret(key.to_secret_string()?);
+        proto.set_activate_at(activate_at);
+        proto.set_retire_at(retire_at);
+        rumor.set_ring_key(proto);
+        Ok(RingKey(rumor))
+    }
+
+    /// Returns the ring key revision this rumor carries.
+    pub fn sym_key(&self) -> Result<SymKey> {
+        Ok(SymKey::from_secret_str(self.get_secret())?)
+    }
+}
+
+impl Rumor for RingKey {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(RingKey::from(rumor))
+    }
+
+    fn merge(&mut self, other: RingKey) -> bool {
+        if *self >= other { false } else { true }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::RingKey
+    }
+
+    fn id(&self) -> &str {
+        self.get_name_with_rev()
+    }
+
+    fn key(&self) -> &str {
+        "ring_key"
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(self.0.write_to_bytes()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::SymKey;
+
+    use super::RingKey;
+    use rumor::Rumor;
+
+    fn create_ring_key(key: &SymKey) -> RingKey {
+        RingKey::new(key, 100, 200).unwrap()
+    }
+
+    #[test]
+    fn sym_key_round_trips() {
+        let key = SymKey::generate_in_memory("beyonce").unwrap();
+        let rumor = create_ring_key(&key);
+        let read = rumor.sym_key().unwrap();
+        assert_eq!(read.name_with_rev(), key.name_with_rev());
+        assert_eq!(read.secret, key.secret);
+    }
+
+    #[test]
+    fn merge_returns_false_if_nothing_changed() {
+        let key = SymKey::generate_in_memory("beyonce").unwrap();
+        let mut r1 = create_ring_key(&key);
+        let r1_check = r1.clone();
+        assert_eq!(r1.merge(create_ring_key(&key)), false);
+        assert_eq!(r1, r1_check);
+    }
+}
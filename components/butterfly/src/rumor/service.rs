@@ -14,7 +14,8 @@
 
 //! The Service rumor.
 //!
-//! Service rumors declare that a given `Server` is running this Service.
+//! Service rumors declare that a given `Server` is running this Service. A tombstone declares
+//! that it no longer is.
 
 use std::cmp::Ordering;
 use std::mem;
@@ -123,6 +124,17 @@ impl Service {
         rumor.set_service(proto);
         Service(rumor)
     }
+
+    /// Returns a tombstone for this service, declaring that its member no longer runs it. The
+    /// tombstone's incarnation is one higher, so it replaces this rumor wherever it spreads.
+    pub fn tombstone(&self) -> Self {
+        let mut tombstone = self.clone();
+        let incarnation = self.get_incarnation() + 1;
+        tombstone.set_incarnation(incarnation);
+        tombstone.clear_cfg();
+        tombstone.set_tombstone(true);
+        tombstone
+    }
 }
 
 impl Rumor for Service {
@@ -154,6 +166,10 @@ impl Rumor for Service {
         self.get_service_group()
     }
 
+    fn incarnation(&self) -> Option<u64> {
+        Some(self.get_incarnation())
+    }
+
     fn write_to_bytes(&self) -> Result<Vec<u8>> {
         Ok(self.0.write_to_bytes()?)
     }
@@ -167,8 +183,10 @@ mod tests {
     use habitat_core::service::ServiceGroup;
     use habitat_core::package::{Identifiable, PackageIdent};
 
+    use time::{Duration, SteadyTime};
+
     use super::Service;
-    use rumor::Rumor;
+    use rumor::{Rumor, RumorStore};
     use rumor::service::SysInfo;
 
     fn create_service(member_id: &str) -> Service {
@@ -255,6 +273,35 @@ mod tests {
         assert_eq!(s1, s1_check);
     }
 
+    #[test]
+    fn tombstone_replaces_the_service() {
+        let mut s1 = create_service("adam");
+        let tombstone = s1.tombstone();
+        assert!(tombstone.get_tombstone());
+        assert_eq!(tombstone.get_incarnation(), 1);
+        assert_eq!(s1.merge(tombstone), true);
+        assert!(s1.get_tombstone());
+    }
+
+    #[test]
+    fn expired_tombstone_refuses_the_service_it_replaced() {
+        let store = RumorStore::default();
+        let s1 = create_service("adam");
+        store.insert(s1.tombstone());
+        let expired = store.expire(SteadyTime::now(), Duration::zero(), |s: &Service| {
+            s.get_tombstone()
+        });
+        assert_eq!(expired.len(), 1);
+        assert!(store.is_stale(&s1));
+        assert_eq!(store.insert(s1.clone()), false);
+        assert!(!store.contains_rumor(s1.key(), s1.id()));
+
+        let mut s2 = create_service("adam");
+        s2.set_incarnation(2);
+        assert!(!store.is_stale(&s2));
+        assert_eq!(store.insert(s2), true);
+    }
+
     #[test]
     #[should_panic]
     fn service_package_name_mismatch() {
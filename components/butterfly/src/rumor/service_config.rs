@@ -159,6 +159,10 @@ impl Rumor for ServiceConfig {
         self.get_service_group()
     }
 
+    fn incarnation(&self) -> Option<u64> {
+        Some(self.get_incarnation())
+    }
+
     fn write_to_bytes(&self) -> Result<Vec<u8>> {
         Ok(self.0.write_to_bytes()?)
     }
@@ -14,7 +14,7 @@
 
 //! The ServiceFile rumor.
 //!
-//! Holds the toml configuration injected for a service.
+//! Holds the toml configuration injected for a service. A tombstone removes the file.
 
 use std::cmp::Ordering;
 use std::mem;
@@ -48,7 +48,8 @@ impl PartialEq for ServiceFile {
             self.get_incarnation() == other.get_incarnation() &&
             self.get_encrypted() == other.get_encrypted() &&
             self.get_filename() == other.get_filename() &&
-            self.get_body() == other.get_body()
+            self.get_body() == other.get_body() &&
+            self.get_tombstone() == other.get_tombstone()
     }
 }
 
@@ -105,6 +106,18 @@ impl ServiceFile {
         ServiceFile(rumor)
     }
 
+    /// Creates a tombstone for a service file, which removes the file wherever it spreads. Give it
+    /// an incarnation higher than the file's.
+    pub fn new_tombstone<S1, S2>(member_id: S1, service_group: ServiceGroup, filename: S2) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut tombstone = ServiceFile::new(member_id, service_group, filename, Vec::new());
+        tombstone.set_tombstone(true);
+        tombstone
+    }
+
     /// Encrypt the contents of the service file
     pub fn encrypt(&mut self, user_pair: &BoxKeyPair, service_pair: &BoxKeyPair) -> Result<()> {
         let body = self.take_body();
@@ -155,6 +168,10 @@ impl Rumor for ServiceFile {
         self.get_service_group()
     }
 
+    fn incarnation(&self) -> Option<u64> {
+        Some(self.get_incarnation())
+    }
+
     fn write_to_bytes(&self) -> Result<Vec<u8>> {
         Ok(self.0.write_to_bytes()?)
     }
@@ -241,6 +258,20 @@ mod tests {
         assert_eq!(s1, s1_check);
     }
 
+    #[test]
+    fn tombstone_with_a_higher_incarnation_replaces_the_file() {
+        let mut s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
+        let mut tombstone = ServiceFile::new_tombstone(
+            "adam",
+            ServiceGroup::new(None, "neurosis", "production", None).unwrap(),
+            "yep",
+        );
+        tombstone.set_incarnation(1);
+        assert_eq!(s1.merge(tombstone), true);
+        assert!(s1.get_tombstone());
+        assert!(s1.body().unwrap().is_empty());
+    }
+
     #[test]
     fn config_comes_back_as_a_string() {
         let s1 = create_service_file("adam", "yep", "tcp-backlog = 128");
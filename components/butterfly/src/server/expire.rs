@@ -15,13 +15,10 @@
 //! Expire suspected members.
 //!
 //! This module keeps track of suspected members, and sets their status to confirmed if they remain
-//! suspect long enough.
+//! suspect long enough. How long is long enough shrinks as other members confirm the suspicion.
 
-use std::thread;
 use std::time::Duration;
 
-use time::SteadyTime;
-
 use message::swim::Rumor_Type;
 use member::Health;
 use rumor::RumorKey;
@@ -47,9 +44,14 @@ impl Expire {
     pub fn run(&self) {
         loop {
             let mut expired_list: Vec<String> = Vec::new();
-            self.server.member_list.with_suspects(|(id, suspect)| {
-                let now = SteadyTime::now();
-                if now >= *suspect + self.timing.suspicion_timeout_duration() {
+            let members = self.server.member_list.len();
+            self.server.member_list.with_suspects(|(id, suspicion)| {
+                let now = self.server.clock.now();
+                let timeout = self.timing.suspicion_timeout_duration(
+                    suspicion.confirmations(),
+                    members,
+                );
+                if now >= suspicion.started + timeout {
                     expired_list.push(String::from(id));
                     self.server.member_list.insert_health_by_id(
                         id,
@@ -78,7 +80,7 @@ impl Expire {
             let mut departed_list: Vec<String> = Vec::new();
             self.server.member_list.with_departures(
                 |(id, departure_time)| {
-                    let now = SteadyTime::now();
+                    let now = self.server.clock.now();
                     if now >= *departure_time + self.timing.departure_timeout_duration() {
                         departed_list.push(String::from(id));
                         self.server.member_list.insert_health_by_id(
@@ -106,7 +108,13 @@ impl Expire {
                 ));
             }
 
-            thread::sleep(Duration::from_millis(500));
+            // Persisting rewrites the dat file from the rumor stores, so expired rumors are
+            // compacted out of it straight away rather than on the next persist loop.
+            if self.server.expire_rumors(self.timing.rumor_ttl_duration()) > 0 {
+                self.server.persist_data();
+            }
+
+            self.server.clock.sleep(Duration::from_millis(500));
         }
     }
 }
@@ -16,23 +16,25 @@
 //!
 //! This module handles all the inbound SWIM messages.
 
+use std::io;
+use std::sync::Arc;
 use std::sync::mpsc;
 use std::sync::atomic::Ordering;
-use std::net::{SocketAddr, UdpSocket};
-use std::thread;
+use std::net::SocketAddr;
 use std::time::Duration;
 
 use protobuf;
 
 use member::{Member, Health};
 use message::swim::{Swim, Swim_Type};
+use network::SwimSocket;
 use server::{Server, outbound};
 use trace::TraceKind;
 
 /// Takes the Server and a channel to send received Acks to the outbound thread.
 pub struct Inbound {
     pub server: Server,
-    pub socket: UdpSocket,
+    pub socket: Arc<SwimSocket>,
     pub tx_outbound: mpsc::Sender<(SocketAddr, Swim)>,
 }
 
@@ -40,7 +42,7 @@ impl Inbound {
     /// Create a new Inbound.
     pub fn new(
         server: Server,
-        socket: UdpSocket,
+        socket: Arc<SwimSocket>,
         tx_outbound: mpsc::Sender<(SocketAddr, Swim)>,
     ) -> Inbound {
         Inbound {
@@ -55,7 +57,7 @@ impl Inbound {
         let mut recv_buffer: Vec<u8> = vec![0; 1024];
         loop {
             if self.server.pause.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(100));
+                self.server.clock.sleep(Duration::from_millis(100));
                 continue;
             }
             match self.socket.recv_from(&mut recv_buffer[..]) {
@@ -131,6 +133,10 @@ impl Inbound {
                             error!("UDP Receive error: {}", e);
                             debug!("UDP Receive error debug: {:?}", e);
                         }
+                        None if e.kind() == io::ErrorKind::WouldBlock ||
+                                    e.kind() == io::ErrorKind::TimedOut => {
+                            // A simulated socket's timeout
+                        }
                         None => {
                             error!("UDP Receive error: {}", e);
                         }
@@ -163,7 +169,7 @@ impl Inbound {
             from.set_address(format!("{}", addr.ip()));
             outbound::ping(
                 &self.server,
-                &self.socket,
+                &*self.socket,
                 target,
                 target.swim_socket_address(),
                 Some(from.into()),
@@ -206,19 +212,11 @@ impl Inbound {
                 msg.mut_ack().mut_from().set_address(
                     format!("{}", addr.ip()),
                 );
-                outbound::forward_ack(&self.server, &self.socket, forward_to_addr, msg);
+                outbound::forward_ack(&self.server, &*self.socket, forward_to_addr, msg);
                 return;
             }
         }
-        let membership = {
-            let membership: Vec<(Member, Health)> = msg.take_membership()
-                .iter()
-                .map(|m| {
-                    (Member::from(m.get_member()), Health::from(m.get_health()))
-                })
-                .collect();
-            membership
-        };
+        let membership = msg.take_membership().into_vec();
         match self.tx_outbound.send((addr, msg)) {
             Ok(()) => {}
             Err(e) => panic!("Outbound thread has died - this shouldn't happen: #{:?}", e),
@@ -233,17 +231,21 @@ impl Inbound {
                   msg.get_ping().get_from().get_id(),
                   addr,
                   &msg);
+        // Take in the rumors before we ack, so that if the pinging member suspects us, our ack
+        // already carries the refutation.
+        let membership = msg.take_membership().into_vec();
+        self.server.insert_member_from_rumors(membership);
         let target: Member = msg.get_ping().get_from().into();
         if msg.get_ping().has_forward_to() {
             outbound::ack(
                 &self.server,
-                &self.socket,
+                &*self.socket,
                 &target,
                 addr,
                 Some(msg.mut_ping().take_forward_to().into()),
             );
         } else {
-            outbound::ack(&self.server, &self.socket, &target, addr, None);
+            outbound::ack(&self.server, &*self.socket, &target, addr, None);
         }
         // Populate the member for this sender with its remote address
         let from = {
@@ -258,12 +260,5 @@ impl Inbound {
         } else {
             self.server.insert_member(from.into(), Health::Alive);
         }
-        let membership: Vec<(Member, Health)> = msg.take_membership()
-            .iter()
-            .map(|m| {
-                (Member::from(m.get_member()), Health::from(m.get_health()))
-            })
-            .collect();
-        self.server.insert_member_from_rumors(membership);
     }
 }
@@ -31,24 +31,25 @@ use std::ffi;
 use std::fmt::{self, Debug};
 use std::fs;
 use std::io;
-use std::net::{ToSocketAddrs, UdpSocket, SocketAddr};
+use std::net::{ToSocketAddrs, SocketAddr};
 use std::path::PathBuf;
 use std::result;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
-use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
 use std::sync::mpsc::channel;
-use std::time::{Instant, Duration};
-use std::thread;
 
 use habitat_core::service::ServiceGroup;
 use habitat_core::crypto::SymKey;
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
+use time::Duration as TimeDuration;
 
 use error::{Result, Error};
 use member::{Member, Health, MemberList};
 use message;
+use message::swim::Membership as ProtoMembership;
+use network::{Clock, Network, SwimSocket, SystemClock, UdpNetwork};
 use rumor::{Rumor, RumorKey, RumorStore};
 use rumor::heat::RumorHeat;
 use rumor::dat_file::DatFile;
@@ -57,6 +58,8 @@ use rumor::service::Service;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
 use rumor::election::{Election, ElectionUpdate};
+use rumor::ring_key::RingKey;
+use ring_keys::RingKeys;
 use trace::{Trace, TraceKind};
 
 pub trait Suitability: Debug + Send + Sync {
@@ -70,7 +73,7 @@ pub struct Server {
     member_id: Arc<String>,
     pub member: Arc<RwLock<Member>>,
     pub member_list: MemberList,
-    ring_key: Arc<Option<SymKey>>,
+    ring_keys: RingKeys,
     rumor_heat: RumorHeat,
     pub service_store: RumorStore<Service>,
     pub service_config_store: RumorStore<ServiceConfig>,
@@ -78,13 +81,17 @@ pub struct Server {
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
     pub departure_store: RumorStore<Departure>,
+    pub ring_key_store: RumorStore<RingKey>,
     swim_addr: Arc<RwLock<SocketAddr>>,
     gossip_addr: Arc<RwLock<SocketAddr>>,
     suitability_lookup: Arc<Box<Suitability>>,
     data_path: Arc<Option<PathBuf>>,
     dat_file: Arc<RwLock<Option<DatFile>>>,
-    socket: Option<UdpSocket>,
+    network: Arc<Network>,
+    clock: Arc<Clock>,
+    socket: Option<Arc<SwimSocket>>,
     departed: Arc<AtomicBool>,
+    local_health: Arc<AtomicUsize>,
     // These are all here for testing support
     pause: Arc<AtomicBool>,
     pub trace: Arc<RwLock<Trace>>,
@@ -100,7 +107,7 @@ impl Clone for Server {
             member_id: self.member_id.clone(),
             member: self.member.clone(),
             member_list: self.member_list.clone(),
-            ring_key: self.ring_key.clone(),
+            ring_keys: self.ring_keys.clone(),
             rumor_heat: self.rumor_heat.clone(),
             service_store: self.service_store.clone(),
             service_config_store: self.service_config_store.clone(),
@@ -108,12 +115,16 @@ impl Clone for Server {
             election_store: self.election_store.clone(),
             update_store: self.update_store.clone(),
             departure_store: self.departure_store.clone(),
+            ring_key_store: self.ring_key_store.clone(),
             swim_addr: self.swim_addr.clone(),
             gossip_addr: self.gossip_addr.clone(),
             suitability_lookup: self.suitability_lookup.clone(),
             data_path: self.data_path.clone(),
             dat_file: self.dat_file.clone(),
+            network: self.network.clone(),
+            clock: self.clock.clone(),
             departed: self.departed.clone(),
+            local_health: self.local_health.clone(),
             pause: self.pause.clone(),
             trace: self.trace.clone(),
             swim_rounds: self.swim_rounds.clone(),
@@ -154,7 +165,7 @@ impl Server {
                     member_id: Arc::new(String::from(member.get_id())),
                     member: Arc::new(RwLock::new(member)),
                     member_list: MemberList::new(),
-                    ring_key: Arc::new(ring_key),
+                    ring_keys: RingKeys::new(ring_key.into_iter().collect()),
                     rumor_heat: RumorHeat::default(),
                     service_store: RumorStore::default(),
                     service_config_store: RumorStore::default(),
@@ -162,12 +173,16 @@ impl Server {
                     election_store: RumorStore::default(),
                     update_store: RumorStore::default(),
                     departure_store: RumorStore::default(),
+                    ring_key_store: RumorStore::default(),
                     swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
                     gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
                     suitability_lookup: Arc::new(suitability_lookup),
                     data_path: Arc::new(data_path.as_ref().map(|p| p.into())),
                     dat_file: Arc::new(RwLock::new(None)),
+                    network: Arc::new(UdpNetwork),
+                    clock: Arc::new(SystemClock),
                     departed: Arc::new(AtomicBool::new(false)),
+                    local_health: Arc::new(AtomicUsize::new(0)),
                     pause: Arc::new(AtomicBool::new(false)),
                     trace: Arc::new(RwLock::new(trace)),
                     swim_rounds: Arc::new(AtomicIsize::new(0)),
@@ -238,6 +253,44 @@ impl Server {
         }
     }
 
+    /// How unhealthy this member seems to itself, from 0 (healthy) to `timing::LOCAL_HEALTH_MAX`.
+    ///
+    /// A member that misses Acks or has to refute suspicions of itself is likely slow to send or
+    /// process messages, rather than surrounded by failed members. The outbound thread waits
+    /// longer for Acks the higher the score, so that it doesn't suspect healthy members.
+    pub fn local_health(&self) -> usize {
+        self.local_health.load(Ordering::SeqCst)
+    }
+
+    /// Worsens the local health score by 1, up to `timing::LOCAL_HEALTH_MAX`.
+    fn degrade_local_health(&self) {
+        let current = self.local_health.load(Ordering::SeqCst);
+        if current < timing::LOCAL_HEALTH_MAX {
+            self.local_health.store(current + 1, Ordering::SeqCst);
+        }
+    }
+
+    /// Improves the local health score by 1, down to 0.
+    fn improve_local_health(&self) {
+        let current = self.local_health.load(Ordering::SeqCst);
+        if current > 0 {
+            self.local_health.store(current - 1, Ordering::SeqCst);
+        }
+    }
+
+    /// Sets the network the server binds its SWIM and gossip sockets on. Call it before `start`;
+    /// servers use UDP and ZeroMQ unless told otherwise.
+    pub fn set_network(&mut self, network: Arc<Network>) {
+        self.network = network;
+    }
+
+    /// Sets the clock which runs the server's threads and keeps their time. Call it before
+    /// `start`; servers use the system's threads and clock unless told otherwise.
+    pub fn set_clock(&mut self, clock: Arc<Clock>) {
+        self.member_list.set_clock(clock.clone());
+        self.clock = clock;
+    }
+
     /// Start the server, along with a `Timing` for outbound connections. Spawns the `inbound`,
     /// `outbound`, and `expire` threads.
     ///
@@ -246,6 +299,7 @@ impl Server {
     /// * Returns `Error::CannotBind` if the socket cannot be bound
     /// * Returns `Error::SocketSetReadTimeout` if the socket read timeout cannot be set
     /// * Returns `Error::SocketSetWriteTimeout` if the socket write timeout cannot be set
+    /// * Returns `Error::ZmqBindError` if the gossip socket cannot be bound
     pub fn start(&mut self, timing: timing::Timing) -> Result<()> {
         let (tx_outbound, rx_inbound) = channel();
         if let Some(ref path) = *self.data_path {
@@ -255,79 +309,65 @@ impl Server {
             let mut file = DatFile::new(&self.member_id, path);
             if file.path().exists() {
                 file.read_into(self)?;
+                self.restore_incarnation();
             }
             let mut dat_file = self.dat_file.write().expect("DatFile lock is poisoned");
             *dat_file = Some(file);
         }
 
-        let socket = match UdpSocket::bind(*self.swim_addr.read().expect(
-            "Swim address lock is poisoned",
-        )) {
-            Ok(socket) => socket,
-            Err(e) => return Err(Error::CannotBind(e)),
-        };
-        socket
-            .set_read_timeout(Some(Duration::from_millis(1000)))
-            .map_err(|e| Error::SocketSetReadTimeout(e))?;
-        socket
-            .set_write_timeout(Some(Duration::from_millis(1000)))
-            .map_err(|e| Error::SocketSetReadTimeout(e))?;
+        let socket = self.network.bind(self.swim_addr())?;
+        let gossip_socket = self.network.bind_gossip(self.gossip_addr())?;
 
         let server_a = self.clone();
-        let socket_a = match socket.try_clone() {
-            Ok(socket_a) => socket_a,
-            Err(_) => return Err(Error::SocketCloneError),
-        };
-        let socket_expire = match socket.try_clone() {
-            Ok(socket_expire) => socket_expire,
-            Err(_) => return Err(Error::SocketCloneError),
-        };
-        self.socket = Some(socket_expire);
+        let socket_a = socket.clone();
+        self.socket = Some(socket.clone());
 
-        let _ = thread::Builder::new()
-            .name(format!("inbound-{}", self.name()))
-            .spawn(move || {
+        let _ = self.clock.spawn(
+            format!("inbound-{}", self.name()),
+            Box::new(move || {
                 inbound::Inbound::new(server_a, socket_a, tx_outbound).run();
                 panic!("You should never, ever get here, judy");
-            });
+            }),
+        );
 
         let server_b = self.clone();
-        let socket_b = match socket.try_clone() {
-            Ok(socket_b) => socket_b,
-            Err(_) => return Err(Error::SocketCloneError),
-        };
+        let socket_b = socket;
         let timing_b = timing.clone();
-        let _ = thread::Builder::new()
-            .name(format!("outbound-{}", self.name()))
-            .spawn(move || {
+        let _ = self.clock.spawn(
+            format!("outbound-{}", self.name()),
+            Box::new(move || {
                 outbound::Outbound::new(server_b, socket_b, rx_inbound, timing_b).run();
                 panic!("You should never, ever get here, bob");
-            });
+            }),
+        );
 
         let server_c = self.clone();
         let timing_c = timing.clone();
-        let _ = thread::Builder::new()
-            .name(format!("expire-{}", self.name()))
-            .spawn(move || {
+        let _ = self.clock.spawn(
+            format!("expire-{}", self.name()),
+            Box::new(move || {
                 expire::Expire::new(server_c, timing_c).run();
                 panic!("You should never, ever get here, frank");
-            });
+            }),
+        );
 
         let server_d = self.clone();
-        let _ = thread::Builder::new()
-            .name(format!("pull-{}", self.name()))
-            .spawn(move || {
-                pull::Pull::new(server_d).run();
+        let _ = self.clock.spawn(
+            format!("pull-{}", self.name()),
+            Box::new(move || {
+                pull::Pull::new(server_d, gossip_socket).run();
                 panic!("You should never, ever get here, davey");
-            });
+            }),
+        );
 
         let server_e = self.clone();
-        let _ = thread::Builder::new()
-            .name(format!("push-{}", self.name()))
-            .spawn(move || {
+        let _ = self.clock.spawn(
+            format!("push-{}", self.name()),
+            Box::new(move || {
                 push::Push::new(server_e, timing).run();
                 panic!("You should never, ever get here, liu");
-            });
+            }),
+        );
 
         if self.dat_file
             .read()
@@ -335,12 +375,13 @@ impl Server {
             .is_some()
         {
             let server_f = self.clone();
-            let _ = thread::Builder::new()
-                .name(format!("persist-{}", self.name()))
-                .spawn(move || {
+            let _ = self.clock.spawn(
+                format!("persist-{}", self.name()),
+                Box::new(move || {
                     persist_loop(server_f);
                     panic!("Data persistence loop unexpectedly quit!");
-                });
+                }),
+            );
         }
 
         Ok(())
@@ -375,6 +416,11 @@ impl Server {
         self.pause.compare_and_swap(false, true, Ordering::Relaxed);
     }
 
+    /// Let the outbound and inbound threads process work again after a `pause`.
+    pub fn unpause(&mut self) {
+        self.pause.compare_and_swap(true, false, Ordering::Relaxed);
+    }
+
     /// Whether this server is currently paused.
     pub fn paused(&self) -> bool {
         self.pause.load(Ordering::Relaxed)
@@ -429,6 +475,7 @@ impl Server {
         let trace_member_id = String::from(member.get_id());
         let trace_incarnation = member.get_incarnation();
         let trace_health = health.clone();
+        let mut hot = false;
         if self.member_list.insert(member, health) {
             trace_it!(
                 MEMBERSHIP: self,
@@ -437,10 +484,46 @@ impl Server {
                 trace_incarnation,
                 trace_health
             );
+            hot = true;
+        }
+        // Any suspicion we insert is our own, and is gossiped to confirm the suspicion elsewhere.
+        if health == Health::Suspect &&
+            self.member_list.confirm_suspicion(&trace_member_id, self.member_id())
+        {
+            hot = true;
+        }
+        if hot {
             self.rumor_heat.start_hot_rumor(rk);
         }
     }
 
+    /// Carries our incarnation on from the record of ourselves persisted by a previous run, since
+    /// the ring ignores a membership older than the one it knows. If we no longer match that
+    /// record, such as when we advertise different labels, our incarnation is incremented past it
+    /// so that the change is gossiped.
+    fn restore_incarnation(&self) {
+        let persisted = match self.member_list
+            .members
+            .read()
+            .expect("Member List read lock poisoned")
+            .get(self.member_id()) {
+            Some(member) => member.clone(),
+            None => return,
+        };
+        let member = {
+            let mut me = self.member.write().expect("Member lock is poisoned");
+            if me.get_incarnation() > persisted.get_incarnation() {
+                return;
+            }
+            me.set_incarnation(persisted.get_incarnation());
+            if *me != persisted {
+                me.set_incarnation(persisted.get_incarnation() + 1);
+            }
+            me.clone()
+        };
+        self.insert_member(member, Health::Alive);
+    }
+
     /// Change the health of a `Member`, and update its `RumorKey`.
     pub fn insert_health(&self, member: &Member, health: Health) {
         let rk: RumorKey = RumorKey::from(&member);
@@ -492,15 +575,21 @@ impl Server {
             for member in check_list.iter().take(10) {
                 let addr = member.swim_socket_address();
                 // Safe because we checked above
-                outbound::ack(&self, self.socket.as_ref().unwrap(), member, addr, None);
+                outbound::ack(&self, &**self.socket.as_ref().unwrap(), member, addr, None);
             }
         } else {
             debug!("No socket present; server was never started, so nothing to depart");
         }
     }
 
-    /// Given a membership record and some health, insert it into the Member List.
-    fn insert_member_from_rumor(&self, member: Member, mut health: Health) {
+    /// Given a membership record, insert its member and health into the Member List.
+    ///
+    /// A rumor that we are not alive is refuted by incrementing our incarnation. Each member who
+    /// suspects another member counts as a confirmation of our own suspicion, if we have one; any
+    /// we hadn't heard of are gossiped on.
+    fn insert_member_from_rumor(&self, mut membership: ProtoMembership) {
+        let member: Member = membership.take_member().into();
+        let mut health: Health = membership.get_health().into();
         let mut incremented_incarnation = false;
         let rk: RumorKey = RumorKey::from(&member);
         if member.get_id() == self.member_id() {
@@ -513,6 +602,9 @@ impl Server {
                 incremented_incarnation = true;
             }
         }
+        if incremented_incarnation {
+            self.degrade_local_health();
+        }
         // NOTE: This sucks so much right here. Check out how we allocate no matter what, because
         // of just how the logic goes. The value of the trace is really high, though, so we suck it
         // for now.
@@ -520,6 +612,7 @@ impl Server {
         let trace_incarnation = member.get_incarnation();
         let trace_health = health.clone();
 
+        let mut hot = false;
         if self.member_list.insert(member, health) || incremented_incarnation {
             trace_it!(
                 MEMBERSHIP: self,
@@ -528,25 +621,39 @@ impl Server {
                 trace_incarnation,
                 trace_health
             );
+            hot = true;
+        }
+        if health == Health::Suspect {
+            for suspected_by in membership.get_suspected_by() {
+                if self.member_list.confirm_suspicion(&trace_member_id, suspected_by) {
+                    hot = true;
+                }
+            }
+        }
+        if hot {
             self.rumor_heat.start_hot_rumor(rk);
         }
     }
 
     /// Insert members from a list of received rumors.
-    fn insert_member_from_rumors(&self, members: Vec<(Member, Health)>) {
-        for (member, health) in members.into_iter() {
-            self.insert_member_from_rumor(member, health);
+    fn insert_member_from_rumors(&self, memberships: Vec<ProtoMembership>) {
+        for membership in memberships.into_iter() {
+            self.insert_member_from_rumor(membership);
         }
     }
 
     /// Insert a service rumor into the service store.
     pub fn insert_service(&self, service: Service) {
+        // A service no newer than one we expired is gossip we've already finished with.
+        if self.service_store.is_stale(&service) {
+            return;
+        }
         let rk = RumorKey::from(&service);
 
         // * If we don't have a rumor
         // * And we do have Confirmed members for this service
         // * Select the first sorted Confirmed member, and change it to departed
-        if !self.service_store.contains_rumor(&rk.key, &rk.id) {
+        if !service.get_tombstone() && !self.service_store.contains_rumor(&rk.key, &rk.id) {
             let mut service_entries: Vec<Service> = Vec::new();
             self.service_store.with_rumors(&rk.key, |service_rumor| {
                 if self.member_list.check_health_of_by_id(
@@ -580,6 +687,85 @@ impl Server {
         }
     }
 
+    /// Whether a member runs a service, as far as we know; it has a service rumor which isn't a
+    /// tombstone.
+    pub fn runs_service(&self, service_group: &str, member_id: &str) -> bool {
+        let mut runs = false;
+        self.service_store.with_rumor(service_group, member_id, |s| {
+            runs = s.map_or(false, |s| !s.get_tombstone())
+        });
+        runs
+    }
+
+    /// Whether a member has tombstoned its service rumor for a service group.
+    fn left_service(&self, service_group: &str, member_id: &str) -> bool {
+        let mut left = false;
+        self.service_store.with_rumor(service_group, member_id, |s| {
+            left = s.map_or(false, |s| s.get_tombstone())
+        });
+        left
+    }
+
+    /// Gossip that we no longer run a service, replacing our service rumor with a tombstone.
+    pub fn tombstone_service(&self, service_group: &str) {
+        let mut tombstone = None;
+        self.service_store.with_rumor(service_group, self.member_id(), |s| {
+            tombstone = s.and_then(|s| if s.get_tombstone() {
+                None
+            } else {
+                Some(s.tombstone())
+            })
+        });
+        if let Some(tombstone) = tombstone {
+            self.insert_service(tombstone);
+        }
+    }
+
+    /// Removes rumors that have been garbage for longer than the rumor TTL, and stops sharing
+    /// them. Garbage is:
+    ///
+    /// * Tombstones
+    /// * Service rumors of departed members
+    /// * Configs, files and elections of service groups whose every service rumor is garbage
+    ///
+    /// A group with no service rumors at all keeps its configs and files, since they're often
+    /// applied before any member runs the service. Returns how many rumors were removed.
+    pub fn expire_rumors(&self, ttl: TimeDuration) -> usize {
+        let now = self.clock.now();
+        let is_garbage = |s: &Service| {
+            s.get_tombstone() ||
+                self.member_list.check_health_of_by_id(
+                    s.get_member_id(),
+                    Health::Departed,
+                )
+        };
+        let mut gone_groups = HashSet::new();
+        self.service_store.with_keys(|(service_group, services)| {
+            if services.values().all(|s| is_garbage(s)) {
+                gone_groups.insert(service_group.clone());
+            }
+        });
+
+        let mut expired = self.service_store.expire(now, ttl, |s| is_garbage(s));
+        expired.extend(self.service_config_store.expire(now, ttl, |sc| {
+            gone_groups.contains(sc.get_service_group())
+        }));
+        expired.extend(self.service_file_store.expire(now, ttl, |sf| {
+            sf.get_tombstone() || gone_groups.contains(sf.get_service_group())
+        }));
+        expired.extend(self.election_store.expire(now, ttl, |e| {
+            gone_groups.contains(e.get_service_group())
+        }));
+        expired.extend(self.update_store.expire(now, ttl, |e| {
+            gone_groups.contains(e.get_service_group())
+        }));
+        for rk in expired.iter() {
+            debug!("Expired rumor {:?}", rk);
+            self.rumor_heat.stop_rumor(rk);
+        }
+        expired.len()
+    }
+
     /// Insert a service config rumor into the service store.
     pub fn insert_service_config(&self, service_config: ServiceConfig) {
         let rk = RumorKey::from(&service_config);
@@ -623,15 +809,57 @@ impl Server {
         }
     }
 
+    /// Adds a known revision of the ring key, such as one still in the key cache, along with the
+    /// schedule it was gossiped with. See `RingKeys::insert`.
+    pub fn add_ring_key(&self, ring_key: SymKey, activate_at: u64, retire_at: Option<u64>) {
+        self.ring_keys.insert(ring_key, activate_at, retire_at);
+    }
+
+    /// Returns the revisions of the ring key which have not been retired, newest first.
+    pub fn ring_key_revisions(&self) -> Vec<String> {
+        self.ring_keys.revisions()
+    }
+
+    /// Insert a ring key rumor into the ring key store. Rumors about another ring, or reaching a
+    /// ring without encryption, are ignored.
+    pub fn insert_ring_key(&self, ring_key: RingKey) {
+        let rk = RumorKey::from(&ring_key);
+        if !self.ring_keys.is_encrypted() {
+            return;
+        }
+        let key = match ring_key.sym_key() {
+            Ok(key) => key,
+            Err(err) => {
+                warn!(
+                    "Ignoring ring key rumor for {}, {}",
+                    ring_key.get_name_with_rev(),
+                    err
+                );
+                return;
+            }
+        };
+        let retire_at = match ring_key.get_retire_at() {
+            0 => None,
+            retire_at => Some(retire_at),
+        };
+        if !self.ring_keys.insert(key, ring_key.get_activate_at(), retire_at) {
+            return;
+        }
+        if self.ring_key_store.insert(ring_key) {
+            self.rumor_heat.start_hot_rumor(rk);
+        }
+    }
+
     /// Get all the Member ID's who are present in a given service group, and eligible to vote
     /// (alive)
     fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
         self.service_store.with_rumors(key, |s| {
-            if self.member_list.check_health_of_by_id(
-                s.get_member_id(),
-                Health::Alive,
-            )
+            if !s.get_tombstone() &&
+                self.member_list.check_health_of_by_id(
+                    s.get_member_id(),
+                    Health::Alive,
+                )
             {
                 electorate.push(String::from(s.get_member_id()));
             }
@@ -643,9 +871,10 @@ impl Server {
     pub fn get_total_population(&self, key: &str) -> usize {
         let mut total_pop = 0;
         self.service_store.with_rumors(key, |s| {
-            if self.member_list.check_in_voting_population_by_id(
-                s.get_member_id(),
-            )
+            if !s.get_tombstone() &&
+                self.member_list.check_in_voting_population_by_id(
+                    s.get_member_id(),
+                )
             {
                 total_pop += 1;
             }
@@ -656,13 +885,26 @@ impl Server {
     /// Check if a given service group has quorum to run an election.
     ///
     /// A given group has quorum if, from this servers perspective, it has an alive population that
-    /// is over 50%, and at least 3 members.
-    fn check_quorum(&self, key: &str) -> bool {
+    /// is over 50%, and at least 3 members. If the election asks for an explicit quorum size, the
+    /// group has quorum as soon as that many of its members are alive instead.
+    fn check_quorum(&self, key: &str, quorum: u32) -> bool {
         let electorate = self.get_electorate(key);
 
         let total_population = self.get_total_population(key);
         let alive_population = electorate.len();
 
+        if quorum > 0 {
+            if alive_population < quorum as usize {
+                trace!(
+                    "Quorum size: {}/{} - election cannot complete",
+                    alive_population,
+                    quorum
+                );
+                return false;
+            }
+            return true;
+        }
+
         if total_population < 3 {
             trace!(
                 "Quorum size: {}/3 - election cannot complete",
@@ -677,22 +919,36 @@ impl Server {
     /// Start an election for the given service group, declaring this members suitability and the
     /// term for the election.
     pub fn start_election(&self, sg: ServiceGroup, term: u64) {
+        self.start_election_with_quorum(sg, term, 0);
+    }
+
+    /// Start an election for the given service group which needs `quorum` alive members to
+    /// complete, rather than a majority of the group. A quorum of 0 means a majority.
+    pub fn start_election_with_quorum(&self, sg: ServiceGroup, term: u64, quorum: u32) {
         let suitability = self.suitability_lookup.get(&sg);
         let mut e = Election::new(self.member_id(), sg, suitability);
         e.set_term(term);
+        e.set_quorum(quorum);
         let ek = RumorKey::from(&e);
-        if !self.check_quorum(e.key()) {
+        if !self.check_quorum(e.key(), quorum) {
             e.no_quorum();
         }
         self.election_store.insert(e);
         self.rumor_heat.start_hot_rumor(ek);
     }
 
-    pub fn start_update_election(&self, sg: ServiceGroup, suitability: u64, term: u64) {
+    pub fn start_update_election(
+        &self,
+        sg: ServiceGroup,
+        suitability: u64,
+        term: u64,
+        quorum: u32,
+    ) {
         let mut e = ElectionUpdate::new(self.member_id(), sg, suitability);
         e.set_term(term);
+        e.set_quorum(quorum);
         let ek = RumorKey::from(&e);
-        if !self.check_quorum(e.key()) {
+        if !self.check_quorum(e.key(), quorum) {
             e.no_quorum();
         }
         self.update_store.insert(e);
@@ -703,16 +959,13 @@ impl Server {
     ///
     /// a) We are the leader, and we have lost quorum with the rest of the group.
     /// b) We are not the leader, and we have detected that the leader is confirmed dead.
+    /// c) We are not the leader, and the leader has tombstoned its service rumor.
     pub fn restart_elections(&self) {
         let mut elections_to_restart = vec![];
         let mut update_elections_to_restart = vec![];
 
         self.election_store.with_keys(|(service_group, rumors)| {
-            if self.service_store.contains_rumor(
-                &service_group,
-                self.member_id(),
-            )
-            {
+            if self.runs_service(&service_group, self.member_id()) {
                 // This is safe; there is only one id for an election, and it is "election"
                 let election = rumors.get("election").expect(
                     "Lost an election struct between looking it up and reading it.",
@@ -720,7 +973,7 @@ impl Server {
                 // If we are finished, and the leader is dead, we should restart the election
                 if election.is_finished() && election.get_member_id() == self.member_id() {
                     // If we are the leader, and we have lost quorum, we should restart the election
-                    if self.check_quorum(election.key()) == false {
+                    if self.check_quorum(election.key(), election.get_quorum()) == false {
                         warn!(
                             "Restarting election with a new term as the leader has lost \
                               quorum: {:?}",
@@ -729,6 +982,7 @@ impl Server {
                         elections_to_restart.push((
                             String::from(&service_group[..]),
                             election.get_term(),
+                            election.get_quorum(),
                         ));
 
                     }
@@ -746,6 +1000,19 @@ impl Server {
                         elections_to_restart.push((
                             String::from(&service_group[..]),
                             election.get_term(),
+                            election.get_quorum(),
+                        ));
+                    } else if self.left_service(&service_group, election.get_member_id()) {
+                        warn!(
+                            "Restarting election with a new term as the leader left the \
+                              service {}: {:?}",
+                            self.member_id(),
+                            election
+                        );
+                        elections_to_restart.push((
+                            String::from(&service_group[..]),
+                            election.get_term(),
+                            election.get_quorum(),
                         ));
                     }
                 }
@@ -753,11 +1020,7 @@ impl Server {
         });
 
         self.update_store.with_keys(|(service_group, rumors)| {
-            if self.service_store.contains_rumor(
-                &service_group,
-                self.member_id(),
-            )
-            {
+            if self.runs_service(&service_group, self.member_id()) {
                 // This is safe; there is only one id for an election, and it is "election"
                 let election = rumors.get("election").expect(
                     "Lost an update election struct between looking it up and reading it.",
@@ -765,7 +1028,7 @@ impl Server {
                 // If we are finished, and the leader is dead, we should restart the election
                 if election.is_finished() && election.get_member_id() == self.member_id() {
                     // If we are the leader, and we have lost quorum, we should restart the election
-                    if self.check_quorum(election.key()) == false {
+                    if self.check_quorum(election.key(), election.get_quorum()) == false {
                         warn!(
                             "Restarting election with a new term as the leader has lost \
                               quorum: {:?}",
@@ -774,6 +1037,7 @@ impl Server {
                         update_elections_to_restart.push((
                             String::from(&service_group[..]),
                             election.get_term(),
+                            election.get_quorum(),
                         ));
 
                     }
@@ -791,13 +1055,26 @@ impl Server {
                         update_elections_to_restart.push((
                             String::from(&service_group[..]),
                             election.get_term(),
+                            election.get_quorum(),
+                        ));
+                    } else if self.left_service(&service_group, election.get_member_id()) {
+                        warn!(
+                            "Restarting election with a new term as the leader left the \
+                              service {}: {:?}",
+                            self.member_id(),
+                            election
+                        );
+                        update_elections_to_restart.push((
+                            String::from(&service_group[..]),
+                            election.get_term(),
+                            election.get_quorum(),
                         ));
                     }
                 }
             }
         });
 
-        for (service_group, old_term) in elections_to_restart {
+        for (service_group, old_term, quorum) in elections_to_restart {
             let sg = match ServiceGroup::from_str(&service_group) {
                 Ok(sg) => sg,
                 Err(e) => {
@@ -812,10 +1089,10 @@ impl Server {
             let term = old_term + 1;
             warn!("Starting a new election for {} {}", sg, term);
             self.election_store.remove(&service_group, "election");
-            self.start_election(sg, term);
+            self.start_election_with_quorum(sg, term, quorum);
         }
 
-        for (service_group, old_term) in update_elections_to_restart {
+        for (service_group, old_term, quorum) in update_elections_to_restart {
             let sg = match ServiceGroup::from_str(&service_group) {
                 Ok(sg) => sg,
                 Err(e) => {
@@ -830,7 +1107,7 @@ impl Server {
             let term = old_term + 1;
             warn!("Starting a new election for {} {}", sg, term);
             self.update_store.remove(&service_group, "election");
-            self.start_update_election(sg, 0, term);
+            self.start_update_election(sg, 0, term, quorum);
         }
     }
 
@@ -841,11 +1118,7 @@ impl Server {
         let rk = RumorKey::from(&election);
 
         // If this is an election for a service group we care about
-        if self.service_store.contains_rumor(
-            election.get_service_group(),
-            self.member_id(),
-        )
-        {
+        if self.runs_service(election.get_service_group(), self.member_id()) {
             // And the election store already has an election rumor for this election
             if self.election_store.contains_rumor(
                 election.key(),
@@ -869,12 +1142,16 @@ impl Server {
                             return;
                         }
                     };
-                    self.start_election(sg, election.get_term());
+                    self.start_election_with_quorum(
+                        sg,
+                        election.get_term(),
+                        election.get_quorum(),
+                    );
                 }
                 // If we are the member that this election is voting for, then check to see if the
                 // election is over! If it is, mark this election as final before you process it.
                 if self.member_id() == election.get_member_id() {
-                    if self.check_quorum(election.key()) {
+                    if self.check_quorum(election.key(), election.get_quorum()) {
                         let electorate = self.get_electorate(election.key());
                         let mut num_votes = 0;
                         for vote in election.get_votes().iter() {
@@ -907,10 +1184,14 @@ impl Server {
                         return;
                     }
                 };
-                self.start_election(sg, election.get_term());
+                self.start_election_with_quorum(
+                    sg,
+                    election.get_term(),
+                    election.get_quorum(),
+                );
             }
             if !election.is_finished() {
-                let has_quorum = self.check_quorum(election.key());
+                let has_quorum = self.check_quorum(election.key(), election.get_quorum());
                 if has_quorum {
                     election.running();
                 } else {
@@ -927,11 +1208,7 @@ impl Server {
         let rk = RumorKey::from(&election);
 
         // If this is an election for a service group we care about
-        if self.service_store.contains_rumor(
-            election.get_service_group(),
-            self.member_id(),
-        )
-        {
+        if self.runs_service(election.get_service_group(), self.member_id()) {
             // And the election store already has an election rumor for this election
             if self.update_store.contains_rumor(
                 election.key(),
@@ -955,12 +1232,17 @@ impl Server {
                             return;
                         }
                     };
-                    self.start_update_election(sg, 0, election.get_term());
+                    self.start_update_election(
+                        sg,
+                        0,
+                        election.get_term(),
+                        election.get_quorum(),
+                    );
                 }
                 // If we are the member that this election is voting for, then check to see if the
                 // election is over! If it is, mark this election as final before you process it.
                 if self.member_id() == election.get_member_id() {
-                    if self.check_quorum(election.key()) {
+                    if self.check_quorum(election.key(), election.get_quorum()) {
                         let electorate = self.get_electorate(election.key());
                         let mut num_votes = 0;
                         for vote in election.get_votes().iter() {
@@ -993,10 +1275,15 @@ impl Server {
                         return;
                     }
                 };
-                self.start_update_election(sg, 0, election.get_term());
+                self.start_update_election(
+                    sg,
+                    0,
+                    election.get_term(),
+                    election.get_quorum(),
+                );
             }
             if !election.is_finished() {
-                let has_quorum = self.check_quorum(election.key());
+                let has_quorum = self.check_quorum(election.key(), election.get_quorum());
                 if has_quorum {
                     election.running();
                 } else {
@@ -1010,11 +1297,11 @@ impl Server {
     }
 
     fn generate_wire(&self, payload: Vec<u8>) -> Result<Vec<u8>> {
-        message::generate_wire(payload, (*self.ring_key).as_ref())
+        message::generate_wire(payload, self.ring_keys.encryption_key().as_ref())
     }
 
     fn unwrap_wire(&self, payload: &[u8]) -> Result<Vec<u8>> {
-        message::unwrap_wire(payload, (*self.ring_key).as_ref())
+        message::unwrap_wire(payload, &self.ring_keys)
     }
 
     fn persist_data(&self) {
@@ -1036,7 +1323,7 @@ impl Serialize for Server {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("butterfly", 6)?;
+        let mut strukt = serializer.serialize_struct("butterfly", 8)?;
         strukt.serialize_field("member", &self.member_list)?;
         strukt.serialize_field("service", &self.service_store)?;
         strukt.serialize_field(
@@ -1053,6 +1340,7 @@ impl Serialize for Server {
             &self.update_store,
         )?;
         strukt.serialize_field("departure", &self.departure_store)?;
+        strukt.serialize_field("ring_key", &self.ring_key_store)?;
         strukt.end()
     }
 }
@@ -1077,10 +1365,9 @@ impl Drop for Server {
 
 fn persist_loop(server: Server) {
     loop {
-        let next_check = Instant::now() + Duration::from_millis(30_000);
+        let next_check = server.clock.now() + TimeDuration::milliseconds(30_000);
         server.persist_data();
-        let time_to_wait = next_check - Instant::now();
-        thread::sleep(time_to_wait);
+        server.clock.sleep_until(next_check);
     }
 }
 
@@ -1090,8 +1377,12 @@ mod tests {
         use habitat_core::service::ServiceGroup;
         use server::{Server, Suitability};
         use server::timing::Timing;
-        use member::Member;
+        use member::{Health, Member};
+        use rumor::dat_file::DatFile;
         use trace::Trace;
+        use std::collections::BTreeMap;
+        use std::env;
+        use std::fs;
         use std::path::PathBuf;
         use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 
@@ -1107,13 +1398,16 @@ mod tests {
         }
 
         fn start_server() -> Server {
+            start_server_with(Member::default(), None)
+        }
+
+        fn start_server_with(mut member: Member, data_path: Option<PathBuf>) -> Server {
             SWIM_PORT.compare_and_swap(0, 6666, Ordering::Relaxed);
             GOSSIP_PORT.compare_and_swap(0, 7777, Ordering::Relaxed);
             let swim_port = SWIM_PORT.fetch_add(1, Ordering::Relaxed);
             let swim_listen = format!("127.0.0.1:{}", swim_port);
             let gossip_port = GOSSIP_PORT.fetch_add(1, Ordering::Relaxed);
             let gossip_listen = format!("127.0.0.1:{}", gossip_port);
-            let mut member = Member::default();
             member.set_swim_port(swim_port as i32);
             member.set_gossip_port(gossip_port as i32);
             Server::new(
@@ -1123,7 +1417,7 @@ mod tests {
                 Trace::default(),
                 None,
                 None,
-                None::<PathBuf>,
+                data_path,
                 Box::new(ZeroSuitability),
             ).unwrap()
         }
@@ -1159,5 +1453,37 @@ mod tests {
                 "Server failed to start",
             );
         }
+
+        #[test]
+        fn start_increments_persisted_incarnation_when_labels_change() {
+            let mut old = Member::default();
+            old.set_incarnation(5);
+            let mut labels = BTreeMap::new();
+            labels.insert("rack".to_string(), "r1".to_string());
+            old.set_label_map(&labels);
+            let data_path = env::temp_dir().join(format!("butterfly-{}", old.get_id()));
+            fs::create_dir_all(&data_path).unwrap();
+            let previous = start_server();
+            previous.insert_member(old.clone(), Health::Alive);
+            DatFile::new(old.get_id(), &data_path)
+                .write(&previous)
+                .unwrap();
+
+            let mut member = old.clone();
+            member.set_incarnation(0);
+            labels.insert("rack".to_string(), "r2".to_string());
+            member.set_label_map(&labels);
+            let mut server = start_server_with(member, Some(data_path.clone()));
+            server.start(Timing::default()).expect(
+                "Server failed to start",
+            );
+            fs::remove_dir_all(&data_path).unwrap();
+
+            let me = server.member.read().unwrap().clone();
+            assert_eq!(me.get_incarnation(), 6);
+            assert_eq!(me.labels(), labels);
+            let members = server.member_list.members.read().unwrap();
+            assert_eq!(members.get(me.get_id()).unwrap().labels(), labels);
+        }
     }
 }
@@ -16,14 +16,13 @@
 //!
 //! This module handles the implementation of the swim probe protocol.
 
+use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use std::sync::mpsc;
-use std::net::{SocketAddr, UdpSocket};
-use std::thread;
+use std::net::SocketAddr;
 use std::time::Duration;
 use std::fmt;
 
-use time::SteadyTime;
 use protobuf::{Message, RepeatedField};
 
 use message::swim::{Ack, Ping, PingReq, Swim, Swim_Type, Rumor_Type};
@@ -31,6 +30,7 @@ use rumor::RumorKey;
 use server::Server;
 use server::timing::Timing;
 use member::{Member, Health};
+use network::SwimSocket;
 use trace::TraceKind;
 
 /// How long to sleep between calls to `recv`.
@@ -55,7 +55,7 @@ impl fmt::Display for AckFrom {
 /// The outbound thread
 pub struct Outbound {
     pub server: Server,
-    pub socket: UdpSocket,
+    pub socket: Arc<SwimSocket>,
     pub rx_inbound: mpsc::Receiver<(SocketAddr, Swim)>,
     pub timing: Timing,
 }
@@ -64,7 +64,7 @@ impl Outbound {
     /// Creates a new Outbound struct.
     pub fn new(
         server: Server,
-        socket: UdpSocket,
+        socket: Arc<SwimSocket>,
         rx_inbound: mpsc::Receiver<(SocketAddr, Swim)>,
         timing: Timing,
     ) -> Outbound {
@@ -95,7 +95,7 @@ impl Outbound {
                     self.server.member_list.with_initial_members(|member| {
                         ping(
                             &self.server,
-                            &self.socket,
+                            &*self.socket,
                             &member,
                             member.swim_socket_address(),
                             None,
@@ -105,13 +105,13 @@ impl Outbound {
             }
 
             if self.server.pause.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(100));
+                self.server.clock.sleep(Duration::from_millis(100));
                 continue;
             }
 
             self.server.update_swim_round();
 
-            let long_wait = self.timing.next_protocol_period();
+            let long_wait = self.timing.next_protocol_period(self.server.clock.now());
 
             let check_list = self.server.member_list.check_list(
                 self.server
@@ -126,27 +126,16 @@ impl Outbound {
                     // This is the timeout for the next protocol period - if we
                     // complete faster than this, we want to wait in the end
                     // until this timer expires.
-                    let next_protocol_period = self.timing.next_protocol_period();
+                    let next_protocol_period =
+                        self.timing.next_protocol_period(self.server.clock.now());
 
                     self.probe(member);
 
-                    if SteadyTime::now() <= next_protocol_period {
-                        let wait_time = (next_protocol_period - SteadyTime::now())
-                            .num_milliseconds();
-                        if wait_time > 0 {
-                            debug!("Waiting {} until the next protocol period", wait_time);
-                            thread::sleep(Duration::from_millis(wait_time as u64));
-                        }
-                    }
+                    self.server.clock.sleep_until(next_protocol_period);
                 }
             }
 
-            if SteadyTime::now() <= long_wait {
-                let wait_time = (long_wait - SteadyTime::now()).num_milliseconds();
-                if wait_time > 0 {
-                    thread::sleep(Duration::from_millis(wait_time as u64));
-                }
-            }
+            self.server.clock.sleep_until(long_wait);
         }
     }
 
@@ -166,14 +155,18 @@ impl Outbound {
     /// PING_RECV_QUEUE_EMPTY_SLEEP_MS, and try again.
     ///
     /// If we don't receive anything at all in the Ping/PingReq loop, we mark the member as Suspect.
+    ///
+    /// An Ack to our Ping improves our local health score, and hearing nothing at all worsens
+    /// it; the score stretches how long we wait for Acks.
     fn probe(&mut self, member: Member) {
         let addr = member.swim_socket_address();
 
         trace_it!(PROBE: &self.server, TraceKind::ProbeBegin, member.get_id(), addr);
 
         // Ping the member, and wait for the ack.
-        ping(&self.server, &self.socket, &member, addr, None);
+        ping(&self.server, &*self.socket, &member, addr, None);
         if self.recv_ack(&member, addr, AckFrom::Ping) {
+            self.server.improve_local_health();
             trace_it!(PROBE: &self.server, TraceKind::ProbeAckReceived, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
             return;
@@ -187,7 +180,7 @@ impl Outbound {
                           TraceKind::ProbePingReq,
                           pingreq_target.get_id(),
                           pingreq_target.get_address());
-                pingreq(&self.server, &self.socket, &pingreq_target, &member);
+                pingreq(&self.server, &*self.socket, &pingreq_target, &member);
             },
         );
         if !self.recv_ack(&member, addr, AckFrom::PingReq) {
@@ -195,6 +188,7 @@ impl Outbound {
             // into the suspicion phase, where anyone marked as suspect has a certain number of
             // protocol periods to recover.
             warn!("Marking {} as Suspect", member.get_id());
+            self.server.degrade_local_health();
             trace_it!(PROBE: &self.server, TraceKind::ProbeSuspect, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
             self.server.insert_member(member, Health::Suspect);
@@ -205,9 +199,11 @@ impl Outbound {
 
     /// Listen for an ack from the `Inbound` thread.
     fn recv_ack(&mut self, member: &Member, addr: SocketAddr, ack_from: AckFrom) -> bool {
+        let local_health = self.server.local_health();
+        let now = self.server.clock.now();
         let timeout = match ack_from {
-            AckFrom::Ping => self.timing.ping_timeout(),
-            AckFrom::PingReq => self.timing.pingreq_timeout(),
+            AckFrom::Ping => self.timing.ping_timeout(now, local_health),
+            AckFrom::PingReq => self.timing.pingreq_timeout(now, local_health),
         };
         loop {
             match self.rx_inbound.try_recv() {
@@ -240,7 +236,7 @@ impl Outbound {
                     }
                 }
                 Err(mpsc::TryRecvError::Empty) => {
-                    if SteadyTime::now() > timeout {
+                    if self.server.clock.now() > timeout {
                         warn!(
                             "Timed out waiting for Ack from {}@{}",
                             member.get_id(),
@@ -248,7 +244,9 @@ impl Outbound {
                         );
                         return false;
                     }
-                    thread::sleep(Duration::from_millis(PING_RECV_QUEUE_EMPTY_SLEEP_MS));
+                    self.server.clock.sleep(
+                        Duration::from_millis(PING_RECV_QUEUE_EMPTY_SLEEP_MS),
+                    );
                 }
                 Err(mpsc::TryRecvError::Disconnected) => {
                     panic!("Outbound thread has disconnected! This is fatal.");
@@ -263,7 +261,9 @@ pub fn populate_membership_rumors(server: &Server, target: &Member, swim: &mut S
     let mut membership_entries = RepeatedField::new();
     // If this isn't the first time we are communicating with this target, we want to include this
     // targets current status. This ensures that members always get a "Confirmed" rumor, before we
-    // have the chance to flip it to "Alive", which helps make sure we heal from a partition.
+    // have the chance to flip it to "Alive", which helps make sure we heal from a partition. It
+    // also tells a member we suspect about it directly, rather than leaving it to hear through
+    // gossip, so it can refute the suspicion in its Ack.
     if server.member_list.contains_member(target.get_id()) {
         if let Some(always_target) = server.member_list.membership_for(target.get_id()) {
             membership_entries.push(always_target);
@@ -294,7 +294,7 @@ pub fn populate_membership_rumors(server: &Server, target: &Member, swim: &mut S
 }
 
 /// Send a PingReq.
-pub fn pingreq(server: &Server, socket: &UdpSocket, pingreq_target: &Member, target: &Member) {
+pub fn pingreq(server: &Server, socket: &SwimSocket, pingreq_target: &Member, target: &Member) {
     let addr = pingreq_target.swim_socket_address();
     let mut swim = Swim::new();
     swim.set_field_type(Swim_Type::PINGREQ);
@@ -353,7 +353,7 @@ pub fn pingreq(server: &Server, socket: &UdpSocket, pingreq_target: &Member, tar
 /// Send a Ping.
 pub fn ping(
     server: &Server,
-    socket: &UdpSocket,
+    socket: &SwimSocket,
     target: &Member,
     addr: SocketAddr,
     mut forward_to: Option<Member>,
@@ -412,7 +412,7 @@ pub fn ping(
 }
 
 /// Forward an ack on.
-pub fn forward_ack(server: &Server, socket: &UdpSocket, addr: SocketAddr, swim: Swim) {
+pub fn forward_ack(server: &Server, socket: &SwimSocket, addr: SocketAddr, swim: Swim) {
     trace_it!(
         SWIM: server,
         TraceKind::SendForwardAck,
@@ -458,7 +458,7 @@ pub fn forward_ack(server: &Server, socket: &UdpSocket, addr: SocketAddr, swim:
 /// Send an Ack.
 pub fn ack(
     server: &Server,
-    socket: &UdpSocket,
+    socket: &SwimSocket,
     target: &Member,
     addr: SocketAddr,
     mut forward_to: Option<Member>,
@@ -14,52 +14,43 @@
 
 //! The pull thread.
 //!
-//! This module handles pulling all the pushed rumors from every member off a ZMQ socket.
+//! This module handles pulling all the pushed rumors from every member off the server's gossip
+//! socket.
 
 use std::sync::atomic::Ordering;
-use std::thread;
 use std::time::Duration;
 
 use protobuf;
-use zmq;
 
-use ZMQ_CONTEXT;
+use network::GossipReceiver;
 use server::Server;
 use message::swim::{Rumor, Rumor_Type};
 use trace::TraceKind;
 
-/// Takes a reference to the server itself
+/// Takes a reference to the server itself, and the socket bound to its `gossip_addr`
 pub struct Pull {
     pub server: Server,
+    pub socket: Box<GossipReceiver>,
 }
 
 impl Pull {
     /// Create a new Pull
-    pub fn new(server: Server) -> Pull {
-        Pull { server: server }
+    pub fn new(server: Server, socket: Box<GossipReceiver>) -> Pull {
+        Pull {
+            server: server,
+            socket: socket,
+        }
     }
 
-    /// Run this thread. Creates a socket, binds to the `gossip_addr`, then processes messages as
-    /// they are received. Uses a ZMQ pull socket, so inbound messages are fair-queued.
+    /// Run this thread. Processes messages as they are received. On the real network, the socket
+    /// is a ZMQ pull socket, so inbound messages are fair-queued.
     pub fn run(&mut self) {
-        let socket = (**ZMQ_CONTEXT).as_mut().socket(zmq::PULL).expect(
-            "Failure to create the ZMQ pull socket",
-        );
-        socket.set_linger(0).expect(
-            "Failure to set the ZMQ Pull socket to not linger",
-        );
-        socket.set_tcp_keepalive(0).expect(
-            "Failure to set the ZMQ Pull socket to not use keepalive",
-        );
-        socket
-            .bind(&format!("tcp://{}", self.server.gossip_addr()))
-            .expect("Failure to bind the ZMQ Pull socket to the port");
         'recv: loop {
             if self.server.pause.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(100));
+                self.server.clock.sleep(Duration::from_millis(100));
                 continue;
             }
-            let msg = match socket.recv_msg(0) {
+            let msg = match self.socket.recv() {
                 Ok(msg) => msg,
                 Err(e) => {
                     error!("Error receiving message: {:?}", e);
@@ -92,9 +83,7 @@ impl Pull {
             trace_it!(GOSSIP: &self.server, TraceKind::RecvRumor, proto.get_from_id(), &proto);
             match proto.get_field_type() {
                 Rumor_Type::Member => {
-                    let member = proto.mut_member().take_member().into();
-                    let health = proto.mut_member().get_health().into();
-                    self.server.insert_member_from_rumor(member, health);
+                    self.server.insert_member_from_rumor(proto.take_member());
                 }
                 Rumor_Type::Service => {
                     self.server.insert_service(proto.into());
@@ -114,6 +103,9 @@ impl Pull {
                 Rumor_Type::Departure => {
                     self.server.insert_departure(proto.into());
                 }
+                Rumor_Type::RingKey => {
+                    self.server.insert_ring_key(proto.into());
+                }
                 Rumor_Type::Fake |
                 Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
             }
@@ -18,16 +18,11 @@
 //! more often than `Timing::GOSSIP_PERIOD_DEFAULT_MS`.
 
 use std::sync::atomic::Ordering;
-use std::thread;
 use std::time::Duration;
 
 use protobuf::Message;
-use time::SteadyTime;
-use zmq;
 
-use ZMQ_CONTEXT;
-use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type, Member as ProtoMember,
-                    Membership as ProtoMembership};
+use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type};
 use rumor::RumorKey;
 use member::Member;
 use server::Server;
@@ -59,14 +54,14 @@ impl Push {
     pub fn run(&mut self) {
         'send: loop {
             if self.server.pause.load(Ordering::Relaxed) {
-                thread::sleep(Duration::from_millis(100));
+                self.server.clock.sleep(Duration::from_millis(100));
                 continue;
             }
 
             self.server.update_gossip_round();
 
             let mut check_list = self.server.member_list.check_list(self.server.member_id());
-            let long_wait = self.timing.gossip_timeout();
+            let long_wait = self.timing.gossip_timeout(self.server.clock.now());
 
             'fanout: loop {
                 let mut thread_list = Vec::with_capacity(FANOUT);
@@ -78,7 +73,7 @@ impl Push {
                 } else {
                     check_list.len()
                 };
-                let next_gossip = self.timing.gossip_timeout();
+                let next_gossip = self.timing.gossip_timeout(self.server.clock.now());
                 for member in check_list.drain(0..drain_length) {
                     if self.server.check_blacklist(member.get_id()) {
                         debug!(
@@ -97,11 +92,12 @@ impl Push {
                         if rumors.len() > 0 {
                             let sc = self.server.clone();
 
-                            let guard = match thread::Builder::new()
-                                      .name(String::from("push-worker"))
-                                      .spawn(move || {
-                                                 PushWorker::new(sc).send_rumors(member, rumors);
-                                             }) {
+                            let guard = match self.server.clock.spawn(
+                                String::from("push-worker"),
+                                Box::new(move || {
+                                    PushWorker::new(sc).send_rumors(member, rumors);
+                                }),
+                            ) {
                                 Ok(guard) => guard,
                                 Err(e) => {
                                     error!("Could not spawn thread: {}", e);
@@ -118,19 +114,9 @@ impl Push {
                         |e| println!("Push worker died: {:?}", e),
                     );
                 }
-                if SteadyTime::now() < next_gossip {
-                    let wait_time = (next_gossip - SteadyTime::now()).num_milliseconds();
-                    if wait_time > 0 {
-                        thread::sleep(Duration::from_millis(wait_time as u64));
-                    }
-                }
-            }
-            if SteadyTime::now() < long_wait {
-                let wait_time = (long_wait - SteadyTime::now()).num_milliseconds();
-                if wait_time > 0 {
-                    thread::sleep(Duration::from_millis(wait_time as u64));
-                }
+                self.server.clock.sleep_until(next_gossip);
             }
+            self.server.clock.sleep_until(long_wait);
         }
     }
 }
@@ -146,37 +132,24 @@ impl PushWorker {
         PushWorker { server: server }
     }
 
-    /// Send the list of rumors to a given member. This method creates an outbound socket and then
-    /// closes the connection as soon as we are done sending rumors. ZeroMQ may choose to keep the
-    /// connection and socket open for 1 second longer - so it is possible, but unlikely, that this
-    /// method can loose messages.
+    /// Send the list of rumors to a given member. This method connects to the member and then
+    /// closes the connection as soon as we are done sending rumors; see
+    /// `Network::connect_gossip`.
     fn send_rumors(&self, member: Member, rumors: Vec<RumorKey>) {
-        let socket = (**ZMQ_CONTEXT).as_mut().socket(zmq::PUSH).expect(
-            "Failure to create the ZMQ push socket",
-        );
-        socket.set_linger(1000).expect(
-            "Failure to set the ZMQ push socket to not linger",
-        );
-        socket.set_tcp_keepalive(0).expect(
-            "Failure to set the ZMQ push socket to not use keepalive",
-        );
-        socket.set_immediate(true).expect(
-            "Failure to set the ZMQ push socket to immediate",
-        );
-        socket.set_sndhwm(1000).expect(
-            "Failure to set the ZMQ push socket hwm",
-        );
-        socket.set_sndtimeo(500).expect(
-            "Failure to set the ZMQ send timeout",
-        );
-        let to_addr = format!("{}:{}", member.get_address(), member.get_gossip_port());
-        match socket.connect(&format!("tcp://{}", to_addr)) {
-            Ok(()) => debug!("Connected push socket to {:?}", member),
+        let to_addr = member.gossip_socket_address();
+        let socket = match self.server.network.connect_gossip(
+            self.server.gossip_addr(),
+            to_addr,
+        ) {
+            Ok(socket) => {
+                debug!("Connected push socket to {:?}", member);
+                socket
+            }
             Err(e) => {
                 println!("Cannot connect push socket to {:?}: {:?}", member, e);
                 return;
             }
-        }
+        };
         'rumorlist: for ref rumor_key in rumors.iter() {
             let rumor_as_bytes = match rumor_key.kind {
                 ProtoRumor_Type::Member => {
@@ -277,6 +250,22 @@ impl PushWorker {
                         }
                     }
                 }
+                ProtoRumor_Type::RingKey => {
+                    match self.server.ring_key_store.write_to_bytes(
+                        &rumor_key.key,
+                        &rumor_key.id,
+                    ) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!(
+                                "Could not write our own rumor to bytes; abandoning \
+                                            sending rumor: {:?}",
+                                e
+                            );
+                            continue 'rumorlist;
+                        }
+                    }
+                }
                 ProtoRumor_Type::Election => {
                     // trace_it!(GOSSIP: &self.server,
                     //           TraceKind::SendRumor,
@@ -326,11 +315,11 @@ impl PushWorker {
                     continue 'rumorlist;
                 }
             };
-            match socket.send(&payload, 0) {
+            match socket.send(&payload) {
                 Ok(()) => debug!("Sent rumor {:?} to {:?}", rumor_key, member),
                 Err(e) => {
                     println!(
-                        "Could not send rumor to {:?} @ {:?}; ZMQ said: {:?}",
+                        "Could not send rumor to {:?} @ {:?}: {:?}",
                         member.get_id(),
                         to_addr,
                         e
@@ -341,26 +330,13 @@ impl PushWorker {
         self.server.rumor_heat.cool_rumors(member.get_id(), &rumors);
     }
 
-    /// Given a rumorkey, creates a protobuf rumor for sharing.
+    /// Given a rumorkey, creates a protobuf rumor for sharing. A suspect member's rumor carries
+    /// every member who suspects it.
     fn create_member_rumor(&self, rumor_key: &RumorKey) -> Option<ProtoRumor> {
-        let mut member: Option<ProtoMember> = None;
-        self.server.member_list.with_member(&rumor_key.key(), |m| {
-            if let Some(m) = m {
-                member = Some(m.proto.clone());
-            }
-        });
-        if member.is_none() {
-            return None;
-        }
-        let mut membership = ProtoMembership::new();
-        membership.set_member(member.unwrap());
-        membership.set_health(
-            self.server
-                .member_list
-                .health_of_by_id(&rumor_key.key())
-                .unwrap()
-                .into(),
-        );
+        let membership = match self.server.member_list.membership_for(&rumor_key.key()) {
+            Some(membership) => membership,
+            None => return None,
+        };
         let mut rumor = ProtoRumor::new();
         rumor.set_field_type(ProtoRumor_Type::Member);
         rumor.set_member(membership);
@@ -12,19 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+
 use time::{SteadyTime, Duration as TimeDuration};
 
+/// The highest local health score; a member this unhealthy waits this many times longer, plus
+/// one, for Acks.
+pub const LOCAL_HEALTH_MAX: usize = 8;
+
 /// How long to wait for an Ack after we ping
 const PING_TIMING_DEFAULT_MS: i64 = 1000;
 /// How long to wait for an Ack after we PingReq - should be at least 2x the PING_TIMING_DEFAULT_MS
 const PINGREQ_TIMING_DEFAULT_MS: i64 = 2100;
-/// How many protocol periods before a suspect member is marked as confirmed.
+/// How many protocol periods before a suspect member is marked as confirmed, once enough other
+/// members have confirmed the suspicion.
 const SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS: i64 = 3;
+/// How many times longer than the shortest suspicion timeout a suspicion nobody else has
+/// confirmed lasts.
+const SUSPICION_TIMEOUT_DEFAULT_MAX_MULTIPLIER: i64 = 4;
+/// How many independent confirmations shrink a suspicion timeout to its shortest.
+const SUSPICION_DEFAULT_CONFIRMATIONS: i64 = 3;
 /// How long is the gossip period
 const GOSSIP_PERIOD_DEFAULT_MS: i64 = 1000;
 /// How long before we set a confirmed member to a departed member, removing them from quorums
 ///   just for your own sanity - this is 3 days.
 const DEPARTURE_TIMEOUT_DEFAULT_MS: i64 = 259200000;
+/// How long a tombstone, or a rumor about a departed member's service, is kept before it's
+/// garbage collected - long enough for a tombstone to reach members across a healed partition.
+const RUMOR_TTL_DEFAULT_MS: i64 = 3600000;
 
 /// The timing of the outbound threads.
 #[derive(Debug, Clone)]
@@ -33,7 +48,10 @@ pub struct Timing {
     pub pingreq_ms: i64,
     pub gossip_period_ms: i64,
     pub suspicion_timeout_protocol_periods: i64,
+    pub suspicion_timeout_max_multiplier: i64,
+    pub suspicion_confirmations: i64,
     pub departure_timeout_ms: i64,
+    pub rumor_ttl_ms: i64,
 }
 
 impl Default for Timing {
@@ -43,7 +61,10 @@ impl Default for Timing {
             pingreq_ms: PINGREQ_TIMING_DEFAULT_MS,
             gossip_period_ms: GOSSIP_PERIOD_DEFAULT_MS,
             suspicion_timeout_protocol_periods: SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS,
+            suspicion_timeout_max_multiplier: SUSPICION_TIMEOUT_DEFAULT_MAX_MULTIPLIER,
+            suspicion_confirmations: SUSPICION_DEFAULT_CONFIRMATIONS,
             departure_timeout_ms: DEPARTURE_TIMEOUT_DEFAULT_MS,
+            rumor_ttl_ms: RUMOR_TTL_DEFAULT_MS,
         }
     }
 }
@@ -55,20 +76,26 @@ impl Timing {
         pingreq_ms: i64,
         gossip_period_ms: i64,
         suspicion_timeout_protocol_periods: i64,
+        suspicion_timeout_max_multiplier: i64,
+        suspicion_confirmations: i64,
         departure_timeout_ms: i64,
+        rumor_ttl_ms: i64,
     ) -> Timing {
         Timing {
             ping_ms: ping_ms,
             pingreq_ms: pingreq_ms,
             gossip_period_ms: gossip_period_ms,
             suspicion_timeout_protocol_periods: suspicion_timeout_protocol_periods,
+            suspicion_timeout_max_multiplier: suspicion_timeout_max_multiplier,
+            suspicion_confirmations: suspicion_confirmations,
             departure_timeout_ms: departure_timeout_ms,
+            rumor_ttl_ms: rumor_ttl_ms,
         }
     }
 
-    /// When should this gossip period expire
-    pub fn gossip_timeout(&self) -> SteadyTime {
-        SteadyTime::now() + TimeDuration::milliseconds(self.gossip_period_ms)
+    /// When should a gossip period starting `now` expire
+    pub fn gossip_timeout(&self, now: SteadyTime) -> SteadyTime {
+        now + TimeDuration::milliseconds(self.gossip_period_ms)
     }
 
     /// How long is a protocol period, in millis.
@@ -76,29 +103,101 @@ impl Timing {
         self.ping_ms + self.pingreq_ms
     }
 
-    /// When should this ping record time out?
-    pub fn ping_timeout(&self) -> SteadyTime {
-        SteadyTime::now() + TimeDuration::milliseconds(self.ping_ms)
+    /// When should a ping sent `now` time out? A member with a worse local health score waits
+    /// longer, so that its own slowness isn't mistaken for a failure of the member it pings.
+    pub fn ping_timeout(&self, now: SteadyTime, local_health: usize) -> SteadyTime {
+        now + TimeDuration::milliseconds(self.ping_ms * scale(local_health))
     }
 
-    /// When should this pingreq timeout?
-    pub fn pingreq_timeout(&self) -> SteadyTime {
-        SteadyTime::now() + TimeDuration::milliseconds(self.pingreq_ms)
+    /// When should a pingreq sent `now` time out? Scaled by the local health score like
+    /// `ping_timeout`.
+    pub fn pingreq_timeout(&self, now: SteadyTime, local_health: usize) -> SteadyTime {
+        now + TimeDuration::milliseconds(self.pingreq_ms * scale(local_health))
     }
 
-    /// How long before the next scheduled protocol period
-    pub fn next_protocol_period(&self) -> SteadyTime {
-        SteadyTime::now() + TimeDuration::milliseconds(self.ping_ms + self.pingreq_ms)
+    /// When the protocol period after one starting `now` is scheduled
+    pub fn next_protocol_period(&self, now: SteadyTime) -> SteadyTime {
+        now + TimeDuration::milliseconds(self.ping_ms + self.pingreq_ms)
     }
 
-    /// How long before this suspect entry times out
-    pub fn suspicion_timeout_duration(&self) -> TimeDuration {
-        TimeDuration::milliseconds(
-            self.protocol_period_ms() * self.suspicion_timeout_protocol_periods,
-        )
+    /// How long before a suspect entry times out, given how many other members independently
+    /// confirmed the suspicion and how many members are in the ring.
+    ///
+    /// An unconfirmed suspicion lasts `suspicion_timeout_max_multiplier` times the shortest
+    /// timeout, which gives a suspected member that is merely slow the time to refute it. Each
+    /// confirmation shrinks the timeout logarithmically, down to the shortest timeout once
+    /// `suspicion_confirmations` members (or every other member, in a small ring) agree.
+    pub fn suspicion_timeout_duration(&self, confirmations: usize, members: usize) -> TimeDuration {
+        let min = self.protocol_period_ms() * self.suspicion_timeout_protocol_periods;
+        let max = min * cmp::max(self.suspicion_timeout_max_multiplier, 1);
+        // Neither the suspected member nor the member who first suspected it can confirm.
+        let expected = cmp::min(
+            cmp::max(self.suspicion_confirmations, 0) as usize,
+            members.saturating_sub(2),
+        );
+        if expected == 0 || confirmations >= expected {
+            return TimeDuration::milliseconds(min);
+        }
+        let fraction = ((confirmations + 1) as f64).ln() / ((expected + 1) as f64).ln();
+        let timeout = max - ((max - min) as f64 * fraction) as i64;
+        TimeDuration::milliseconds(cmp::max(timeout, min))
     }
 
     pub fn departure_timeout_duration(&self) -> TimeDuration {
         TimeDuration::milliseconds(self.departure_timeout_ms)
     }
+
+    /// How long a rumor stays garbage before it's removed.
+    pub fn rumor_ttl_duration(&self) -> TimeDuration {
+        TimeDuration::milliseconds(self.rumor_ttl_ms)
+    }
+}
+
+/// The multiplier for a local health score.
+fn scale(local_health: usize) -> i64 {
+    (cmp::min(local_health, LOCAL_HEALTH_MAX) + 1) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ping_timeouts_scale_with_local_health() {
+        let timing = Timing::default();
+        let now = SteadyTime::now();
+        let healthy = timing.ping_timeout(now, 0) - now;
+        let unhealthy = timing.ping_timeout(now, LOCAL_HEALTH_MAX + 5) - now;
+        assert_eq!(healthy.num_milliseconds(), timing.ping_ms);
+        assert_eq!(
+            unhealthy.num_milliseconds(),
+            timing.ping_ms * (LOCAL_HEALTH_MAX + 1) as i64
+        );
+    }
+
+    #[test]
+    fn suspicion_timeout_shrinks_with_confirmations() {
+        let timing = Timing::default();
+        let min = timing.protocol_period_ms() * timing.suspicion_timeout_protocol_periods;
+        let max = min * timing.suspicion_timeout_max_multiplier;
+
+        assert_eq!(timing.suspicion_timeout_duration(0, 10).num_milliseconds(), max);
+        let one = timing.suspicion_timeout_duration(1, 10).num_milliseconds();
+        let two = timing.suspicion_timeout_duration(2, 10).num_milliseconds();
+        assert!(one < max && one > two && two > min);
+        assert_eq!(timing.suspicion_timeout_duration(3, 10).num_milliseconds(), min);
+        assert_eq!(timing.suspicion_timeout_duration(7, 10).num_milliseconds(), min);
+    }
+
+    #[test]
+    fn suspicion_timeout_in_a_small_ring() {
+        let timing = Timing::default();
+        let min = timing.protocol_period_ms() * timing.suspicion_timeout_protocol_periods;
+
+        // With two members, nobody can confirm a suspicion.
+        assert_eq!(timing.suspicion_timeout_duration(0, 2).num_milliseconds(), min);
+        // With three, one confirmation is all there can be.
+        assert!(timing.suspicion_timeout_duration(0, 3).num_milliseconds() > min);
+        assert_eq!(timing.suspicion_timeout_duration(1, 3).num_milliseconds(), min);
+    }
 }
@@ -398,6 +398,12 @@ macro_rules! trace_it {
                     Rumor_Type::Departure => {
                         format!("{}", $payload.get_departure().get_member_id())
                     }
+                    Rumor_Type::RingKey => {
+                        format!("{}-{}-{}",
+                                $payload.get_ring_key().get_name_with_rev(),
+                                $payload.get_ring_key().get_activate_at(),
+                                $payload.get_ring_key().get_retire_at())
+                    }
                     Rumor_Type::Fake | Rumor_Type::Fake2 => format!("nothing-to-see"),
                 };
 
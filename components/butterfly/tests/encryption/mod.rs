@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::thread;
+use std::time::Duration;
+
+use habitat_butterfly::client::Client;
 use habitat_butterfly::member::Health;
+use habitat_butterfly::ring_keys;
 use habitat_core::crypto::keys::sym_key::SymKey;
 
 use btest;
@@ -32,3 +37,48 @@ fn symmetric_encryption_of_wire_payloads() {
         |u| assert!(u.is_some()),
     );
 }
+
+#[test]
+fn rotating_the_ring_key_keeps_members_talking() {
+    let old_key =
+        SymKey::generate_in_memory("wolverine").expect("Failed to generate an in memory symkey");
+    let generated =
+        SymKey::generate_in_memory("wolverine").expect("Failed to generate an in memory symkey");
+    // Key revisions have a resolution of one second, so make sure the new one sorts last.
+    let new_key = SymKey::new(
+        generated.name,
+        format!("{}9", old_key.rev),
+        generated.public,
+        generated.secret,
+    );
+    let mut net = btest::SwimNet::new_ring_encryption(3, Some(old_key.clone()));
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+
+    let now = ring_keys::now();
+    let mut client = Client::new(net[0].gossip_addr(), Some(old_key.clone()))
+        .expect("Cannot create Butterfly Client");
+    client
+        .send_ring_key(&new_key, now + 3, now + 6)
+        .expect("Cannot send the ring key");
+    net.wait_for_gossip_rounds(2);
+    for member in net.iter() {
+        assert_eq!(
+            member.ring_key_revisions(),
+            vec![new_key.rev.clone(), old_key.rev.clone()]
+        );
+    }
+
+    thread::sleep(Duration::from_secs(7));
+    for member in net.iter() {
+        assert_eq!(member.ring_key_revisions(), vec![new_key.rev.clone()]);
+    }
+    net.add_service(0, "core/beast/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[2].service_store.with_rumor(
+        "beast.prod",
+        net[0].member_id(),
+        |u| assert!(u.is_some()),
+    );
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+}
@@ -22,6 +22,7 @@ extern crate habitat_core;
 
 mod rumor;
 mod encryption;
+mod simulation;
 
 use habitat_butterfly::member::Health;
 
@@ -146,6 +147,63 @@ fn six_members_unmeshed_allows_graceful_departure() {
     assert_wait_for_health_of!(net, 0, Health::Departed);
 }
 
+#[test]
+fn six_members_meshed_suspected_member_refutes_suspicion() {
+    let mut net = btest::SwimNet::new(6);
+    net.mesh();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    let incarnation = net[0]
+        .member
+        .read()
+        .expect("Member lock is poisoned")
+        .get_incarnation();
+    trace_it!(TEST: &net[0], "Paused");
+    net[0].pause();
+    assert_wait_for_health_of!(net, 1, 0, Health::Suspect);
+    trace_it!(TEST: &net[0], "Unpaused");
+    net[0].unpause();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    assert!(
+        net[0]
+            .member
+            .read()
+            .expect("Member lock is poisoned")
+            .get_incarnation() > incarnation
+    );
+}
+
+#[test]
+fn six_members_meshed_suspicion_is_confirmed_by_other_members() {
+    let mut net = btest::SwimNet::new(6);
+    net.mesh();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    trace_it!(TEST: &net[0], "Paused");
+    net[0].pause();
+    assert!(net.wait_for_suspicion_confirmations(1, 0, 1));
+    assert_wait_for_health_of!(net, 0, Health::Confirmed);
+}
+
+#[test]
+fn six_members_meshed_suspicion_confirmations_are_gossiped() {
+    let mut net = btest::SwimNet::new(6);
+    net.mesh();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    net[0].pause();
+    // At most one of the members suspecting 0 is member 1 itself, so at least two of these
+    // confirmations reached it by gossip.
+    assert!(net.wait_for_suspicion_confirmations(1, 0, 3));
+    assert_wait_for_health_of!(net, 0, Health::Confirmed);
+}
+
+#[test]
+fn six_members_meshed_isolated_member_degrades_its_local_health() {
+    let mut net = btest::SwimNet::new(6);
+    net.mesh();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    net.partition(0..1, 1..6);
+    assert!(net.wait_for_local_health_of(0, 2));
+}
+
 #[test]
 #[ignore]
 fn fifty_members_meshed_confirm_one_member() {
@@ -56,6 +56,17 @@ fn two_members_fail_to_find_quorum() {
     assert_wait_for_election_status!(net, [0..2], "witcher.prod", Election_Status::NoQuorum);
 }
 
+#[test]
+fn two_members_find_an_explicit_quorum_of_two() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_election_with_quorum(0, "witcher", 2);
+    assert_wait_for_election_status!(net, [0..2], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..2, 0..2], "witcher.prod");
+}
+
 #[test]
 fn two_members_find_quorum_when_a_third_comes() {
     let mut net = btest::SwimNet::new(2);
@@ -14,6 +14,7 @@
 
 use btest;
 use habitat_butterfly::member::Health;
+use time::Duration;
 
 #[test]
 fn two_members_share_services() {
@@ -53,3 +54,49 @@ fn six_members_unmeshed_with_same_service_forces_departure_on_new_members() {
     assert_wait_for_health_of!(net, 6, Health::Alive);
     assert_wait_for_health_of!(net, 0, Health::Departed);
 }
+
+#[test]
+fn two_members_share_service_tombstones() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.wait_for_rounds(2);
+    net[0].tombstone_service("witcher.prod");
+    net.wait_for_rounds(2);
+    net[1].service_store.with_rumor(
+        "witcher.prod",
+        net[0].member_id(),
+        |u| assert!(u.unwrap().get_tombstone()),
+    );
+    assert!(!net[1].runs_service("witcher.prod", net[0].member_id()));
+}
+
+#[test]
+fn expired_service_tombstones_keep_older_services_from_coming_back() {
+    let mut net = btest::SwimNet::new(2);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.wait_for_rounds(2);
+    let mut service = None;
+    net[1].service_store.with_rumor(
+        "witcher.prod",
+        net[0].member_id(),
+        |s| service = s.cloned(),
+    );
+    net[0].tombstone_service("witcher.prod");
+    net.wait_for_rounds(2);
+    assert!(net[0].expire_rumors(Duration::zero()) > 0);
+    assert!(net[1].expire_rumors(Duration::zero()) > 0);
+    assert!(!net[1].service_store.contains_rumor(
+        "witcher.prod",
+        net[0].member_id(),
+    ));
+
+    // A member that missed the tombstone gossips the service it replaced.
+    net[1].insert_service(service.expect("Service rumor never arrived"));
+    assert!(!net[1].service_store.contains_rumor(
+        "witcher.prod",
+        net[0].member_id(),
+    ));
+    assert!(!net[1].runs_service("witcher.prod", net[0].member_id()));
+}
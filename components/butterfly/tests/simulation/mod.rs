@@ -0,0 +1,78 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use habitat_butterfly::member::Health;
+
+use btest::sim::SimNet;
+
+#[test]
+fn five_members_stay_alive_despite_loss_and_latency() {
+    let mut net = SimNet::new(5, 1);
+    net.simulation.set_loss(0.02);
+    net.simulation.set_latency(
+        Duration::from_millis(5),
+        Duration::from_millis(50),
+    );
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..5, 0..5], Health::Alive);
+    net.advance(Duration::from_secs(60));
+    assert_wait_for_health_of!(net, [0..5, 0..5], Health::Alive);
+    assert!(net.simulation.dropped() > 0);
+}
+
+#[test]
+fn six_members_reach_each_other_around_a_one_way_block() {
+    let mut net = SimNet::new(6, 2);
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..6, 0..6], Health::Alive);
+    net.block(0, 1);
+    net.advance(Duration::from_secs(60));
+    // Probes each way fail directly, since either the ping or the ack is blocked, but succeed
+    // through PingReqs.
+    assert_eq!(net.health_of(0, 1), Some(Health::Alive));
+    assert_eq!(net.health_of(1, 0), Some(Health::Alive));
+}
+
+#[test]
+fn six_members_confirm_a_paused_member() {
+    let mut net = SimNet::new(6, 3);
+    net.mesh();
+    assert_wait_for_health_of!(net, 0, Health::Alive);
+    net[0].pause();
+    assert_wait_for_health_of!(net, 0, Health::Confirmed);
+}
+
+#[test]
+fn six_members_partitioned_in_half_confirm_the_other_side() {
+    let mut net = SimNet::new(6, 4);
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..6, 0..6], Health::Alive);
+    net.partition(0..3, 3..6);
+    assert_wait_for_health_of!(net, [0..3, 3..6], Health::Confirmed);
+}
+
+#[test]
+fn two_hundred_members_with_loss_confirm_a_paused_member() {
+    let mut net = SimNet::new(200, 5);
+    net.simulation.set_loss(0.01);
+    net.simulation.set_latency(
+        Duration::from_millis(1),
+        Duration::from_millis(20),
+    );
+    net.mesh();
+    net[0].pause();
+    assert_wait_for_health_of!(net, 0, Health::Confirmed);
+}
@@ -267,6 +267,43 @@ impl SymKey {
         }
     }
 
+    /// Returns the contents of the sym key's secret key file, as read by `write_file_from_str`
+    /// and `from_secret_str`.
+    ///
+    /// # Errors
+    ///
+    /// * If the secret key component of the `SymKey` is not present
+    pub fn to_secret_string(&self) -> Result<String> {
+        let key = self.secret()?;
+        Ok(format!(
+            "{}\n{}\n\n{}",
+            SECRET_SYM_KEY_VERSION,
+            self.name_with_rev(),
+            base64::encode(&key[..])
+        ))
+    }
+
+    /// Reads a sym key from the contents of a string slice, without writing it to the key cache.
+    ///
+    /// # Errors
+    ///
+    /// * If there is a key version mismatch
+    /// * If the key version, name with revision or value is missing
+    /// * If the key value is not a Base64 encoded sym key
+    pub fn from_secret_str(content: &str) -> Result<Self> {
+        let (name_with_rev, encoded) = Self::parse_secret_str(content)?;
+        let (name, rev) = parse_name_with_rev(name_with_rev)?;
+        let bytes = base64::decode(encoded).map_err(|e| {
+            Error::CryptoError(format!("Can't read raw key for {}: {}", name_with_rev, e))
+        })?;
+        match SymSecretKey::from_slice(&bytes) {
+            Some(sk) => Ok(Self::new(name, rev, Some(()), Some(sk))),
+            None => Err(Error::CryptoError(
+                format!("Can't read sym secret key for {}", name_with_rev),
+            )),
+        }
+    }
+
     fn get_public_key(_key_with_rev: &str, _cache_key_path: &Path) -> Result<()> {
         Err(Error::CryptoError(
             "SymKey never contains a public key".to_string(),
@@ -329,44 +366,7 @@ impl SymKey {
         content: &str,
         cache_key_path: &P,
     ) -> Result<(Self, PairType)> {
-        let mut lines = content.lines();
-        let _ = match lines.next() {
-            Some(val) => {
-                if val != SECRET_SYM_KEY_VERSION {
-                    return Err(Error::CryptoError(
-                        format!("Unsupported key version: {}", val),
-                    ));
-                }
-                ()
-            }
-            None => {
-                let msg = format!(
-                    "write_sym_key_from_str:1 Malformed sym key string:\n({})",
-                    content
-                );
-                return Err(Error::CryptoError(msg));
-            }
-        };
-        let name_with_rev = match lines.next() {
-            Some(val) => val,
-            None => {
-                let msg = format!(
-                    "write_sym_key_from_str:2 Malformed sym key string:\n({})",
-                    content
-                );
-                return Err(Error::CryptoError(msg));
-            }
-        };
-        let sk = match lines.nth(1) {
-            Some(val) => val,
-            None => {
-                let msg = format!(
-                    "write_sym_key_from_str:3 Malformed sym key string:\n({})",
-                    content
-                );
-                return Err(Error::CryptoError(msg));
-            }
-        };
+        let (name_with_rev, sk) = Self::parse_secret_str(content)?;
         let secret_keyfile = mk_key_filename(
             cache_key_path.as_ref(),
             &name_with_rev,
@@ -432,6 +432,50 @@ impl SymKey {
         ))
     }
 
+    /// Splits the contents of a sym key string slice into the key's name with revision and its
+    /// Base64 encoded secret.
+    fn parse_secret_str(content: &str) -> Result<(&str, &str)> {
+        let mut lines = content.lines();
+        let _ = match lines.next() {
+            Some(val) => {
+                if val != SECRET_SYM_KEY_VERSION {
+                    return Err(Error::CryptoError(
+                        format!("Unsupported key version: {}", val),
+                    ));
+                }
+                ()
+            }
+            None => {
+                let msg = format!(
+                    "write_sym_key_from_str:1 Malformed sym key string:\n({})",
+                    content
+                );
+                return Err(Error::CryptoError(msg));
+            }
+        };
+        let name_with_rev = match lines.next() {
+            Some(val) => val,
+            None => {
+                let msg = format!(
+                    "write_sym_key_from_str:2 Malformed sym key string:\n({})",
+                    content
+                );
+                return Err(Error::CryptoError(msg));
+            }
+        };
+        let sk = match lines.nth(1) {
+            Some(val) => val,
+            None => {
+                let msg = format!(
+                    "write_sym_key_from_str:3 Malformed sym key string:\n({})",
+                    content
+                );
+                return Err(Error::CryptoError(msg));
+            }
+        };
+        Ok((name_with_rev, sk))
+    }
+
     fn mk_key_name_for_ring(name: &str, revision: &str) -> String {
         format!("{}-{}", name, revision)
     }
@@ -749,4 +793,20 @@ mod test {
             cache.path(),
         ).unwrap();
     }
+
+    #[test]
+    fn secret_string_round_trip() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let mut content = String::new();
+        File::open(SymKey::get_secret_key_path(&pair.name_with_rev(), cache.path()).unwrap())
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(pair.to_secret_string().unwrap(), content);
+
+        let read = SymKey::from_secret_str(&content).unwrap();
+        assert_eq!(read.name_with_rev(), pair.name_with_rev());
+        assert_eq!(read.secret, pair.secret);
+    }
 }
@@ -97,6 +97,8 @@ pub enum Error {
     InvalidPlatform(String),
     /// Occurs when a service group string cannot be successfully parsed.
     InvalidServiceGroup(String),
+    /// Occurs when a signal name cannot be successfully parsed.
+    InvalidSignal(String),
     /// Occurs when an origin is in an invalid format
     InvalidOrigin(String),
     /// Occurs when making lower level IO calls.
@@ -295,6 +297,13 @@ impl fmt::Display for Error {
                     e
                 )
             }
+            Error::InvalidSignal(ref e) => {
+                format!(
+                    "Invalid signal: {}. A valid signal is one of HUP, INT, QUIT, ILL, ABRT, FPE, \
+                         KILL, USR1, SEGV, USR2, ALRM or TERM",
+                    e
+                )
+            }
             Error::InvalidOrigin(ref origin) => {
                 format!(
                     "Invalid origin: {}. Origins must begin with a lowercase letter or number. \
@@ -438,6 +447,7 @@ impl error::Error for Error {
             Error::InvalidServiceGroup(_) => {
                 "Service group strings must be in service.group format (example: redis.production)"
             }
+            Error::InvalidSignal(_) => "Signals must be named, such as TERM or SIGTERM",
             Error::InvalidOrigin(_) => {
                 "Origins must begin with a lowercase letter or number.  \
                     Allowed characters include a - z, 0 - 9, _, and -. No more than 255 characters."
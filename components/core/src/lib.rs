@@ -20,6 +20,7 @@ extern crate base64;
 #[cfg(windows)]
 extern crate ctrlc;
 extern crate errno;
+extern crate flate2;
 extern crate hex;
 #[cfg(test)]
 extern crate hyper;
@@ -24,6 +24,11 @@ mod imp;
 #[path = "linux.rs"]
 mod imp;
 
+use std::fmt;
+use std::str::FromStr;
+
+use error::Error;
+
 pub use self::imp::*;
 
 pub trait OsSignal {
@@ -68,6 +73,40 @@ impl From<i32> for Signal {
     }
 }
 
+impl FromStr for Signal {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let name = value.trim().to_uppercase();
+        let name = if name.starts_with("SIG") {
+            &name[3..]
+        } else {
+            &name[..]
+        };
+        match name {
+            "HUP" => Ok(Signal::HUP),
+            "INT" => Ok(Signal::INT),
+            "QUIT" => Ok(Signal::QUIT),
+            "ILL" => Ok(Signal::ILL),
+            "ABRT" => Ok(Signal::ABRT),
+            "FPE" => Ok(Signal::FPE),
+            "KILL" => Ok(Signal::KILL),
+            "USR1" => Ok(Signal::USR1),
+            "SEGV" => Ok(Signal::SEGV),
+            "USR2" => Ok(Signal::USR2),
+            "ALRM" => Ok(Signal::ALRM),
+            "TERM" => Ok(Signal::TERM),
+            _ => Err(Error::InvalidSignal(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 impl From<Signal> for i32 {
     fn from(value: Signal) -> i32 {
         match value {
@@ -86,3 +125,25 @@ impl From<Signal> for i32 {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Signal;
+
+    #[test]
+    fn signal_from_str() {
+        assert_eq!(i32::from(Signal::from_str("TERM").unwrap()), 15);
+        assert_eq!(i32::from(Signal::from_str("sigquit").unwrap()), 3);
+        assert_eq!(i32::from(Signal::from_str(" INT ").unwrap()), 2);
+        assert!(Signal::from_str("SIGBOGUS").is_err());
+        assert!(Signal::from_str("").is_err());
+    }
+
+    #[test]
+    fn signal_round_trips_through_its_name() {
+        let signal = Signal::from_str(&Signal::USR2.to_string()).unwrap();
+        assert_eq!(i32::from(signal), 12);
+    }
+}
@@ -317,6 +317,34 @@ impl PackageInstall {
         }
     }
 
+    /// Returns a Rust representation of the mappings defined by the `pkg_health_probe` plan
+    /// variable, a health check which the Supervisor runs in place of a `health_check` hook.
+    pub fn health_probe(&self) -> Result<HashMap<String, String>> {
+        match self.read_metafile(MetaFile::HealthProbe) {
+            Ok(body) => {
+                Ok(parse_key_value(&body).map_err(|_| {
+                    Error::MetaFileMalformed(MetaFile::HealthProbe)
+                })?)
+            }
+            Err(Error::MetaFileNotFound(MetaFile::HealthProbe)) => Ok(HashMap::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns a Rust representation of the mappings defined by the `pkg_hook_timeouts` plan
+    /// variable, the seconds each of the package's hooks may run before it is killed.
+    pub fn hook_timeouts(&self) -> Result<HashMap<String, String>> {
+        match self.read_metafile(MetaFile::HookTimeouts) {
+            Ok(body) => {
+                Ok(parse_key_value(&body).map_err(|_| {
+                    Error::MetaFileMalformed(MetaFile::HookTimeouts)
+                })?)
+            }
+            Err(Error::MetaFileNotFound(MetaFile::HookTimeouts)) => Ok(HashMap::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     /// A vector of ports we expose
     pub fn exposes(&self) -> Result<Vec<String>> {
         match self.read_metafile(MetaFile::Exposes) {
@@ -455,6 +483,20 @@ impl PackageInstall {
         }
     }
 
+    /// Returns a Rust representation of the mappings defined by the `pkg_svc_limits` plan
+    /// variable, the default resource limits for the package when it is run as a service.
+    pub fn svc_limits(&self) -> Result<HashMap<String, String>> {
+        match self.read_metafile(MetaFile::SvcLimits) {
+            Ok(body) => {
+                Ok(parse_key_value(&body).map_err(|_| {
+                    Error::MetaFileMalformed(MetaFile::SvcLimits)
+                })?)
+            }
+            Err(Error::MetaFileNotFound(MetaFile::SvcLimits)) => Ok(HashMap::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     fn target(&self) -> Result<PackageTarget> {
         match self.read_metafile(MetaFile::Target) {
             Ok(body) => PackageTarget::from_str(&body),
@@ -137,6 +137,8 @@ pub enum MetaFile {
     EnvironmentSep,
     Exports,
     Exposes,
+    HealthProbe,
+    HookTimeouts,
     Ident,
     LdRunPath,
     LdFlags,
@@ -144,6 +146,7 @@ pub enum MetaFile {
     Path,
     SvcUser,
     SvcGroup,
+    SvcLimits,
     Target,
 }
 
@@ -160,6 +163,8 @@ impl fmt::Display for MetaFile {
             MetaFile::EnvironmentSep => "ENVIRONMENT_SEP",
             MetaFile::Exports => "EXPORTS",
             MetaFile::Exposes => "EXPOSES",
+            MetaFile::HealthProbe => "HEALTH_PROBE",
+            MetaFile::HookTimeouts => "HOOK_TIMEOUTS",
             MetaFile::Ident => "IDENT",
             MetaFile::LdRunPath => "LD_RUN_PATH",
             MetaFile::LdFlags => "LDFLAGS",
@@ -167,6 +172,7 @@ impl fmt::Display for MetaFile {
             MetaFile::Path => "PATH",
             MetaFile::SvcUser => "SVC_USER",
             MetaFile::SvcGroup => "SVC_GROUP",
+            MetaFile::SvcLimits => "SVC_LIMITS",
             MetaFile::Target => "TARGET",
         };
         write!(f, "{}", id)
@@ -0,0 +1,413 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Line oriented log files which are rotated once they grow too large or too old.
+//!
+//! Every line is stamped with the time it was written and the stream it came from, so that the
+//! lines of several logs can be merged back into order when they are read. Rotated logs sit
+//! beside the current one as `<name>.1`, `<name>.2` and so on, oldest last, with a `.gz`
+//! extension when they were compressed.
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use time;
+
+/// Bytes a log may grow to before it is rotated, unless its policy says otherwise.
+pub const DEFAULT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+/// Number of rotated logs kept, unless a policy says otherwise.
+pub const DEFAULT_KEEP: u32 = 5;
+
+const TIME_FORMAT: &'static str = "%Y-%m-%dT%H:%M:%SZ";
+const COMPRESSED_EXT: &'static str = "gz";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RotationPolicy {
+    /// Bytes the log may grow to before it is rotated.
+    pub max_bytes: u64,
+    /// Seconds after its first line was written that the log is rotated, if ever.
+    pub max_age: Option<u64>,
+    /// Number of rotated logs to keep. Older ones are removed.
+    pub keep: u32,
+    /// Whether rotated logs are compressed with gzip.
+    pub compress: bool,
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        RotationPolicy {
+            max_bytes: DEFAULT_MAX_BYTES,
+            max_age: None,
+            keep: DEFAULT_KEEP,
+            compress: true,
+        }
+    }
+}
+
+/// A single line of a log.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogLine {
+    /// Seconds since the epoch at which the line was written.
+    pub time: i64,
+    /// The stream the line came from, such as `stdout` or `init.stderr`.
+    pub source: String,
+    pub text: String,
+}
+
+impl LogLine {
+    /// A line written now.
+    pub fn new<S, T>(source: S, text: T) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        LogLine {
+            time: time::now_utc().to_timespec().sec,
+            source: source.into(),
+            text: text.into(),
+        }
+    }
+
+    /// Parses a line as written to a log, returning `None` if it is not in the log's format.
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.splitn(2, ' ');
+        let (stamp, rest) = match (parts.next(), parts.next()) {
+            (Some(stamp), Some(rest)) => (stamp, rest),
+            _ => return None,
+        };
+        let time = match time::strptime(stamp, TIME_FORMAT) {
+            Ok(tm) => tm.to_timespec().sec,
+            Err(_) => return None,
+        };
+        let mut parts = rest.splitn(2, ": ");
+        let source = parts.next().unwrap_or("");
+        let text = parts.next().unwrap_or("");
+        Some(LogLine {
+            time: time,
+            source: source.trim_right_matches(':').to_string(),
+            text: text.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for LogLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let stamp = time::at_utc(time::Timespec::new(self.time, 0));
+        write!(f, "{} {}: {}", stamp.rfc3339(), self.source, self.text)
+    }
+}
+
+/// A log which is appended to and rotated according to its policy.
+pub struct RotatingLog {
+    path: PathBuf,
+    policy: RotationPolicy,
+    file: File,
+    size: u64,
+    started: Option<i64>,
+}
+
+impl RotatingLog {
+    /// Opens the log at the given path for appending, creating it if needed.
+    pub fn open<P>(path: P, policy: RotationPolicy) -> io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        let started = first_line_time(&path);
+        Ok(RotatingLog {
+            path: path,
+            policy: policy,
+            file: file,
+            size: size,
+            started: started,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends a line to the log, rotating it first if the line would take it past its policy.
+    pub fn write(&mut self, line: &LogLine) -> io::Result<()> {
+        let entry = format!("{}\n", line);
+        if self.should_rotate(line.time, entry.len() as u64) {
+            self.rotate()?;
+        }
+        self.file.write_all(entry.as_bytes())?;
+        self.size += entry.len() as u64;
+        if self.started.is_none() {
+            self.started = Some(line.time);
+        }
+        Ok(())
+    }
+
+    /// Moves the current log aside and starts a new one, removing the rotated logs which are
+    /// no longer kept.
+    pub fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let keep = self.policy.keep;
+        for n in (1..keep + 1).rev() {
+            for compressed in &[false, true] {
+                let rotated = rotated_path(&self.path, n, *compressed);
+                if !rotated.exists() {
+                    continue;
+                }
+                if n == keep {
+                    fs::remove_file(&rotated)?;
+                } else {
+                    fs::rename(&rotated, rotated_path(&self.path, n + 1, *compressed))?;
+                }
+            }
+        }
+        if keep == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let rotated = rotated_path(&self.path, 1, false);
+            fs::rename(&self.path, &rotated)?;
+            if self.policy.compress {
+                compress(&rotated, &rotated_path(&self.path, 1, true))?;
+                fs::remove_file(&rotated)?;
+            }
+        }
+        self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        self.size = 0;
+        self.started = None;
+        Ok(())
+    }
+
+    fn should_rotate(&self, now: i64, incoming: u64) -> bool {
+        if self.size == 0 {
+            return false;
+        }
+        if self.size + incoming > self.policy.max_bytes {
+            return true;
+        }
+        match (self.policy.max_age, self.started) {
+            (Some(max_age), Some(started)) => now - started >= max_age as i64,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the paths of the rotated logs and then the current log which exist for the log at
+/// the given path, oldest first.
+pub fn log_files<P>(path: P) -> Vec<PathBuf>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let mut rotated = vec![];
+    if let (Some(dir), Some(name)) = (path.parent(), path.file_name()) {
+        let prefix = format!("{}.", name.to_string_lossy());
+        if let Ok(entries) = fs::read_dir(dir) {
+            for entry in entries.filter_map(|e| e.ok()) {
+                let file_name = entry.file_name().to_string_lossy().into_owned();
+                if !file_name.starts_with(&prefix) {
+                    continue;
+                }
+                let suffix = &file_name[prefix.len()..];
+                let number = suffix
+                    .trim_right_matches(&format!(".{}", COMPRESSED_EXT))
+                    .parse::<u32>();
+                if let Ok(n) = number {
+                    rotated.push((n, entry.path()));
+                }
+            }
+        }
+    }
+    rotated.sort_by(|a, b| b.0.cmp(&a.0));
+    let mut files: Vec<PathBuf> = rotated.into_iter().map(|(_, p)| p).collect();
+    if path.is_file() {
+        files.push(path.to_path_buf());
+    }
+    files
+}
+
+/// Opens a current or rotated log for reading, decompressing it if needed.
+pub fn open<P>(path: P) -> io::Result<Box<Read>>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let file = File::open(path)?;
+    if is_compressed(path) {
+        Ok(Box::new(GzDecoder::new(file)))
+    } else {
+        Ok(Box::new(file))
+    }
+}
+
+/// Reads the lines of a current or rotated log, skipping any which are not in the log's format.
+pub fn read_lines<P>(path: P) -> io::Result<Vec<LogLine>>
+where
+    P: AsRef<Path>,
+{
+    let mut lines = vec![];
+    for line in BufReader::new(open(path)?).lines() {
+        if let Some(line) = LogLine::parse(&line?) {
+            lines.push(line);
+        }
+    }
+    Ok(lines)
+}
+
+fn rotated_path(path: &Path, n: u32, compressed: bool) -> PathBuf {
+    let mut name = path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    name.push_str(&format!(".{}", n));
+    if compressed {
+        name.push_str(&format!(".{}", COMPRESSED_EXT));
+    }
+    path.with_file_name(name)
+}
+
+fn is_compressed(path: &Path) -> bool {
+    path.extension().map_or(false, |ext| ext == COMPRESSED_EXT)
+}
+
+fn compress(src: &Path, dst: &Path) -> io::Result<()> {
+    let mut input = File::open(src)?;
+    let mut encoder = GzEncoder::new(File::create(dst)?, Compression::default());
+    io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?;
+    Ok(())
+}
+
+fn first_line_time(path: &Path) -> Option<i64> {
+    let mut line = String::new();
+    match File::open(path) {
+        Ok(file) => BufReader::new(file).read_line(&mut line).ok(),
+        Err(_) => return None,
+    };
+    LogLine::parse(line.trim_right_matches('\n')).map(|l| l.time)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn line_at(time: i64, text: &str) -> LogLine {
+        LogLine {
+            time: time,
+            source: "stdout".to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn log_line_round_trips() {
+        let line = LogLine {
+            time: 1508320800,
+            source: "init.stderr".to_string(),
+            text: "oh: no".to_string(),
+        };
+        let written = line.to_string();
+        assert_eq!(written, "2017-10-18T10:00:00Z init.stderr: oh: no");
+        assert_eq!(LogLine::parse(&written), Some(line));
+    }
+
+    #[test]
+    fn log_line_parse_rejects_foreign_lines() {
+        assert_eq!(LogLine::parse("just some output"), None);
+        assert_eq!(LogLine::parse(""), None);
+    }
+
+    #[test]
+    fn rotates_when_too_large() {
+        let dir = TempDir::new("log_file").unwrap();
+        let path = dir.path().join("service.log");
+        let policy = RotationPolicy {
+            max_bytes: 64,
+            max_age: None,
+            keep: 2,
+            compress: false,
+        };
+        let mut log = RotatingLog::open(&path, policy).unwrap();
+        for n in 0..4 {
+            log.write(&line_at(1508320800 + n, &format!("line {}", n)))
+                .unwrap();
+        }
+        let files = log_files(&path);
+        assert_eq!(
+            files,
+            vec![
+                dir.path().join("service.log.2"),
+                dir.path().join("service.log.1"),
+                path.clone(),
+            ]
+        );
+        let texts: Vec<String> = files
+            .iter()
+            .flat_map(|f| read_lines(f).unwrap())
+            .map(|l| l.text)
+            .collect();
+        assert_eq!(texts, vec!["line 1", "line 2", "line 3"]);
+    }
+
+    #[test]
+    fn rotates_when_too_old_and_compresses() {
+        let dir = TempDir::new("log_file").unwrap();
+        let path = dir.path().join("hooks.log");
+        let policy = RotationPolicy {
+            max_bytes: DEFAULT_MAX_BYTES,
+            max_age: Some(60),
+            keep: 5,
+            compress: true,
+        };
+        let mut log = RotatingLog::open(&path, policy.clone()).unwrap();
+        log.write(&line_at(1508320800, "first")).unwrap();
+        log.write(&line_at(1508320830, "second")).unwrap();
+        drop(log);
+        // The age of the log survives reopening it.
+        let mut log = RotatingLog::open(&path, policy).unwrap();
+        log.write(&line_at(1508320860, "third")).unwrap();
+        let files = log_files(&path);
+        assert_eq!(
+            files,
+            vec![dir.path().join("hooks.log.1.gz"), path.clone()]
+        );
+        let lines = read_lines(&files[0]).unwrap();
+        assert_eq!(lines, vec![line_at(1508320800, "first"), line_at(1508320830, "second")]);
+        assert!(!dir.path().join("hooks.log.1").exists());
+    }
+
+    #[test]
+    fn keeping_none_discards_the_log() {
+        let dir = TempDir::new("log_file").unwrap();
+        let path = dir.path().join("service.log");
+        let policy = RotationPolicy {
+            max_bytes: 1,
+            max_age: None,
+            keep: 0,
+            compress: false,
+        };
+        let mut log = RotatingLog::open(&path, policy).unwrap();
+        log.write(&line_at(1508320800, "first")).unwrap();
+        log.write(&line_at(1508320801, "second")).unwrap();
+        assert_eq!(log_files(&path), vec![path.clone()]);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
@@ -30,6 +30,7 @@ pub fn get() -> App<'static, 'static> {
             (aliases: &["co", "con", "conf", "confi"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
+            (subcommand: sub_config_rollback().aliases(&["r", "ro", "rol", "roll"]))
         )
         (@subcommand depart =>
             (about: "Manage the departure status of a butterfly member")
@@ -62,6 +63,32 @@ pub fn get() -> App<'static, 'static> {
                 (@arg RING: -r --ring +takes_value
                     "Ring key name, which will encrypt communication messages")
             )
+            (@subcommand remove =>
+                (about: "Remove a file from the supervisor ring.")
+                (aliases: &["rem", "remo", "remov"])
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg VERSION_NUMBER: +required
+                    "A version number (positive integer) higher than the file's (ex: 43)")
+                (@arg FILENAME: +required "Name of the file to remove (ex: cert.pem)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+            )
+        )
+        (@subcommand ring =>
+            (about: "Commands relating to Habitat rings")
+            (aliases: &["r", "ri", "rin"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand key =>
+                (about: "Commands relating to Habitat ring keys")
+                (aliases: &["k", "ke"])
+                (@setting ArgRequiredElseHelp)
+                (subcommand: sub_ring_key_rotate())
+            )
         )
     )
 }
@@ -85,6 +112,35 @@ fn sub_config_apply() -> App<'static, 'static> {
     )
 }
 
+fn sub_config_rollback() -> App<'static, 'static> {
+    clap_app!(@subcommand rollback =>
+        (about: "Gossips an earlier version of a service group's configuration again, taken from \
+            a Supervisor's configuration history")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "The version number of the configuration to roll back to (ex: 41)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg REMOTE_SUP: --("remote-sup") +takes_value
+            "Address of the HTTP gateway of a Supervisor in the ring \
+            (default: http://127.0.0.1:9631)")
+    )
+}
+
+fn sub_ring_key_rotate() -> App<'static, 'static> {
+    clap_app!(@subcommand rotate =>
+        (about: "Generates a new ring key revision and gossips it to a ring, which switches to \
+            it and retires the older revisions without a restart")
+        (@arg RING: +required +takes_value "Ring key name")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg GRACE: --grace +takes_value {valid_grace}
+            "Seconds until the ring uses the new revision, and again until older revisions \
+            are retired (default: 60)")
+    )
+}
+
 fn file_exists(val: String) -> result::Result<(), String> {
     if Path::new(&val).is_file() {
         Ok(())
@@ -97,6 +153,13 @@ fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
 
+fn valid_grace(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(grace) if grace > 0 => Ok(()),
+        _ => Err(format!("Grace period: '{}' is not a positive number of seconds", &val)),
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     match ServiceGroup::validate(&val) {
         Ok(()) => Ok(()),
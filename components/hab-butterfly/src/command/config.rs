@@ -113,3 +113,73 @@ pub mod apply {
         Ok(())
     }
 }
+
+pub mod rollback {
+    use std::io::Read;
+
+    use common::ui::{Status, UI};
+    use hcore::service::ServiceGroup;
+    use http_client::ApiClient;
+    use hyper::header::{Authorization, Bearer};
+    use hyper::status::StatusCode;
+
+    use error::{Error, Result};
+    use {PRODUCT, VERSION};
+
+    pub fn start(
+        ui: &mut UI,
+        sg: &ServiceGroup,
+        number: u64,
+        remote_sup: &str,
+        auth_token: Option<&str>,
+    ) -> Result<()> {
+        ui.begin(format!(
+            "Rolling back configuration for {} to incarnation {}",
+            sg,
+            number
+        ))?;
+        ui.status(
+            Status::Applying,
+            format!("through the Supervisor at {}", remote_sup),
+        )?;
+
+        let client = ApiClient::new(remote_sup, PRODUCT, VERSION, None).map_err(|e| {
+            Error::GatewayError(format!("{}", e))
+        })?;
+        let mut path = format!("services/{}/{}", sg.service(), sg.group());
+        if let Some(org) = sg.org() {
+            path.push_str(&format!("/{}", org));
+        }
+        path.push_str("/config/rollback");
+        let mut request = client.post_with_custom_url(&path, |url| {
+            url.query_pairs_mut().append_pair(
+                "version",
+                &number.to_string(),
+            );
+        });
+        if let Some(token) = auth_token {
+            request = request.header(Authorization(Bearer { token: token.to_string() }));
+        }
+        let mut response = request.send().map_err(
+            |e| Error::GatewayError(format!("{}", e)),
+        )?;
+        let mut body = String::new();
+        response.read_to_string(&mut body)?;
+
+        match response.status {
+            StatusCode::Accepted => {
+                ui.end(format!(
+                    "Rolled back configuration, gossiped as incarnation {}",
+                    body.trim()
+                ))?;
+                Ok(())
+            }
+            StatusCode::NotFound => Err(Error::GatewayError(format!(
+                "incarnation {} is not in the configuration history of {}",
+                number,
+                sg
+            ))),
+            status => Err(Error::GatewayError(format!("{}", status))),
+        }
+    }
+}
@@ -99,3 +99,50 @@ pub mod upload {
         Ok(())
     }
 }
+
+pub mod remove {
+    use std::thread;
+    use std::time;
+
+    use butterfly::client::Client;
+    use common::ui::{Status, UI};
+    use hcore::crypto::SymKey;
+    use hcore::service::ServiceGroup;
+
+    use error::{Error, Result};
+
+    pub fn start(
+        ui: &mut UI,
+        sg: &ServiceGroup,
+        number: u64,
+        filename: &str,
+        peers: &Vec<String>,
+        ring_key: Option<&SymKey>,
+    ) -> Result<()> {
+        ui.begin(format!(
+            "Removing file {} from {} incarnation {}",
+            filename,
+            sg,
+            number
+        ))?;
+        ui.status(Status::Creating, format!("service file tombstone"))?;
+
+        for peer in peers.iter() {
+            ui.status(Status::Applying, format!("to peer {}", peer))?;
+            let mut client = Client::new(peer, ring_key.map(|k| k.clone())).map_err(
+                |e| {
+                    Error::ButterflyError(format!("{}", e))
+                },
+            )?;
+            client
+                .send_service_file_tombstone(sg.clone(), filename, number)
+                .map_err(|e| Error::ButterflyError(format!("{}", e)))?;
+
+            // We must sleep to allow messages to be sent before freeing the socket to prevent
+            // loss; see the upload command.
+            thread::sleep(time::Duration::from_millis(100));
+        }
+        ui.end("Removed file")?;
+        Ok(())
+    }
+}
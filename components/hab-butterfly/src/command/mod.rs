@@ -15,3 +15,4 @@
 pub mod config;
 pub mod depart;
 pub mod file;
+pub mod ring;
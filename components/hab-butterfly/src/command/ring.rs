@@ -0,0 +1,70 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod rotate {
+    use std::path::Path;
+    use std::thread;
+    use std::time;
+
+    use butterfly::client::Client;
+    use butterfly::ring_keys;
+    use common::ui::{Status, UI};
+    use hcore::crypto::SymKey;
+
+    use error::{Error, Result};
+
+    /// Generates a new revision of a ring's key and gossips it to the ring, encrypted with the
+    /// latest revision the ring uses now. Members switch to the new revision `grace` seconds
+    /// from now, and stop accepting older revisions `grace` seconds after that.
+    pub fn start(
+        ui: &mut UI,
+        ring: &str,
+        cache: &Path,
+        peers: &Vec<String>,
+        grace: u64,
+    ) -> Result<()> {
+        ui.begin(format!("Rotating ring key for {}", ring))?;
+        let current = SymKey::get_latest_pair_for(ring, cache)?;
+        let new = SymKey::generate_pair_for_ring(ring, cache)?;
+        ui.status(
+            Status::Created,
+            format!("ring key {}", new.name_with_rev()),
+        )?;
+
+        let activate_at = ring_keys::now() + grace;
+        let retire_at = activate_at + grace;
+        for peer in peers.iter() {
+            ui.status(Status::Applying, format!("to peer {}", peer))?;
+            let mut client = Client::new(peer, Some(current.clone())).map_err(|e| {
+                Error::ButterflyError(e.to_string())
+            })?;
+            client
+                .send_ring_key(&new, activate_at, retire_at)
+                .map_err(|e| Error::ButterflyError(e.to_string()))?;
+            // please take a moment to weep over the following line
+            // of code. We must sleep to allow messages to be sent
+            // before freeing the socket to prevent loss.
+            // see https://github.com/zeromq/libzmq/issues/1264
+            thread::sleep(time::Duration::from_millis(100));
+        }
+        ui.end(format!(
+            "Ring key {} will be used in {} seconds, and {} retired {} seconds after that.",
+            new.name_with_rev(),
+            grace,
+            current.name_with_rev(),
+            grace
+        ))?;
+        Ok(())
+    }
+}
@@ -47,7 +47,11 @@ use hab_butterfly::error::{Error, Result};
 const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
 /// Makes the --user CLI param optional when this env var is set
 const HABITAT_USER_ENVVAR: &'static str = "HAB_USER";
+/// Authenticates requests to the Supervisor's HTTP gateway when set
+const GATEWAY_AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_GATEWAY_AUTH_TOKEN";
 const HABITAT_BUTTERFLY_PORT: u64 = 9638;
+const DEFAULT_REMOTE_SUP: &'static str = "http://127.0.0.1:9631";
+const DEFAULT_RING_KEY_GRACE_SECONDS: u64 = 60;
 const MAX_FILE_UPLOAD_SIZE_BYTES: u64 = 4096;
 
 lazy_static! {
@@ -88,6 +92,7 @@ fn start(ui: &mut UI) -> Result<()> {
         ("config", Some(matches)) => {
             match matches.subcommand() {
                 ("apply", Some(m)) => sub_config_apply(ui, m)?,
+                ("rollback", Some(m)) => sub_config_rollback(ui, m)?,
                 _ => unreachable!(),
             }
         }
@@ -97,6 +102,18 @@ fn start(ui: &mut UI) -> Result<()> {
         ("file", Some(matches)) => {
             match matches.subcommand() {
                 ("upload", Some(m)) => sub_file_upload(ui, m)?,
+                ("remove", Some(m)) => sub_file_remove(ui, m)?,
+                _ => unreachable!(),
+            }
+        }
+        ("ring", Some(matches)) => {
+            match matches.subcommand() {
+                ("key", Some(m)) => {
+                    match m.subcommand() {
+                        ("rotate", Some(sc)) => sub_ring_key_rotate(ui, sc)?,
+                        _ => unreachable!(),
+                    }
+                }
                 _ => unreachable!(),
             }
         }
@@ -172,6 +189,23 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     )
 }
 
+fn sub_config_rollback(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+    let remote_sup = m.value_of("REMOTE_SUP").unwrap_or(DEFAULT_REMOTE_SUP);
+    let mut sg = ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())?;
+    if let Some(org) = org_param_or_env(&m) {
+        sg.set_org(org);
+    }
+    let auth_token = henv::var(GATEWAY_AUTH_TOKEN_ENVVAR).ok();
+    command::config::rollback::start(
+        ui,
+        &sg,
+        number,
+        remote_sup,
+        auth_token.as_ref().map(|t| t.as_str()),
+    )
+}
+
 fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
     let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
@@ -232,6 +266,57 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     )
 }
 
+fn sub_file_remove(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+    let filename = m.value_of("FILENAME").unwrap(); // Required via clap
+
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(SymKey::get_latest_pair_for(&name, &cache)?),
+        None => None,
+    };
+
+    let mut sg = ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())?;
+    if let Some(org) = org_param_or_env(&m) {
+        sg.set_org(org);
+    }
+    command::file::remove::start(ui, &sg, number, filename, &peers, ring_key.as_ref())
+}
+
+fn sub_ring_key_rotate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let ring = m.value_of("RING").unwrap(); // Required via clap
+    let grace = match m.value_of("GRACE") {
+        Some(_) => value_t!(m, "GRACE", u64).unwrap_or_else(|e| e.exit()),
+        None => DEFAULT_RING_KEY_GRACE_SECONDS,
+    };
+
+    init();
+    command::ring::rotate::start(
+        ui,
+        ring,
+        &default_cache_key_path(Some(&*FS_ROOT)),
+        &peers,
+        grace,
+    )
+}
+
 fn ui() -> UI {
     let isatty = if henv::var(NONINTERACTIVE_ENVVAR)
         .map(|val| val == "true")
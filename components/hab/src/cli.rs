@@ -53,6 +53,7 @@ pub fn get() -> App<'static, 'static> {
             (aliases: &["co", "con", "conf", "confi"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
+            (subcommand: sub_config_rollback().aliases(&["r", "ro", "rol", "roll"]))
         )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
@@ -74,6 +75,21 @@ pub fn get() -> App<'static, 'static> {
                 (@arg RING: -r --ring +takes_value
                     "Ring key name, which will encrypt communication messages")
             )
+            (@subcommand remove =>
+                (about: "Remove a file from the supervisor ring.")
+                (aliases: &["rem", "remo", "remov"])
+                (@arg SERVICE_GROUP: +required +takes_value {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg VERSION_NUMBER: +required
+                    "A version number (positive integer) higher than the file's (ex: 43)")
+                (@arg FILENAME: +required "Name of the file to remove (ex: cert.pem)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+            )
         )
         (@subcommand bldr =>
             (about: "Commands relating to Habitat build service")
@@ -181,7 +197,8 @@ pub fn get() -> App<'static, 'static> {
                     "Sets the destination directory (default: /bin)")
             )
             (@subcommand config =>
-                (about: "Displays the default configuration options for a service")
+                (about: "Displays the default configuration options for a service; \
+                    'hab pkg config render' renders its templates and hooks instead")
                 (aliases: &["conf", "cfg"])
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
@@ -360,6 +377,17 @@ pub fn get() -> App<'static, 'static> {
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg RING: +required +takes_value "Ring key name")
                 )
+                (@subcommand rotate =>
+                    (about: "Generates a new ring key revision and gossips it to a ring, which \
+                    switches to it and retires the older revisions without a restart")
+                    (@arg RING: +required +takes_value "Ring key name")
+                    (@arg PEER: -p --peer +takes_value
+                        "A comma-delimited list of one or more Habitat Supervisor peers \
+                        (default: 127.0.0.1:9638)")
+                    (@arg GRACE: --grace +takes_value
+                        "Seconds until the ring uses the new revision, and again until older \
+                        revisions are retired (default: 60)")
+                )
             )
         )
         (@subcommand svc =>
@@ -530,6 +558,21 @@ fn sub_config_apply() -> App<'static, 'static> {
     )
 }
 
+fn sub_config_rollback() -> App<'static, 'static> {
+    clap_app!(@subcommand rollback =>
+        (about: "Gossips an earlier version of a service group's configuration again, taken from \
+            a Supervisor's configuration history")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "The version number of the configuration to roll back to (ex: 41)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg REMOTE_SUP: --("remote-sup") +takes_value {valid_url}
+            "Address of the HTTP gateway of a Supervisor in the ring \
+            (default: http://127.0.0.1:9631)")
+    )
+}
+
 fn sub_pkg_build() -> App<'static, 'static> {
     let sub = clap_app!(@subcommand build =>
         (about: "Builds a Plan using a Studio")
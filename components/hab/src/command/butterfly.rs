@@ -85,7 +85,9 @@ mod inner {
             ("config", "apply") => "config apply",
             ("config", _) => "config",
             ("file", "upload") => "file upload",
+            ("file", "remove") => "file remove",
             ("file", _) => "file",
+            ("ring", _) => "ring key rotate",
             (_, _) => unreachable!(),
         };
         ui.warn(format!(
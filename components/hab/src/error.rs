@@ -46,6 +46,7 @@ pub enum Error {
     ExecCommandNotFound(PathBuf),
     FFINulError(ffi::NulError),
     FileNotFound(String),
+    GatewayError(String),
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
     HandlebarsRenderError(handlebars::TemplateRenderError),
@@ -124,6 +125,7 @@ impl fmt::Display for Error {
             }
             Error::FFINulError(ref e) => format!("{}", e),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
+            Error::GatewayError(ref e) => format!("Supervisor HTTP gateway error: {}", e),
             Error::HabitatCommon(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HandlebarsRenderError(ref e) => format!("{}", e),
@@ -180,6 +182,7 @@ impl error::Error for Error {
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::FFINulError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
+            Error::GatewayError(_) => "The Supervisor's HTTP gateway has had an error",
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HandlebarsRenderError(ref err) => err.description(),
@@ -644,6 +644,14 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
         ("config", _) | ("file", _) => {
             command::butterfly::start(ui, env::args_os().skip(1).collect())
         }
+        ("pkg", "config") |
+        ("pkg", "conf") |
+        ("pkg", "cfg") if env::args().nth(3) == Some("render".to_string()) => {
+            command::sup::start(ui, env::args_os().skip(3).collect())
+        }
+        ("ring", "key") if env::args().nth(3) == Some("rotate".to_string()) => {
+            command::butterfly::start(ui, env::args_os().skip(1).collect())
+        }
         ("run", _) => command::launcher::start(ui, env::args_os().skip(1).collect()),
         ("stu", _) | ("stud", _) | ("studi", _) | ("studio", _) => {
             command::studio::enter::start(ui, env::args_os().skip(2).collect())
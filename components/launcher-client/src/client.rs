@@ -104,16 +104,28 @@ impl LauncherCli {
         }
     }
 
-    /// Restart a running process with the same arguments
-    pub fn restart(&self, pid: u32) -> Result<u32> {
+    /// Restart a running process with the same arguments, except for its resource limits and
+    /// shutdown policy which are replaced by the given ones. Passing no limits removes any the
+    /// process was spawned with, and passing no policy restores the Launcher's defaults. The old
+    /// process is stopped with the new policy.
+    pub fn restart(
+        &self,
+        pid: u32,
+        limits: Option<protocol::ResourceLimits>,
+        shutdown: Option<protocol::ShutdownPolicy>,
+    ) -> Result<protocol::SpawnOk> {
         let mut msg = protocol::Restart::new();
         msg.set_pid(pid);
+        msg.set_limits(limits.unwrap_or_default());
+        msg.set_shutdown(shutdown.unwrap_or_default());
         Self::send(&self.tx, &msg)?;
-        let reply = Self::recv::<protocol::SpawnOk>(&self.rx)?;
-        Ok(reply.get_pid())
+        Self::recv::<protocol::SpawnOk>(&self.rx)
     }
 
-    /// Send a process spawn command to the connected Launcher
+    /// Send a process spawn command to the connected Launcher. The reply carries the new
+    /// process's pid and the resource limits the Launcher was able to apply to it. The shutdown
+    /// policy is used whenever the Launcher stops the process, unless a terminate command
+    /// overrides it. The process's output is also captured to the given log file, if any.
     pub fn spawn<I, B, U, G, P>(
         &self,
         id: I,
@@ -122,7 +134,10 @@ impl LauncherCli {
         group: G,
         password: Option<P>,
         env: Env,
-    ) -> Result<u32>
+        limits: Option<protocol::ResourceLimits>,
+        shutdown: Option<protocol::ShutdownPolicy>,
+        logs: Option<protocol::LogFiles>,
+    ) -> Result<protocol::SpawnOk>
     where
         I: ToString,
         B: AsRef<Path>,
@@ -138,15 +153,28 @@ impl LauncherCli {
             msg.set_svc_password(password.to_string());
         }
         msg.set_env(env);
+        if let Some(limits) = limits {
+            msg.set_limits(limits);
+        }
+        if let Some(shutdown) = shutdown {
+            msg.set_shutdown(shutdown);
+        }
+        if let Some(logs) = logs {
+            msg.set_logs(logs);
+        }
         msg.set_id(id.to_string());
         Self::send(&self.tx, &msg)?;
-        let reply = Self::recv::<protocol::SpawnOk>(&self.rx)?;
-        Ok(reply.get_pid())
+        Self::recv::<protocol::SpawnOk>(&self.rx)
     }
 
-    pub fn terminate(&self, pid: u32) -> Result<i32> {
+    /// Stop a running process, using the given shutdown policy in place of the one the process
+    /// was spawned with.
+    pub fn terminate(&self, pid: u32, shutdown: Option<protocol::ShutdownPolicy>) -> Result<i32> {
         let mut msg = protocol::Terminate::new();
         msg.set_pid(pid);
+        if let Some(shutdown) = shutdown {
+            msg.set_shutdown(shutdown);
+        }
         Self::send(&self.tx, &msg)?;
         let reply = Self::recv::<protocol::TerminateOk>(&self.rx)?;
         Ok(reply.get_exit_code())
@@ -21,7 +21,7 @@ pub mod error;
 mod client;
 
 pub use protocol::{LAUNCHER_LOCK_CLEAN_ENV, LAUNCHER_PID_ENV, ERR_NO_RETRY_EXCODE,
-                   OK_NO_RETRY_EXCODE};
+                   OK_NO_RETRY_EXCODE, LogFiles, ResourceLimits, ShutdownPolicy, SpawnOk};
 
 pub use client::LauncherCli;
 pub use error::Error;
@@ -21,6 +21,380 @@
 use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
+#[derive(PartialEq,Clone,Default)]
+pub struct LogFiles {
+    // message fields
+    path: ::protobuf::SingularField<::std::string::String>,
+    max_bytes: ::std::option::Option<u64>,
+    max_age: ::std::option::Option<u64>,
+    keep: ::std::option::Option<u32>,
+    compress: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for LogFiles {}
+
+impl LogFiles {
+    pub fn new() -> LogFiles {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static LogFiles {
+        static mut instance: ::protobuf::lazy::Lazy<LogFiles> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const LogFiles,
+        };
+        unsafe {
+            instance.get(LogFiles::new)
+        }
+    }
+
+    // optional string path = 1;
+
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    pub fn has_path(&self) -> bool {
+        self.path.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        if self.path.is_none() {
+            self.path.set_default();
+        }
+        self.path.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        self.path.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_path(&self) -> &str {
+        match self.path.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_path_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.path
+    }
+
+    fn mut_path_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.path
+    }
+
+    // optional uint64 max_bytes = 2;
+
+    pub fn clear_max_bytes(&mut self) {
+        self.max_bytes = ::std::option::Option::None;
+    }
+
+    pub fn has_max_bytes(&self) -> bool {
+        self.max_bytes.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_bytes(&mut self, v: u64) {
+        self.max_bytes = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_max_bytes(&self) -> u64 {
+        self.max_bytes.unwrap_or(0)
+    }
+
+    fn get_max_bytes_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.max_bytes
+    }
+
+    fn mut_max_bytes_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.max_bytes
+    }
+
+    // optional uint64 max_age = 3;
+
+    pub fn clear_max_age(&mut self) {
+        self.max_age = ::std::option::Option::None;
+    }
+
+    pub fn has_max_age(&self) -> bool {
+        self.max_age.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_age(&mut self, v: u64) {
+        self.max_age = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_max_age(&self) -> u64 {
+        self.max_age.unwrap_or(0)
+    }
+
+    fn get_max_age_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.max_age
+    }
+
+    fn mut_max_age_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.max_age
+    }
+
+    // optional uint32 keep = 4;
+
+    pub fn clear_keep(&mut self) {
+        self.keep = ::std::option::Option::None;
+    }
+
+    pub fn has_keep(&self) -> bool {
+        self.keep.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keep(&mut self, v: u32) {
+        self.keep = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_keep(&self) -> u32 {
+        self.keep.unwrap_or(0)
+    }
+
+    fn get_keep_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.keep
+    }
+
+    fn mut_keep_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.keep
+    }
+
+    // optional bool compress = 5;
+
+    pub fn clear_compress(&mut self) {
+        self.compress = ::std::option::Option::None;
+    }
+
+    pub fn has_compress(&self) -> bool {
+        self.compress.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compress(&mut self, v: bool) {
+        self.compress = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_compress(&self) -> bool {
+        self.compress.unwrap_or(false)
+    }
+
+    fn get_compress_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.compress
+    }
+
+    fn mut_compress_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.compress
+    }
+}
+
+impl ::protobuf::Message for LogFiles {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.max_bytes = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.max_age = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.keep = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.compress = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.path.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.max_bytes {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_age {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.keep {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.compress {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.path.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.max_bytes {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.max_age {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.keep {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.compress {
+            os.write_bool(5, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for LogFiles {
+    fn new() -> LogFiles {
+        LogFiles::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<LogFiles>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "path",
+                    LogFiles::get_path_for_reflect,
+                    LogFiles::mut_path_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "max_bytes",
+                    LogFiles::get_max_bytes_for_reflect,
+                    LogFiles::mut_max_bytes_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "max_age",
+                    LogFiles::get_max_age_for_reflect,
+                    LogFiles::mut_max_age_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "keep",
+                    LogFiles::get_keep_for_reflect,
+                    LogFiles::mut_keep_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "compress",
+                    LogFiles::get_compress_for_reflect,
+                    LogFiles::mut_compress_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<LogFiles>(
+                    "LogFiles",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for LogFiles {
+    fn clear(&mut self) {
+        self.clear_path();
+        self.clear_max_bytes();
+        self.clear_max_age();
+        self.clear_keep();
+        self.clear_compress();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LogFiles {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LogFiles {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Register {
     // message fields
@@ -117,8 +491,603 @@ impl ::protobuf::Message for Register {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.pipe.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
+        if let Some(ref v) = self.pipe.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.pipe.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Register {
+    fn new() -> Register {
+        Register::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Register>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "pipe",
+                    Register::get_pipe_for_reflect,
+                    Register::mut_pipe_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Register>(
+                    "Register",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Register {
+    fn clear(&mut self) {
+        self.clear_pipe();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Register {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Register {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResourceLimits {
+    // message fields
+    cpu_shares: ::std::option::Option<u64>,
+    memory_bytes: ::std::option::Option<u64>,
+    pids_max: ::std::option::Option<u64>,
+    io_weight: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ResourceLimits {}
+
+impl ResourceLimits {
+    pub fn new() -> ResourceLimits {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ResourceLimits {
+        static mut instance: ::protobuf::lazy::Lazy<ResourceLimits> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ResourceLimits,
+        };
+        unsafe {
+            instance.get(ResourceLimits::new)
+        }
+    }
+
+    // optional uint64 cpu_shares = 1;
+
+    pub fn clear_cpu_shares(&mut self) {
+        self.cpu_shares = ::std::option::Option::None;
+    }
+
+    pub fn has_cpu_shares(&self) -> bool {
+        self.cpu_shares.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_shares(&mut self, v: u64) {
+        self.cpu_shares = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_cpu_shares(&self) -> u64 {
+        self.cpu_shares.unwrap_or(0)
+    }
+
+    fn get_cpu_shares_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.cpu_shares
+    }
+
+    fn mut_cpu_shares_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.cpu_shares
+    }
+
+    // optional uint64 memory_bytes = 2;
+
+    pub fn clear_memory_bytes(&mut self) {
+        self.memory_bytes = ::std::option::Option::None;
+    }
+
+    pub fn has_memory_bytes(&self) -> bool {
+        self.memory_bytes.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_bytes(&mut self, v: u64) {
+        self.memory_bytes = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_memory_bytes(&self) -> u64 {
+        self.memory_bytes.unwrap_or(0)
+    }
+
+    fn get_memory_bytes_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.memory_bytes
+    }
+
+    fn mut_memory_bytes_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.memory_bytes
+    }
+
+    // optional uint64 pids_max = 3;
+
+    pub fn clear_pids_max(&mut self) {
+        self.pids_max = ::std::option::Option::None;
+    }
+
+    pub fn has_pids_max(&self) -> bool {
+        self.pids_max.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pids_max(&mut self, v: u64) {
+        self.pids_max = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pids_max(&self) -> u64 {
+        self.pids_max.unwrap_or(0)
+    }
+
+    fn get_pids_max_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.pids_max
+    }
+
+    fn mut_pids_max_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.pids_max
+    }
+
+    // optional uint32 io_weight = 4;
+
+    pub fn clear_io_weight(&mut self) {
+        self.io_weight = ::std::option::Option::None;
+    }
+
+    pub fn has_io_weight(&self) -> bool {
+        self.io_weight.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_io_weight(&mut self, v: u32) {
+        self.io_weight = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_io_weight(&self) -> u32 {
+        self.io_weight.unwrap_or(0)
+    }
+
+    fn get_io_weight_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.io_weight
+    }
+
+    fn mut_io_weight_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.io_weight
+    }
+}
+
+impl ::protobuf::Message for ResourceLimits {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.cpu_shares = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.memory_bytes = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.pids_max = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.io_weight = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.cpu_shares {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.memory_bytes {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.pids_max {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.io_weight {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.cpu_shares {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.memory_bytes {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.pids_max {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.io_weight {
+            os.write_uint32(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ResourceLimits {
+    fn new() -> ResourceLimits {
+        ResourceLimits::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ResourceLimits>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cpu_shares",
+                    ResourceLimits::get_cpu_shares_for_reflect,
+                    ResourceLimits::mut_cpu_shares_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "memory_bytes",
+                    ResourceLimits::get_memory_bytes_for_reflect,
+                    ResourceLimits::mut_memory_bytes_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "pids_max",
+                    ResourceLimits::get_pids_max_for_reflect,
+                    ResourceLimits::mut_pids_max_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "io_weight",
+                    ResourceLimits::get_io_weight_for_reflect,
+                    ResourceLimits::mut_io_weight_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResourceLimits>(
+                    "ResourceLimits",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ResourceLimits {
+    fn clear(&mut self) {
+        self.clear_cpu_shares();
+        self.clear_memory_bytes();
+        self.clear_pids_max();
+        self.clear_io_weight();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResourceLimits {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResourceLimits {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Restart {
+    // message fields
+    pid: ::std::option::Option<u32>,
+    limits: ::protobuf::SingularPtrField<ResourceLimits>,
+    shutdown: ::protobuf::SingularPtrField<ShutdownPolicy>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Restart {}
+
+impl Restart {
+    pub fn new() -> Restart {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Restart {
+        static mut instance: ::protobuf::lazy::Lazy<Restart> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Restart,
+        };
+        unsafe {
+            instance.get(Restart::new)
+        }
+    }
+
+    // optional uint32 pid = 1;
+
+    pub fn clear_pid(&mut self) {
+        self.pid = ::std::option::Option::None;
+    }
+
+    pub fn has_pid(&self) -> bool {
+        self.pid.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pid(&mut self, v: u32) {
+        self.pid = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pid(&self) -> u32 {
+        self.pid.unwrap_or(0)
+    }
+
+    fn get_pid_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.pid
+    }
+
+    fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.pid
+    }
+
+    // optional .ResourceLimits limits = 2;
+
+    pub fn clear_limits(&mut self) {
+        self.limits.clear();
+    }
+
+    pub fn has_limits(&self) -> bool {
+        self.limits.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limits(&mut self, v: ResourceLimits) {
+        self.limits = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_limits(&mut self) -> &mut ResourceLimits {
+        if self.limits.is_none() {
+            self.limits.set_default();
+        }
+        self.limits.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_limits(&mut self) -> ResourceLimits {
+        self.limits.take().unwrap_or_else(|| ResourceLimits::new())
+    }
+
+    pub fn get_limits(&self) -> &ResourceLimits {
+        self.limits.as_ref().unwrap_or_else(|| ResourceLimits::default_instance())
+    }
+
+    fn get_limits_for_reflect(&self) -> &::protobuf::SingularPtrField<ResourceLimits> {
+        &self.limits
+    }
+
+    fn mut_limits_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<ResourceLimits> {
+        &mut self.limits
+    }
+
+    // optional .ShutdownPolicy shutdown = 3;
+
+    pub fn clear_shutdown(&mut self) {
+        self.shutdown.clear();
+    }
+
+    pub fn has_shutdown(&self) -> bool {
+        self.shutdown.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shutdown(&mut self, v: ShutdownPolicy) {
+        self.shutdown = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_shutdown(&mut self) -> &mut ShutdownPolicy {
+        if self.shutdown.is_none() {
+            self.shutdown.set_default();
+        }
+        self.shutdown.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_shutdown(&mut self) -> ShutdownPolicy {
+        self.shutdown.take().unwrap_or_else(|| ShutdownPolicy::new())
+    }
+
+    pub fn get_shutdown(&self) -> &ShutdownPolicy {
+        self.shutdown.as_ref().unwrap_or_else(|| ShutdownPolicy::default_instance())
+    }
+
+    fn get_shutdown_for_reflect(&self) -> &::protobuf::SingularPtrField<ShutdownPolicy> {
+        &self.shutdown
+    }
+
+    fn mut_shutdown_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<ShutdownPolicy> {
+        &mut self.shutdown
+    }
+}
+
+impl ::protobuf::Message for Restart {
+    fn is_initialized(&self) -> bool {
+        for v in &self.limits {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.shutdown {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.pid = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.limits)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.shutdown)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.pid {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.limits.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.shutdown.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -126,8 +1095,18 @@ impl ::protobuf::Message for Register {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.pipe.as_ref() {
-            os.write_string(1, &v)?;
+        if let Some(v) = self.pid {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(ref v) = self.limits.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.shutdown.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -160,12 +1139,12 @@ impl ::protobuf::Message for Register {
     }
 }
 
-impl ::protobuf::MessageStatic for Register {
-    fn new() -> Register {
-        Register::new()
+impl ::protobuf::MessageStatic for Restart {
+    fn new() -> Restart {
+        Restart::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Register>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<Restart>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -173,13 +1152,23 @@ impl ::protobuf::MessageStatic for Register {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "pipe",
-                    Register::get_pipe_for_reflect,
-                    Register::mut_pipe_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "pid",
+                    Restart::get_pid_for_reflect,
+                    Restart::mut_pid_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Register>(
-                    "Register",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceLimits>>(
+                    "limits",
+                    Restart::get_limits_for_reflect,
+                    Restart::mut_limits_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShutdownPolicy>>(
+                    "shutdown",
+                    Restart::get_shutdown_for_reflect,
+                    Restart::mut_shutdown_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Restart>(
+                    "Restart",
                     fields,
                     file_descriptor_proto()
                 )
@@ -188,81 +1177,128 @@ impl ::protobuf::MessageStatic for Register {
     }
 }
 
-impl ::protobuf::Clear for Register {
+impl ::protobuf::Clear for Restart {
     fn clear(&mut self) {
-        self.clear_pipe();
+        self.clear_pid();
+        self.clear_limits();
+        self.clear_shutdown();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Register {
+impl ::std::fmt::Debug for Restart {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Register {
+impl ::protobuf::reflect::ProtobufValue for Restart {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Restart {
+pub struct ShutdownPolicy {
     // message fields
-    pid: ::std::option::Option<u32>,
+    signal: ::protobuf::SingularField<::std::string::String>,
+    timeout: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Restart {}
+unsafe impl ::std::marker::Sync for ShutdownPolicy {}
 
-impl Restart {
-    pub fn new() -> Restart {
+impl ShutdownPolicy {
+    pub fn new() -> ShutdownPolicy {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Restart {
-        static mut instance: ::protobuf::lazy::Lazy<Restart> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static ShutdownPolicy {
+        static mut instance: ::protobuf::lazy::Lazy<ShutdownPolicy> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Restart,
+            ptr: 0 as *const ShutdownPolicy,
         };
         unsafe {
-            instance.get(Restart::new)
+            instance.get(ShutdownPolicy::new)
         }
     }
 
-    // optional uint32 pid = 1;
+    // optional string signal = 1;
 
-    pub fn clear_pid(&mut self) {
-        self.pid = ::std::option::Option::None;
+    pub fn clear_signal(&mut self) {
+        self.signal.clear();
     }
 
-    pub fn has_pid(&self) -> bool {
-        self.pid.is_some()
+    pub fn has_signal(&self) -> bool {
+        self.signal.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_pid(&mut self, v: u32) {
-        self.pid = ::std::option::Option::Some(v);
+    pub fn set_signal(&mut self, v: ::std::string::String) {
+        self.signal = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_pid(&self) -> u32 {
-        self.pid.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signal(&mut self) -> &mut ::std::string::String {
+        if self.signal.is_none() {
+            self.signal.set_default();
+        }
+        self.signal.as_mut().unwrap()
     }
 
-    fn get_pid_for_reflect(&self) -> &::std::option::Option<u32> {
-        &self.pid
+    // Take field
+    pub fn take_signal(&mut self) -> ::std::string::String {
+        self.signal.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
-        &mut self.pid
+    pub fn get_signal(&self) -> &str {
+        match self.signal.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_signal_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.signal
+    }
+
+    fn mut_signal_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.signal
+    }
+
+    // optional uint32 timeout = 2;
+
+    pub fn clear_timeout(&mut self) {
+        self.timeout = ::std::option::Option::None;
+    }
+
+    pub fn has_timeout(&self) -> bool {
+        self.timeout.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timeout(&mut self, v: u32) {
+        self.timeout = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_timeout(&self) -> u32 {
+        self.timeout.unwrap_or(0)
+    }
+
+    fn get_timeout_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.timeout
+    }
+
+    fn mut_timeout_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.timeout
     }
 }
 
-impl ::protobuf::Message for Restart {
+impl ::protobuf::Message for ShutdownPolicy {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -272,11 +1308,14 @@ impl ::protobuf::Message for Restart {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.signal)?;
+                },
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.pid = ::std::option::Option::Some(tmp);
+                    self.timeout = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -290,8 +1329,11 @@ impl ::protobuf::Message for Restart {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.pid {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.signal.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.timeout {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -299,8 +1341,11 @@ impl ::protobuf::Message for Restart {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.pid {
-            os.write_uint32(1, v)?;
+        if let Some(ref v) = self.signal.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.timeout {
+            os.write_uint32(2, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -333,12 +1378,12 @@ impl ::protobuf::Message for Restart {
     }
 }
 
-impl ::protobuf::MessageStatic for Restart {
-    fn new() -> Restart {
-        Restart::new()
+impl ::protobuf::MessageStatic for ShutdownPolicy {
+    fn new() -> ShutdownPolicy {
+        ShutdownPolicy::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Restart>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<ShutdownPolicy>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -346,13 +1391,18 @@ impl ::protobuf::MessageStatic for Restart {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "signal",
+                    ShutdownPolicy::get_signal_for_reflect,
+                    ShutdownPolicy::mut_signal_for_reflect,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "pid",
-                    Restart::get_pid_for_reflect,
-                    Restart::mut_pid_for_reflect,
+                    "timeout",
+                    ShutdownPolicy::get_timeout_for_reflect,
+                    ShutdownPolicy::mut_timeout_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Restart>(
-                    "Restart",
+                ::protobuf::reflect::MessageDescriptor::new::<ShutdownPolicy>(
+                    "ShutdownPolicy",
                     fields,
                     file_descriptor_proto()
                 )
@@ -361,20 +1411,21 @@ impl ::protobuf::MessageStatic for Restart {
     }
 }
 
-impl ::protobuf::Clear for Restart {
+impl ::protobuf::Clear for ShutdownPolicy {
     fn clear(&mut self) {
-        self.clear_pid();
+        self.clear_signal();
+        self.clear_timeout();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Restart {
+impl ::std::fmt::Debug for ShutdownPolicy {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Restart {
+impl ::protobuf::reflect::ProtobufValue for ShutdownPolicy {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -389,6 +1440,9 @@ pub struct Spawn {
     svc_group: ::protobuf::SingularField<::std::string::String>,
     svc_password: ::protobuf::SingularField<::std::string::String>,
     pub env: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    limits: ::protobuf::SingularPtrField<ResourceLimits>,
+    shutdown: ::protobuf::SingularPtrField<ShutdownPolicy>,
+    logs: ::protobuf::SingularPtrField<LogFiles>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -664,10 +1718,148 @@ impl Spawn {
     fn mut_env_for_reflect(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
         &mut self.env
     }
+
+    // optional .ResourceLimits limits = 7;
+
+    pub fn clear_limits(&mut self) {
+        self.limits.clear();
+    }
+
+    pub fn has_limits(&self) -> bool {
+        self.limits.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limits(&mut self, v: ResourceLimits) {
+        self.limits = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_limits(&mut self) -> &mut ResourceLimits {
+        if self.limits.is_none() {
+            self.limits.set_default();
+        }
+        self.limits.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_limits(&mut self) -> ResourceLimits {
+        self.limits.take().unwrap_or_else(|| ResourceLimits::new())
+    }
+
+    pub fn get_limits(&self) -> &ResourceLimits {
+        self.limits.as_ref().unwrap_or_else(|| ResourceLimits::default_instance())
+    }
+
+    fn get_limits_for_reflect(&self) -> &::protobuf::SingularPtrField<ResourceLimits> {
+        &self.limits
+    }
+
+    fn mut_limits_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<ResourceLimits> {
+        &mut self.limits
+    }
+
+    // optional .ShutdownPolicy shutdown = 8;
+
+    pub fn clear_shutdown(&mut self) {
+        self.shutdown.clear();
+    }
+
+    pub fn has_shutdown(&self) -> bool {
+        self.shutdown.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shutdown(&mut self, v: ShutdownPolicy) {
+        self.shutdown = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_shutdown(&mut self) -> &mut ShutdownPolicy {
+        if self.shutdown.is_none() {
+            self.shutdown.set_default();
+        }
+        self.shutdown.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_shutdown(&mut self) -> ShutdownPolicy {
+        self.shutdown.take().unwrap_or_else(|| ShutdownPolicy::new())
+    }
+
+    pub fn get_shutdown(&self) -> &ShutdownPolicy {
+        self.shutdown.as_ref().unwrap_or_else(|| ShutdownPolicy::default_instance())
+    }
+
+    fn get_shutdown_for_reflect(&self) -> &::protobuf::SingularPtrField<ShutdownPolicy> {
+        &self.shutdown
+    }
+
+    fn mut_shutdown_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<ShutdownPolicy> {
+        &mut self.shutdown
+    }
+
+    // optional .LogFiles logs = 9;
+
+    pub fn clear_logs(&mut self) {
+        self.logs.clear();
+    }
+
+    pub fn has_logs(&self) -> bool {
+        self.logs.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_logs(&mut self, v: LogFiles) {
+        self.logs = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_logs(&mut self) -> &mut LogFiles {
+        if self.logs.is_none() {
+            self.logs.set_default();
+        }
+        self.logs.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_logs(&mut self) -> LogFiles {
+        self.logs.take().unwrap_or_else(|| LogFiles::new())
+    }
+
+    pub fn get_logs(&self) -> &LogFiles {
+        self.logs.as_ref().unwrap_or_else(|| LogFiles::default_instance())
+    }
+
+    fn get_logs_for_reflect(&self) -> &::protobuf::SingularPtrField<LogFiles> {
+        &self.logs
+    }
+
+    fn mut_logs_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<LogFiles> {
+        &mut self.logs
+    }
 }
 
 impl ::protobuf::Message for Spawn {
     fn is_initialized(&self) -> bool {
+        for v in &self.limits {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.shutdown {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.logs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -693,6 +1885,15 @@ impl ::protobuf::Message for Spawn {
                 6 => {
                     ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.env)?;
                 },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.limits)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.shutdown)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.logs)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -721,6 +1922,18 @@ impl ::protobuf::Message for Spawn {
             my_size += ::protobuf::rt::string_size(5, &v);
         }
         my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.env);
+        if let Some(ref v) = self.limits.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.shutdown.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.logs.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -743,6 +1956,21 @@ impl ::protobuf::Message for Spawn {
             os.write_string(5, &v)?;
         }
         ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(6, &self.env, os)?;
+        if let Some(ref v) = self.limits.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.shutdown.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.logs.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -817,6 +2045,21 @@ impl ::protobuf::MessageStatic for Spawn {
                     Spawn::get_env_for_reflect,
                     Spawn::mut_env_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceLimits>>(
+                    "limits",
+                    Spawn::get_limits_for_reflect,
+                    Spawn::mut_limits_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShutdownPolicy>>(
+                    "shutdown",
+                    Spawn::get_shutdown_for_reflect,
+                    Spawn::mut_shutdown_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LogFiles>>(
+                    "logs",
+                    Spawn::get_logs_for_reflect,
+                    Spawn::mut_logs_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Spawn>(
                     "Spawn",
                     fields,
@@ -835,6 +2078,9 @@ impl ::protobuf::Clear for Spawn {
         self.clear_svc_group();
         self.clear_svc_password();
         self.clear_env();
+        self.clear_limits();
+        self.clear_shutdown();
+        self.clear_logs();
         self.unknown_fields.clear();
     }
 }
@@ -855,6 +2101,7 @@ impl ::protobuf::reflect::ProtobufValue for Spawn {
 pub struct SpawnOk {
     // message fields
     pid: ::std::option::Option<u32>,
+    limits: ::protobuf::SingularPtrField<ResourceLimits>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -904,10 +2151,56 @@ impl SpawnOk {
     fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
         &mut self.pid
     }
+
+    // optional .ResourceLimits limits = 2;
+
+    pub fn clear_limits(&mut self) {
+        self.limits.clear();
+    }
+
+    pub fn has_limits(&self) -> bool {
+        self.limits.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limits(&mut self, v: ResourceLimits) {
+        self.limits = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_limits(&mut self) -> &mut ResourceLimits {
+        if self.limits.is_none() {
+            self.limits.set_default();
+        }
+        self.limits.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_limits(&mut self) -> ResourceLimits {
+        self.limits.take().unwrap_or_else(|| ResourceLimits::new())
+    }
+
+    pub fn get_limits(&self) -> &ResourceLimits {
+        self.limits.as_ref().unwrap_or_else(|| ResourceLimits::default_instance())
+    }
+
+    fn get_limits_for_reflect(&self) -> &::protobuf::SingularPtrField<ResourceLimits> {
+        &self.limits
+    }
+
+    fn mut_limits_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<ResourceLimits> {
+        &mut self.limits
+    }
 }
 
 impl ::protobuf::Message for SpawnOk {
     fn is_initialized(&self) -> bool {
+        for v in &self.limits {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -922,6 +2215,9 @@ impl ::protobuf::Message for SpawnOk {
                     let tmp = is.read_uint32()?;
                     self.pid = ::std::option::Option::Some(tmp);
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.limits)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -937,6 +2233,10 @@ impl ::protobuf::Message for SpawnOk {
         if let Some(v) = self.pid {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(ref v) = self.limits.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -946,6 +2246,11 @@ impl ::protobuf::Message for SpawnOk {
         if let Some(v) = self.pid {
             os.write_uint32(1, v)?;
         }
+        if let Some(ref v) = self.limits.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -995,6 +2300,11 @@ impl ::protobuf::MessageStatic for SpawnOk {
                     SpawnOk::get_pid_for_reflect,
                     SpawnOk::mut_pid_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceLimits>>(
+                    "limits",
+                    SpawnOk::get_limits_for_reflect,
+                    SpawnOk::mut_limits_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SpawnOk>(
                     "SpawnOk",
                     fields,
@@ -1008,6 +2318,7 @@ impl ::protobuf::MessageStatic for SpawnOk {
 impl ::protobuf::Clear for SpawnOk {
     fn clear(&mut self) {
         self.clear_pid();
+        self.clear_limits();
         self.unknown_fields.clear();
     }
 }
@@ -1028,6 +2339,7 @@ impl ::protobuf::reflect::ProtobufValue for SpawnOk {
 pub struct Terminate {
     // message fields
     pid: ::std::option::Option<u32>,
+    shutdown: ::protobuf::SingularPtrField<ShutdownPolicy>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -1077,10 +2389,56 @@ impl Terminate {
     fn mut_pid_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
         &mut self.pid
     }
+
+    // optional .ShutdownPolicy shutdown = 2;
+
+    pub fn clear_shutdown(&mut self) {
+        self.shutdown.clear();
+    }
+
+    pub fn has_shutdown(&self) -> bool {
+        self.shutdown.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shutdown(&mut self, v: ShutdownPolicy) {
+        self.shutdown = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_shutdown(&mut self) -> &mut ShutdownPolicy {
+        if self.shutdown.is_none() {
+            self.shutdown.set_default();
+        }
+        self.shutdown.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_shutdown(&mut self) -> ShutdownPolicy {
+        self.shutdown.take().unwrap_or_else(|| ShutdownPolicy::new())
+    }
+
+    pub fn get_shutdown(&self) -> &ShutdownPolicy {
+        self.shutdown.as_ref().unwrap_or_else(|| ShutdownPolicy::default_instance())
+    }
+
+    fn get_shutdown_for_reflect(&self) -> &::protobuf::SingularPtrField<ShutdownPolicy> {
+        &self.shutdown
+    }
+
+    fn mut_shutdown_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<ShutdownPolicy> {
+        &mut self.shutdown
+    }
 }
 
 impl ::protobuf::Message for Terminate {
     fn is_initialized(&self) -> bool {
+        for v in &self.shutdown {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1095,6 +2453,9 @@ impl ::protobuf::Message for Terminate {
                     let tmp = is.read_uint32()?;
                     self.pid = ::std::option::Option::Some(tmp);
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.shutdown)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1110,6 +2471,10 @@ impl ::protobuf::Message for Terminate {
         if let Some(v) = self.pid {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(ref v) = self.shutdown.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1119,6 +2484,11 @@ impl ::protobuf::Message for Terminate {
         if let Some(v) = self.pid {
             os.write_uint32(1, v)?;
         }
+        if let Some(ref v) = self.shutdown.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1168,6 +2538,11 @@ impl ::protobuf::MessageStatic for Terminate {
                     Terminate::get_pid_for_reflect,
                     Terminate::mut_pid_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShutdownPolicy>>(
+                    "shutdown",
+                    Terminate::get_shutdown_for_reflect,
+                    Terminate::mut_shutdown_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Terminate>(
                     "Terminate",
                     fields,
@@ -1181,6 +2556,7 @@ impl ::protobuf::MessageStatic for Terminate {
 impl ::protobuf::Clear for Terminate {
     fn clear(&mut self) {
         self.clear_pid();
+        self.clear_shutdown();
         self.unknown_fields.clear();
     }
 }
@@ -1470,78 +2846,157 @@ impl ::protobuf::reflect::ProtobufValue for ShutdownMethod {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x18protocols/launcher.proto\x12\x08launcher\"\x1e\n\x08Register\x12\
-    \x12\n\x04pipe\x18\x01\x20\x01(\tR\x04pipe\"\x1b\n\x07Restart\x12\x10\n\
-    \x03pid\x18\x01\x20\x01(\rR\x03pid\"\xee\x01\n\x05Spawn\x12\x0e\n\x02id\
-    \x18\x01\x20\x01(\tR\x02id\x12\x16\n\x06binary\x18\x02\x20\x01(\tR\x06bi\
-    nary\x12\x19\n\x08svc_user\x18\x03\x20\x01(\tR\x07svcUser\x12\x1b\n\tsvc\
-    _group\x18\x04\x20\x01(\tR\x08svcGroup\x12!\n\x0csvc_password\x18\x05\
-    \x20\x01(\tR\x0bsvcPassword\x12*\n\x03env\x18\x06\x20\x03(\x0b2\x18.laun\
-    cher.Spawn.EnvEntryR\x03env\x1a6\n\x08EnvEntry\x12\x10\n\x03key\x18\x01\
-    \x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x02\
-    8\x01\"\x1b\n\x07SpawnOk\x12\x10\n\x03pid\x18\x01\x20\x01(\rR\x03pid\"\
-    \x1d\n\tTerminate\x12\x10\n\x03pid\x18\x01\x20\x01(\rR\x03pid\"m\n\x0bTe\
-    rminateOk\x12\x1b\n\texit_code\x18\x01\x20\x01(\x05R\x08exitCode\x12A\n\
-    \x0fshutdown_method\x18\x02\x20\x01(\x0e2\x18.launcher.ShutdownMethodR\
-    \x0eshutdownMethod*H\n\x0eShutdownMethod\x12\x11\n\rAlreadyExited\x10\0\
-    \x12\x17\n\x13GracefulTermination\x10\x01\x12\n\n\x06Killed\x10\x02J\xfc\
-    \x08\n\x06\x12\x04\0\0&\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\
-    \x02\x12\x03\x02\x08\x10\n\n\n\x02\x04\0\x12\x04\x04\0\x06\x01\n\n\n\x03\
-    \x04\0\x01\x12\x03\x04\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x02\
-    \x1b\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x05\x02\n\n\x0c\n\x05\x04\0\x02\
-    \0\x05\x12\x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x12\
-    \x16\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x05\x19\x1a\n\n\n\x02\x04\x01\
-    \x12\x04\x08\0\n\x01\n\n\n\x03\x04\x01\x01\x12\x03\x08\x08\x0f\n\x0b\n\
-    \x04\x04\x01\x02\0\x12\x03\t\x02\x1a\n\x0c\n\x05\x04\x01\x02\0\x04\x12\
-    \x03\t\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\t\x0b\x11\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03\t\x12\x15\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03\t\x18\x19\n\n\n\x02\x04\x02\x12\x04\x0c\0\x13\x01\n\n\n\x03\x04\x02\
-    \x01\x12\x03\x0c\x08\r\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x02\x19\n\x0c\
-    \n\x05\x04\x02\x02\0\x04\x12\x03\r\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\
-    \x12\x03\r\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\r\x12\x14\n\x0c\
-    \n\x05\x04\x02\x02\0\x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\x02\x02\x01\
-    \x12\x03\x0e\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03\x0e\x02\n\n\
-    \x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x02\
-    \x02\x01\x01\x12\x03\x0e\x12\x18\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\
-    \x0e\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x02\x1f\n\x0c\n\x05\
-    \x04\x02\x02\x02\x04\x12\x03\x0f\x02\n\n\x0c\n\x05\x04\x02\x02\x02\x05\
-    \x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x12\x1a\
-    \n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x1d\x1e\n\x0b\n\x04\x04\x02\
-    \x02\x03\x12\x03\x10\x02\x20\n\x0c\n\x05\x04\x02\x02\x03\x04\x12\x03\x10\
-    \x02\n\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x10\x0b\x11\n\x0c\n\x05\
-    \x04\x02\x02\x03\x01\x12\x03\x10\x12\x1b\n\x0c\n\x05\x04\x02\x02\x03\x03\
-    \x12\x03\x10\x1e\x1f\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x11\x02#\n\x0c\
-    \n\x05\x04\x02\x02\x04\x04\x12\x03\x11\x02\n\n\x0c\n\x05\x04\x02\x02\x04\
-    \x05\x12\x03\x11\x0b\x11\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x11\x12\
-    \x1e\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x11!\"\n\x0b\n\x04\x04\x02\
-    \x02\x05\x12\x03\x12\x02\x1e\n\r\n\x05\x04\x02\x02\x05\x04\x12\x04\x12\
-    \x02\x11#\n\x0c\n\x05\x04\x02\x02\x05\x06\x12\x03\x12\x02\x15\n\x0c\n\
-    \x05\x04\x02\x02\x05\x01\x12\x03\x12\x16\x19\n\x0c\n\x05\x04\x02\x02\x05\
-    \x03\x12\x03\x12\x1c\x1d\n\n\n\x02\x04\x03\x12\x04\x15\0\x17\x01\n\n\n\
-    \x03\x04\x03\x01\x12\x03\x15\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
-    \x16\x02\x1a\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x16\x02\n\n\x0c\n\x05\
-    \x04\x03\x02\0\x05\x12\x03\x16\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\
-    \x03\x16\x12\x15\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x16\x18\x19\n\n\n\
-    \x02\x04\x04\x12\x04\x19\0\x1b\x01\n\n\n\x03\x04\x04\x01\x12\x03\x19\x08\
-    \x11\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1a\x02\x1a\n\x0c\n\x05\x04\x04\
-    \x02\0\x04\x12\x03\x1a\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x1a\
-    \x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1a\x12\x15\n\x0c\n\x05\
-    \x04\x04\x02\0\x03\x12\x03\x1a\x18\x19\n\n\n\x02\x04\x05\x12\x04\x1d\0\
-    \x20\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1d\x08\x13\n\x0b\n\x04\x04\x05\
-    \x02\0\x12\x03\x1e\x02\x1f\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03\x1e\x02\
-    \n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1e\x0b\x10\n\x0c\n\x05\x04\x05\
-    \x02\0\x01\x12\x03\x1e\x11\x1a\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1e\
-    \x1d\x1e\n\x0b\n\x04\x04\x05\x02\x01\x12\x03\x1f\x02.\n\x0c\n\x05\x04\
-    \x05\x02\x01\x04\x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x05\x02\x01\x06\x12\
-    \x03\x1f\x0b\x19\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03\x1f\x1a)\n\x0c\
-    \n\x05\x04\x05\x02\x01\x03\x12\x03\x1f,-\n\n\n\x02\x05\0\x12\x04\"\0&\
-    \x01\n\n\n\x03\x05\0\x01\x12\x03\"\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\
-    \x03#\x02\x14\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03#\x02\x0f\n\x0c\n\x05\
-    \x05\0\x02\0\x02\x12\x03#\x12\x13\n\x0b\n\x04\x05\0\x02\x01\x12\x03$\x02\
-    \x1a\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03$\x02\x15\n\x0c\n\x05\x05\0\
-    \x02\x01\x02\x12\x03$\x18\x19\n\x0b\n\x04\x05\0\x02\x02\x12\x03%\x02\r\n\
-    \x0c\n\x05\x05\0\x02\x02\x01\x12\x03%\x02\x08\n\x0c\n\x05\x05\0\x02\x02\
-    \x02\x12\x03%\x0b\x0c\
+    \n\x18protocols/launcher.proto\x12\x08launcher\"\x84\x01\n\x08LogFiles\
+    \x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x12\x1b\n\tmax_bytes\x18\
+    \x02\x20\x01(\x04R\x08maxBytes\x12\x17\n\x07max_age\x18\x03\x20\x01(\x04\
+    R\x06maxAge\x12\x12\n\x04keep\x18\x04\x20\x01(\rR\x04keep\x12\x1a\n\x08c\
+    ompress\x18\x05\x20\x01(\x08R\x08compress\"\x1e\n\x08Register\x12\x12\n\
+    \x04pipe\x18\x01\x20\x01(\tR\x04pipe\"\x8a\x01\n\x0eResourceLimits\x12\
+    \x1d\n\ncpu_shares\x18\x01\x20\x01(\x04R\tcpuShares\x12!\n\x0cmemory_byt\
+    es\x18\x02\x20\x01(\x04R\x0bmemoryBytes\x12\x19\n\x08pids_max\x18\x03\
+    \x20\x01(\x04R\x07pidsMax\x12\x1b\n\tio_weight\x18\x04\x20\x01(\rR\x08io\
+    Weight\"\x83\x01\n\x07Restart\x12\x10\n\x03pid\x18\x01\x20\x01(\rR\x03pi\
+    d\x120\n\x06limits\x18\x02\x20\x01(\x0b2\x18.launcher.ResourceLimitsR\
+    \x06limits\x124\n\x08shutdown\x18\x03\x20\x01(\x0b2\x18.launcher.Shutdow\
+    nPolicyR\x08shutdown\"B\n\x0eShutdownPolicy\x12\x16\n\x06signal\x18\x01\
+    \x20\x01(\tR\x06signal\x12\x18\n\x07timeout\x18\x02\x20\x01(\rR\x07timeo\
+    ut\"\xfe\x02\n\x05Spawn\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\
+    \x16\n\x06binary\x18\x02\x20\x01(\tR\x06binary\x12\x19\n\x08svc_user\x18\
+    \x03\x20\x01(\tR\x07svcUser\x12\x1b\n\tsvc_group\x18\x04\x20\x01(\tR\x08\
+    svcGroup\x12!\n\x0csvc_password\x18\x05\x20\x01(\tR\x0bsvcPassword\x12*\
+    \n\x03env\x18\x06\x20\x03(\x0b2\x18.launcher.Spawn.EnvEntryR\x03env\x120\
+    \n\x06limits\x18\x07\x20\x01(\x0b2\x18.launcher.ResourceLimitsR\x06limit\
+    s\x124\n\x08shutdown\x18\x08\x20\x01(\x0b2\x18.launcher.ShutdownPolicyR\
+    \x08shutdown\x12&\n\x04logs\x18\t\x20\x01(\x0b2\x12.launcher.LogFilesR\
+    \x04logs\x1a6\n\x08EnvEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
+    \x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"M\n\x07Spawn\
+    Ok\x12\x10\n\x03pid\x18\x01\x20\x01(\rR\x03pid\x120\n\x06limits\x18\x02\
+    \x20\x01(\x0b2\x18.launcher.ResourceLimitsR\x06limits\"S\n\tTerminate\
+    \x12\x10\n\x03pid\x18\x01\x20\x01(\rR\x03pid\x124\n\x08shutdown\x18\x02\
+    \x20\x01(\x0b2\x18.launcher.ShutdownPolicyR\x08shutdown\"m\n\x0bTerminat\
+    eOk\x12\x1b\n\texit_code\x18\x01\x20\x01(\x05R\x08exitCode\x12A\n\x0fshu\
+    tdown_method\x18\x02\x20\x01(\x0e2\x18.launcher.ShutdownMethodR\x0eshutd\
+    ownMethod*H\n\x0eShutdownMethod\x12\x11\n\rAlreadyExited\x10\0\x12\x17\n\
+    \x13GracefulTermination\x10\x01\x12\n\n\x06Killed\x10\x02J\x8f\x13\n\x06\
+    \x12\x04\0\0A\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\
+    \x03\x02\0\x11\n\n\n\x02\x04\0\x12\x04\x04\0\n\x01\n\n\n\x03\x04\0\x01\
+    \x12\x03\x04\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x05\x02\x1b\n\x0c\n\
+    \x05\x04\0\x02\0\x04\x12\x03\x05\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\
+    \x03\x05\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x05\x12\x16\n\x0c\n\
+    \x05\x04\0\x02\0\x03\x12\x03\x05\x19\x1a\n\x0b\n\x04\x04\0\x02\x01\x12\
+    \x03\x06\x02\x20\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x06\x02\n\n\x0c\n\
+    \x05\x04\0\x02\x01\x05\x12\x03\x06\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\
+    \x12\x03\x06\x12\x1b\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x06\x1e\x1f\n\
+    \x0b\n\x04\x04\0\x02\x02\x12\x03\x07\x02\x1e\n\x0c\n\x05\x04\0\x02\x02\
+    \x04\x12\x03\x07\x02\n\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x07\x0b\x11\
+    \n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x07\x12\x19\n\x0c\n\x05\x04\0\x02\
+    \x02\x03\x12\x03\x07\x1c\x1d\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x08\x02\
+    \x1b\n\x0c\n\x05\x04\0\x02\x03\x04\x12\x03\x08\x02\n\n\x0c\n\x05\x04\0\
+    \x02\x03\x05\x12\x03\x08\x0b\x11\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\
+    \x08\x12\x16\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x08\x19\x1a\n\x0b\n\
+    \x04\x04\0\x02\x04\x12\x03\t\x02\x1d\n\x0c\n\x05\x04\0\x02\x04\x04\x12\
+    \x03\t\x02\n\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\t\x0b\x0f\n\x0c\n\x05\
+    \x04\0\x02\x04\x01\x12\x03\t\x10\x18\n\x0c\n\x05\x04\0\x02\x04\x03\x12\
+    \x03\t\x1b\x1c\n\n\n\x02\x04\x01\x12\x04\x0c\0\x0e\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\x0c\x08\x10\n\x0b\n\x04\x04\x01\x02\0\x12\x03\r\x02\x1b\n\
+    \x0c\n\x05\x04\x01\x02\0\x04\x12\x03\r\x02\n\n\x0c\n\x05\x04\x01\x02\0\
+    \x05\x12\x03\r\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\r\x12\x16\n\
+    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03\r\x19\x1a\n\n\n\x02\x04\x02\x12\x04\
+    \x10\0\x15\x01\n\n\n\x03\x04\x02\x01\x12\x03\x10\x08\x16\n\x0b\n\x04\x04\
+    \x02\x02\0\x12\x03\x11\x02!\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\x11\
+    \x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x11\x0b\x11\n\x0c\n\x05\x04\
+    \x02\x02\0\x01\x12\x03\x11\x12\x1c\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\
+    \x11\x1f\x20\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x12\x02#\n\x0c\n\x05\
+    \x04\x02\x02\x01\x04\x12\x03\x12\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\
+    \x12\x03\x12\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x12\x12\x1e\
+    \n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x12!\"\n\x0b\n\x04\x04\x02\x02\
+    \x02\x12\x03\x13\x02\x1f\n\x0c\n\x05\x04\x02\x02\x02\x04\x12\x03\x13\x02\
+    \n\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x13\x0b\x11\n\x0c\n\x05\x04\
+    \x02\x02\x02\x01\x12\x03\x13\x12\x1a\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\
+    \x03\x13\x1d\x1e\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x14\x02\x20\n\x0c\n\
+    \x05\x04\x02\x02\x03\x04\x12\x03\x14\x02\n\n\x0c\n\x05\x04\x02\x02\x03\
+    \x05\x12\x03\x14\x0b\x11\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x14\x12\
+    \x1b\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x14\x1e\x1f\n\n\n\x02\x04\
+    \x03\x12\x04\x17\0\x1b\x01\n\n\n\x03\x04\x03\x01\x12\x03\x17\x08\x0f\n\
+    \x0b\n\x04\x04\x03\x02\0\x12\x03\x18\x02\x1a\n\x0c\n\x05\x04\x03\x02\0\
+    \x04\x12\x03\x18\x02\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x18\x0b\x11\
+    \n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x18\x12\x15\n\x0c\n\x05\x04\x03\
+    \x02\0\x03\x12\x03\x18\x18\x19\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x19\
+    \x02%\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03\x19\x02\n\n\x0c\n\x05\x04\
+    \x03\x02\x01\x06\x12\x03\x19\x0b\x19\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\
+    \x03\x19\x1a\x20\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x19#$\n\x0b\n\
+    \x04\x04\x03\x02\x02\x12\x03\x1a\x02'\n\x0c\n\x05\x04\x03\x02\x02\x04\
+    \x12\x03\x1a\x02\n\n\x0c\n\x05\x04\x03\x02\x02\x06\x12\x03\x1a\x0b\x19\n\
+    \x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x1a\x1a\"\n\x0c\n\x05\x04\x03\x02\
+    \x02\x03\x12\x03\x1a%&\n\n\n\x02\x04\x04\x12\x04\x1d\0\x20\x01\n\n\n\x03\
+    \x04\x04\x01\x12\x03\x1d\x08\x16\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1e\
+    \x02\x1d\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1e\x02\n\n\x0c\n\x05\x04\
+    \x04\x02\0\x05\x12\x03\x1e\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\
+    \x1e\x12\x18\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1e\x1b\x1c\n\x0b\n\
+    \x04\x04\x04\x02\x01\x12\x03\x1f\x02\x1e\n\x0c\n\x05\x04\x04\x02\x01\x04\
+    \x12\x03\x1f\x02\n\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x1f\x0b\x11\n\
+    \x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x1f\x12\x19\n\x0c\n\x05\x04\x04\
+    \x02\x01\x03\x12\x03\x1f\x1c\x1d\n\n\n\x02\x04\x05\x12\x04\"\0,\x01\n\n\
+    \n\x03\x04\x05\x01\x12\x03\"\x08\r\n\x0b\n\x04\x04\x05\x02\0\x12\x03#\
+    \x02\x19\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03#\x02\n\n\x0c\n\x05\x04\
+    \x05\x02\0\x05\x12\x03#\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03#\
+    \x12\x14\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03#\x17\x18\n\x0b\n\x04\x04\
+    \x05\x02\x01\x12\x03$\x02\x1d\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03$\
+    \x02\n\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03$\x0b\x11\n\x0c\n\x05\x04\
+    \x05\x02\x01\x01\x12\x03$\x12\x18\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\
+    \x03$\x1b\x1c\n\x0b\n\x04\x04\x05\x02\x02\x12\x03%\x02\x1f\n\x0c\n\x05\
+    \x04\x05\x02\x02\x04\x12\x03%\x02\n\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\
+    \x03%\x0b\x11\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03%\x12\x1a\n\x0c\n\
+    \x05\x04\x05\x02\x02\x03\x12\x03%\x1d\x1e\n\x0b\n\x04\x04\x05\x02\x03\
+    \x12\x03&\x02\x20\n\x0c\n\x05\x04\x05\x02\x03\x04\x12\x03&\x02\n\n\x0c\n\
+    \x05\x04\x05\x02\x03\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\x05\x02\x03\
+    \x01\x12\x03&\x12\x1b\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03&\x1e\x1f\n\
+    \x0b\n\x04\x04\x05\x02\x04\x12\x03'\x02#\n\x0c\n\x05\x04\x05\x02\x04\x04\
+    \x12\x03'\x02\n\n\x0c\n\x05\x04\x05\x02\x04\x05\x12\x03'\x0b\x11\n\x0c\n\
+    \x05\x04\x05\x02\x04\x01\x12\x03'\x12\x1e\n\x0c\n\x05\x04\x05\x02\x04\
+    \x03\x12\x03'!\"\n\x0b\n\x04\x04\x05\x02\x05\x12\x03(\x02\x1e\n\x0c\n\
+    \x05\x04\x05\x02\x05\x06\x12\x03(\x02\x15\n\x0c\n\x05\x04\x05\x02\x05\
+    \x01\x12\x03(\x16\x19\n\x0c\n\x05\x04\x05\x02\x05\x03\x12\x03(\x1c\x1d\n\
+    \x0b\n\x04\x04\x05\x02\x06\x12\x03)\x02%\n\x0c\n\x05\x04\x05\x02\x06\x04\
+    \x12\x03)\x02\n\n\x0c\n\x05\x04\x05\x02\x06\x06\x12\x03)\x0b\x19\n\x0c\n\
+    \x05\x04\x05\x02\x06\x01\x12\x03)\x1a\x20\n\x0c\n\x05\x04\x05\x02\x06\
+    \x03\x12\x03)#$\n\x0b\n\x04\x04\x05\x02\x07\x12\x03*\x02'\n\x0c\n\x05\
+    \x04\x05\x02\x07\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\x05\x02\x07\x06\x12\
+    \x03*\x0b\x19\n\x0c\n\x05\x04\x05\x02\x07\x01\x12\x03*\x1a\"\n\x0c\n\x05\
+    \x04\x05\x02\x07\x03\x12\x03*%&\n\x0b\n\x04\x04\x05\x02\x08\x12\x03+\x02\
+    \x1d\n\x0c\n\x05\x04\x05\x02\x08\x04\x12\x03+\x02\n\n\x0c\n\x05\x04\x05\
+    \x02\x08\x06\x12\x03+\x0b\x13\n\x0c\n\x05\x04\x05\x02\x08\x01\x12\x03+\
+    \x14\x18\n\x0c\n\x05\x04\x05\x02\x08\x03\x12\x03+\x1b\x1c\n\n\n\x02\x04\
+    \x06\x12\x04.\01\x01\n\n\n\x03\x04\x06\x01\x12\x03.\x08\x0f\n\x0b\n\x04\
+    \x04\x06\x02\0\x12\x03/\x02\x1a\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03/\
+    \x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03/\x0b\x11\n\x0c\n\x05\x04\
+    \x06\x02\0\x01\x12\x03/\x12\x15\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03/\
+    \x18\x19\n\x0b\n\x04\x04\x06\x02\x01\x12\x030\x02%\n\x0c\n\x05\x04\x06\
+    \x02\x01\x04\x12\x030\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x06\x12\x030\x0b\
+    \x19\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x030\x1a\x20\n\x0c\n\x05\x04\
+    \x06\x02\x01\x03\x12\x030#$\n\n\n\x02\x04\x07\x12\x043\06\x01\n\n\n\x03\
+    \x04\x07\x01\x12\x033\x08\x11\n\x0b\n\x04\x04\x07\x02\0\x12\x034\x02\x1a\
+    \n\x0c\n\x05\x04\x07\x02\0\x04\x12\x034\x02\n\n\x0c\n\x05\x04\x07\x02\0\
+    \x05\x12\x034\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x034\x12\x15\n\
+    \x0c\n\x05\x04\x07\x02\0\x03\x12\x034\x18\x19\n\x0b\n\x04\x04\x07\x02\
+    \x01\x12\x035\x02'\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x035\x02\n\n\x0c\
+    \n\x05\x04\x07\x02\x01\x06\x12\x035\x0b\x19\n\x0c\n\x05\x04\x07\x02\x01\
+    \x01\x12\x035\x1a\"\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x035%&\n\n\n\x02\
+    \x04\x08\x12\x048\0;\x01\n\n\n\x03\x04\x08\x01\x12\x038\x08\x13\n\x0b\n\
+    \x04\x04\x08\x02\0\x12\x039\x02\x1f\n\x0c\n\x05\x04\x08\x02\0\x04\x12\
+    \x039\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x039\x0b\x10\n\x0c\n\x05\
+    \x04\x08\x02\0\x01\x12\x039\x11\x1a\n\x0c\n\x05\x04\x08\x02\0\x03\x12\
+    \x039\x1d\x1e\n\x0b\n\x04\x04\x08\x02\x01\x12\x03:\x02.\n\x0c\n\x05\x04\
+    \x08\x02\x01\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x06\x12\x03:\
+    \x0b\x19\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03:\x1a)\n\x0c\n\x05\x04\
+    \x08\x02\x01\x03\x12\x03:,-\n\n\n\x02\x05\0\x12\x04=\0A\x01\n\n\n\x03\
+    \x05\0\x01\x12\x03=\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\x03>\x02\x14\n\
+    \x0c\n\x05\x05\0\x02\0\x01\x12\x03>\x02\x0f\n\x0c\n\x05\x05\0\x02\0\x02\
+    \x12\x03>\x12\x13\n\x0b\n\x04\x05\0\x02\x01\x12\x03?\x02\x1a\n\x0c\n\x05\
+    \x05\0\x02\x01\x01\x12\x03?\x02\x15\n\x0c\n\x05\x05\0\x02\x01\x02\x12\
+    \x03?\x18\x19\n\x0b\n\x04\x05\0\x02\x02\x12\x03@\x02\r\n\x0c\n\x05\x05\0\
+    \x02\x02\x01\x12\x03@\x02\x08\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03@\x0b\
+    \x0c\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -23,7 +23,7 @@ impl Handler for RestartHandler {
     type Message = protocol::Restart;
     type Reply = protocol::SpawnOk;
 
-    fn handle(msg: Self::Message, services: &mut ServiceTable) -> HandleResult<Self::Reply> {
+    fn handle(mut msg: Self::Message, services: &mut ServiceTable) -> HandleResult<Self::Reply> {
         let mut service = match services.remove(msg.get_pid()) {
             Some(service) => service,
             None => {
@@ -32,13 +32,29 @@ impl Handler for RestartHandler {
                 return Err(reply);
             }
         };
+        // The old process is stopped with the new policy, which the new process keeps.
+        if msg.has_shutdown() {
+            service.set_shutdown(msg.take_shutdown());
+        }
         service.kill();
         match service.wait() {
             Ok(_status) => {
-                match service::run(service.take_args()) {
+                let mut args = service.take_args();
+                // An empty set of limits from the Supervisor means the service no longer has any,
+                // while a message without limits at all keeps the ones it was spawned with.
+                if msg.has_limits() {
+                    let limits = msg.take_limits();
+                    if limits == protocol::ResourceLimits::new() {
+                        args.clear_limits();
+                    } else {
+                        args.set_limits(limits);
+                    }
+                }
+                match service::run(args) {
                     Ok(new_service) => {
                         let mut reply = protocol::SpawnOk::new();
                         reply.set_pid(new_service.id());
+                        reply.set_limits(new_service.limits().clone());
                         services.insert(new_service);
                         Ok(reply)
                     }
@@ -28,6 +28,7 @@ impl Handler for SpawnHandler {
             Ok(service) => {
                 let mut reply = protocol::SpawnOk::new();
                 reply.set_pid(service.id());
+                reply.set_limits(service.limits().clone());
                 services.insert(service);
                 Ok(reply)
             }
@@ -26,7 +26,7 @@ impl Handler for TerminateHandler {
         match services.get_mut(msg.get_pid()) {
             Some(service) => {
                 debug!("Terminating: {}", service.id());
-                let shutdown_method = service.kill();
+                let shutdown_method = service.terminate(msg.get_shutdown());
                 match service.wait() {
                     Ok(status) => {
                         let mut reply = protocol::TerminateOk::new();
@@ -147,7 +147,20 @@ impl Server {
                 );
             }
         }
-        self.supervisor.wait().ok();
+        // The Supervisor stops its services in dependency order on its way out, so keep
+        // answering it until it exits; anything it leaves running is killed below.
+        loop {
+            match self.supervisor.try_wait() {
+                Ok(None) => {
+                    match self.rx.try_recv() {
+                        Ok(bytes) => dispatch(&self.tx, &bytes, &mut self.services),
+                        Err(_) => thread::sleep(Duration::from_millis(100)),
+                    }
+                }
+                _ => break,
+            }
+        }
+        self.reap_zombies();
         self.services.kill_all();
         outputln!("Hasta la vista, services.");
     }
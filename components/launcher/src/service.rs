@@ -14,18 +14,26 @@
 
 use std::fmt;
 use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
 #[cfg(unix)]
 use std::process::{ChildStderr, ChildStdout, ExitStatus};
+use std::sync::{Arc, Mutex};
 use std::thread;
 
 use ansi_term::Colour;
 #[cfg(windows)]
 use core::os::process::windows_child::{ChildStderr, ChildStdout, ExitStatus};
+use core::os::process::Signal;
+use core::util::log_file::{LogLine, RotatingLog, RotationPolicy};
 use protocol;
 
 pub use sys::service::*;
 use error::Result;
 
+/// Seconds a service is given to shut down after it is signalled, unless it was spawned with
+/// another grace period.
+pub const DEFAULT_SHUTDOWN_TIMEOUT: u32 = 8;
+
 pub struct Service {
     args: protocol::Spawn,
     process: Process,
@@ -39,18 +47,20 @@ impl Service {
         stdout: Option<ChildStdout>,
         stderr: Option<ChildStderr>,
     ) -> Self {
+        let log = Arc::new(Mutex::new(open_log(&spawn)));
         if let Some(stdout) = stdout {
             let id = spawn.get_id().to_string();
+            let log = log.clone();
             thread::Builder::new()
                 .name(format!("{}-out", spawn.get_id()))
-                .spawn(move || pipe_stdout(stdout, id))
+                .spawn(move || pipe_stdout(stdout, id, log))
                 .ok();
         }
         if let Some(stderr) = stderr {
             let id = spawn.get_id().to_string();
             thread::Builder::new()
                 .name(format!("{}-err", spawn.get_id()))
-                .spawn(move || pipe_stderr(stderr, id))
+                .spawn(move || pipe_stderr(stderr, id, log))
                 .ok();
         }
         Service {
@@ -68,10 +78,60 @@ impl Service {
         self.process.id()
     }
 
-    /// Attempt to gracefully terminate a proccess and then forcefully kill it after
-    /// 8 seconds if it has not terminated.
+    /// Replace the shutdown policy the service was spawned with. An empty policy restores the
+    /// defaults.
+    pub fn set_shutdown(&mut self, policy: protocol::ShutdownPolicy) {
+        if policy == protocol::ShutdownPolicy::new() {
+            self.args.clear_shutdown();
+        } else {
+            self.args.set_shutdown(policy);
+        }
+    }
+
+    /// Attempt to gracefully terminate a proccess with the shutdown policy it was spawned with
+    /// and then forcefully kill it if it has not terminated within the policy's grace period.
     pub fn kill(&mut self) -> protocol::ShutdownMethod {
-        self.process.kill()
+        self.terminate(protocol::ShutdownPolicy::default_instance())
+    }
+
+    /// Like `kill`, with the settings of the given policy taking precedence over those the
+    /// service was spawned with.
+    pub fn terminate(&mut self, policy: &protocol::ShutdownPolicy) -> protocol::ShutdownMethod {
+        let spawned = self.args.get_shutdown();
+        let signal = if policy.has_signal() {
+            policy.get_signal()
+        } else if spawned.has_signal() {
+            spawned.get_signal()
+        } else {
+            "TERM"
+        };
+        let signal = match Signal::from_str(signal) {
+            Ok(signal) => signal,
+            Err(err) => {
+                warn!("{}, sending TERM to {} instead", err, self.name());
+                Signal::TERM
+            }
+        };
+        let timeout = if policy.has_timeout() {
+            policy.get_timeout()
+        } else if spawned.has_timeout() {
+            spawned.get_timeout()
+        } else {
+            DEFAULT_SHUTDOWN_TIMEOUT
+        };
+        debug!(
+            "Terminating {} with {}, killing it after {}s",
+            self.name(),
+            signal,
+            timeout
+        );
+        self.process.kill(signal, timeout)
+    }
+
+    /// The resource limits applied to the service's process, which may be fewer than were
+    /// requested if the platform could not apply them all.
+    pub fn limits(&self) -> &protocol::ResourceLimits {
+        self.process.limits()
     }
 
     pub fn name(&self) -> &str {
@@ -103,7 +163,7 @@ impl fmt::Debug for Service {
 }
 
 /// Consume output from a child process until EOF, then finish
-fn pipe_stdout<T>(out: T, id: String)
+fn pipe_stdout<T>(out: T, id: String, log: Arc<Mutex<Option<RotatingLog>>>)
 where
     T: Read,
 {
@@ -113,12 +173,13 @@ where
         let mut line = output_format!(preamble &id, logkey "O");
         line.push_str(&buffer);
         write!(&mut io::stdout(), "{}", line).expect("unable to write to stdout");
+        write_log(&log, &id, "stdout", &buffer);
         buffer.clear();
     }
 }
 
 /// Consume standard error from a child process until EOF, then finish
-fn pipe_stderr<T>(err: T, id: String)
+fn pipe_stderr<T>(err: T, id: String, log: Arc<Mutex<Option<RotatingLog>>>)
 where
     T: Read,
 {
@@ -129,6 +190,66 @@ where
         let c = format!("{}", Colour::Red.bold().paint(buffer.clone()));
         line.push_str(c.as_str());
         write!(&mut io::stderr(), "{}", line).expect("unable to write to stderr");
+        write_log(&log, &id, "stderr", &buffer);
         buffer.clear();
     }
 }
+
+/// Open the log file the service's output is captured to, if it was spawned with one.
+fn open_log(spawn: &protocol::Spawn) -> Option<RotatingLog> {
+    if !spawn.has_logs() {
+        return None;
+    }
+    let logs = spawn.get_logs();
+    let mut policy = RotationPolicy::default();
+    if logs.has_max_bytes() {
+        policy.max_bytes = logs.get_max_bytes();
+    }
+    if logs.has_max_age() {
+        policy.max_age = Some(logs.get_max_age());
+    }
+    if logs.has_keep() {
+        policy.keep = logs.get_keep();
+    }
+    if logs.has_compress() {
+        policy.compress = logs.get_compress();
+    }
+    match RotatingLog::open(logs.get_path(), policy) {
+        Ok(log) => Some(log),
+        Err(err) => {
+            warn!(
+                "Unable to open log file {} for {}, {}",
+                logs.get_path(),
+                spawn.get_id(),
+                err
+            );
+            None
+        }
+    }
+}
+
+/// Append a line of the service's output to its log file. A log which fails to be written is
+/// closed, so that the service carries on running with its output only sent to the console.
+fn write_log(log: &Arc<Mutex<Option<RotatingLog>>>, id: &str, source: &str, text: &str) {
+    let mut log = log.lock().expect("Service log lock poisoned");
+    let failed = match *log {
+        Some(ref mut file) => {
+            match file.write(&LogLine::new(source, text.trim_right_matches(&['\r', '\n'][..]))) {
+                Ok(()) => false,
+                Err(err) => {
+                    warn!(
+                        "Unable to write to log file {} for {}, {}",
+                        file.path().display(),
+                        id,
+                        err
+                    );
+                    true
+                }
+            }
+        }
+        None => false,
+    };
+    if failed {
+        *log = None;
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resource limits for services, applied through Linux control groups.
+//!
+//! Each service gets its own cgroup named after it beneath a `hab` cgroup. Both the unified (v2)
+//! hierarchy and the per-controller (v1) hierarchies are supported. A limit which cannot be
+//! applied is logged and skipped rather than failing the spawn; only the limits which were
+//! applied are reported back to the Supervisor.
+
+use std::ffi::CString;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+use libc::{self, c_void};
+use protocol;
+
+const CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+const HAB_CGROUP: &'static str = "hab";
+
+pub struct Cgroup {
+    dirs: Vec<PathBuf>,
+    limits: protocol::ResourceLimits,
+}
+
+impl Cgroup {
+    pub fn create(id: &str, limits: &protocol::ResourceLimits) -> Self {
+        let name = cgroup_name(id);
+        let mut cgroup = Cgroup {
+            dirs: Vec::new(),
+            limits: protocol::ResourceLimits::new(),
+        };
+        if Path::new(CGROUP_ROOT).join("cgroup.controllers").exists() {
+            cgroup.create_unified(&name, limits);
+        } else {
+            cgroup.create_legacy(&name, limits);
+        }
+        cgroup
+    }
+
+    /// The limits which were applied to the cgroup.
+    pub fn limits(&self) -> &protocol::ResourceLimits {
+        &self.limits
+    }
+
+    /// Paths of the `cgroup.procs` files a process must be written to in order to join the
+    /// cgroup. They are C strings so that they can be opened between fork and exec.
+    pub fn procs_files(&self) -> Vec<CString> {
+        self.dirs
+            .iter()
+            .filter_map(|dir| {
+                CString::new(dir.join("cgroup.procs").as_os_str().as_bytes()).ok()
+            })
+            .collect()
+    }
+
+    fn create_unified(&mut self, name: &str, limits: &protocol::ResourceLimits) {
+        let root = Path::new(CGROUP_ROOT);
+        let parent = root.join(HAB_CGROUP);
+        let dir = parent.join(name);
+        if let Err(err) = fs::create_dir_all(&dir) {
+            warn!("Unable to create cgroup {}, {}", dir.display(), err);
+            return;
+        }
+        // Controllers are only available to a cgroup once its parents have enabled them for
+        // their children.
+        for controller in ["cpu", "memory", "pids", "io"].iter() {
+            for cgroup in [root, parent.as_path()].iter() {
+                write_value(
+                    &cgroup.join("cgroup.subtree_control"),
+                    format!("+{}", controller).as_bytes(),
+                ).ok();
+            }
+        }
+        if limits.has_cpu_shares() {
+            let weight = shares_to_weight(limits.get_cpu_shares());
+            if apply(&dir.join("cpu.weight"), weight.to_string()) {
+                self.limits.set_cpu_shares(limits.get_cpu_shares());
+            }
+        }
+        if limits.has_memory_bytes() {
+            let value = limits.get_memory_bytes().to_string();
+            if apply(&dir.join("memory.max"), value) {
+                self.limits.set_memory_bytes(limits.get_memory_bytes());
+            }
+        }
+        if limits.has_pids_max() {
+            let value = limits.get_pids_max().to_string();
+            if apply(&dir.join("pids.max"), value) {
+                self.limits.set_pids_max(limits.get_pids_max());
+            }
+        }
+        if limits.has_io_weight() {
+            let value = format!("default {}", clamp(limits.get_io_weight() as u64, 1, 10000));
+            if apply(&dir.join("io.weight"), value) {
+                self.limits.set_io_weight(limits.get_io_weight());
+            }
+        }
+        self.dirs.push(dir);
+    }
+
+    fn create_legacy(&mut self, name: &str, limits: &protocol::ResourceLimits) {
+        if limits.has_cpu_shares() {
+            let value = clamp(limits.get_cpu_shares(), 2, 262144).to_string();
+            if self.set_legacy("cpu", name, "cpu.shares", value) {
+                self.limits.set_cpu_shares(limits.get_cpu_shares());
+            }
+        }
+        if limits.has_memory_bytes() {
+            let value = limits.get_memory_bytes().to_string();
+            if self.set_legacy("memory", name, "memory.limit_in_bytes", value) {
+                self.limits.set_memory_bytes(limits.get_memory_bytes());
+            }
+        }
+        if limits.has_pids_max() {
+            let value = limits.get_pids_max().to_string();
+            if self.set_legacy("pids", name, "pids.max", value) {
+                self.limits.set_pids_max(limits.get_pids_max());
+            }
+        }
+        if limits.has_io_weight() {
+            let value = clamp(limits.get_io_weight() as u64, 10, 1000).to_string();
+            if self.set_legacy("blkio", name, "blkio.weight", value) {
+                self.limits.set_io_weight(limits.get_io_weight());
+            }
+        }
+    }
+
+    fn set_legacy(&mut self, controller: &str, name: &str, file: &str, value: String) -> bool {
+        let dir = Path::new(CGROUP_ROOT).join(controller).join(HAB_CGROUP).join(name);
+        if let Err(err) = fs::create_dir_all(&dir) {
+            warn!("Unable to create cgroup {}, {}", dir.display(), err);
+            return false;
+        }
+        if !self.dirs.contains(&dir) {
+            self.dirs.push(dir.clone());
+        }
+        apply(&dir.join(file), value)
+    }
+}
+
+impl Drop for Cgroup {
+    fn drop(&mut self) {
+        // Removing a cgroup fails while processes remain in it, which leaves it for the next
+        // service with the same name to reuse.
+        for dir in self.dirs.iter() {
+            if let Err(err) = fs::remove_dir(dir) {
+                debug!("Unable to remove cgroup {}, {}", dir.display(), err);
+            }
+        }
+    }
+}
+
+/// Moves the calling process into the cgroups owning the given `cgroup.procs` files. This runs
+/// in the child between fork and exec, so it must not allocate.
+pub fn join(procs_files: &[CString]) -> io::Result<()> {
+    let mut buf = [0u8; 20];
+    let mut pid = unsafe { libc::getpid() } as u64;
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (pid % 10) as u8;
+        pid /= 10;
+        if pid == 0 {
+            break;
+        }
+    }
+    let digits = &buf[start..];
+    for path in procs_files.iter() {
+        unsafe {
+            let fd = libc::open(path.as_ptr(), libc::O_WRONLY);
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let written = libc::write(fd, digits.as_ptr() as *const c_void, digits.len());
+            let err = io::Error::last_os_error();
+            libc::close(fd);
+            if written < 0 {
+                return Err(err);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Converts cgroup v1 CPU shares, 2 to 262144 with a default of 1024, into a cgroup v2 CPU
+/// weight, 1 to 10000 with a default of 100.
+fn shares_to_weight(shares: u64) -> u64 {
+    1 + ((clamp(shares, 2, 262144) - 2) * 9999) / 262142
+}
+
+fn clamp(value: u64, min: u64, max: u64) -> u64 {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Service ids are `<service>.<group>`, which are safe cgroup names apart from any path
+/// separators.
+fn cgroup_name(id: &str) -> String {
+    id.replace('/', "-")
+}
+
+/// Writes a limit to a cgroup file, returning true if it was applied.
+fn apply(path: &Path, value: String) -> bool {
+    match write_value(path, value.as_bytes()) {
+        Ok(()) => true,
+        Err(err) => {
+            warn!("Unable to write '{}' to {}, {}", value, path.display(), err);
+            false
+        }
+    }
+}
+
+fn write_value(path: &Path, value: &[u8]) -> io::Result<()> {
+    File::create(path).and_then(|mut file| file.write_all(value))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shares_to_weight_maps_the_v1_range_onto_v2() {
+        assert_eq!(shares_to_weight(2), 1);
+        assert_eq!(shares_to_weight(1024), 39);
+        assert_eq!(shares_to_weight(262144), 10000);
+        assert_eq!(shares_to_weight(0), 1);
+        assert_eq!(shares_to_weight(1 << 20), 10000);
+    }
+}
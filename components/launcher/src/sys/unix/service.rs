@@ -26,13 +26,27 @@ use time::{Duration, SteadyTime};
 
 use error::{Error, Result};
 use service::Service;
+#[cfg(target_os = "linux")]
+use super::cgroup::{self, Cgroup};
 
 pub struct Process {
     pid: pid_t,
     status: Option<ExitStatus>,
+    #[cfg(target_os = "linux")]
+    cgroup: Option<Cgroup>,
 }
 
 impl Process {
+    #[cfg(target_os = "linux")]
+    fn new(pid: u32, cgroup: Option<Cgroup>) -> Self {
+        Process {
+            pid: pid as pid_t,
+            status: None,
+            cgroup: cgroup,
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
     fn new(pid: u32) -> Self {
         Process {
             pid: pid as pid_t,
@@ -44,9 +58,24 @@ impl Process {
         self.pid as u32
     }
 
-    /// Attempt to gracefully terminate a proccess and then forcefully kill it after
-    /// 8 seconds if it has not terminated.
-    pub fn kill(&mut self) -> ShutdownMethod {
+    /// The resource limits applied to the process.
+    #[cfg(target_os = "linux")]
+    pub fn limits(&self) -> &protocol::ResourceLimits {
+        match self.cgroup {
+            Some(ref cgroup) => cgroup.limits(),
+            None => protocol::ResourceLimits::default_instance(),
+        }
+    }
+
+    /// The resource limits applied to the process. Limits are only supported on Linux.
+    #[cfg(not(target_os = "linux"))]
+    pub fn limits(&self) -> &protocol::ResourceLimits {
+        protocol::ResourceLimits::default_instance()
+    }
+
+    /// Attempt to gracefully terminate a proccess with the given signal and then forcefully kill
+    /// it after `timeout` seconds if it has not terminated.
+    pub fn kill(&mut self, shutdown_signal: Signal, timeout: u32) -> ShutdownMethod {
         // check the group of the process being killed
         // if it is the root process of the process group
         // we send our signals to the entire process group
@@ -64,10 +93,10 @@ impl Process {
 
         // JW TODO: Determine if the error represents a case where the process was already
         // exited before we return out and assume so.
-        if signal(self.id(), Signal::TERM).is_err() {
+        if signal(self.id(), shutdown_signal).is_err() {
             return ShutdownMethod::AlreadyExited;
         }
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + Duration::seconds(timeout as i64);
         loop {
             if let Ok(Some(_status)) = self.try_wait() {
                 return ShutdownMethod::GracefulTermination;
@@ -132,7 +161,28 @@ pub fn run(msg: protocol::Spawn) -> Result<Service> {
     for (key, val) in msg.get_env().iter() {
         cmd.env(key, val);
     }
+    #[cfg(target_os = "linux")]
+    let cgroup = if msg.has_limits() {
+        let cgroup = Cgroup::create(msg.get_id(), msg.get_limits());
+        let procs_files = cgroup.procs_files();
+        cmd.before_exec(move || cgroup::join(&procs_files));
+        Some(cgroup)
+    } else {
+        None
+    };
+    #[cfg(not(target_os = "linux"))]
+    {
+        if msg.has_limits() {
+            warn!(
+                "Ignoring resource limits for {}, they are only supported on Linux",
+                msg.get_id()
+            );
+        }
+    }
     let child = cmd.spawn().map_err(Error::Spawn)?;
+    #[cfg(target_os = "linux")]
+    let process = Process::new(child.id(), cgroup);
+    #[cfg(not(target_os = "linux"))]
     let process = Process::new(child.id());
     Ok(Service::new(msg, process, child.stdout, child.stderr))
 }
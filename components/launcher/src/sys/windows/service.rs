@@ -16,7 +16,7 @@ use std::collections::HashMap;
 use std::io;
 use std::mem;
 
-use core::os::process::handle_from_pid;
+use core::os::process::{handle_from_pid, Signal};
 use core::os::process::windows_child::{Child, ExitStatus, Handle};
 use kernel32;
 use protocol::{self, ShutdownMethod};
@@ -46,7 +46,15 @@ impl Process {
         unsafe { kernel32::GetProcessId(self.handle.raw()) as u32 }
     }
 
-    pub fn kill(&mut self) -> ShutdownMethod {
+    /// The resource limits applied to the process. Limits are only supported on Linux.
+    pub fn limits(&self) -> &protocol::ResourceLimits {
+        protocol::ResourceLimits::default_instance()
+    }
+
+    /// Attempt to gracefully terminate a process with a ctrl-break event and then forcefully
+    /// kill it and its descendants after `timeout` seconds if it has not terminated. Windows has
+    /// no signals, so the requested signal is ignored.
+    pub fn kill(&mut self, _signal: Signal, timeout: u32) -> ShutdownMethod {
         if self.status().is_some() {
             return ShutdownMethod::AlreadyExited;
         }
@@ -59,7 +67,7 @@ impl Process {
             );
         }
 
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + Duration::seconds(timeout as i64);
         loop {
             if ret == 0 || SteadyTime::now() > stop_time {
                 let proc_table = build_proc_table();
@@ -131,6 +139,12 @@ pub fn run(mut msg: protocol::Spawn) -> Result<Service> {
         password,
     ) {
         Ok(child) => {
+            if msg.has_limits() {
+                warn!(
+                    "Ignoring resource limits for {}, they are only supported on Linux",
+                    msg.get_id()
+                );
+            }
             let process = Process::new(child.handle);
             Ok(Service::new(msg, process, child.stdout, child.stderr))
         }
@@ -173,6 +173,44 @@
 # pkg_svc_run="bin/haproxy -f $pkg_svc_config_path/haproxy.conf"
 # ```
 #
+# ### pkg_svc_limits
+# An associative array of default resource limits for the service, applied by the Launcher through
+# cgroups on Linux. Valid keys are `cpu_shares`, `memory_bytes`, `pids_max` and `io_weight`. Any
+# limit may be overridden when the service is loaded.
+# ```
+# pkg_svc_limits=(
+#   [cpu_shares]=512
+#   [memory_bytes]=536870912
+# )
+# ```
+#
+# ### pkg_hook_timeouts
+# An associative array of the seconds each hook may run before the Supervisor kills it. Valid keys
+# are `init`, `file_updated`, `reload`, `reconfigure`, `suitability`, `post_run`, `pre_stop`,
+# `post_stop`, `smoke_test` and `health_check`. `health_check_result` sets the health reported
+# when the health check hook times out, either `Unknown` or `Critical`. Any timeout may be
+# overridden when the service is loaded.
+# ```
+# pkg_hook_timeouts=(
+#   [init]=600
+#   [health_check]=10
+# )
+# ```
+#
+# ### pkg_health_probe
+# An associative array describing a health check which the Supervisor runs itself, in place of a
+# `health_check` hook. Set exactly one of `http` (a URL which must answer with a 2xx status, or
+# `status` when set, and whose body must contain `body` when set), `tcp` (a `host:port` which
+# must accept connections) or `exec` (an executable, relative to the package's path, whose exit
+# code is read like the hook's). `interval` and `timeout` are in seconds. The probe may be
+# replaced when the service is loaded.
+# ```
+# pkg_health_probe=(
+#   [http]="http://localhost:8080/health"
+#   [interval]=10
+# )
+# ```
+#
 # ### pkg_exports
 # An associative array representing configuration data which should be gossiped to peers. The keys
 # in this array represent the name the value will be assigned and the values represent the toml path
@@ -402,6 +440,12 @@ pkg_include_dirs=()
 pkg_pconfig_dirs=()
 # The command to run the service - must not fork or return
 pkg_svc_run=''
+# The default resource limits of the service
+declare -A pkg_svc_limits
+# The default number of seconds each hook may run for
+declare -A pkg_hook_timeouts
+# The health check the Supervisor runs in place of a health_check hook
+declare -A pkg_health_probe
 pkg_exposes=()
 declare -A pkg_exports
 declare -A pkg_binds
@@ -2531,6 +2575,9 @@ do_default_install() {
 # * `$pkg_prefix/FILES` - blake2b checksums of all files in the package
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
+# * `$pkg_prefix/SVC_LIMITS` - The default resource limits of the service and their values
+# * `$pkg_prefix/HOOK_TIMEOUTS` - The default hook timeouts of the service and their values
+# * `$pkg_prefix/HEALTH_PROBE` - The settings of the service's built-in health check
 _build_metadata() {
   build_line "Building package metadata"
   local ld_run_path_part=()
@@ -2678,6 +2725,15 @@ _build_metadata() {
   if [[ -f "$PLAN_CONTEXT/hooks/run" || -n "${pkg_svc_run:-}" ]]; then
     echo "$pkg_svc_user" > $pkg_prefix/SVC_USER
     echo "$pkg_svc_group" > $pkg_prefix/SVC_GROUP
+    for limit in "${!pkg_svc_limits[@]}"; do
+      echo "$limit=${pkg_svc_limits[$limit]}" >> $pkg_prefix/SVC_LIMITS
+    done
+    for hook in "${!pkg_hook_timeouts[@]}"; do
+      echo "$hook=${pkg_hook_timeouts[$hook]}" >> $pkg_prefix/HOOK_TIMEOUTS
+    done
+    for setting in "${!pkg_health_probe[@]}"; do
+      echo "$setting=${pkg_health_probe[$setting]}" >> $pkg_prefix/HEALTH_PROBE
+    done
   fi
 
   # Generate the blake2b hashes of all the files in the package. This
@@ -2695,7 +2751,7 @@ _build_metadata() {
 }
 
 # Copy the `./config` directory, relative to the Plan, to `$pkg_prefix/config`.
-# Do the same with `default.toml`. Delegates most of the implementation to the
+# Do the same with `default.toml` and its `schema.toml`. Delegates most of the implementation to the
 # `do_default_build_config()` function.
 do_build_config() {
   do_default_build_config
@@ -2733,6 +2789,9 @@ do_default_build_config() {
   if [[ -f "$PLAN_CONTEXT/default.toml" ]]; then
     cp "$PLAN_CONTEXT/default.toml" $pkg_prefix
   fi
+  if [[ -f "$PLAN_CONTEXT/schema.toml" ]]; then
+    cp "$PLAN_CONTEXT/schema.toml" $pkg_prefix
+  fi
   return 0
 }
 
@@ -31,6 +31,7 @@ use hcore::package::PackageIdent;
 use toml;
 
 use error::{Error, SupError};
+use manager::service::HealthCheck;
 
 static LOGKEY: &'static str = "CE";
 
@@ -284,6 +285,8 @@ pub struct ServiceFile {
     pub filename: String,
     pub incarnation: u64,
     pub body: Vec<u8>,
+    /// Whether the file has been removed from the ring by a tombstone.
+    pub removed: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -298,6 +301,8 @@ pub struct CensusGroup {
     pub election_status: ElectionStatus,
     pub update_election_status: ElectionStatus,
     pub leader_id: Option<MemberId>,
+    pub standby_ids: Vec<MemberId>,
+    pub quorum: Option<u32>,
     pub service_config: Option<ServiceConfig>,
 
     local_member_id: MemberId,
@@ -316,6 +321,8 @@ impl CensusGroup {
             local_member_id: local_member_id.clone(),
             population: BTreeMap::new(),
             leader_id: None,
+            standby_ids: Vec::new(),
+            quorum: None,
             update_leader_id: None,
             service_config: None,
             service_files: HashMap::new(),
@@ -335,6 +342,16 @@ impl CensusGroup {
         }
     }
 
+    /// Returns the alive members ranked to take over from the leader, in order, once the group's
+    /// election has finished.
+    pub fn standbys(&self) -> Vec<&CensusMember> {
+        self.standby_ids
+            .iter()
+            .filter_map(|id| self.population.get(id))
+            .filter(|cm| cm.alive)
+            .collect()
+    }
+
     pub fn update_leader(&self) -> Option<&CensusMember> {
         match self.update_leader_id {
             Some(ref id) => self.population.get(id),
@@ -347,6 +364,16 @@ impl CensusGroup {
         self.population.values().map(|cm| cm).collect()
     }
 
+    /// Returns the first `count` alive members of the ordered members list. These are the members
+    /// updated ahead of the rest of the group by the canary update strategy.
+    pub fn canaries(&self, count: usize) -> Vec<&CensusMember> {
+        self.population
+            .values()
+            .filter(|cm| cm.alive)
+            .take(count)
+            .collect()
+    }
+
     pub fn changed_service_files(&self) -> Vec<&ServiceFile> {
         self.changed_service_files
             .iter()
@@ -377,7 +404,15 @@ impl CensusGroup {
     }
 
     fn update_from_service_rumors(&mut self, rumors: &HashMap<String, ServiceRumor>) {
+        // Members which have left the group, by tombstoning their service rumor or having it
+        // expire, are no longer part of its population.
+        self.population.retain(|member_id, _| {
+            rumors.get(member_id).map_or(false, |r| !r.get_tombstone())
+        });
         for (member_id, service_rumor) in rumors.iter() {
+            if service_rumor.get_tombstone() {
+                continue;
+            }
             // Yeah - we are ourself - we're alive.
             let is_self = member_id == &self.local_member_id;
             let mut member = self.population
@@ -393,8 +428,17 @@ impl CensusGroup {
 
     fn update_from_election_rumor(&mut self, election: &ElectionRumor) {
         self.leader_id = None;
+        self.standby_ids = if election.is_finished() {
+            election.standbys()
+        } else {
+            Vec::new()
+        };
+        self.quorum = match election.get_quorum() {
+            0 => None,
+            quorum => Some(quorum),
+        };
         for census_member in self.population.values_mut() {
-            if census_member.update_from_election_rumor(election) {
+            if census_member.update_from_election_rumor(election, &self.standby_ids) {
                 self.leader_id = Some(census_member.member_id.clone());
             }
         }
@@ -460,12 +504,21 @@ impl CensusGroup {
             );
 
             if service_file_rumor.get_incarnation() > file.incarnation {
+                if service_file_rumor.get_tombstone() {
+                    self.changed_service_files.push(filename.clone());
+                    file.filename = filename.clone();
+                    file.incarnation = service_file_rumor.get_incarnation();
+                    file.body = Vec::new();
+                    file.removed = true;
+                    continue;
+                }
                 match service_file_rumor.body() {
                     Ok(body) => {
                         self.changed_service_files.push(filename.clone());
                         file.filename = filename.clone();
                         file.incarnation = service_file_rumor.get_incarnation();
                         file.body = body;
+                        file.removed = false;
                     }
                     Err(e) => {
                         warn!(
@@ -498,6 +551,8 @@ pub struct CensusMember {
     pub persistent: bool,
     pub leader: bool,
     pub follower: bool,
+    pub standby: bool,
+    pub standby_rank: Option<usize>,
     pub update_leader: bool,
     pub update_follower: bool,
     pub election_is_running: bool,
@@ -506,7 +561,12 @@ pub struct CensusMember {
     pub update_election_is_running: bool,
     pub update_election_is_no_quorum: bool,
     pub update_election_is_finished: bool,
+    pub health: HealthCheck,
+    pub health_restarts: u64,
+    pub last_health_restart: Option<i64>,
     pub sys: SysInfo,
+    /// The labels the member's Supervisor advertises to the ring.
+    pub labels: BTreeMap<String, String>,
     alive: bool,
     suspect: bool,
     confirmed: bool,
@@ -534,14 +594,31 @@ impl CensusMember {
             Ok(ident) => self.pkg = Some(ident),
             Err(err) => warn!("Received a bad package ident from gossip data, err={}", err),
         };
+        self.health = if rumor.has_health() {
+            HealthCheck::from(rumor.get_health() as i8)
+        } else {
+            HealthCheck::Unknown
+        };
+        self.health_restarts = rumor.get_health_restarts();
+        self.last_health_restart = if rumor.has_last_health_restart() {
+            Some(rumor.get_last_health_restart())
+        } else {
+            None
+        };
         self.sys = rumor.get_sys().clone().into();
         self.cfg = toml::from_slice(rumor.get_cfg()).unwrap_or(toml::value::Table::default());
     }
 
-    fn update_from_election_rumor(&mut self, election: &ElectionRumor) -> bool {
+    fn update_from_election_rumor(
+        &mut self,
+        election: &ElectionRumor,
+        standbys: &[MemberId],
+    ) -> bool {
         self.election_is_running = election.get_status() == ElectionStatusRumor::Running;
         self.election_is_no_quorum = election.get_status() == ElectionStatusRumor::NoQuorum;
         self.election_is_finished = election.get_status() == ElectionStatusRumor::Finished;
+        self.standby_rank = standbys.iter().position(|id| *id == self.member_id);
+        self.standby = self.standby_rank.is_some();
         if self.election_is_finished {
             if self.member_id == election.get_member_id() {
                 self.leader = true;
@@ -573,9 +650,25 @@ impl CensusMember {
     fn update_from_member(&mut self, member: &Member) {
         self.sys.set_gossip_ip(member.get_address().to_string());
         self.sys.set_gossip_port(member.get_gossip_port() as u32);
+        self.labels = member.labels();
         self.persistent = true;
     }
 
+    /// Returns the member's health as seen by the gossip layer, if it is known.
+    pub fn member_health(&self) -> Option<&'static str> {
+        if self.alive {
+            Some("alive")
+        } else if self.suspect {
+            Some("suspect")
+        } else if self.confirmed {
+            Some("confirmed")
+        } else if self.departed {
+            Some("departed")
+        } else {
+            None
+        }
+    }
+
     fn update_from_health(&mut self, health: Health) {
         self.alive = false;
         self.suspect = false;
@@ -612,8 +705,9 @@ mod tests {
     use butterfly::rumor::election::Election as ElectionRumor;
     use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
     use butterfly::rumor::service::SysInfo;
-    use butterfly::rumor::RumorStore;
+    use butterfly::rumor::{Rumor, RumorStore};
     use census::CensusRing;
+    use manager::service::HealthCheck;
 
     #[test]
     fn update_from_rumors() {
@@ -638,15 +732,24 @@ mod tests {
         let sg_two = ServiceGroup::new(None, "shield", "two", None).unwrap();
         let service_two =
             ServiceRumor::new("member-b".to_string(), &pg_id, &sg_two, &sys_info, None);
-        let service_three =
+        let mut service_three =
             ServiceRumor::new("member-a".to_string(), &pg_id, &sg_two, &sys_info, None);
+        service_three.set_health(2);
+        service_three.set_health_restarts(2);
+        service_three.set_last_health_restart(1492604148);
+
+        let service_four =
+            ServiceRumor::new("member-c".to_string(), &pg_id, &sg_one, &sys_info, None);
 
         service_store.insert(service_one);
         service_store.insert(service_two);
         service_store.insert(service_three);
+        service_store.insert(service_four);
 
         let election_store: RumorStore<ElectionRumor> = RumorStore::default();
         let mut election = ElectionRumor::new("member-a", sg_one.clone(), 10);
+        election.merge(ElectionRumor::new("member-c", sg_one.clone(), 5));
+        election.set_quorum(2);
         election.finish();
         election_store.insert(election);
 
@@ -671,6 +774,9 @@ mod tests {
         let census_group_one = ring.census_group_for(&sg_one).unwrap();
         assert!(census_group_one.me().is_none());
         assert_eq!(census_group_one.leader().unwrap().member_id, "member-a");
+        assert_eq!(census_group_one.standby_ids, vec!["member-c".to_string()]);
+        assert_eq!(census_group_one.quorum, Some(2));
+        assert_eq!(census_group_one.members()[1].standby_rank, Some(0));
         assert!(census_group_one.update_leader().is_none());
 
         let census_group_two = ring.census_group_for(&sg_two).unwrap();
@@ -685,6 +791,63 @@ mod tests {
 
         let members = census_group_two.members();
         assert_eq!(members[0].member_id, "member-a");
+        assert_eq!(members[0].health, HealthCheck::Critical);
+        assert_eq!(members[0].health_restarts, 2);
+        assert_eq!(members[0].last_health_restart, Some(1492604148));
         assert_eq!(members[1].member_id, "member-b");
+        assert_eq!(members[1].health, HealthCheck::Unknown);
+        assert_eq!(members[1].health_restarts, 0);
+        assert_eq!(members[1].last_health_restart, None);
+    }
+
+    #[test]
+    fn update_from_rumors_drops_tombstoned_members() {
+        let mut sys_info = SysInfo::new();
+        sys_info.set_ip("1.2.3.4".to_string());
+        sys_info.set_hostname("hostname".to_string());
+        let pg_id = PackageIdent::new(
+            "starkandwayne",
+            "shield",
+            Some("0.10.4"),
+            Some("20170419115548"),
+        );
+        let sg_one = ServiceGroup::new(None, "shield", "one", None).unwrap();
+
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        let service_one =
+            ServiceRumor::new("member-a".to_string(), &pg_id, &sg_one, &sys_info, None);
+        let service_two =
+            ServiceRumor::new("member-b".to_string(), &pg_id, &sg_one, &sys_info, None);
+        service_store.insert(service_one.clone());
+        service_store.insert(service_two);
+
+        let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+        let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+        let member_list = MemberList::new();
+        let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
+        let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let mut ring = CensusRing::new("member-b".to_string());
+        ring.update_from_rumors(
+            &service_store,
+            &election_store,
+            &election_update_store,
+            &member_list,
+            &service_config_store,
+            &service_file_store,
+        );
+        assert_eq!(ring.census_group_for(&sg_one).unwrap().members().len(), 2);
+
+        service_store.insert(service_one.tombstone());
+        ring.update_from_rumors(
+            &service_store,
+            &election_store,
+            &election_update_store,
+            &member_list,
+            &service_config_store,
+            &service_file_store,
+        );
+        let members = ring.census_group_for(&sg_one).unwrap().members();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].member_id, "member-b");
     }
 }
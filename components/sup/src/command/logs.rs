@@ -0,0 +1,256 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Print the logs a service's output and the output of its hooks are captured to, merged into
+//! the order they were written in.
+
+use std::fs;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use hcore::util::log_file::{self, LogLine};
+use time;
+
+use error::{Error, Result};
+use fs as supfs;
+
+/// Our output key
+static LOGKEY: &'static str = "LG";
+
+/// How often the logs are checked for new lines when following them.
+const FOLLOW_INTERVAL_MS: u64 = 500;
+
+pub struct LogsOptions {
+    /// Print only this many of the most recent lines.
+    pub tail: Option<usize>,
+    /// Print only lines written at or after this many seconds since the epoch.
+    pub since: Option<i64>,
+    /// Keep printing lines as they are written.
+    pub follow: bool,
+}
+
+pub fn start(service: &str, opts: &LogsOptions) -> Result<()> {
+    let paths = vec![supfs::svc_output_log(service), supfs::svc_hooks_log(service)];
+    let mut lines = vec![];
+    for path in &paths {
+        for file in log_file::log_files(path) {
+            match log_file::read_lines(&file) {
+                Ok(file_lines) => lines.extend(file_lines),
+                // The file was rotated away since the logs were listed.
+                Err(ref err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(sup_error!(Error::Io(err))),
+            }
+        }
+    }
+    let mut lines = filter(lines, opts);
+    lines.sort_by_key(|l| l.time);
+    let skip = opts.tail.map_or(0, |n| lines.len().saturating_sub(n));
+    for line in &lines[skip..] {
+        println!("{}", line);
+    }
+    if opts.follow {
+        follow(paths, opts)
+    } else {
+        Ok(())
+    }
+}
+
+/// Parses a `--since` value, either a duration before now such as `90s`, `10m`, `2h` or `1d`,
+/// or a UTC time such as `2017-10-18T10:00:00Z`, into seconds since the epoch.
+pub fn parse_since(value: &str, now: i64) -> Result<i64> {
+    let value = value.trim();
+    let multiplier = match value.chars().last() {
+        Some('s') => Some(1),
+        Some('m') => Some(60),
+        Some('h') => Some(60 * 60),
+        Some('d') => Some(24 * 60 * 60),
+        _ => None,
+    };
+    if let Some(multiplier) = multiplier {
+        if let Ok(n) = value[..value.len() - 1].parse::<i64>() {
+            return Ok(now - n * multiplier);
+        }
+    }
+    match time::strptime(value, "%Y-%m-%dT%H:%M:%SZ") {
+        Ok(tm) => Ok(tm.to_timespec().sec),
+        Err(_) => Err(sup_error!(Error::InvalidTimeSpec(value.to_string()))),
+    }
+}
+
+fn filter(lines: Vec<LogLine>, opts: &LogsOptions) -> Vec<LogLine> {
+    match opts.since {
+        Some(since) => lines.into_iter().filter(|l| l.time >= since).collect(),
+        None => lines,
+    }
+}
+
+fn follow(paths: Vec<PathBuf>, opts: &LogsOptions) -> Result<()> {
+    let mut followers: Vec<Follower> = paths.into_iter().map(Follower::new).collect();
+    loop {
+        thread::sleep(Duration::from_millis(FOLLOW_INTERVAL_MS));
+        let mut lines = vec![];
+        for follower in followers.iter_mut() {
+            lines.extend(follower.read_new_lines()?);
+        }
+        let mut lines = filter(lines, opts);
+        lines.sort_by_key(|l| l.time);
+        for line in lines {
+            println!("{}", line);
+        }
+    }
+}
+
+/// Reads the lines appended to a log since it was last read, including those written just
+/// before the log was rotated.
+struct Follower {
+    path: PathBuf,
+    offset: u64,
+    /// Identifies the file last read, which changes when the log is rotated even if the new
+    /// log has already grown past the offset read up to in the old one.
+    file_id: Option<FileId>,
+}
+
+impl Follower {
+    fn new(path: PathBuf) -> Self {
+        let metadata = fs::metadata(&path).ok();
+        Follower {
+            path: path,
+            offset: metadata.as_ref().map_or(0, |m| m.len()),
+            file_id: metadata.as_ref().and_then(file_id),
+        }
+    }
+
+    fn read_new_lines(&mut self) -> Result<Vec<LogLine>> {
+        let (len, id) = match fs::metadata(&self.path) {
+            Ok(metadata) => (metadata.len(), file_id(&metadata)),
+            Err(_) => return Ok(vec![]),
+        };
+        let replaced = self.file_id.is_some() && id != self.file_id;
+        self.file_id = id;
+        let mut lines = vec![];
+        if replaced || len < self.offset {
+            // The log was rotated, so finish reading the file it was moved to.
+            if let Some(rotated) = log_file::log_files(&self.path).into_iter().rev().nth(1) {
+                if let Ok(mut reader) = log_file::open(&rotated) {
+                    io::copy(&mut reader.by_ref().take(self.offset), &mut io::sink())?;
+                    lines.extend(read_complete_lines(&mut reader)?.0);
+                }
+            }
+            self.offset = 0;
+        }
+        if len > self.offset {
+            let mut file = fs::File::open(&self.path)?;
+            file.seek(SeekFrom::Start(self.offset))?;
+            let (new_lines, read) = read_complete_lines(&mut file)?;
+            lines.extend(new_lines);
+            self.offset += read;
+        }
+        Ok(lines)
+    }
+}
+
+/// The device and inode of a file.
+type FileId = (u64, u64);
+
+#[cfg(unix)]
+fn file_id(metadata: &fs::Metadata) -> Option<FileId> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+/// Files have no stable identity here, so rotation is only noticed when the log shrinks.
+#[cfg(not(unix))]
+fn file_id(_: &fs::Metadata) -> Option<FileId> {
+    None
+}
+
+/// Reads the complete lines available from a reader, returning them and the number of bytes
+/// they took up. A trailing partial line is left to be read once it has been finished.
+fn read_complete_lines<R: Read>(reader: &mut R) -> Result<(Vec<LogLine>, u64)> {
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf)?;
+    let complete = match buf.rfind('\n') {
+        Some(end) => &buf[..end + 1],
+        None => "",
+    };
+    let lines = complete.lines().filter_map(LogLine::parse).collect();
+    Ok((lines, complete.len() as u64))
+}
+
+#[cfg(test)]
+mod test {
+    use hcore::util::log_file::{RotatingLog, RotationPolicy};
+    use tempdir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn parse_since_durations() {
+        let now = 1508320800;
+        assert_eq!(parse_since("90s", now).unwrap(), now - 90);
+        assert_eq!(parse_since("10m", now).unwrap(), now - 600);
+        assert_eq!(parse_since("2h", now).unwrap(), now - 7200);
+        assert_eq!(parse_since("1d", now).unwrap(), now - 86400);
+    }
+
+    #[test]
+    fn parse_since_times() {
+        assert_eq!(
+            parse_since("2017-10-18T10:00:00Z", 0).unwrap(),
+            1508320800
+        );
+        assert!(parse_since("yesterday", 0).is_err());
+        assert!(parse_since("10x", 0).is_err());
+        assert!(parse_since("", 0).is_err());
+    }
+
+    #[test]
+    fn read_complete_lines_leaves_partial_lines() {
+        let input = "2017-10-18T10:00:00Z stdout: one\n2017-10-18T10:00:01Z stdout: tw";
+        let (lines, read) = read_complete_lines(&mut input.as_bytes()).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].text, "one");
+        assert_eq!(read, 33);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn follower_reads_across_rotation_to_a_longer_log() {
+        let tmpdir = TempDir::new("logs").unwrap();
+        let path = tmpdir.path().join("sup.log");
+        let policy = RotationPolicy {
+            compress: false,
+            ..RotationPolicy::default()
+        };
+        let mut log = RotatingLog::open(&path, policy).unwrap();
+        log.write(&LogLine::new("stdout", "before")).unwrap();
+        let mut follower = Follower::new(path.clone());
+
+        log.write(&LogLine::new("stdout", "last")).unwrap();
+        log.rotate().unwrap();
+        for n in 0..3 {
+            log.write(&LogLine::new("stdout", format!("after {}", n))).unwrap();
+        }
+
+        let texts: Vec<String> = follower
+            .read_new_lines()
+            .unwrap()
+            .into_iter()
+            .map(|l| l.text)
+            .collect();
+        assert_eq!(texts, vec!["last", "after 0", "after 1", "after 2"]);
+    }
+}
@@ -14,5 +14,7 @@
 
 //! The CLI commands.
 
+pub mod logs;
+pub mod render;
 pub mod shell;
 pub mod start;
@@ -0,0 +1,596 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Render a package's configuration templates and hooks without running the service, against a
+//! census read from a file rather than gossiped by a ring.
+//!
+//! The census file is JSON describing the other members of the service's group and the members
+//! of the service groups it binds to. Every key is optional:
+//!
+//! ```json
+//! {
+//!   "leader": false,
+//!   "members": [
+//!     { "member_id": "redis-2", "sys": { "ip": "10.0.0.2" }, "cfg": { "port": 6379 } }
+//!   ],
+//!   "bind": {
+//!     "database": {
+//!       "service_group": "postgresql.default",
+//!       "members": [
+//!         { "sys": { "ip": "10.0.0.5" }, "cfg": { "port": 5432 }, "leader": true }
+//!       ]
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! The census is built from the same rumors a Supervisor builds it from, and the templates are
+//! rendered by the same `TemplateRenderer` with the same `RenderContext`, so a template renders
+//! here as it would under a Supervisor with that census.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use butterfly::member::{Health, Member, MemberList};
+use butterfly::rumor::RumorStore;
+use butterfly::rumor::election::{Election as ElectionRumor,
+                                 ElectionUpdate as ElectionUpdateRumor};
+use butterfly::rumor::service::{Service as ServiceRumor, SysInfo};
+use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
+use butterfly::rumor::service_file::ServiceFile as ServiceFileRumor;
+use hcore::fs::FS_ROOT_PATH;
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
+use hcore::service::ServiceGroup;
+use serde_json::{self, Value as Json};
+use toml;
+
+use census::CensusRing;
+use config::GossipListenAddr;
+use error::{Error, Result};
+use http_gateway;
+use manager::Sys;
+use manager::service::{Cfg, Pkg, ServiceBind};
+use templating::{RenderContext, TemplateRenderer};
+
+/// Our output key
+static LOGKEY: &'static str = "RD";
+
+/// The member id of the member the service is rendered for.
+const LOCAL_MEMBER_ID: &'static str = "local";
+
+/// The top level keys of the render context. Template references starting with any other key
+/// are relative to a block's context and aren't checked.
+const CONTEXT_ROOTS: &'static [&'static str] = &["bind", "cfg", "pkg", "svc", "sys"];
+
+pub struct RenderOptions {
+    /// The group of the service group the service is rendered as a member of.
+    pub group: String,
+    /// A directory holding templates, hooks and a `default.toml` to use instead of the package's.
+    pub config_from: Option<PathBuf>,
+    /// A `user.toml` to render with instead of the service's.
+    pub user_toml: Option<PathBuf>,
+    /// A census file to render against. Without one the service is alone in its group.
+    pub census: Option<PathBuf>,
+    /// Write the rendered files under this directory instead of printing them.
+    pub render_dir: Option<PathBuf>,
+}
+
+pub fn start(ident: &PackageIdent, opts: &RenderOptions) -> Result<()> {
+    let package = PackageInstall::load(ident, Some(FS_ROOT_PATH.as_path()))?;
+    let pkg = Pkg::from_install(package)?;
+    let mut cfg = Cfg::new(&pkg, opts.config_from.as_ref())?;
+    if let Some(ref user_toml) = opts.user_toml {
+        cfg.load_user_file(user_toml)?;
+    }
+    for error in cfg.errors() {
+        outputln!("Warning: {}", error);
+    }
+    let service_group = ServiceGroup::new(None, &pkg.name, &opts.group, None)?;
+    let mut sys = Sys::new(
+        false,
+        GossipListenAddr::default(),
+        http_gateway::ListenAddr::default(),
+    );
+    sys.member_id = LOCAL_MEMBER_ID.to_string();
+    let census = match opts.census {
+        Some(ref path) => MockCensus::from_file(path)?,
+        None => MockCensus::default(),
+    };
+    let (ring, binds) = census.census_ring(&sys, &pkg, &cfg, &service_group)?;
+    let ctx = RenderContext::new(&service_group, &sys, &pkg, &cfg, &ring, binds.iter());
+    let json = serde_json::to_value(&ctx).map_err(|e| {
+        sup_error!(Error::RenderContextSerialization(e))
+    })?;
+
+    let root = opts.config_from.as_ref().unwrap_or(&pkg.path);
+    for dir in &["config", "hooks"] {
+        for (name, source, rendered) in render_templates(&root.join(dir), &ctx)? {
+            let file = format!("{}/{}", dir, name);
+            match opts.render_dir {
+                Some(ref render_dir) => {
+                    let dest = render_dir.join(dir);
+                    fs::create_dir_all(&dest)?;
+                    File::create(dest.join(&name))?.write_all(
+                        rendered.as_bytes(),
+                    )?;
+                    outputln!("Rendered {}", dest.join(&name).display());
+                }
+                None => {
+                    outputln!("{}:", file);
+                    println!("{}", rendered);
+                }
+            }
+            let missing = missing_keys(&source, &json);
+            if !missing.is_empty() {
+                outputln!(
+                    "Warning: {} references keys which aren't set: {}",
+                    file,
+                    missing.join(", ")
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Renders each template in a directory, returning the name, source and rendered content of
+/// each, ordered by name.
+fn render_templates(dir: &Path, ctx: &RenderContext) -> Result<Vec<(String, String, String)>> {
+    let mut paths = vec![];
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                paths.push(entry.path());
+            }
+        }
+    }
+    paths.sort();
+    let mut renderer = TemplateRenderer::new();
+    let mut rendered = vec![];
+    for path in paths {
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        let mut source = String::new();
+        File::open(&path)?.read_to_string(&mut source)?;
+        renderer.register_template_file(&name, &path).map_err(|e| {
+            sup_error!(Error::TemplateFileError(e))
+        })?;
+        let content = renderer.render(&name, ctx)?;
+        rendered.push((name, source, content));
+    }
+    Ok(rendered)
+}
+
+/// How the references within a block are checked.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Block {
+    /// The block renders against the context it's in.
+    Checked,
+    /// The block renders against another context, such as an element of an array, so only
+    /// references through `@root` can be checked.
+    Relative,
+    /// The block only renders when a key which isn't set is set, so it isn't checked.
+    Skipped,
+}
+
+/// Returns the keys a template references which the render context doesn't have, which would
+/// render as empty. References within blocks which only render when a key is set, or which
+/// change the context they render against, are only checked when they're unambiguous.
+fn missing_keys(template: &str, ctx: &Json) -> Vec<String> {
+    let mut missing = vec![];
+    // Each open block, with whether it's an `if` or `unless` and whether its condition is set
+    let mut blocks: Vec<(Block, Option<(bool, bool)>)> = vec![];
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = match after.find("}}") {
+            Some(end) => end,
+            None => break,
+        };
+        let expr = after[..end]
+            .trim_matches(|c| c == '{' || c == '}' || c == '~')
+            .trim();
+        rest = &after[end + 2..];
+        let block = blocks.iter().fold(Block::Checked, |state, &(block, _)| {
+            match (state, block) {
+                (Block::Skipped, _) | (_, Block::Skipped) => Block::Skipped,
+                (Block::Relative, _) | (_, Block::Relative) => Block::Relative,
+                _ => Block::Checked,
+            }
+        });
+        if expr.starts_with('!') || expr.starts_with('>') {
+            continue;
+        }
+        if expr.starts_with('/') {
+            blocks.pop();
+            continue;
+        }
+        if expr == "else" {
+            // The `else` of an `if` renders when its condition isn't set, and the `else` of an
+            // `unless` when it is.
+            if let Some(last) = blocks.last_mut() {
+                if let Some((is_if, set)) = last.1 {
+                    last.0 = if is_if != set {
+                        Block::Checked
+                    } else {
+                        Block::Skipped
+                    };
+                }
+            }
+            continue;
+        }
+        if expr.starts_with("else ") {
+            // Chained conditions aren't followed, so nothing past them is checked.
+            if let Some(last) = blocks.last_mut() {
+                last.0 = Block::Skipped;
+            }
+            continue;
+        }
+        let opens = expr.starts_with('#');
+        let mut tokens = expr.trim_left_matches('#').split_whitespace();
+        let first = tokens.next().unwrap_or("");
+        let params: Vec<&str> = if opens {
+            tokens.collect()
+        } else {
+            expr.split_whitespace().collect()
+        };
+        let conditional = opens && (first == "if" || first == "unless");
+        // The condition of an `if` or `unless` may be unset; that's what it's for.
+        for param in params.iter().skip(if conditional { 1 } else { 0 }) {
+            let path = param
+                .splitn(2, '=')
+                .last()
+                .unwrap()
+                .trim_matches(|c| c == '(' || c == ')');
+            if let Some(key) = checked_key(path, block) {
+                if lookup(ctx, key).is_none() && !missing.contains(&key.to_string()) {
+                    missing.push(key.to_string());
+                }
+            }
+        }
+        if opens {
+            let condition = params.first().map_or(false, |param| {
+                checked_key(param, Block::Checked).map_or(true, |key| lookup(ctx, key).is_some())
+            });
+            let is_if = first == "if";
+            blocks.push(if !conditional {
+                (Block::Relative, None)
+            } else if is_if == condition {
+                (Block::Checked, Some((is_if, condition)))
+            } else {
+                (Block::Skipped, Some((is_if, condition)))
+            });
+        }
+    }
+    missing.sort();
+    missing
+}
+
+/// Returns the key of the render context a template path references, if it can be checked
+/// within a block of the given kind.
+fn checked_key(path: &str, block: Block) -> Option<&str> {
+    let (key, root) = if path.starts_with("@root.") {
+        (&path["@root.".len()..], true)
+    } else {
+        (path, false)
+    };
+    let checked = match block {
+        Block::Checked => true,
+        Block::Relative => root,
+        Block::Skipped => false,
+    };
+    let top = key.split('.').next().unwrap_or("");
+    if checked && key.contains('.') && CONTEXT_ROOTS.contains(&top) {
+        Some(key)
+    } else {
+        None
+    }
+}
+
+/// Looks up a dotted path in the render context.
+fn lookup<'a>(ctx: &'a Json, key: &str) -> Option<&'a Json> {
+    let mut value = ctx;
+    for part in key.split('.') {
+        let part = part.trim_matches(|c| c == '[' || c == ']');
+        let next = match *value {
+            Json::Object(ref map) => map.get(part),
+            Json::Array(ref list) => part.parse::<usize>().ok().and_then(|i| list.get(i)),
+            _ => None,
+        };
+        match next {
+            Some(next) => value = next,
+            None => return None,
+        }
+    }
+    Some(value)
+}
+
+/// A census described by a file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct MockCensus {
+    /// Whether the member the service is rendered for is its group's leader.
+    leader: bool,
+    /// The other members of the service's group.
+    members: Vec<MockMember>,
+    /// The service groups the service binds to, by bind name.
+    bind: HashMap<String, MockGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MockGroup {
+    service_group: String,
+    #[serde(default)]
+    members: Vec<MockMember>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct MockMember {
+    member_id: Option<String>,
+    /// The fully qualified identifier of the package the member runs.
+    pkg: Option<String>,
+    leader: bool,
+    /// One of `alive`, `suspect`, `confirmed` or `departed`; `alive` when unset.
+    health: Option<String>,
+    sys: MockSys,
+    cfg: toml::value::Table,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct MockSys {
+    ip: String,
+    hostname: String,
+    gossip_ip: String,
+    gossip_port: u32,
+    http_gateway_ip: String,
+    http_gateway_port: u32,
+}
+
+impl MockSys {
+    fn to_sys_info(&self) -> SysInfo {
+        let mut sys_info = SysInfo::new();
+        sys_info.set_ip(self.ip.clone());
+        sys_info.set_hostname(self.hostname.clone());
+        sys_info.set_gossip_ip(self.gossip_ip.clone());
+        sys_info.set_gossip_port(self.gossip_port);
+        sys_info.set_http_gateway_ip(self.http_gateway_ip.clone());
+        sys_info.set_http_gateway_port(self.http_gateway_port);
+        sys_info
+    }
+}
+
+impl MockCensus {
+    fn from_file<T: AsRef<Path>>(path: T) -> Result<Self> {
+        let file = File::open(path.as_ref())?;
+        serde_json::from_reader(file).map_err(|e| {
+            sup_error!(Error::InvalidMockCensus(e.to_string()))
+        })
+    }
+
+    /// Builds a census ring out of the rumors a ring with these members would gossip, with the
+    /// service the given `pkg` runs in `service_group`. Returns the ring and the binds of the
+    /// service to the groups it describes.
+    fn census_ring(
+        &self,
+        sys: &Sys,
+        pkg: &Pkg,
+        cfg: &Cfg,
+        service_group: &ServiceGroup,
+    ) -> Result<(CensusRing, Vec<ServiceBind>)> {
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+        let member_list = MemberList::new();
+
+        let sys_info = sys.as_sys_info();
+        let exported = cfg.to_exported(pkg)?;
+        service_store.insert(ServiceRumor::new(
+            sys.member_id.as_str(),
+            &pkg.ident,
+            service_group,
+            &sys_info,
+            Some(&exported),
+        ));
+        member_list.insert(gossip_member(&sys.member_id, &sys_info), Health::Alive);
+        let leader = if self.leader {
+            Some(sys.member_id.clone())
+        } else {
+            None
+        };
+        insert_group(
+            &service_store,
+            &election_store,
+            &member_list,
+            service_group,
+            &self.members,
+            leader,
+        )?;
+
+        let mut binds = vec![];
+        for (name, group) in self.bind.iter() {
+            let bind_group = ServiceGroup::from_str(&group.service_group)?;
+            insert_group(
+                &service_store,
+                &election_store,
+                &member_list,
+                &bind_group,
+                &group.members,
+                None,
+            )?;
+            binds.push(ServiceBind {
+                name: name.clone(),
+                service_group: bind_group,
+            });
+        }
+
+        let mut ring = CensusRing::new(sys.member_id.as_str());
+        ring.update_from_rumors(
+            &service_store,
+            &election_store,
+            &RumorStore::<ElectionUpdateRumor>::default(),
+            &member_list,
+            &RumorStore::<ServiceConfigRumor>::default(),
+            &RumorStore::<ServiceFileRumor>::default(),
+        );
+        Ok((ring, binds))
+    }
+}
+
+/// Gossips the members of a service group, electing the leader among them, if there is one.
+fn insert_group(
+    service_store: &RumorStore<ServiceRumor>,
+    election_store: &RumorStore<ElectionRumor>,
+    member_list: &MemberList,
+    service_group: &ServiceGroup,
+    members: &[MockMember],
+    mut leader: Option<String>,
+) -> Result<()> {
+    for (n, member) in members.iter().enumerate() {
+        let member_id = member.member_id.clone().unwrap_or(
+            format!("{}-{}", service_group, n + 1),
+        );
+        let ident = match member.pkg {
+            Some(ref pkg) => PackageIdent::from_str(pkg)?,
+            None => PackageIdent::new(
+                "mock",
+                service_group.service(),
+                Some("0.0.0"),
+                Some("19700101000000"),
+            ),
+        };
+        if !ident.fully_qualified() || ident.name() != service_group.service() {
+            return Err(sup_error!(Error::InvalidMockCensus(format!(
+                "member {} must run a fully qualified package named {}, not {}",
+                member_id,
+                service_group.service(),
+                ident
+            ))));
+        }
+        let health = match member.health.as_ref().map(|h| h.as_str()) {
+            None | Some("alive") => Health::Alive,
+            Some("suspect") => Health::Suspect,
+            Some("confirmed") => Health::Confirmed,
+            Some("departed") => Health::Departed,
+            Some(other) => {
+                return Err(sup_error!(Error::InvalidMockCensus(
+                    format!("member {} has unknown health '{}'", member_id, other),
+                )))
+            }
+        };
+        let sys_info = member.sys.to_sys_info();
+        service_store.insert(ServiceRumor::new(
+            member_id.as_str(),
+            &ident,
+            service_group,
+            &sys_info,
+            Some(&member.cfg),
+        ));
+        member_list.insert(gossip_member(&member_id, &sys_info), health);
+        if member.leader {
+            leader = Some(member_id);
+        }
+    }
+    if let Some(leader) = leader {
+        let mut election = ElectionRumor::new(leader, service_group.clone(), 0);
+        election.finish();
+        election_store.insert(election);
+    }
+    Ok(())
+}
+
+fn gossip_member(member_id: &str, sys_info: &SysInfo) -> Member {
+    let mut member = Member::default();
+    member.set_id(member_id.to_string());
+    member.set_address(sys_info.get_gossip_ip().to_string());
+    member.set_gossip_port(sys_info.get_gossip_port() as i32);
+    member
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use super::*;
+
+    fn ctx() -> Json {
+        serde_json::from_str(
+            r#"{
+                "cfg": { "port": 6379, "tls": { "enabled": false } },
+                "svc": { "members": [ { "sys": { "ip": "10.0.0.2" } } ] },
+                "bind": {}
+            }"#,
+        ).unwrap()
+    }
+
+    #[test]
+    fn missing_keys_finds_unset_references() {
+        let template = "port {{cfg.port}}\nbind {{cfg.bind}}\n\
+                        {{toJson cfg.tls}}{{strJoin cfg.hosts \",\"}}";
+        assert_eq!(
+            missing_keys(template, &ctx()),
+            vec!["cfg.bind".to_string(), "cfg.hosts".to_string()]
+        );
+    }
+
+    #[test]
+    fn missing_keys_follows_blocks() {
+        let template = "{{#if cfg.auth}}{{cfg.auth.password}}{{else}}{{cfg.anonymous}}{{/if}}\
+                        {{#each svc.members}}{{sys.ip}}{{cfg.x}}{{@root.cfg.y}}{{/each}}\
+                        {{#unless cfg.port}}{{cfg.z}}{{else}}{{cfg.port}}{{/unless}}\
+                        {{#if bind.database}}{{bind.database.first.sys.ip}}{{/if}}";
+        assert_eq!(
+            missing_keys(template, &ctx()),
+            vec!["cfg.anonymous".to_string(), "cfg.y".to_string()]
+        );
+    }
+
+    #[test]
+    fn lookup_paths() {
+        let ctx = ctx();
+        assert_eq!(lookup(&ctx, "cfg.port"), Some(&Json::from(6379)));
+        assert_eq!(lookup(&ctx, "svc.members.0.sys.ip"), Some(&Json::from("10.0.0.2")));
+        assert_eq!(lookup(&ctx, "svc.members.[0].sys.ip"), Some(&Json::from("10.0.0.2")));
+        assert_eq!(lookup(&ctx, "svc.members.1"), None);
+        assert_eq!(lookup(&ctx, "cfg.port.number"), None);
+    }
+
+    #[test]
+    fn mock_census_from_json() {
+        let census: MockCensus = serde_json::from_str(
+            r#"{
+                "members": [ { "member_id": "redis-2", "cfg": { "port": 6379 } } ],
+                "bind": {
+                    "database": {
+                        "service_group": "postgresql.default",
+                        "members": [ { "sys": { "ip": "10.0.0.5" }, "leader": true } ]
+                    }
+                }
+            }"#,
+        ).unwrap();
+        assert!(!census.leader);
+        assert_eq!(census.members[0].member_id, Some("redis-2".to_string()));
+        assert_eq!(
+            census.members[0].cfg.get("port"),
+            Some(&toml::Value::Integer(6379))
+        );
+        let database = census.bind.get("database").unwrap();
+        assert_eq!(database.service_group, "postgresql.default");
+        assert!(database.members[0].leader);
+        assert_eq!(database.members[0].sys.ip, "10.0.0.5");
+    }
+}
@@ -104,6 +104,7 @@ impl SupError {
 #[derive(Debug)]
 pub enum Error {
     Departed,
+    ArtifactSourceIO(PathBuf, io::Error),
     BadDataFile(PathBuf, io::Error),
     BadDataPath(PathBuf, io::Error),
     BadDesiredState(String),
@@ -113,18 +114,33 @@ pub enum Error {
     BadStartStyle(String),
     BadEnvConfig(String),
     ButterflyError(butterfly::error::Error),
+    CompositeLoaded(String),
     DepotClient(depot_client::Error),
     EnvJoinPathsError(env::JoinPathsError),
     ExecCommandNotFound(String),
     FileNotFound(String),
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
+    HttpGatewayAuthNoRing,
+    HttpGatewayTls(String),
     TemplateFileError(handlebars::TemplateFileError),
     TemplateRenderError(handlebars::RenderError),
     InvalidBinding(String),
     InvalidBinds(Vec<String>),
+    InvalidCompositeSpec(String),
+    InvalidConfigSchema(String),
+    InvalidDependency(String),
+    InvalidHealthProbe(String),
+    InvalidHookTimeouts(String),
     InvalidKeyParameter(String),
+    InvalidLogPolicy(String),
+    InvalidMaintenanceWindow(String),
+    InvalidMockCensus(String),
     InvalidPidFile,
+    InvalidQuorum(String),
+    InvalidResourceLimits(String),
+    InvalidShutdownPolicy(String),
+    InvalidTimeSpec(String),
     InvalidTopology(String),
     InvalidUpdateStrategy(String),
     Io(io::Error),
@@ -145,6 +161,7 @@ pub enum Error {
     ProcessLockIO(PathBuf, io::Error),
     RenderContextSerialization(serde_json::Error),
     ServiceDeserializationError(serde_json::Error),
+    ServiceInComposite(package::PackageIdent, String),
     ServiceLoaded(package::PackageIdent),
     ServiceNotLoaded(package::PackageIdent),
     ServiceSerializationError(serde_json::Error),
@@ -169,6 +186,9 @@ impl fmt::Display for SupError {
     // verbose on, and print it.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let content = match self.err {
+            Error::ArtifactSourceIO(ref path, ref err) => {
+                format!("Unable to read artifact source at {}, {}", path.display(), err)
+            }
             Error::Departed => {
                 format!(
                     "This supervisor has been manually departed.\n\nFor the safety of the system, this supervisor cannot be started (if we did, we would risk the services on this machine behaving badly without our knowledge.) If you know that the services on this system are safe, and want them to rejoin the habitat ring, you need to:\n\n  rm -rf /hab/sup/default/MEMBER_ID /hab/sup/default/data\n\nThis will cause the supervisor to join the ring as a new member.\n\nIf you are in doubt, it is better to consider the services managed by this supervisor as unsafe to run."
@@ -205,12 +225,19 @@ impl fmt::Display for SupError {
                 format!("Unable to find valid TOML or JSON in {} ENVVAR", varname)
             }
             Error::ButterflyError(ref err) => format!("Butterfly error: {}", err),
+            Error::CompositeLoaded(ref name) => {
+                format!("Composite already loaded, unload '{}' and try again", name)
+            }
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
             }
             Error::Permissions(ref err) => format!("{}", err),
             Error::HabitatCommon(ref err) => format!("{}", err),
             Error::HabitatCore(ref err) => format!("{}", err),
+            Error::HttpGatewayAuthNoRing => {
+                format!("HTTP gateway ring authentication requires a ring key with a secret")
+            }
+            Error::HttpGatewayTls(ref e) => format!("Unable to set up HTTP gateway TLS, {}", e),
             Error::TemplateFileError(ref err) => format!("{:?}", err),
             Error::TemplateRenderError(ref err) => format!("{}", err),
             Error::DepotClient(ref err) => format!("{}", err),
@@ -224,10 +251,35 @@ impl fmt::Display for SupError {
                 )
             }
             Error::InvalidBinds(ref e) => format!("Invalid bind(s), {}", e.join(", ")),
+            Error::InvalidCompositeSpec(ref e) => format!("Invalid composite spec: {}", e),
+            Error::InvalidConfigSchema(ref e) => format!("Invalid config schema: {}", e),
+            Error::InvalidDependency(ref dependency) => {
+                format!(
+                    "Invalid dependency \"{}\", must be of the form <NAME>[:<CONDITION>] where \
+                         <NAME> is the name of a service on this Supervisor and <CONDITION> is \
+                         one of up or healthy",
+                    dependency
+                )
+            }
+            Error::InvalidHealthProbe(ref e) => format!("Invalid health probe: {}", e),
+            Error::InvalidHookTimeouts(ref e) => format!("Invalid hook timeouts: {}", e),
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
+            Error::InvalidLogPolicy(ref e) => format!("Invalid log policy: {}", e),
+            Error::InvalidMaintenanceWindow(ref e) => format!("Invalid maintenance window: {}", e),
+            Error::InvalidMockCensus(ref e) => format!("Invalid census file: {}", e),
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidQuorum(ref e) => format!("Invalid quorum: {}", e),
+            Error::InvalidResourceLimits(ref e) => format!("Invalid resource limits: {}", e),
+            Error::InvalidShutdownPolicy(ref e) => format!("Invalid shutdown policy: {}", e),
+            Error::InvalidTimeSpec(ref t) => {
+                format!(
+                    "Invalid time, {}: expected a duration such as 10m or a UTC time such as \
+                    2017-10-18T10:00:00Z",
+                    t
+                )
+            }
             Error::InvalidTopology(ref t) => format!("Invalid topology: {}", t),
             Error::InvalidUpdateStrategy(ref s) => format!("Invalid update strategy: {}", s),
             Error::Io(ref err) => format!("{}", err),
@@ -280,6 +332,14 @@ impl fmt::Display for SupError {
             Error::ServiceDeserializationError(ref e) => {
                 format!("Can't deserialize service status: {}", e)
             }
+            Error::ServiceInComposite(ref ident, ref composite) => {
+                format!(
+                    "Service {} was loaded as part of the '{}' composite, unload the composite \
+                     instead",
+                    ident,
+                    composite
+                )
+            }
             Error::ServiceNotLoaded(ref ident) => format!("Service {} not loaded", ident),
             Error::ServiceLoaded(ref ident) => {
                 format!("Service already loaded, unload '{}' and try again", ident)
@@ -335,6 +395,7 @@ impl fmt::Display for SupError {
 impl error::Error for SupError {
     fn description(&self) -> &str {
         match self.err {
+            Error::ArtifactSourceIO(_, _) => "Unable to read artifact source",
             Error::Departed => "Supervisor has been manually departed",
             Error::BadDataFile(_, _) => "Unable to read or write to a data file",
             Error::BadDataPath(_, _) => "Unable to read or write to data directory",
@@ -345,11 +406,14 @@ impl error::Error for SupError {
             Error::BadStartStyle(_) => "Unknown start style in service spec",
             Error::BadEnvConfig(_) => "Unknown syntax in Env Configuration",
             Error::ButterflyError(ref err) => err.description(),
+            Error::CompositeLoaded(_) => "Composite load called when composite already loaded",
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::TemplateFileError(ref err) => err.description(),
             Error::TemplateRenderError(ref err) => err.description(),
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::HttpGatewayAuthNoRing => "HTTP gateway ring authentication without a ring key",
+            Error::HttpGatewayTls(_) => "Unable to load the HTTP gateway's TLS certificate or key",
             Error::DepotClient(ref err) => err.description(),
             Error::EnvJoinPathsError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
@@ -357,8 +421,20 @@ impl error::Error for SupError {
             Error::InvalidBinds(_) => {
                 "Service binds detected that are neither required nor optional package binds"
             }
+            Error::InvalidCompositeSpec(_) => "Invalid composite spec",
+            Error::InvalidConfigSchema(_) => "Invalid config schema",
+            Error::InvalidDependency(_) => "Invalid dependency parameter",
+            Error::InvalidHealthProbe(_) => "Invalid health probe",
+            Error::InvalidHookTimeouts(_) => "Invalid hook timeouts",
             Error::InvalidKeyParameter(_) => "Key parameter error",
+            Error::InvalidLogPolicy(_) => "Invalid log policy",
+            Error::InvalidMaintenanceWindow(_) => "Invalid maintenance window",
+            Error::InvalidMockCensus(_) => "Invalid census file",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidQuorum(_) => "Invalid quorum",
+            Error::InvalidResourceLimits(_) => "Invalid resource limits",
+            Error::InvalidShutdownPolicy(_) => "Invalid shutdown policy",
+            Error::InvalidTimeSpec(_) => "Invalid time",
             Error::InvalidTopology(_) => "Invalid topology",
             Error::InvalidUpdateStrategy(_) => "Invalid update strategy",
             Error::Io(ref err) => err.description(),
@@ -387,6 +463,9 @@ impl error::Error for SupError {
             Error::ProcessLockIO(_, _) => "Unable to read or write to a process lock",
             Error::RenderContextSerialization(_) => "Unable to serialize rendering context",
             Error::ServiceDeserializationError(_) => "Can't deserialize service status",
+            Error::ServiceInComposite(_, _) => {
+                "Service unload called for a service loaded as part of a composite"
+            }
             Error::ServiceNotLoaded(_) => "Service status called when service not loaded",
             Error::ServiceLoaded(_) => "Service load or start called when service already loaded",
             Error::ServiceSerializationError(_) => "Can't serialize service to file",
@@ -0,0 +1,354 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Events describing changes to the census and to the services of this Supervisor, streamed to
+//! HTTP gateway clients as they happen.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+
+use census::CensusRing;
+use manager::service::HealthCheck;
+
+/// How many events are held for a subscriber which hasn't received them yet. A subscriber which
+/// falls this far behind is disconnected, rather than held events piling up.
+const SUBSCRIBER_BUFFER: usize = 256;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    MemberJoined {
+        service_group: String,
+        member_id: String,
+    },
+    MemberLeft {
+        service_group: String,
+        member_id: String,
+    },
+    MemberHealthChanged {
+        service_group: String,
+        member_id: String,
+        health: Option<&'static str>,
+    },
+    ServiceHealthChanged {
+        service_group: String,
+        member_id: String,
+        health: HealthCheck,
+    },
+    LeaderChanged {
+        service_group: String,
+        leader_id: Option<String>,
+    },
+    ServiceConfigChanged {
+        service_group: String,
+        incarnation: u64,
+    },
+    ProcessStateChanged {
+        service_group: String,
+        state: String,
+    },
+}
+
+impl Event {
+    /// The name of the event, as used for the `event` field of a server-sent event.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Event::MemberJoined { .. } => "member_joined",
+            Event::MemberLeft { .. } => "member_left",
+            Event::MemberHealthChanged { .. } => "member_health_changed",
+            Event::ServiceHealthChanged { .. } => "service_health_changed",
+            Event::LeaderChanged { .. } => "leader_changed",
+            Event::ServiceConfigChanged { .. } => "service_config_changed",
+            Event::ProcessStateChanged { .. } => "process_state_changed",
+        }
+    }
+}
+
+/// Hands every published event to each of its subscribers.
+#[derive(Clone, Default)]
+pub struct EventStream {
+    subscribers: Arc<Mutex<Vec<SyncSender<Event>>>>,
+}
+
+impl EventStream {
+    pub fn subscribe(&self) -> Receiver<Event> {
+        let (tx, rx) = mpsc::sync_channel(SUBSCRIBER_BUFFER);
+        self.subscribers
+            .lock()
+            .expect("Event stream lock poisoned")
+            .push(tx);
+        rx
+    }
+
+    /// Sends the event to all subscribers, dropping the ones which have gone away or whose buffer
+    /// is full. A dropped subscriber receives the events already buffered, then finds the stream
+    /// disconnected.
+    pub fn publish(&self, event: Event) {
+        self.subscribers
+            .lock()
+            .expect("Event stream lock poisoned")
+            .retain(|tx| tx.try_send(event.clone()).is_ok());
+    }
+}
+
+/// The parts of the census which events are emitted for.
+#[derive(Debug, Default)]
+pub struct CensusSnapshot {
+    groups: HashMap<String, GroupSnapshot>,
+}
+
+#[derive(Debug, Default)]
+struct GroupSnapshot {
+    leader_id: Option<String>,
+    config_incarnation: Option<u64>,
+    members: HashMap<String, MemberSnapshot>,
+}
+
+#[derive(Debug)]
+struct MemberSnapshot {
+    health: Option<&'static str>,
+    service_health: HealthCheck,
+}
+
+impl CensusSnapshot {
+    pub fn new(census_ring: &CensusRing) -> Self {
+        let mut snapshot = CensusSnapshot::default();
+        for census_group in census_ring.groups() {
+            let group = GroupSnapshot {
+                leader_id: census_group.leader_id.as_ref().map(|id| id.to_string()),
+                config_incarnation: census_group.service_config.as_ref().map(
+                    |config| config.incarnation,
+                ),
+                members: census_group
+                    .members()
+                    .into_iter()
+                    .map(|member| {
+                        (member.member_id.to_string(), MemberSnapshot {
+                            health: member.member_health(),
+                            service_health: member.health,
+                        })
+                    })
+                    .collect(),
+            };
+            snapshot.groups.insert(
+                census_group.service_group.to_string(),
+                group,
+            );
+        }
+        snapshot
+    }
+
+    /// Returns the events which lead from this snapshot of the census to the next one.
+    pub fn events_until(&self, next: &CensusSnapshot) -> Vec<Event> {
+        let empty = GroupSnapshot::default();
+        let mut events = Vec::new();
+        for (sg, group) in self.groups.iter() {
+            if next.groups.contains_key(sg) {
+                continue;
+            }
+            for member_id in group.members.keys() {
+                events.push(Event::MemberLeft {
+                    service_group: sg.clone(),
+                    member_id: member_id.clone(),
+                });
+            }
+        }
+        for (sg, group) in next.groups.iter() {
+            let previous = self.groups.get(sg).unwrap_or(&empty);
+            for (member_id, member) in group.members.iter() {
+                match previous.members.get(member_id) {
+                    Some(before) => {
+                        if before.health != member.health {
+                            events.push(Event::MemberHealthChanged {
+                                service_group: sg.clone(),
+                                member_id: member_id.clone(),
+                                health: member.health,
+                            });
+                        }
+                        if before.service_health != member.service_health {
+                            events.push(Event::ServiceHealthChanged {
+                                service_group: sg.clone(),
+                                member_id: member_id.clone(),
+                                health: member.service_health,
+                            });
+                        }
+                    }
+                    None => {
+                        events.push(Event::MemberJoined {
+                            service_group: sg.clone(),
+                            member_id: member_id.clone(),
+                        })
+                    }
+                }
+            }
+            for member_id in previous.members.keys() {
+                if !group.members.contains_key(member_id) {
+                    events.push(Event::MemberLeft {
+                        service_group: sg.clone(),
+                        member_id: member_id.clone(),
+                    });
+                }
+            }
+            if previous.leader_id != group.leader_id {
+                events.push(Event::LeaderChanged {
+                    service_group: sg.clone(),
+                    leader_id: group.leader_id.clone(),
+                });
+            }
+            if previous.config_incarnation != group.config_incarnation {
+                if let Some(incarnation) = group.config_incarnation {
+                    events.push(Event::ServiceConfigChanged {
+                        service_group: sg.clone(),
+                        incarnation: incarnation,
+                    });
+                }
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use serde_json;
+
+    use manager::service::HealthCheck;
+    use super::*;
+
+    fn snapshot(members: Vec<(&str, &'static str, HealthCheck)>) -> CensusSnapshot {
+        let mut group = GroupSnapshot::default();
+        for (member_id, health, service_health) in members {
+            group.members.insert(member_id.to_string(), MemberSnapshot {
+                health: Some(health),
+                service_health: service_health,
+            });
+        }
+        let mut groups = HashMap::new();
+        groups.insert("redis.default".to_string(), group);
+        CensusSnapshot { groups: groups }
+    }
+
+    #[test]
+    fn events_until_membership_changes() {
+        let before = snapshot(vec![("a", "alive", HealthCheck::Ok)]);
+        let after = snapshot(vec![("b", "alive", HealthCheck::Ok)]);
+        let events = before.events_until(&after);
+
+        assert_eq!(events.len(), 2);
+        assert!(events.contains(&Event::MemberJoined {
+            service_group: "redis.default".to_string(),
+            member_id: "b".to_string(),
+        }));
+        assert!(events.contains(&Event::MemberLeft {
+            service_group: "redis.default".to_string(),
+            member_id: "a".to_string(),
+        }));
+    }
+
+    #[test]
+    fn events_until_group_removed() {
+        let before = snapshot(vec![("a", "alive", HealthCheck::Ok)]);
+        let after = CensusSnapshot::default();
+
+        assert_eq!(
+            before.events_until(&after),
+            vec![
+                Event::MemberLeft {
+                    service_group: "redis.default".to_string(),
+                    member_id: "a".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn events_until_health_changes() {
+        let before = snapshot(vec![("a", "alive", HealthCheck::Ok)]);
+        let after = snapshot(vec![("a", "suspect", HealthCheck::Critical)]);
+
+        assert_eq!(
+            before.events_until(&after),
+            vec![
+                Event::MemberHealthChanged {
+                    service_group: "redis.default".to_string(),
+                    member_id: "a".to_string(),
+                    health: Some("suspect"),
+                },
+                Event::ServiceHealthChanged {
+                    service_group: "redis.default".to_string(),
+                    member_id: "a".to_string(),
+                    health: HealthCheck::Critical,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn events_until_no_changes() {
+        let before = snapshot(vec![("a", "alive", HealthCheck::Ok)]);
+        let after = snapshot(vec![("a", "alive", HealthCheck::Ok)]);
+
+        assert!(before.events_until(&after).is_empty());
+    }
+
+    #[test]
+    fn event_stream_publishes_to_subscribers() {
+        let stream = EventStream::default();
+        let rx = stream.subscribe();
+        let event = Event::ProcessStateChanged {
+            service_group: "redis.default".to_string(),
+            state: "up".to_string(),
+        };
+        stream.publish(event.clone());
+
+        assert_eq!(rx.try_recv().unwrap(), event);
+        drop(rx);
+        stream.publish(event);
+        assert!(stream.subscribers.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn event_stream_disconnects_subscribers_which_fall_behind() {
+        let stream = EventStream::default();
+        let rx = stream.subscribe();
+        let event = Event::ProcessStateChanged {
+            service_group: "redis.default".to_string(),
+            state: "up".to_string(),
+        };
+        for _ in 0..SUBSCRIBER_BUFFER {
+            stream.publish(event.clone());
+        }
+        assert_eq!(stream.subscribers.lock().unwrap().len(), 1);
+        stream.publish(event);
+        assert!(stream.subscribers.lock().unwrap().is_empty());
+
+        assert_eq!(rx.iter().count(), SUBSCRIBER_BUFFER);
+    }
+
+    #[test]
+    fn event_serializes_with_type() {
+        let event = Event::LeaderChanged {
+            service_group: "redis.default".to_string(),
+            leader_id: Some("a".to_string()),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"type":"leader_changed","service_group":"redis.default","leader_id":"a"}"#
+        );
+    }
+}
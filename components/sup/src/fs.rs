@@ -62,6 +62,16 @@ pub fn svc_logs_path<T: AsRef<Path>>(service_name: T) -> PathBuf {
     svc_path(service_name).join("logs")
 }
 
+/// Returns the path to the log a given service's output is captured to.
+pub fn svc_output_log<T: AsRef<Path>>(service_name: T) -> PathBuf {
+    svc_logs_path(service_name).join("service.log")
+}
+
+/// Returns the path to the log the output of all of a given service's hooks is appended to.
+pub fn svc_hooks_log<T: AsRef<Path>>(service_name: T) -> PathBuf {
+    svc_logs_path(service_name).join("hooks.log")
+}
+
 /// Returns the path to a given service's pid file.
 pub fn svc_pid_file<T: AsRef<Path>>(service_name: T) -> PathBuf {
     svc_path(service_name).join("PID")
@@ -12,34 +12,59 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::collections::HashMap;
 use std::fmt;
-use std::fs::File;
-use std::io::{self, Read};
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::atomic::{ATOMIC_USIZE_INIT, AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
 use std::thread::{self, JoinHandle};
-
+use std::time::{Duration, Instant};
+
+use butterfly;
+use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
+use common::ui::UI;
+use hcore::crypto::SymKey;
+use hcore::crypto::hash;
+use hcore::package::PackageIdent;
 use hcore::service::{ApplicationEnvironment, ServiceGroup};
 use iron::prelude::*;
 use iron::{headers, status, typemap};
 use iron::modifiers::Header;
+use iron::response::WriteBody;
+use hyper_openssl::OpensslServer;
 use persistent;
 use prometheus::{self, CounterVec, HistogramVec, TextEncoder, Encoder};
 use router::Router;
 use serde_json::{self, Value as Json};
+use toml;
 
 use error::{Result, Error, SupError};
-use manager;
-use manager::service::HealthCheck;
+use event_stream::{Event, EventStream};
+use manager::{self, ConfigHistory, Manager, ManagerConfig};
+use manager::service::{CompositeSpec, DesiredState, HealthCheck, ServiceSpec, StartStyle};
 use manager::service::hooks::{self, HealthCheckHook};
+use util;
 
 static LOGKEY: &'static str = "HG";
+/// How often an idle event stream sends a comment, so that closed connections are noticed.
+const EVENT_KEEPALIVE_SECS: u64 = 15;
+/// How long an event stream stays open before the client has to reconnect. Each stream holds
+/// one of the gateway's worker threads for as long as it's open.
+const EVENT_STREAM_MAX_SECS: u64 = 300;
+/// How many event streams may be open at once, leaving the rest of the workers to other requests.
+const EVENT_STREAMS_MAX: usize = 4;
+
+/// Number of open event streams.
+static EVENT_STREAMS: AtomicUsize = ATOMIC_USIZE_INIT;
 const APIDOCS: &'static str = include_str!(concat!(env!("OUT_DIR"), "/api.html"));
 
 // Simple macro to encapsulate the HTTP metrics for each endpoint
@@ -56,6 +81,18 @@ macro_rules! with_metrics {
     }}
 }
 
+// Requires requests to a route to authenticate whenever the gateway has authentication enabled
+macro_rules! protected {
+    ($method:expr) => {
+        move |req: &mut Request| -> IronResult<Response> {
+            if let Err(response) = authenticate(req, false) {
+                return Ok(response);
+            }
+            $method(req)
+        }
+    }
+}
+
 lazy_static! {
     static ref HTTP_COUNTER: CounterVec = register_counter_vec!(
         opts!(
@@ -135,57 +172,212 @@ impl fmt::Display for ListenAddr {
     }
 }
 
+/// Paths to the PEM encoded certificate and private key the gateway serves TLS with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TlsCfg {
+    cert: PathBuf,
+    key: PathBuf,
+}
+
+impl TlsCfg {
+    pub fn new<C, K>(cert: C, key: K) -> Self
+    where
+        C: Into<PathBuf>,
+        K: Into<PathBuf>,
+    {
+        TlsCfg {
+            cert: cert.into(),
+            key: key.into(),
+        }
+    }
+}
+
+/// Returns the bearer token clients authenticate with when the gateway derives it from the ring
+/// key: the hex encoded BLAKE2b hash of the key's secret.
+pub fn ring_key_token(ring_key: &SymKey) -> Result<String> {
+    match ring_key.secret {
+        Some(ref secret) => Ok(hash::hash_bytes(&secret.0)),
+        None => Err(sup_error!(Error::HttpGatewayAuthNoRing)),
+    }
+}
+
 struct ManagerFs;
 
 impl typemap::Key for ManagerFs {
     type Value = manager::FsCfg;
 }
 
-pub struct Server(Iron<Chain>, ListenAddr);
+struct ManagerCfg;
+
+impl typemap::Key for ManagerCfg {
+    type Value = ManagerConfig;
+}
+
+struct Butterfly;
+
+impl typemap::Key for Butterfly {
+    type Value = butterfly::Server;
+}
+
+struct Events;
+
+impl typemap::Key for Events {
+    type Value = EventStream;
+}
+
+pub struct Server {
+    iron: Iron<Chain>,
+    listen_addr: ListenAddr,
+    tls: Option<TlsCfg>,
+}
 
 impl Server {
-    pub fn new(manager_state: Arc<manager::FsCfg>, listen_addr: ListenAddr) -> Self {
+    pub fn new(
+        manager_state: Arc<manager::FsCfg>,
+        manager_cfg: Arc<ManagerConfig>,
+        butterfly: butterfly::Server,
+        event_stream: EventStream,
+        listen_addr: ListenAddr,
+    ) -> Self {
         let router =
             router!(
             doc: get "/" => with_metrics!(doc, "doc"),
-            butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
-            census: get "/census" => with_metrics!(census, "census"),
+            butterfly: get "/butterfly" => protected!(with_metrics!(butterfly, "butterfly")),
+            census: get "/census" => protected!(with_metrics!(census, "census")),
+            events: get "/events" => protected!(with_metrics!(events, "events")),
             metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
-            services: get "/services" => with_metrics!(services, "services"),
+            services: get "/services" => protected!(with_metrics!(services, "services")),
             service: get "/services/:svc/:group" => {
-                with_metrics!(service, "service")
+                protected!(with_metrics!(service, "service"))
             },
             service_org: get "/services/:svc/:group/:org" => {
-                with_metrics!(service, "service")
+                protected!(with_metrics!(service, "service"))
             },
             service_config: get "/services/:svc/:group/config" => {
-                with_metrics!(config, "config")
+                protected!(with_metrics!(config, "config"))
+            },
+            service_config_history: get "/services/:svc/:group/config/history" => {
+                protected!(with_metrics!(config_history, "config_history"))
             },
             service_health: get "/services/:svc/:group/health" => with_metrics!(health, "health"),
             service_config_org: get "/services/:svc/:group/:org/config" => {
-                with_metrics!(config, "config")
+                protected!(with_metrics!(config, "config"))
+            },
+            service_config_history_org: get "/services/:svc/:group/:org/config/history" => {
+                protected!(with_metrics!(config_history, "config_history"))
             },
             service_health_org: get "/services/:svc/:group/:org/health" => {
                 with_metrics!(health, "config")
+            },
+            service_load: post "/services" => with_metrics!(load, "load"),
+            composite_unload: delete "/composites/:name" => {
+                with_metrics!(unload_composite, "unload_composite")
+            },
+            service_unload: delete "/services/:svc/:group" => with_metrics!(unload, "unload"),
+            service_unload_org: delete "/services/:svc/:group/:org" => {
+                with_metrics!(unload, "unload")
+            },
+            service_start: post "/services/:svc/:group/start" => with_metrics!(start, "start"),
+            service_start_org: post "/services/:svc/:group/:org/start" => {
+                with_metrics!(start, "start")
+            },
+            service_stop: post "/services/:svc/:group/stop" => with_metrics!(stop, "stop"),
+            service_stop_org: post "/services/:svc/:group/:org/stop" => {
+                with_metrics!(stop, "stop")
+            },
+            service_apply: post "/services/:svc/:group/config" => {
+                with_metrics!(apply_config, "apply_config")
+            },
+            service_apply_org: post "/services/:svc/:group/:org/config" => {
+                with_metrics!(apply_config, "apply_config")
+            },
+            service_rollback: post "/services/:svc/:group/config/rollback" => {
+                with_metrics!(rollback_config, "rollback_config")
+            },
+            service_rollback_org: post "/services/:svc/:group/:org/config/rollback" => {
+                with_metrics!(rollback_config, "rollback_config")
             }
         );
+        let tls = manager_cfg.http_tls.clone();
         let mut chain = Chain::new(router);
         chain.link(persistent::Read::<ManagerFs>::both(manager_state));
-        Server(Iron::new(chain), listen_addr)
+        chain.link(persistent::Read::<ManagerCfg>::both(manager_cfg));
+        chain.link(persistent::Read::<Butterfly>::both(Arc::new(butterfly)));
+        chain.link(persistent::Read::<Events>::both(Arc::new(event_stream)));
+        Server {
+            iron: Iron::new(chain),
+            listen_addr: listen_addr,
+            tls: tls,
+        }
     }
 
     pub fn start(self) -> Result<JoinHandle<()>> {
+        let ssl = match self.tls {
+            Some(ref tls) => {
+                Some(OpensslServer::from_files(&tls.key, &tls.cert).map_err(|e| {
+                    sup_error!(Error::HttpGatewayTls(e.to_string()))
+                })?)
+            }
+            None => None,
+        };
         let handle = thread::Builder::new()
             .name("http-gateway".to_string())
             .spawn(move || {
-                self.0.http(*self.1).expect(
-                    "unable to start http-gateway thread",
-                );
+                let listening = match ssl {
+                    Some(ssl) => self.iron.https(*self.listen_addr, ssl),
+                    None => self.iron.http(*self.listen_addr),
+                };
+                listening.expect("unable to start http-gateway thread");
             })?;
         Ok(handle)
     }
 }
 
+/// Streams events to a client as server-sent events until the client goes away, or the stream
+/// has been open for `EVENT_STREAM_MAX_SECS`.
+struct EventBody(Receiver<Event>);
+
+impl EventBody {
+    /// Opens an event stream, unless `EVENT_STREAMS_MAX` are open already.
+    fn open(event_stream: &EventStream) -> Option<EventBody> {
+        if EVENT_STREAMS.fetch_add(1, Ordering::SeqCst) >= EVENT_STREAMS_MAX {
+            EVENT_STREAMS.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        Some(EventBody(event_stream.subscribe()))
+    }
+}
+
+impl Drop for EventBody {
+    fn drop(&mut self) {
+        EVENT_STREAMS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl WriteBody for EventBody {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        let opened = Instant::now();
+        // Ask clients to reconnect shortly after we close the stream
+        write!(res, "retry: {}\n\n", EVENT_KEEPALIVE_SECS * 1000)?;
+        while opened.elapsed() < Duration::from_secs(EVENT_STREAM_MAX_SECS) {
+            match self.0.recv_timeout(Duration::from_secs(EVENT_KEEPALIVE_SECS)) {
+                Ok(event) => {
+                    write!(
+                        res,
+                        "event: {}\ndata: {}\n\n",
+                        event.name(),
+                        serde_json::to_string(&event).unwrap()
+                    )?
+                }
+                Err(RecvTimeoutError::Timeout) => write!(res, ": keep-alive\n\n")?,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+            res.flush()?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Default, Serialize)]
 struct HealthCheckBody {
     stdout: String,
@@ -212,6 +404,20 @@ fn census(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn events(req: &mut Request) -> IronResult<Response> {
+    let event_stream = req.get::<persistent::Read<Events>>().unwrap();
+    let body: Box<WriteBody> = match EventBody::open(&event_stream) {
+        Some(body) => Box::new(body),
+        None => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    Ok(Response::with((
+        status::Ok,
+        Header(headers::ContentType("text/event-stream".parse().unwrap())),
+        Header(headers::CacheControl(vec![headers::CacheDirective::NoCache])),
+        body,
+    )))
+}
+
 fn config(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     let service_group = match build_service_group(req) {
@@ -231,6 +437,20 @@ fn config(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn config_history(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    match File::open(state.config_history(&service_group)) {
+        Ok(file) => Ok(Response::with(
+            (status::Ok, Header(headers::ContentType::json()), file),
+        )),
+        Err(_) => Ok(Response::with(status::NotFound)),
+    }
+}
+
 fn health(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     let (health_file, stdout_path, stderr_path) = match build_service_group(req) {
@@ -295,6 +515,211 @@ fn services(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Load a service from the spec file sent as the request body, or all the services of a composite
+/// from a composite spec file. A service or composite which is already loaded is only replaced if
+/// the `force` query parameter is `true`.
+fn load(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authenticate(req, true) {
+        return Ok(response);
+    }
+    let cfg = req.get::<persistent::Read<ManagerCfg>>().unwrap();
+    let force = query_param(req, "force") == Some("true");
+    let mut body = String::new();
+    if req.body.read_to_string(&mut body).is_err() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    if is_composite(&body) {
+        return Ok(load_composite(&cfg, &body, force));
+    }
+    let mut spec = match ServiceSpec::from_str(&body) {
+        Ok(spec) => spec,
+        Err(err) => return Ok(error_response(status::BadRequest, err)),
+    };
+    if !force {
+        if let Ok(loaded) = ServiceSpec::from_file(Manager::spec_path_for(&cfg, &spec)) {
+            return Ok(error_response(
+                status::Conflict,
+                sup_error!(Error::ServiceLoaded(loaded.ident)),
+            ));
+        }
+    }
+    spec.start_style = StartStyle::Persistent;
+    if let Err(err) = util::pkg::install_from_spec(&mut UI::default(), &spec) {
+        return Ok(error_response(status::InternalServerError, err));
+    }
+    match Manager::save_spec_for(&cfg, spec) {
+        Ok(()) => Ok(Response::with(status::Accepted)),
+        Err(err) => Ok(error_response(status::InternalServerError, err)),
+    }
+}
+
+/// Whether a spec file sent to `load` describes a composite, which lists its services in
+/// `[[services]]` tables.
+fn is_composite(body: &str) -> bool {
+    toml::from_str::<toml::value::Table>(body)
+        .map(|spec| spec.contains_key("services"))
+        .unwrap_or(false)
+}
+
+/// Installs each service of a composite and saves the composite, as `hab svc load` does with a
+/// composite spec file.
+fn load_composite(cfg: &ManagerConfig, body: &str, force: bool) -> Response {
+    let composite = match CompositeSpec::from_str(body) {
+        Ok(composite) => composite,
+        Err(err) => return error_response(status::BadRequest, err),
+    };
+    if let Err(err) = Manager::check_composite_for(cfg, &composite, force) {
+        return error_response(status::Conflict, err);
+    }
+    for spec in composite.service_specs() {
+        if let Err(err) = util::pkg::install_from_spec(&mut UI::default(), &spec) {
+            return error_response(status::InternalServerError, err);
+        }
+    }
+    match Manager::save_composite_for(cfg, &composite) {
+        Ok(()) => Response::with(status::Accepted),
+        Err(err) => error_response(status::InternalServerError, err),
+    }
+}
+
+fn unload(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authenticate(req, true) {
+        return Ok(response);
+    }
+    let cfg = req.get::<persistent::Read<ManagerCfg>>().unwrap();
+    let spec = match loaded_spec(req) {
+        Ok(spec) => spec,
+        Err(response) => return Ok(response),
+    };
+    let spec_file = Manager::spec_path_for(&cfg, &spec);
+    match fs::remove_file(&spec_file) {
+        Ok(()) => Ok(Response::with(status::Accepted)),
+        Err(err) => Ok(error_response(
+            status::InternalServerError,
+            sup_error!(Error::ServiceSpecFileIO(spec_file, err)),
+        )),
+    }
+}
+
+/// Unload a composite, stopping and unloading all of its services at once.
+fn unload_composite(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authenticate(req, true) {
+        return Ok(response);
+    }
+    let cfg = req.get::<persistent::Read<ManagerCfg>>().unwrap();
+    let composite_file = {
+        let name = req.extensions
+            .get::<Router>()
+            .unwrap()
+            .find("name")
+            .unwrap_or("");
+        Manager::composite_path_for(&cfg, name)
+    };
+    if !composite_file.is_file() {
+        return Ok(Response::with(status::NotFound));
+    }
+    match fs::remove_file(&composite_file) {
+        Ok(()) => Ok(Response::with(status::Accepted)),
+        Err(err) => Ok(error_response(
+            status::InternalServerError,
+            sup_error!(Error::ServiceSpecFileIO(composite_file, err)),
+        )),
+    }
+}
+
+fn start(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Up)
+}
+
+fn stop(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Down)
+}
+
+fn set_desired_state(req: &mut Request, desired_state: DesiredState) -> IronResult<Response> {
+    if let Err(response) = authenticate(req, true) {
+        return Ok(response);
+    }
+    let cfg = req.get::<persistent::Read<ManagerCfg>>().unwrap();
+    let ident = match loaded_ident(req) {
+        Ok(ident) => ident,
+        Err(response) => return Ok(response),
+    };
+    match Manager::set_desired_state_for(&cfg, ident, desired_state) {
+        Ok(()) => Ok(Response::with(status::Accepted)),
+        Err(err @ SupError { err: Error::ServiceNotLoaded(_), .. }) => {
+            Ok(error_response(status::NotFound, err))
+        }
+        Err(err) => Ok(error_response(status::InternalServerError, err)),
+    }
+}
+
+/// Gossip the TOML sent as the request body as the service group's configuration. The
+/// configuration's incarnation is taken from the `incarnation` query parameter, or is one more
+/// than the incarnation of the configuration currently gossiped for the service group.
+fn apply_config(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authenticate(req, true) {
+        return Ok(response);
+    }
+    let butterfly = req.get::<persistent::Read<Butterfly>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let incarnation = match query_param(req, "incarnation").map(u64::from_str) {
+        Some(Ok(incarnation)) => incarnation,
+        Some(Err(_)) => return Ok(Response::with(status::BadRequest)),
+        None => next_config_incarnation(&butterfly, &service_group),
+    };
+    let mut body = String::new();
+    if req.body.read_to_string(&mut body).is_err() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    if let Err(err) = toml::from_str::<toml::value::Table>(&body) {
+        return Ok(Response::with((status::BadRequest, err.to_string())));
+    }
+    let mut rumor = ServiceConfigRumor::new(
+        butterfly.member_id(),
+        service_group,
+        body.into_bytes(),
+    );
+    rumor.set_incarnation(incarnation);
+    butterfly.insert_service_config(rumor);
+    Ok(Response::with(status::Accepted))
+}
+
+/// Gossip a version of the service group's configuration from its config history again, with an
+/// incarnation newer than any the service group has seen. The version is named by the `version`
+/// query parameter.
+fn rollback_config(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authenticate(req, true) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let butterfly = req.get::<persistent::Read<Butterfly>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let version = match query_param(req, "version").map(u64::from_str) {
+        Some(Ok(version)) => version,
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+    let history = match ConfigHistory::load(state.config_history(&service_group)) {
+        Ok(history) => history,
+        Err(err) => return Ok(error_response(status::InternalServerError, err)),
+    };
+    let incarnation = cmp::max(
+        next_config_incarnation(&butterfly, &service_group),
+        history.latest().map_or(1, |latest| latest.incarnation + 1),
+    );
+    let rumor = match history.get(version) {
+        Some(config) => config.to_rumor(butterfly.member_id(), service_group, incarnation),
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    butterfly.insert_service_config(rumor);
+    Ok(Response::with((status::Accepted, incarnation.to_string())))
+}
+
 fn doc(_req: &mut Request) -> IronResult<Response> {
     Ok(Response::with(
         (status::Ok, Header(headers::ContentType::html()), APIDOCS),
@@ -307,9 +732,13 @@ fn metrics(_req: &mut Request) -> IronResult<Response> {
     let metric_familys = prometheus::gather();
     encoder.encode(&metric_familys, &mut buffer).unwrap();
 
-    Ok(Response::with(
-        (status::Ok, String::from_utf8(buffer).unwrap()),
-    ))
+    Ok(Response::with((
+        status::Ok,
+        Header(headers::ContentType(
+            encoder.format_type().parse().unwrap(),
+        )),
+        String::from_utf8(buffer).unwrap(),
+    )))
 }
 
 impl Into<Response> for HealthCheck {
@@ -329,6 +758,94 @@ impl Into<status::Status> for HealthCheck {
     }
 }
 
+/// Checks that the request carries the gateway's bearer token. When no token is configured,
+/// requests are let through unless `required` is set, as it is for requests which change the
+/// Supervisor's state.
+fn authenticate(req: &mut Request, required: bool) -> result::Result<(), Response> {
+    let cfg = req.get::<persistent::Read<ManagerCfg>>().unwrap();
+    let token = match cfg.http_auth_token {
+        Some(ref token) => token,
+        None if required => return Err(Response::with(status::Forbidden)),
+        None => return Ok(()),
+    };
+    match req.headers.get::<headers::Authorization<headers::Bearer>>() {
+        Some(&headers::Authorization(ref bearer)) if tokens_match(&bearer.token, token) => Ok(()),
+        _ => Err(Response::with(status::Unauthorized)),
+    }
+}
+
+/// Compares two tokens in time independent of where they first differ.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len() &&
+        given.bytes().zip(expected.bytes()).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+fn query_param<'a>(req: &'a Request, name: &str) -> Option<&'a str> {
+    req.url.query().and_then(|query| find_param(query, name))
+}
+
+fn find_param<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .map(|pair| pair.splitn(2, '='))
+        .filter_map(|mut pair| match (pair.next(), pair.next()) {
+            (Some(key), value) if key == name => Some(value.unwrap_or("")),
+            _ => None,
+        })
+        .next()
+}
+
+fn error_response(status: status::Status, err: SupError) -> Response {
+    Response::with((status, err.to_string()))
+}
+
+/// Reads the spec of the service loaded for the service group named in the request path.
+/// Returns the identifier of the spec the service group's service was loaded with.
+fn loaded_ident(req: &mut Request) -> result::Result<PackageIdent, Response> {
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = build_service_group(req).map_err(|_| {
+        Response::with(status::BadRequest)
+    })?;
+    match service_from_file(&service_group, &state.services_data_path) {
+        Ok(Some(service)) => {
+            serde_json::from_value::<PackageIdent>(service["spec_ident"].clone())
+                .map_err(|_| Response::with(status::ServiceUnavailable))
+        }
+        Ok(None) => Err(Response::with(status::NotFound)),
+        Err(_) => Err(Response::with(status::ServiceUnavailable)),
+    }
+}
+
+/// Reads the spec of the service loaded for the service group named in the request path. A
+/// service loaded as part of a composite has no spec of its own, and is a conflict, since only
+/// the whole composite can be unloaded.
+fn loaded_spec(req: &mut Request) -> result::Result<ServiceSpec, Response> {
+    let cfg = req.get::<persistent::Read<ManagerCfg>>().unwrap();
+    let ident = loaded_ident(req)?;
+    let spec_file = Manager::spec_path_for(&cfg, &ServiceSpec::default_for(ident.clone()));
+    match ServiceSpec::from_file(&spec_file) {
+        Ok(spec) => Ok(spec),
+        Err(err) => {
+            match Manager::composite_for_service(&cfg, &ident.name) {
+                Some((_, composite)) => Err(error_response(
+                    status::Conflict,
+                    sup_error!(Error::ServiceInComposite(ident, composite.name)),
+                )),
+                None => Err(error_response(status::NotFound, err)),
+            }
+        }
+    }
+}
+
+fn next_config_incarnation(butterfly: &butterfly::Server, service_group: &ServiceGroup) -> u64 {
+    let list = butterfly.service_config_store.list.read().expect(
+        "Rumor store lock poisoned",
+    );
+    list.get(&**service_group)
+        .and_then(|rumors| rumors.get("service_config"))
+        .map_or(1, |rumor| rumor.get_incarnation() + 1)
+}
+
 fn build_service_group(req: &mut Request) -> Result<ServiceGroup> {
     let app_env = match req.extensions.get::<Router>().unwrap().find(
         "application_environment",
@@ -379,3 +896,44 @@ where
         Err(err) => Err(err),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{find_param, is_composite, tokens_match};
+
+    #[test]
+    fn find_param_in_query() {
+        let query = "force=true&incarnation=42&flag";
+
+        assert_eq!(find_param(query, "force"), Some("true"));
+        assert_eq!(find_param(query, "incarnation"), Some("42"));
+        assert_eq!(find_param(query, "flag"), Some(""));
+        assert_eq!(find_param(query, "missing"), None);
+    }
+
+    #[test]
+    fn tokens_match_only_identical_tokens() {
+        assert!(tokens_match("s3cr3t", "s3cr3t"));
+        assert!(!tokens_match("s3cr3T", "s3cr3t"));
+        assert!(!tokens_match("s3cr3", "s3cr3t"));
+        assert!(!tokens_match("", "s3cr3t"));
+    }
+
+    #[test]
+    fn composite_specs_are_told_apart_from_service_specs() {
+        let composite = r#"
+        name = "shop"
+
+        [[services]]
+        ident = "core/storefront"
+        "#;
+        let service = r#"
+        ident = "core/redis"
+        group = "default"
+        "#;
+
+        assert!(is_composite(composite));
+        assert!(!is_composite(service));
+        assert!(!is_composite("not toml ["));
+    }
+}
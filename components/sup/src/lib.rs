@@ -56,6 +56,7 @@ extern crate habitat_depot_client as depot_client;
 extern crate habitat_eventsrv_client as eventsrv_client;
 extern crate habitat_launcher_client as launcher_client;
 extern crate handlebars;
+extern crate hyper_openssl;
 extern crate iron;
 #[macro_use]
 extern crate lazy_static;
@@ -98,6 +99,7 @@ pub mod command;
 pub mod config;
 pub mod census;
 pub mod error;
+pub mod event_stream;
 pub mod fs;
 pub mod http_gateway;
 pub mod manager;
@@ -55,8 +55,9 @@ use sup::feat;
 use sup::command;
 use sup::http_gateway;
 use sup::manager::{Manager, ManagerConfig};
-use sup::manager::service::{DesiredState, ServiceBind, Topology, UpdateStrategy};
-use sup::manager::service::{ServiceSpec, StartStyle};
+use sup::manager::service::{DesiredState, LocalDependency, ServiceBind, Topology,
+                            UpdateStrategy};
+use sup::manager::service::{CompositeSpec, ServiceSpec, StartStyle};
 use sup::util;
 
 /// Our output key
@@ -64,6 +65,7 @@ static LOGKEY: &'static str = "MN";
 
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
+static GATEWAY_AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_GATEWAY_AUTH_TOKEN";
 
 fn main() {
     if let Err(err) = start() {
@@ -113,6 +115,8 @@ fn start() -> Result<()> {
         ("bash", Some(m)) => sub_bash(m),
         ("config", Some(m)) => sub_config(m),
         ("load", Some(m)) => sub_load(m),
+        ("logs", Some(m)) => sub_logs(m),
+        ("render", Some(m)) => sub_render(m),
         ("run", Some(m)) => {
             let launcher = launcher.ok_or(sup_error!(Error::NoLauncher))?;
             sub_run(m, launcher)
@@ -155,7 +159,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 artifact. Services started in this manner will persist through Supervisor \
                 restarts.")
             (aliases: &["lo", "loa"])
-            (@arg PKG_IDENT: +required +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg PKG_IDENT_OR_COMPOSITE: +required +takes_value
+                "A Habitat package identifier (ex: core/redis) or the path to a composite spec \
+                file, whose services are loaded together")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
@@ -168,23 +174,57 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg GROUP: --group +takes_value
                 "The service group; shared config and topology [default: default].")
             (@arg DEPOT_URL: --url -u +takes_value {valid_url}
-                "Receive package updates from the Depot at the specified URL \
+                "Receive package updates from the Depot at the specified URL, or from a \
+                directory of artifacts given as a file:// URL or absolute path \
                 [default: https://bldr.habitat.sh/v1/depot]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] \
+                [values: standalone, leader, primary-replica, quorum]")
+            (@arg QUORUM: --quorum +takes_value {valid_quorum}
+                "The number of alive members needed to elect a leader \
+                [default: a majority of at least 3 members]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg DEPENDS_ON: --("depends-on") +takes_value +multiple
+                "One or more services on this Supervisor to start after, as <NAME>[:<CONDITION>] \
+                where <CONDITION> is up or healthy [default condition: up]")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
                 previously loaded and running this operation will also restart the service")
         )
+        (@subcommand logs =>
+            (about: "Print the captured output of a Habitat service and its hooks.")
+            (@arg PKG_IDENT: +required +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg FOLLOW: -f --follow "Keep printing output as it is written")
+            (@arg TAIL: -n --tail +takes_value {valid_tail} "Print only this many of the most \
+                recent lines")
+            (@arg SINCE: --since +takes_value "Print only output written since a duration ago \
+                (ex: 10m, 2h, 1d) or a UTC time (ex: 2017-10-18T10:00:00Z)")
+        )
+        (@subcommand render =>
+            (about: "Render the configuration templates and hooks of an installed package \
+                without running it, reporting keys they reference which aren't set")
+            (@arg PKG_IDENT: +required +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg GROUP: --group +takes_value
+                "The service group; shorthand for specifying a group name [default: default]")
+            (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
+                "Render templates and hooks from this directory rather than the package's")
+            (@arg USER_TOML: --("user-toml") +takes_value {file_exists}
+                "Render with this user.toml rather than the service's")
+            (@arg CENSUS: --census +takes_value {file_exists}
+                "A JSON file describing the other members of the service group and the members \
+                of the service groups it binds to")
+            (@arg RENDER_DIR: --("render-dir") +takes_value
+                "Write the rendered files under this directory rather than printing them")
+        )
         (@subcommand unload =>
             (about: "Unload a persistent or transient service started by the Habitat \
                 supervisor. If the Supervisor is running when the service is unloaded the \
                 service will be stopped.")
             (aliases: &["un", "unl", "unlo", "unloa"])
-            (@arg PKG_IDENT: +required +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg PKG_IDENT_OR_COMPOSITE: +required +takes_value
+                "A Habitat package identifier (ex: core/redis) or the name of a loaded composite")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
@@ -196,6 +236,13 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address for the gossip system [default: 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
                 "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+            (@arg HTTP_TLS_CERT: --("http-tls-cert") +takes_value requires[HTTP_TLS_KEY]
+                "Path to a PEM encoded certificate; serves the HTTP gateway over TLS")
+            (@arg HTTP_TLS_KEY: --("http-tls-key") +takes_value requires[HTTP_TLS_CERT]
+                "Path to the PEM encoded private key of the HTTP gateway's certificate")
+            (@arg HTTP_AUTH_RING: --("http-auth-ring")
+                "Require HTTP gateway clients to authenticate with a token derived from the \
+                ring key")
             (@arg NAME: --("override-name") +takes_value
                 "The name of the Supervisor if launching more than one [default: default]")
             (@arg ORGANIZATION: --org +takes_value
@@ -204,11 +251,15 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PEER: --peer +takes_value +multiple
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
+            (@arg LABEL: --label +takes_value +multiple {valid_label}
+                "One or more labels this Supervisor advertises to the ring, as <KEY>=<VALUE> \
+                (ex: datacenter=east)")
             (@arg RING: --ring -r +takes_value "Ring key name")
             (@arg CHANNEL: --channel +takes_value
                 "Receive Supervisor updates from the specified release channel [default: stable]")
             (@arg DEPOT_URL: --url -u +takes_value {valid_url}
-                "Receive Supervisor updates from the Depot at the specified URL \
+                "Receive Supervisor updates from the Depot at the specified URL, or from a \
+                directory of artifacts given as a file:// URL or absolute path \
                 [default: https://bldr.habitat.sh/v1/depot]")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
                 itself")
@@ -228,6 +279,13 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address for the gossip system [default: 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
                 "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+            (@arg HTTP_TLS_CERT: --("http-tls-cert") +takes_value requires[HTTP_TLS_KEY]
+                "Path to a PEM encoded certificate; serves the HTTP gateway over TLS")
+            (@arg HTTP_TLS_KEY: --("http-tls-key") +takes_value requires[HTTP_TLS_CERT]
+                "Path to the PEM encoded private key of the HTTP gateway's certificate")
+            (@arg HTTP_AUTH_RING: --("http-auth-ring")
+                "Require HTTP gateway clients to authenticate with a token derived from the \
+                ring key")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if launching more than one Supervisor \
                 [default: default]")
@@ -237,6 +295,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PEER: --peer +takes_value +multiple
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
+            (@arg LABEL: --label +takes_value +multiple {valid_label}
+                "One or more labels this Supervisor advertises to the ring, as <KEY>=<VALUE> \
+                (ex: datacenter=east)")
             (@arg RING: --ring -r +takes_value "Ring key name")
             (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
                 "A Habitat package identifier (ex: core/redis) or filepath to a Habitat Artifact \
@@ -250,14 +311,22 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg GROUP: --group +takes_value
                 "The service group; shared config and topology [default: default]")
             (@arg DEPOT_URL: --url -u +takes_value {valid_url}
-                "Receive package updates from the Depot at the specified URL \
+                "Receive package updates from the Depot at the specified URL, or from a \
+                directory of artifacts given as a file:// URL or absolute path \
                 [default: https://bldr.habitat.sh/v1/depot]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] \
+                [values: standalone, leader, primary-replica, quorum]")
+            (@arg QUORUM: --quorum +takes_value {valid_quorum}
+                "The number of alive members needed to elect a leader \
+                [default: a majority of at least 3 members]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg DEPENDS_ON: --("depends-on") +takes_value +multiple
+                "One or more services on this Supervisor to start after, as <NAME>[:<CONDITION>] \
+                where <CONDITION> is up or healthy [default condition: up]")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                 "Use package config from this path, rather than the package itself")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
@@ -268,7 +337,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@subcommand status =>
             (about: "Query the status of Habitat services.")
             (aliases: &["stat", "statu", "status"])
-            (@arg PKG_IDENT: +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg PKG_IDENT_OR_COMPOSITE: +takes_value
+                "A Habitat package identifier (ex: core/redis) or the name of a loaded composite")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
@@ -314,7 +384,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 artifact. Services started in this manner will persist through Supervisor \
                 restarts.")
             (aliases: &["lo", "loa"])
-            (@arg PKG_IDENT: +required +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg PKG_IDENT_OR_COMPOSITE: +required +takes_value
+                "A Habitat package identifier (ex: core/redis) or the path to a composite spec \
+                file, whose services are loaded together")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
@@ -327,25 +399,59 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg GROUP: --group +takes_value
                 "The service group; shared config and topology [default: default].")
             (@arg DEPOT_URL: --url -u +takes_value {valid_url}
-                "Receive package updates from the Depot at the specified URL \
+                "Receive package updates from the Depot at the specified URL, or from a \
+                directory of artifacts given as a file:// URL or absolute path \
                 [default: https://bldr.habitat.sh/v1/depot]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] \
+                [values: standalone, leader, primary-replica, quorum]")
+            (@arg QUORUM: --quorum +takes_value {valid_quorum}
+                "The number of alive members needed to elect a leader \
+                [default: a majority of at least 3 members]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg DEPENDS_ON: --("depends-on") +takes_value +multiple
+                "One or more services on this Supervisor to start after, as <NAME>[:<CONDITION>] \
+                where <CONDITION> is up or healthy [default condition: up]")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
                 previously loaded and running this operation will also restart the service")
                 (@arg PASSWORD: --password +takes_value
                     "Password of the service user")
         )
+        (@subcommand logs =>
+            (about: "Print the captured output of a Habitat service and its hooks.")
+            (@arg PKG_IDENT: +required +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg FOLLOW: -f --follow "Keep printing output as it is written")
+            (@arg TAIL: -n --tail +takes_value {valid_tail} "Print only this many of the most \
+                recent lines")
+            (@arg SINCE: --since +takes_value "Print only output written since a duration ago \
+                (ex: 10m, 2h, 1d) or a UTC time (ex: 2017-10-18T10:00:00Z)")
+        )
+        (@subcommand render =>
+            (about: "Render the configuration templates and hooks of an installed package \
+                without running it, reporting keys they reference which aren't set")
+            (@arg PKG_IDENT: +required +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg GROUP: --group +takes_value
+                "The service group; shorthand for specifying a group name [default: default]")
+            (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
+                "Render templates and hooks from this directory rather than the package's")
+            (@arg USER_TOML: --("user-toml") +takes_value {file_exists}
+                "Render with this user.toml rather than the service's")
+            (@arg CENSUS: --census +takes_value {file_exists}
+                "A JSON file describing the other members of the service group and the members \
+                of the service groups it binds to")
+            (@arg RENDER_DIR: --("render-dir") +takes_value
+                "Write the rendered files under this directory rather than printing them")
+        )
         (@subcommand unload =>
             (about: "Unload a persistent or transient service started by the Habitat \
                 supervisor. If the Supervisor is running when the service is unloaded the \
                 service will be stopped.")
             (aliases: &["un", "unl", "unlo", "unloa"])
-            (@arg PKG_IDENT: +required +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg PKG_IDENT_OR_COMPOSITE: +required +takes_value
+                "A Habitat package identifier (ex: core/redis) or the name of a loaded composite")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
@@ -357,6 +463,13 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address for the gossip system [default: 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
                 "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+            (@arg HTTP_TLS_CERT: --("http-tls-cert") +takes_value requires[HTTP_TLS_KEY]
+                "Path to a PEM encoded certificate; serves the HTTP gateway over TLS")
+            (@arg HTTP_TLS_KEY: --("http-tls-key") +takes_value requires[HTTP_TLS_CERT]
+                "Path to the PEM encoded private key of the HTTP gateway's certificate")
+            (@arg HTTP_AUTH_RING: --("http-auth-ring")
+                "Require HTTP gateway clients to authenticate with a token derived from the \
+                ring key")
             (@arg NAME: --("override-name") +takes_value
                 "The name of the Supervisor if launching more than one [default: default]")
             (@arg ORGANIZATION: --org +takes_value
@@ -365,11 +478,15 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PEER: --peer +takes_value +multiple
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
+            (@arg LABEL: --label +takes_value +multiple {valid_label}
+                "One or more labels this Supervisor advertises to the ring, as <KEY>=<VALUE> \
+                (ex: datacenter=east)")
             (@arg RING: --ring -r +takes_value "Ring key name")
             (@arg CHANNEL: --channel +takes_value
                 "Receive Supervisor updates from the specified release channel [default: stable]")
             (@arg DEPOT_URL: --url -u +takes_value {valid_url}
-                "Receive Supervisor updates from the Depot at the specified URL \
+                "Receive Supervisor updates from the Depot at the specified URL, or from a \
+                directory of artifacts given as a file:// URL or absolute path \
                 [default: https://bldr.habitat.sh/v1/depot]")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
                 itself")
@@ -389,6 +506,13 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address for the gossip system [default: 0.0.0.0:9638]")
             (@arg LISTEN_HTTP: --("listen-http") +takes_value
                 "The listen address for the HTTP gateway [default: 0.0.0.0:9631]")
+            (@arg HTTP_TLS_CERT: --("http-tls-cert") +takes_value requires[HTTP_TLS_KEY]
+                "Path to a PEM encoded certificate; serves the HTTP gateway over TLS")
+            (@arg HTTP_TLS_KEY: --("http-tls-key") +takes_value requires[HTTP_TLS_CERT]
+                "Path to the PEM encoded private key of the HTTP gateway's certificate")
+            (@arg HTTP_AUTH_RING: --("http-auth-ring")
+                "Require HTTP gateway clients to authenticate with a token derived from the \
+                ring key")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if launching more than one Supervisor \
                 [default: default]")
@@ -398,6 +522,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg PEER: --peer +takes_value +multiple
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
+            (@arg LABEL: --label +takes_value +multiple {valid_label}
+                "One or more labels this Supervisor advertises to the ring, as <KEY>=<VALUE> \
+                (ex: datacenter=east)")
             (@arg RING: --ring -r +takes_value "Ring key name")
             (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
                 "A Habitat package identifier (ex: core/redis) or filepath to a Habitat Artifact \
@@ -411,14 +538,22 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg GROUP: --group +takes_value
                 "The service group; shared config and topology [default: default]")
             (@arg DEPOT_URL: --url -u +takes_value {valid_url}
-                "Receive package updates from the Depot at the specified URL \
+                "Receive package updates from the Depot at the specified URL, or from a \
+                directory of artifacts given as a file:// URL or absolute path \
                 [default: https://bldr.habitat.sh/v1/depot]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] \
+                [values: standalone, leader, primary-replica, quorum]")
+            (@arg QUORUM: --quorum +takes_value {valid_quorum}
+                "The number of alive members needed to elect a leader \
+                [default: a majority of at least 3 members]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg DEPENDS_ON: --("depends-on") +takes_value +multiple
+                "One or more services on this Supervisor to start after, as <NAME>[:<CONDITION>] \
+                where <CONDITION> is up or healthy [default condition: up]")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                 "Use package config from this path, rather than the package itself")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
@@ -430,7 +565,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@subcommand status =>
             (about: "Query the status of Habitat services.")
             (aliases: &["stat", "statu", "status"])
-            (@arg PKG_IDENT: +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg PKG_IDENT_OR_COMPOSITE: +takes_value
+                "A Habitat package identifier (ex: core/redis) or the name of a loaded composite")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
@@ -472,7 +608,11 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
         hcore::output::set_no_color(true);
     }
     let cfg = mgrcfg_from_matches(m)?;
-    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
+    let ident_or_composite = m.value_of("PKG_IDENT_OR_COMPOSITE").unwrap();
+    if Path::new(ident_or_composite).is_file() {
+        return load_composite(&cfg, Path::new(ident_or_composite), m.is_present("FORCE"));
+    }
+    let ident = PackageIdent::from_str(ident_or_composite)?;
     let default_spec = ServiceSpec::default_for(ident);
     let spec_file = Manager::spec_path_for(&cfg, &default_spec);
     if let Ok(spec) = ServiceSpec::from_file(&spec_file) {
@@ -489,6 +629,62 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+/// Installs each service of a composite spec file and saves the composite, so that the
+/// Supervisor starts its services together.
+fn load_composite(cfg: &ManagerConfig, path: &Path, force: bool) -> Result<()> {
+    let composite = CompositeSpec::from_file(path)?;
+    Manager::check_composite_for(cfg, &composite, force)?;
+    for spec in composite.service_specs() {
+        util::pkg::install_from_spec(&mut UI::default(), &spec)?;
+    }
+    Manager::save_composite_for(cfg, &composite)?;
+    outputln!("The {} composite was successfully loaded", composite.name);
+    Ok(())
+}
+
+fn sub_logs(m: &ArgMatches) -> Result<()> {
+    if m.is_present("VERBOSE") {
+        hcore::output::set_verbose(true);
+    }
+    if m.is_present("NO_COLOR") {
+        hcore::output::set_no_color(true);
+    }
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
+    let since = match m.value_of("SINCE") {
+        Some(since) => {
+            Some(command::logs::parse_since(
+                since,
+                time::now_utc().to_timespec().sec,
+            )?)
+        }
+        None => None,
+    };
+    let opts = command::logs::LogsOptions {
+        tail: m.value_of("TAIL").map(|n| n.parse().unwrap()),
+        since: since,
+        follow: m.is_present("FOLLOW"),
+    };
+    command::logs::start(&ident.name, &opts)
+}
+
+fn sub_render(m: &ArgMatches) -> Result<()> {
+    if m.is_present("VERBOSE") {
+        hcore::output::set_verbose(true);
+    }
+    if m.is_present("NO_COLOR") {
+        hcore::output::set_no_color(true);
+    }
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
+    let opts = command::render::RenderOptions {
+        group: m.value_of("GROUP").unwrap_or("default").to_string(),
+        config_from: m.value_of("CONFIG_DIR").map(PathBuf::from),
+        user_toml: m.value_of("USER_TOML").map(PathBuf::from),
+        census: m.value_of("CENSUS").map(PathBuf::from),
+        render_dir: m.value_of("RENDER_DIR").map(PathBuf::from),
+    };
+    command::render::start(&ident, &opts)
+}
+
 fn sub_unload(m: &ArgMatches) -> Result<()> {
     if m.is_present("VERBOSE") {
         hcore::output::set_verbose(true);
@@ -497,7 +693,15 @@ fn sub_unload(m: &ArgMatches) -> Result<()> {
         hcore::output::set_no_color(true);
     }
     let cfg = mgrcfg_from_matches(m)?;
-    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
+    let ident_or_composite = m.value_of("PKG_IDENT_OR_COMPOSITE").unwrap();
+    // Removing a composite's file stops and unloads all of its services at once.
+    let composite_file = Manager::composite_path_for(&cfg, ident_or_composite);
+    if composite_file.is_file() {
+        return std::fs::remove_file(&composite_file).map_err(|err| {
+            sup_error!(Error::ServiceSpecFileIO(composite_file, err))
+        });
+    }
+    let ident = PackageIdent::from_str(ident_or_composite)?;
     let spec = spec_from_matches(ident, m)?;
     let spec_file = Manager::spec_path_for(&cfg, &spec);
     std::fs::remove_file(&spec_file).map_err(|err| {
@@ -555,6 +759,13 @@ fn sub_start(m: &ArgMatches, launcher: LauncherCli) -> Result<()> {
                         }
                     }
                 }
+                // A service of a composite is started through the composite, which it has no
+                // spec of its own apart from.
+                Err(_) if Manager::composite_for_service(&cfg, &default_spec.ident.name)
+                    .is_some() => {
+                    Manager::set_desired_state_for(&cfg, default_spec.ident, DesiredState::Up)?;
+                    None
+                }
                 Err(_) => {
                     let spec = spec_from_matches(default_spec.ident, m)?;
                     util::pkg::install_from_spec(&mut UI::default(), &spec)?;
@@ -596,7 +807,17 @@ fn sub_status(m: &ArgMatches) -> Result<()> {
         println!("The supervisor is not running.");
         process::exit(3);
     }
-    match m.value_of("PKG_IDENT") {
+    match m.value_of("PKG_IDENT_OR_COMPOSITE") {
+        Some(name) if Manager::composite_path_for(&cfg, name).is_file() => {
+            let statuses = Manager::composite_status(cfg, name)?;
+            if statuses.is_empty() {
+                println!("No services of {} are running yet.", name);
+                return Ok(());
+            }
+            for status in statuses {
+                println!("{}", status);
+            }
+        }
         Some(pkg) => {
             match Manager::service_status(cfg, PackageIdent::from_str(pkg)?) {
                 Ok(status) => outputln!("{}", status),
@@ -629,10 +850,7 @@ fn sub_stop(m: &ArgMatches) -> Result<()> {
     }
     let cfg = mgrcfg_from_matches(m)?;
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
-    let spec_file = Manager::spec_path_for(&cfg, &ServiceSpec::default_for(ident));
-    let mut spec = ServiceSpec::from_file(&spec_file)?;
-    spec.desired_state = DesiredState::Down;
-    Manager::save_spec_for(&cfg, spec)
+    Manager::set_desired_state_for(&cfg, ident, DesiredState::Down)
 }
 
 fn sub_term(m: &ArgMatches) -> Result<()> {
@@ -663,6 +881,9 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     if let Some(addr_str) = m.value_of("LISTEN_HTTP") {
         cfg.http_listen = http_gateway::ListenAddr::from_str(addr_str)?;
     }
+    if let (Some(cert), Some(key)) = (m.value_of("HTTP_TLS_CERT"), m.value_of("HTTP_TLS_KEY")) {
+        cfg.http_tls = Some(http_gateway::TlsCfg::new(cert, key));
+    }
     if let Some(name_str) = m.value_of("NAME") {
         cfg.name = Some(String::from(name_str));
         outputln!("");
@@ -687,6 +908,12 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     }
     cfg.organization = m.value_of("ORGANIZATION").map(|org| org.to_string());
     cfg.gossip_permanent = m.is_present("PERMANENT_PEER");
+    if let Some(labels) = m.values_of("LABEL") {
+        for label in labels {
+            let (key, value) = label.split_at(label.find('=').unwrap());
+            cfg.labels.insert(key.to_string(), value[1..].to_string());
+        }
+    }
     // TODO fn: Clean this up--using a for loop doesn't feel good however an iterator was
     // causing a lot of developer/compiler type confusion
     let mut gossip_peers: Vec<SocketAddr> = Vec::new();
@@ -735,6 +962,19 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
             }
         }
     };
+    cfg.http_auth_token = match henv::var(GATEWAY_AUTH_TOKEN_ENVVAR) {
+        Ok(token) => Some(token),
+        Err(_) => {
+            if m.is_present("HTTP_AUTH_RING") {
+                match ring {
+                    Some(ref ring) => Some(http_gateway::ring_key_token(ring)?),
+                    None => return Err(sup_error!(Error::HttpGatewayAuthNoRing)),
+                }
+            } else {
+                None
+            }
+        }
+    };
     if let Some(ring) = ring {
         cfg.ring = Some(ring.name_with_rev());
     }
@@ -765,6 +1005,11 @@ fn spec_from_matches(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec>
     if let Some(topology) = m.value_of("TOPOLOGY") {
         spec.topology = Topology::from_str(topology)?;
     }
+    if let Some(quorum) = m.value_of("QUORUM") {
+        spec.quorum = Some(quorum.parse().map_err(|_| {
+            sup_error!(Error::InvalidQuorum(quorum.to_string()))
+        })?);
+    }
     if let Some(ref strategy) = m.value_of("STRATEGY") {
         spec.update_strategy = UpdateStrategy::from_str(strategy)?;
     }
@@ -775,6 +1020,13 @@ fn spec_from_matches(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec>
         }
         spec.binds = binds;
     }
+    if let Some(dependency_strs) = m.values_of("DEPENDS_ON") {
+        let mut dependencies = Vec::new();
+        for dependency_str in dependency_strs {
+            dependencies.push(LocalDependency::from_str(dependency_str)?);
+        }
+        spec.depends_on = dependencies;
+    }
     if let Some(ref config_from) = m.value_of("CONFIG_DIR") {
         spec.config_from = Some(PathBuf::from(config_from));
         outputln!("");
@@ -814,6 +1066,14 @@ fn dir_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn file_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_file() {
+        Ok(())
+    } else {
+        Err(format!("File: '{}' cannot be found", &val))
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     match ServiceGroup::validate(&val) {
         Ok(()) => Ok(()),
@@ -828,6 +1088,37 @@ fn valid_topology(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_label(val: String) -> result::Result<(), String> {
+    let key = val.split('=').next().unwrap();
+    let valid_key = key.chars().all(|c| match c {
+        'a'...'z' | 'A'...'Z' | '0'...'9' | '_' => true,
+        _ => false,
+    });
+    if val.contains('=') && !key.is_empty() && valid_key {
+        Ok(())
+    } else {
+        Err(format!(
+            "Label: '{}' is not valid, expected <KEY>=<VALUE> with a key of letters, digits \
+             and underscores",
+            &val
+        ))
+    }
+}
+
+fn valid_quorum(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(quorum) if quorum > 0 => Ok(()),
+        _ => Err(format!("Quorum: '{}' is not a positive number", &val)),
+    }
+}
+
+fn valid_tail(val: String) -> result::Result<(), String> {
+    match val.parse::<usize>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Tail: '{}' is not a number of lines", &val)),
+    }
+}
+
 fn valid_update_strategy(val: String) -> result::Result<(), String> {
     match UpdateStrategy::from_str(&val) {
         Ok(_) => Ok(()),
@@ -836,6 +1127,9 @@ fn valid_update_strategy(val: String) -> result::Result<(), String> {
 }
 
 fn valid_url(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_absolute() {
+        return Ok(());
+    }
     match Url::parse(&val) {
         Ok(_) => Ok(()),
         Err(_) => Err(format!("URL: '{}' is not valid", &val)),
@@ -0,0 +1,199 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A bounded history of the configuration gossiped to each service group, kept on disk so that
+//! an earlier version can be gossiped again.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::str;
+
+use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
+use hcore::service::ServiceGroup;
+use serde_json;
+use time;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "CH";
+
+/// How many versions of a service group's configuration are kept.
+pub const CONFIG_HISTORY_LIMIT: usize = 10;
+
+/// A version of a service group's configuration, as it was gossiped.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ConfigVersion {
+    pub incarnation: u64,
+    /// When this Supervisor first heard of the version, in seconds since the epoch.
+    pub applied_at: i64,
+    /// Whether `config` is encrypted for the service group's organization.
+    pub encrypted: bool,
+    pub config: String,
+}
+
+impl ConfigVersion {
+    /// Returns the version a rumor carries, or `None` if its configuration isn't text.
+    pub fn from_rumor(rumor: &ServiceConfigRumor) -> Option<Self> {
+        match str::from_utf8(rumor.get_config()) {
+            Ok(config) => Some(ConfigVersion {
+                incarnation: rumor.get_incarnation(),
+                applied_at: time::get_time().sec,
+                encrypted: rumor.get_encrypted(),
+                config: config.to_string(),
+            }),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns a rumor gossiping this version's configuration again as the given incarnation.
+    pub fn to_rumor<T>(
+        &self,
+        member_id: T,
+        service_group: ServiceGroup,
+        incarnation: u64,
+    ) -> ServiceConfigRumor
+    where
+        T: Into<String>,
+    {
+        let mut rumor =
+            ServiceConfigRumor::new(member_id, service_group, self.config.clone().into_bytes());
+        rumor.set_incarnation(incarnation);
+        rumor.set_encrypted(self.encrypted);
+        rumor
+    }
+}
+
+/// The versions of a service group's configuration, oldest first.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ConfigHistory(Vec<ConfigVersion>);
+
+impl ConfigHistory {
+    /// Reads a history from disk. A missing file is an empty history.
+    pub fn load<T: AsRef<Path>>(path: T) -> Result<Self> {
+        let path = path.as_ref();
+        match File::open(path) {
+            Ok(file) => {
+                serde_json::from_reader(file).map_err(|err| {
+                    sup_error!(Error::BadDataFile(path.to_path_buf(), io::Error::from(err)))
+                })
+            }
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(sup_error!(Error::BadDataFile(path.to_path_buf(), err))),
+        }
+    }
+
+    /// Writes the history to disk, replacing the previous file in one step.
+    pub fn save<T: AsRef<Path>>(&self, path: T) -> Result<()> {
+        let path = path.as_ref();
+        let tmp_file = path.with_extension("tmp");
+        let result = File::create(&tmp_file).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            writer.write_all(serde_json::to_string(self).unwrap().as_bytes())?;
+            writer.flush()?;
+            fs::rename(&tmp_file, path)
+        });
+        result.map_err(|err| sup_error!(Error::BadDataFile(path.to_path_buf(), err)))
+    }
+
+    /// Adds a version which isn't in the history yet, forgetting the oldest versions beyond
+    /// `CONFIG_HISTORY_LIMIT`. Returns true if the history changed.
+    pub fn record(&mut self, version: ConfigVersion) -> bool {
+        if self.get(version.incarnation).is_some() {
+            return false;
+        }
+        self.0.push(version);
+        self.0.sort_by_key(|v| v.incarnation);
+        if self.0.len() > CONFIG_HISTORY_LIMIT {
+            let excess = self.0.len() - CONFIG_HISTORY_LIMIT;
+            self.0.drain(..excess);
+        }
+        true
+    }
+
+    pub fn get(&self, incarnation: u64) -> Option<&ConfigVersion> {
+        self.0.iter().find(|v| v.incarnation == incarnation)
+    }
+
+    pub fn latest(&self) -> Option<&ConfigVersion> {
+        self.0.last()
+    }
+
+    pub fn versions(&self) -> &[ConfigVersion] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn version(incarnation: u64) -> ConfigVersion {
+        ConfigVersion {
+            incarnation: incarnation,
+            applied_at: 1508320800,
+            encrypted: false,
+            config: format!("port = {}", incarnation),
+        }
+    }
+
+    #[test]
+    fn record_keeps_a_bounded_ordered_history() {
+        let mut history = ConfigHistory::default();
+        for incarnation in (1..CONFIG_HISTORY_LIMIT as u64 + 3).rev() {
+            assert!(history.record(version(incarnation)));
+        }
+        assert!(!history.record(version(CONFIG_HISTORY_LIMIT as u64)));
+        let incarnations: Vec<u64> = history.versions().iter().map(|v| v.incarnation).collect();
+        assert_eq!(
+            incarnations,
+            (3..CONFIG_HISTORY_LIMIT as u64 + 3).collect::<Vec<u64>>()
+        );
+        assert_eq!(
+            history.latest().unwrap().incarnation,
+            CONFIG_HISTORY_LIMIT as u64 + 2
+        );
+        assert!(history.get(1).is_none());
+    }
+
+    #[test]
+    fn save_and_load() {
+        let tmpdir = TempDir::new("confighistory").unwrap();
+        let path = tmpdir.path().join("redis.default.config_history");
+        assert_eq!(ConfigHistory::load(&path).unwrap(), ConfigHistory::default());
+
+        let mut history = ConfigHistory::default();
+        history.record(version(1));
+        history.record(version(2));
+        history.save(&path).unwrap();
+        assert_eq!(ConfigHistory::load(&path).unwrap(), history);
+    }
+
+    #[test]
+    fn rumor_round_trip() {
+        let sg = ServiceGroup::new(None, "redis", "default", None).unwrap();
+        let mut rumor = ServiceConfigRumor::new("member", sg.clone(), b"port = 6379".to_vec());
+        rumor.set_incarnation(4);
+        let version = ConfigVersion::from_rumor(&rumor).unwrap();
+        assert_eq!(version.incarnation, 4);
+        assert_eq!(version.config, "port = 6379");
+
+        let again = version.to_rumor("member", sg, 7);
+        assert_eq!(again.get_incarnation(), 7);
+        assert_eq!(again.get_config(), rumor.get_config());
+        assert!(!again.get_encrypted());
+    }
+}
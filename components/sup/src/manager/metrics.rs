@@ -0,0 +1,224 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Supervisor metrics, registered with the default Prometheus registry and rendered by the HTTP
+//! gateway's `/metrics` endpoint.
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use butterfly;
+use hcore::service::ServiceGroup;
+use prometheus::{Counter, CounterVec, GaugeVec, HistogramVec};
+use time;
+
+use census::CensusRing;
+use manager::service::Service;
+
+const CENSUS_HEALTH: [&'static str; 4] = ["alive", "suspect", "confirmed", "departed"];
+
+lazy_static! {
+    static ref SERVICE_UP: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_service_up",
+            "Whether the service's process is running (1) or not (0)."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_UPTIME: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_service_uptime_seconds",
+            "Seconds since the service's process was started, 0 if it is down."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_RESTARTS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_service_restarts_total",
+            "Number of times the service's process was restarted by this Supervisor."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_HEALTH: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_service_health_check",
+            "Last health check result: 0 ok, 1 warning, 2 critical, 3 unknown."),
+        &["service_group"]).unwrap();
+
+    static ref HOOK_DURATION: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "hab_sup_hook_duration_seconds",
+            "Hook execution time in seconds."),
+        &["service_group", "hook"]).unwrap();
+
+    static ref HOOK_EXIT_CODE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_hook_exit_code",
+            "Exit code of the hook's last run, -1 if it did not exit normally."),
+        &["service_group", "hook"]).unwrap();
+
+    static ref HOOK_TIMEOUTS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_sup_hook_timeouts_total",
+            "Number of times the hook was killed for running longer than its timeout."),
+        &["service_group", "hook"]).unwrap();
+
+    static ref CENSUS_MEMBERS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_sup_census_members",
+            "Number of members in the service group's census by health."),
+        &["service_group", "health"]).unwrap();
+
+    static ref SWIM_ROUNDS: Counter = register_counter!(
+        opts!(
+            "hab_butterfly_swim_rounds_total",
+            "Number of SWIM protocol rounds completed by the gossip server.")).unwrap();
+
+    static ref GOSSIP_ROUNDS: Counter = register_counter!(
+        opts!(
+            "hab_butterfly_gossip_rounds_total",
+            "Number of gossip rounds completed by the gossip server.")).unwrap();
+
+    /// Service groups with census series, so those of groups without members can be dropped.
+    static ref CENSUS_GROUPS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+/// Records the current process state, uptime, restart count and health of a service.
+pub fn update_service(service: &Service) {
+    let sg = service.service_group.to_string();
+    let labels = [sg.as_str()];
+    let uptime = if service.process_up() {
+        (time::get_time() - service.last_state_change()).num_seconds()
+    } else {
+        0
+    };
+    SERVICE_UP.with_label_values(&labels).set(
+        if service.process_up() { 1.0 } else { 0.0 },
+    );
+    SERVICE_UPTIME.with_label_values(&labels).set(uptime as f64);
+    advance(
+        &SERVICE_RESTARTS.with_label_values(&labels),
+        service.restarts() as f64,
+    );
+    SERVICE_HEALTH.with_label_values(&labels).set(
+        service.health_check() as i8 as f64,
+    );
+}
+
+/// Drops the metrics of a service which is no longer supervised.
+pub fn remove_service(service_group: &ServiceGroup) {
+    let sg = service_group.to_string();
+    let labels = [sg.as_str()];
+    let _ = SERVICE_UP.remove_label_values(&labels);
+    let _ = SERVICE_UPTIME.remove_label_values(&labels);
+    let _ = SERVICE_RESTARTS.remove_label_values(&labels);
+    let _ = SERVICE_HEALTH.remove_label_values(&labels);
+}
+
+/// Records how long a hook ran, from `started` until now, and the code it exited with.
+pub fn observe_hook(
+    service_group: &ServiceGroup,
+    hook: &str,
+    started: Instant,
+    code: Option<i32>,
+) {
+    let sg = service_group.to_string();
+    let labels = [sg.as_str(), hook];
+    HOOK_DURATION.with_label_values(&labels).observe(
+        seconds(started.elapsed()),
+    );
+    HOOK_EXIT_CODE.with_label_values(&labels).set(
+        code.unwrap_or(-1) as f64,
+    );
+}
+
+/// Records a hook which was killed for running longer than its timeout, from `started` until now.
+pub fn observe_hook_timeout(service_group: &ServiceGroup, hook: &str, started: Instant) {
+    observe_hook(service_group, hook, started, None);
+    let sg = service_group.to_string();
+    HOOK_TIMEOUTS.with_label_values(&[sg.as_str(), hook]).inc();
+}
+
+/// Records the number of members of each census group by health. The series of groups left
+/// without members are dropped.
+pub fn update_census(census_ring: &CensusRing) {
+    let mut exported = CENSUS_GROUPS.lock().expect("Census metrics lock poisoned");
+    let mut current = HashSet::new();
+    for census_group in census_ring.groups() {
+        if census_group.members().is_empty() {
+            continue;
+        }
+        let sg = census_group.service_group.to_string();
+        current.insert(sg.clone());
+        for health in CENSUS_HEALTH.iter() {
+            let count = census_group
+                .members()
+                .iter()
+                .filter(|cm| cm.member_health() == Some(*health))
+                .count();
+            CENSUS_MEMBERS
+                .with_label_values(&[sg.as_str(), *health])
+                .set(count as f64);
+        }
+    }
+    for sg in exported.difference(&current) {
+        for health in CENSUS_HEALTH.iter() {
+            let _ = CENSUS_MEMBERS.remove_label_values(&[sg.as_str(), *health]);
+        }
+    }
+    *exported = current;
+}
+
+/// Records the gossip server's SWIM and gossip round counters.
+pub fn update_butterfly(server: &butterfly::Server) {
+    advance(&SWIM_ROUNDS, server.swim_rounds() as f64);
+    advance(&GOSSIP_ROUNDS, server.gossip_rounds() as f64);
+}
+
+/// Brings a counter up to a total kept elsewhere. Counters only go up, so a lower total is
+/// ignored.
+fn advance(counter: &Counter, total: f64) {
+    let delta = total - counter.get();
+    if delta > 0.0 {
+        let _ = counter.inc_by(delta);
+    }
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1_000_000_000.0
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use prometheus::Counter;
+
+    use super::{advance, seconds};
+
+    #[test]
+    fn seconds_from_duration() {
+        assert_eq!(seconds(Duration::from_millis(1500)), 1.5);
+        assert_eq!(seconds(Duration::from_secs(3)), 3.0);
+    }
+
+    #[test]
+    fn advance_only_moves_counters_up() {
+        let counter = Counter::new("test_total", "A test counter.").unwrap();
+        advance(&counter, 3.0);
+        assert_eq!(counter.get(), 3.0);
+        advance(&counter, 2.0);
+        assert_eq!(counter.get(), 3.0);
+        advance(&counter, 5.0);
+        assert_eq!(counter.get(), 5.0);
+    }
+}
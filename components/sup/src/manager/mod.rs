@@ -13,13 +13,16 @@
 // limitations under the License.
 
 pub mod service;
+mod config_history;
 mod events;
+mod metrics;
+mod ring_key_schedule;
 mod self_updater;
 mod service_updater;
 mod spec_watcher;
 mod sys;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
@@ -47,16 +50,20 @@ use serde;
 use serde_json;
 use time::{self, Timespec, Duration as TimeDuration};
 
-pub use self::service::{Service, ServiceSpec, UpdateStrategy, Topology};
+pub use self::config_history::{ConfigHistory, ConfigVersion};
+pub use self::service::{CompositeSpec, Service, ServiceSpec, UpdateStrategy, Topology};
 pub use self::sys::Sys;
+use self::ring_key_schedule::RingKeySchedule;
 use self::self_updater::{SUP_PKG_IDENT, SelfUpdater};
-use self::service::{DesiredState, Pkg, ProcessState, StartStyle};
+use self::service::{DependencyState, DesiredState, Pkg, ProcessState, ResourceLimits,
+                    RestartHistory, Rollback, StartStyle};
 use self::service_updater::ServiceUpdater;
 use self::spec_watcher::{SpecWatcher, SpecWatcherEvent};
 use VERSION;
 use error::{Error, Result, SupError};
 use config::GossipListenAddr;
 use census::CensusRing;
+use event_stream::{CensusSnapshot, Event, EventStream};
 use http_gateway;
 
 const MEMBER_ID_FILE: &'static str = "MEMBER_ID";
@@ -110,6 +117,12 @@ impl FsCfg {
             format!("{}.health", service_group.service()),
         )
     }
+
+    pub fn config_history(&self, service_group: &ServiceGroup) -> PathBuf {
+        self.data_path.join(
+            format!("{}.config_history", service_group),
+        )
+    }
 }
 
 #[derive(Clone, Default)]
@@ -120,8 +133,12 @@ pub struct ManagerConfig {
     pub update_channel: String,
     pub gossip_listen: GossipListenAddr,
     pub http_listen: http_gateway::ListenAddr,
+    pub http_auth_token: Option<String>,
+    pub http_tls: Option<http_gateway::TlsCfg>,
     pub gossip_peers: Vec<SocketAddr>,
     pub gossip_permanent: bool,
+    /// Labels advertised to the ring as part of this Supervisor's membership.
+    pub labels: BTreeMap<String, String>,
     pub ring: Option<String>,
     pub name: Option<String>,
     pub organization: Option<String>,
@@ -132,15 +149,21 @@ pub struct ManagerConfig {
 pub struct Manager {
     butterfly: butterfly::Server,
     census_ring: CensusRing,
+    census_snapshot: CensusSnapshot,
+    cfg: Arc<ManagerConfig>,
+    config_history_counter: usize,
+    event_stream: EventStream,
     events_group: Option<ServiceGroup>,
     fs_cfg: Arc<FsCfg>,
     launcher: LauncherCli,
+    ring_key_counter: usize,
     services: Arc<RwLock<Vec<Service>>>,
     updater: ServiceUpdater,
     watcher: SpecWatcher,
     organization: Option<String>,
     self_updater: Option<SelfUpdater>,
     service_states: HashMap<PackageIdent, Timespec>,
+    process_states: HashMap<ServiceGroup, bool>,
     sys: Arc<Sys>,
 }
 
@@ -190,6 +213,16 @@ impl Manager {
         Err(sup_error!(Error::ServiceNotLoaded(ident)))
     }
 
+    /// Returns the status of each running service of the named composite.
+    pub fn composite_status(cfg: ManagerConfig, name: &str) -> Result<Vec<ServiceStatus>> {
+        Ok(
+            Self::status(cfg)?
+                .into_iter()
+                .filter(|s| s.composite.as_ref().map_or(false, |c| c == name))
+                .collect(),
+        )
+    }
+
     pub fn status(cfg: ManagerConfig) -> Result<Vec<ServiceStatus>> {
         let state_path = Self::state_path_from(&cfg);
         let fs_cfg = FsCfg::new(state_path);
@@ -213,6 +246,7 @@ impl Manager {
     }
 
     fn new(cfg: ManagerConfig, fs_cfg: FsCfg, launcher: LauncherCli) -> Result<Manager> {
+        let manager_cfg = Arc::new(cfg.clone());
         let current = PackageIdent::from_str(&format!("{}/{}", SUP_PKG_IDENT, VERSION)).unwrap();
         let self_updater = if cfg.auto_update {
             if current.fully_qualified() {
@@ -229,16 +263,34 @@ impl Manager {
             None
         };
         let mut sys = Sys::new(cfg.gossip_permanent, cfg.gossip_listen, cfg.http_listen);
-        let member = Self::load_member(&mut sys, &fs_cfg)?;
-        let ring_key = match cfg.ring {
+        let mut member = Self::load_member(&mut sys, &fs_cfg)?;
+        member.set_label_map(&cfg.labels);
+        let cache_key_path = default_cache_key_path(None);
+        let (ring_key, ring_key_revisions) = match cfg.ring {
             Some(ref ring_with_revision) => {
                 outputln!("Joining ring {}", ring_with_revision);
-                Some(SymKey::get_pair_for(
-                    &ring_with_revision,
-                    &default_cache_key_path(None),
-                )?)
+                let ring_key = SymKey::get_pair_for(&ring_with_revision, &cache_key_path)?;
+                // Older revisions still decrypt traffic from members which haven't yet moved to
+                // this one, unless a revision since has retired them. Each revision, this one
+                // included, keeps the schedule it was gossiped with.
+                let mut revisions = Vec::new();
+                for key in SymKey::get_pairs_for(&ring_key.name, &cache_key_path)? {
+                    if key.rev <= ring_key.rev {
+                        let path = RingKeySchedule::path(&key.name_with_rev(), &cache_key_path);
+                        revisions.push((key, RingKeySchedule::load(path)?));
+                    }
+                }
+                let now = time::get_time().sec as u64;
+                let retired_before = ring_key_schedule::last_retiring_revision(
+                    revisions.iter().map(|&(ref key, ref schedule)| (&key.rev[..], schedule)),
+                    now,
+                ).map(|rev| rev.to_string());
+                if let Some(ref rev) = retired_before {
+                    revisions.retain(|&(ref key, _)| key.rev >= *rev);
+                }
+                (Some(ring_key), revisions)
             }
-            None => None,
+            None => (None, Vec::new()),
         };
         let services = Arc::new(RwLock::new(Vec::new()));
         let server = butterfly::Server::new(
@@ -251,6 +303,9 @@ impl Manager {
             Some(&fs_cfg.data_path),
             Box::new(SuitabilityLookup(services.clone())),
         )?;
+        for (ring_key, schedule) in ring_key_revisions {
+            server.add_ring_key(ring_key, schedule.activate_at, schedule.retire_at);
+        }
         outputln!("Supervisor Member-ID {}", sys.member_id);
         for peer_addr in &cfg.gossip_peers {
             let mut peer = Member::default();
@@ -263,14 +318,20 @@ impl Manager {
             self_updater: self_updater,
             updater: ServiceUpdater::new(server.clone()),
             census_ring: CensusRing::new(sys.member_id.clone()),
+            census_snapshot: CensusSnapshot::default(),
             butterfly: server,
+            cfg: manager_cfg,
+            config_history_counter: 0,
+            event_stream: EventStream::default(),
             events_group: cfg.eventsrv_group,
             launcher: launcher,
+            ring_key_counter: 0,
             services: services,
             watcher: SpecWatcher::run(&fs_cfg.specs_path)?,
             fs_cfg: Arc::new(fs_cfg),
             organization: cfg.organization,
             service_states: HashMap::new(),
+            process_states: HashMap::new(),
             sys: Arc::new(sys),
         })
     }
@@ -315,10 +376,65 @@ impl Manager {
         Self::specs_path(&Self::state_path_from(cfg)).join(spec.file_name())
     }
 
+    pub fn composite_path_for(cfg: &ManagerConfig, name: &str) -> PathBuf {
+        Self::specs_path(&Self::state_path_from(cfg)).join(CompositeSpec::file_name_for(name))
+    }
+
     pub fn save_spec_for(cfg: &ManagerConfig, spec: ServiceSpec) -> Result<()> {
         spec.to_file(Self::spec_path_for(cfg, &spec))
     }
 
+    /// Checks that a composite may be loaded: it isn't loaded already, unless `force` is given,
+    /// and none of its services is loaded on its own.
+    pub fn check_composite_for(
+        cfg: &ManagerConfig,
+        composite: &CompositeSpec,
+        force: bool,
+    ) -> Result<()> {
+        if Self::composite_path_for(cfg, &composite.name).is_file() && !force {
+            return Err(sup_error!(Error::CompositeLoaded(composite.name.clone())));
+        }
+        for spec in composite.service_specs() {
+            if Self::spec_path_for(cfg, &spec).is_file() {
+                return Err(sup_error!(Error::ServiceLoaded(spec.ident)));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn save_composite_for(cfg: &ManagerConfig, composite: &CompositeSpec) -> Result<()> {
+        composite.to_file(Self::composite_path_for(cfg, &composite.name))
+    }
+
+    /// Returns the path and spec of the loaded composite which runs the named service, if any.
+    pub fn composite_for_service(
+        cfg: &ManagerConfig,
+        name: &str,
+    ) -> Option<(PathBuf, CompositeSpec)> {
+        CompositeSpec::find_for_service(Self::specs_path(&Self::state_path_from(cfg)), name)
+    }
+
+    /// Starts or stops a loaded service, whether it has a spec of its own or was loaded as part
+    /// of a composite.
+    pub fn set_desired_state_for(
+        cfg: &ManagerConfig,
+        ident: PackageIdent,
+        desired_state: DesiredState,
+    ) -> Result<()> {
+        let spec_file = Self::spec_path_for(cfg, &ServiceSpec::default_for(ident.clone()));
+        if let Ok(mut spec) = ServiceSpec::from_file(&spec_file) {
+            spec.desired_state = desired_state;
+            return Self::save_spec_for(cfg, spec);
+        }
+        match Self::composite_for_service(cfg, &ident.name) {
+            Some((path, mut composite)) => {
+                composite.set_service_desired_state(&ident.name, desired_state);
+                composite.to_file(path)
+            }
+            None => Err(sup_error!(Error::ServiceNotLoaded(ident))),
+        }
+    }
+
     fn clean_dirty_state<T>(state_path: T) -> Result<()>
     where
         T: AsRef<Path>,
@@ -429,10 +545,11 @@ impl Manager {
         }
 
         self.gossip_latest_service_rumor(&service);
-        if service.topology == Topology::Leader {
-            self.butterfly.start_election(
+        if service.topology.elects_leader() {
+            self.butterfly.start_election_with_quorum(
                 service.service_group.clone(),
                 0,
+                service.quorum.unwrap_or(0),
             );
         }
         self.updater.add(&service);
@@ -454,8 +571,13 @@ impl Manager {
         self.persist_state();
         let http_listen_addr = self.sys.http_listen();
         outputln!("Starting http-gateway on {}", &http_listen_addr);
-        http_gateway::Server::new(self.fs_cfg.clone(), http_listen_addr)
-            .start()?;
+        http_gateway::Server::new(
+            self.fs_cfg.clone(),
+            self.cfg.clone(),
+            self.butterfly.clone(),
+            self.event_stream.clone(),
+            http_listen_addr,
+        ).start()?;
         debug!("http-gateway started");
         let events = match self.events_group {
             Some(ref evg) => Some(events::EventsMgr::start(evg.clone())),
@@ -464,11 +586,11 @@ impl Manager {
         loop {
             let next_check = time::get_time() + TimeDuration::milliseconds(1000);
             if self.launcher.is_stopping() {
-                self.shutdown();
+                self.shutdown(true);
                 return Ok(());
             }
             if self.check_for_departure() {
-                self.shutdown();
+                self.shutdown(true);
                 return Err(sup_error!(Error::Departed));
             }
             if let Some(package) = self.check_for_updated_supervisor() {
@@ -476,7 +598,7 @@ impl Manager {
                     "Supervisor shutting down for automatic update to {}",
                     package
                 );
-                self.shutdown();
+                self.shutdown(false);
                 return Ok(());
             }
             self.update_running_services_from_watcher()?;
@@ -490,6 +612,8 @@ impl Manager {
                 &self.butterfly.service_config_store,
                 &self.butterfly.service_file_store,
             );
+            self.record_config_history();
+            self.save_ring_keys();
 
             if self.check_for_changed_services() {
                 self.persist_state();
@@ -497,6 +621,7 @@ impl Manager {
 
             if self.census_ring.changed {
                 self.persist_state();
+                self.publish_census_events();
                 events.as_ref().map(|events| {
                     events.try_connect(&self.census_ring)
                 });
@@ -518,15 +643,20 @@ impl Manager {
                 }
             }
 
+            let dependencies = self.dependency_states();
             for service in self.services
                 .write()
                 .expect("Services lock is poisoned!")
                 .iter_mut()
             {
-                if service.tick(&self.census_ring, &self.launcher) {
+                if service.tick(&self.census_ring, &self.launcher, &dependencies) {
                     self.gossip_latest_service_rumor(&service);
                 }
+                metrics::update_service(service);
             }
+            metrics::update_census(&self.census_ring);
+            metrics::update_butterfly(&self.butterfly);
+            self.publish_process_events();
             let time_to_wait = (next_check - time::get_time()).num_milliseconds();
             if time_to_wait > 0 {
                 thread::sleep(Duration::from_millis(time_to_wait as u64));
@@ -534,6 +664,18 @@ impl Manager {
         }
     }
 
+    /// Returns the state of each service, by name, for the services which depend on it.
+    fn dependency_states(&self) -> HashMap<String, DependencyState> {
+        self.services
+            .read()
+            .expect("Services lock is poisoned!")
+            .iter()
+            .map(|service| {
+                (service.spec_ident.name.clone(), service.dependency_state())
+            })
+            .collect()
+    }
+
     fn check_for_updated_supervisor(&mut self) -> Option<PackageInstall> {
         if let Some(ref mut updater) = self.self_updater {
             return updater.updated();
@@ -580,6 +722,93 @@ impl Manager {
         self.butterfly.insert_service(service.to_rumor(incarnation));
     }
 
+    /// Records each version of configuration gossiped to a service group in the group's config
+    /// history.
+    fn record_config_history(&mut self) {
+        let store = &self.butterfly.service_config_store;
+        if store.get_update_counter() <= self.config_history_counter {
+            return;
+        }
+        self.config_history_counter = store.get_update_counter();
+        let fs_cfg = &self.fs_cfg;
+        store.with_keys(|(service_group, rumors)| {
+            let sg = match ServiceGroup::from_str(service_group) {
+                Ok(sg) => sg,
+                Err(_) => return,
+            };
+            let version = match rumors.get("service_config").and_then(
+                ConfigVersion::from_rumor,
+            ) {
+                Some(version) => version,
+                None => return,
+            };
+            let path = fs_cfg.config_history(&sg);
+            match ConfigHistory::load(&path) {
+                Ok(mut history) => {
+                    if history.record(version) {
+                        if let Err(err) = history.save(&path) {
+                            warn!("Couldn't save config history, {}", err);
+                        }
+                    }
+                }
+                Err(err) => warn!("Couldn't read config history, {}", err),
+            }
+        });
+    }
+
+    /// Writes each revision of the ring key gossiped to the ring into the key cache, so it is
+    /// still known after a restart and to `hab` commands run on this host.
+    fn save_ring_keys(&mut self) {
+        let store = &self.butterfly.ring_key_store;
+        if store.get_update_counter() <= self.ring_key_counter {
+            return;
+        }
+        self.ring_key_counter = store.get_update_counter();
+        let cache_key_path = default_cache_key_path(None);
+        store.with_rumors("ring_key", |rumor| {
+            let name_with_rev = rumor.get_name_with_rev();
+            if SymKey::get_secret_key_path(name_with_rev, &cache_key_path).is_err() {
+                match SymKey::write_file_from_str(rumor.get_secret(), &cache_key_path) {
+                    Ok(_) => outputln!("Saved ring key {}", name_with_rev),
+                    Err(err) => warn!("Couldn't save ring key {}, {}", name_with_rev, err),
+                }
+            }
+            let path = RingKeySchedule::path(name_with_rev, &cache_key_path);
+            let schedule = RingKeySchedule::from_rumor(rumor);
+            if RingKeySchedule::load(&path).ok() == Some(schedule) {
+                return;
+            }
+            if let Err(err) = schedule.save(&path) {
+                warn!("Couldn't save schedule of ring key {}, {}", name_with_rev, err);
+            }
+        });
+    }
+
+    fn publish_census_events(&mut self) {
+        let snapshot = CensusSnapshot::new(&self.census_ring);
+        for event in self.census_snapshot.events_until(&snapshot) {
+            self.event_stream.publish(event);
+        }
+        self.census_snapshot = snapshot;
+    }
+
+    /// Publishes an event for each service whose process went up or down since the last call.
+    fn publish_process_events(&mut self) {
+        let services = self.services.read().expect("Services lock is poisoned!");
+        let mut process_states = HashMap::new();
+        for service in services.iter() {
+            let up = service.process_up();
+            if self.process_states.get(&service.service_group) != Some(&up) {
+                self.event_stream.publish(Event::ProcessStateChanged {
+                    service_group: service.service_group.to_string(),
+                    state: String::from(if up { "up" } else { "down" }),
+                });
+            }
+            process_states.insert(service.service_group.clone(), up);
+        }
+        self.process_states = process_states;
+    }
+
     fn check_for_departure(&self) -> bool {
         self.butterfly.is_departed()
     }
@@ -746,10 +975,15 @@ impl Manager {
     /// useful if you want the Supervisor to shutdown temporarily and then come back and re-attach
     /// to all running processes.
     fn remove_service(&self, service: &mut Service, term: bool) {
-        // JW TODO: Update service rumor to remove service from cluster
         if term {
             service.stop(&self.launcher);
+            // Let the ring know we no longer run the service. On shutdown we depart instead, and
+            // our service rumors expire with us.
+            self.butterfly.tombstone_service(
+                &service.service_group.to_string(),
+            );
         }
+        metrics::remove_service(&service.service_group);
         if service.start_style == StartStyle::Transient {
             // JW TODO: If we cleanup our Service structure to hold the ServiceSpec instead of
             // deconstruct it (see my comments in `add_service()` in this module) then we could
@@ -793,15 +1027,34 @@ impl Manager {
         self.butterfly.restart_elections();
     }
 
-    fn shutdown(&self) {
+    /// Depart from the ring and let go of every service.
+    ///
+    /// Passing `true` for the term argument also stops the services, like `stop_dependents`,
+    /// each one before the services it depends on; the Launcher answers once a service has
+    /// exited, so its dependencies outlive it. Passing `false` leaves them running for the next
+    /// Supervisor to re-attach to.
+    fn shutdown(&self, term: bool) {
         outputln!("Gracefully departing from butterfly network.");
         self.butterfly.set_departed();
 
         let mut services = self.services.write().expect("Services lock is poisend!");
-
-        for mut service in services.drain(..) {
+        let dependencies: Vec<(String, Vec<String>)> = services
+            .iter()
+            .map(|service| {
+                let names = service.depends_on.iter().map(|d| d.name.clone()).collect();
+                (service.spec_ident.name.clone(), names)
+            })
+            .collect();
+        let services: Vec<Service> = services.drain(..).collect();
+        // We depart instead of tombstoning our services, so they're stopped here rather than by
+        // `remove_service`.
+        in_stop_order(services, &dependencies, |mut service| {
+            if term {
+                outputln!(preamble service.service_group, "Stopping...");
+                service.stop(&self.launcher);
+            }
             self.remove_service(&mut service, false);
-        }
+        });
         release_process_lock(&self.fs_cfg);
     }
 
@@ -860,10 +1113,38 @@ impl Manager {
             }
         };
         let mut service = services.remove(services_idx);
+        self.stop_dependents(&mut services, &service.spec_ident.name);
         self.remove_service(&mut service, true);
+        self.updater.remove(&service.service_group);
         Ok(())
     }
 
+    /// Stops the running services which depend on the named service, and in turn the services
+    /// which depend on those, so that each stops before the services it depends on. They start
+    /// again once their dependencies are ready.
+    fn stop_dependents(&self, services: &mut Vec<Service>, name: &str) {
+        let dependents: Vec<String> = services
+            .iter()
+            .filter(|service| {
+                service.process_up() && service.depends_on.iter().any(|d| d.name == name)
+            })
+            .map(|service| service.spec_ident.name.clone())
+            .collect();
+        for dependent in dependents {
+            self.stop_dependents(services, &dependent);
+            if let Some(service) = services.iter_mut().find(
+                |service| service.spec_ident.name == dependent,
+            )
+            {
+                if service.process_up() {
+                    outputln!(preamble service.service_group,
+                              "Stopping service before its local dependency {}", name);
+                    service.stop(&self.launcher);
+                }
+            }
+        }
+    }
+
     /// Remove the on disk representation of the given service spec
     fn remove_spec(&self, spec: &ServiceSpec) {
         if let Err(err) = fs::remove_file(self.fs_cfg.specs_path.join(spec.file_name())) {
@@ -882,6 +1163,8 @@ pub struct ProcessStatus {
     pub elapsed: TimeDuration,
     pub pid: Option<u32>,
     pub state: ProcessState,
+    #[serde(default)]
+    pub limits: ResourceLimits,
 }
 
 impl fmt::Display for ProcessStatus {
@@ -894,11 +1177,14 @@ impl fmt::Display for ProcessStatus {
                     self.state,
                     self.elapsed,
                     pid
-                )
+                )?
             }
-            None => write!(f, "state:{}, time:{}", self.state, self.elapsed),
+            None => write!(f, "state:{}, time:{}", self.state, self.elapsed)?,
         }
-
+        if !self.limits.is_empty() {
+            write!(f, ", limits:{}", self.limits)?;
+        }
+        Ok(())
     }
 }
 
@@ -908,6 +1194,14 @@ pub struct ServiceStatus {
     pub process: ProcessStatus,
     pub service_group: ServiceGroup,
     pub start_style: StartStyle,
+    #[serde(default)]
+    pub health_restarts: RestartHistory,
+    #[serde(default)]
+    pub last_rollback: Option<Rollback>,
+    #[serde(default)]
+    pub config_errors: Vec<String>,
+    #[serde(default)]
+    pub composite: Option<String>,
 }
 
 impl fmt::Display for ServiceStatus {
@@ -919,7 +1213,20 @@ impl fmt::Display for ServiceStatus {
             self.process,
             self.service_group,
             self.start_style
-        )
+        )?;
+        if let Some(ref composite) = self.composite {
+            write!(f, ", composite:{}", composite)?;
+        }
+        if self.health_restarts.count > 0 {
+            write!(f, ", health restarts:{}", self.health_restarts.count)?;
+        }
+        if let Some(ref rollback) = self.last_rollback {
+            write!(f, ", {}", rollback)?;
+        }
+        for error in &self.config_errors {
+            write!(f, ", config error: {}", error)?;
+        }
+        Ok(())
     }
 }
 
@@ -1045,11 +1352,47 @@ where
     }
 }
 
+/// Returns the order to stop services in, given the name of each and the names of the services
+/// it depends on, so that no service stops while a service depending on it is still running.
+/// Services in a dependency cycle stop in the order they were loaded.
+fn stop_order(services: &[(String, Vec<String>)]) -> Vec<usize> {
+    let mut stopped = vec![false; services.len()];
+    let mut order = Vec::with_capacity(services.len());
+    while order.len() < services.len() {
+        let running: Vec<usize> = (0..services.len()).filter(|&i| !stopped[i]).collect();
+        let next = running
+            .iter()
+            .cloned()
+            .find(|&i| {
+                !running.iter().any(|&j| {
+                    j != i && services[j].1.contains(&services[i].0)
+                })
+            })
+            .unwrap_or(running[0]);
+        stopped[next] = true;
+        order.push(next);
+    }
+    order
+}
+
+/// Hands each service to `stop`, in the order given by `stop_order`.
+fn in_stop_order<T, F>(services: Vec<T>, dependencies: &[(String, Vec<String>)], mut stop: F)
+where
+    F: FnMut(T),
+{
+    let mut remaining: Vec<Option<T>> = services.into_iter().map(Some).collect();
+    for i in stop_order(dependencies) {
+        if let Some(service) = remaining[i].take() {
+            stop(service);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::path::PathBuf;
 
-    use super::{Manager, ManagerConfig, STATE_PATH_PREFIX};
+    use super::{in_stop_order, stop_order, Manager, ManagerConfig, STATE_PATH_PREFIX};
 
     #[test]
     fn manager_state_path_default() {
@@ -1092,4 +1435,38 @@ mod test {
 
         assert_eq!(PathBuf::from("/tmp/partay"), path);
     }
+
+    #[test]
+    fn stop_order_stops_dependents_first() {
+        let services = vec![
+            ("postgres".to_string(), vec![]),
+            ("app".to_string(), vec!["postgres".to_string(), "redis".to_string()]),
+            ("redis".to_string(), vec![]),
+            ("proxy".to_string(), vec!["app".to_string()]),
+        ];
+        assert_eq!(stop_order(&services), vec![3, 1, 0, 2]);
+
+        let cycle = vec![
+            ("a".to_string(), vec!["b".to_string()]),
+            ("b".to_string(), vec!["a".to_string()]),
+        ];
+        assert_eq!(stop_order(&cycle), vec![0, 1]);
+    }
+
+    #[test]
+    fn in_stop_order_terminates_each_service_after_its_dependents() {
+        let dependencies = vec![
+            ("postgres".to_string(), vec![]),
+            ("app".to_string(), vec!["postgres".to_string(), "redis".to_string()]),
+            ("redis".to_string(), vec![]),
+            ("proxy".to_string(), vec!["app".to_string()]),
+        ];
+        let services: Vec<String> = dependencies
+            .iter()
+            .map(|&(ref name, _)| name.clone())
+            .collect();
+        let mut terminated = vec![];
+        in_stop_order(services, &dependencies, |service| terminated.push(service));
+        assert_eq!(terminated, vec!["proxy", "app", "postgres", "redis"]);
+    }
 }
@@ -0,0 +1,141 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The activation and retirement times gossiped with a ring key revision, kept next to the key
+//! in the key cache so that they still apply after the Supervisor restarts.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use butterfly::rumor::ring_key::RingKey as RingKeyRumor;
+use serde_json;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "RS";
+
+/// When a revision of the ring key becomes active, and when the revisions older than it are
+/// retired, in seconds since the Unix epoch.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RingKeySchedule {
+    pub activate_at: u64,
+    pub retire_at: Option<u64>,
+}
+
+impl RingKeySchedule {
+    pub fn from_rumor(rumor: &RingKeyRumor) -> Self {
+        RingKeySchedule {
+            activate_at: rumor.get_activate_at(),
+            retire_at: match rumor.get_retire_at() {
+                0 => None,
+                retire_at => Some(retire_at),
+            },
+        }
+    }
+
+    /// Returns where the schedule of a revision, such as `beyonce-20160504220722`, is kept.
+    pub fn path<T: AsRef<Path>>(name_with_rev: &str, cache_key_path: T) -> PathBuf {
+        cache_key_path
+            .as_ref()
+            .join(format!("{}.sym.schedule", name_with_rev))
+    }
+
+    /// Reads a schedule from disk. A missing file is a revision which is active immediately and
+    /// retires nothing.
+    pub fn load<T: AsRef<Path>>(path: T) -> Result<Self> {
+        let path = path.as_ref();
+        match File::open(path) {
+            Ok(file) => {
+                serde_json::from_reader(file).map_err(|err| {
+                    sup_error!(Error::BadDataFile(path.to_path_buf(), io::Error::from(err)))
+                })
+            }
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(sup_error!(Error::BadDataFile(path.to_path_buf(), err))),
+        }
+    }
+
+    /// Writes the schedule to disk, replacing the previous file in one step.
+    pub fn save<T: AsRef<Path>>(&self, path: T) -> Result<()> {
+        let path = path.as_ref();
+        let tmp_file = path.with_extension("tmp");
+        let result = File::create(&tmp_file).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            writer.write_all(serde_json::to_string(self).unwrap().as_bytes())?;
+            writer.flush()?;
+            fs::rename(&tmp_file, path)
+        });
+        result.map_err(|err| sup_error!(Error::BadDataFile(path.to_path_buf(), err)))
+    }
+}
+
+/// Returns the newest revision which had retired the revisions older than it by `now`, given
+/// each revision and its schedule. Revisions older than the one returned are no longer in use.
+pub fn last_retiring_revision<'a, I>(schedules: I, now: u64) -> Option<&'a str>
+where
+    I: IntoIterator<Item = (&'a str, &'a RingKeySchedule)>,
+{
+    schedules
+        .into_iter()
+        .filter(|&(_, schedule)| {
+            schedule.retire_at.map_or(false, |retire_at| retire_at <= now)
+        })
+        .map(|(rev, _)| rev)
+        .max()
+}
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn schedule(activate_at: u64, retire_at: Option<u64>) -> RingKeySchedule {
+        RingKeySchedule {
+            activate_at: activate_at,
+            retire_at: retire_at,
+        }
+    }
+
+    #[test]
+    fn save_and_load() {
+        let tmpdir = TempDir::new("ringkeyschedule").unwrap();
+        let path = RingKeySchedule::path("beyonce-20160504220722", tmpdir.path());
+        assert_eq!(
+            RingKeySchedule::load(&path).unwrap(),
+            RingKeySchedule::default()
+        );
+
+        let saved = schedule(1508320800, Some(1508324400));
+        saved.save(&path).unwrap();
+        assert_eq!(RingKeySchedule::load(&path).unwrap(), saved);
+    }
+
+    #[test]
+    fn last_retiring_revision_ignores_retirements_still_to_come() {
+        let schedules = vec![
+            ("20160504220722", schedule(0, None)),
+            ("20170504220722", schedule(100, Some(200))),
+            ("20171004220722", schedule(300, Some(400))),
+            ("20171018220722", schedule(500, None)),
+        ];
+        let revisions = |now| {
+            last_retiring_revision(schedules.iter().map(|&(rev, ref s)| (rev, s)), now)
+        };
+        assert_eq!(revisions(199), None);
+        assert_eq!(revisions(200), Some("20170504220722"));
+        assert_eq!(revisions(1000), Some("20171004220722"));
+    }
+}
@@ -0,0 +1,387 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A set of services which are loaded, updated and unloaded together as one unit. A composite
+//! spec is saved next to the service specs and expands into a `ServiceSpec` for each of its
+//! services when the Supervisor reads it.
+
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::result;
+use std::str::FromStr;
+
+use hcore::channel::STABLE_CHANNEL;
+use hcore::package::PackageIdent;
+use hcore::url::DEFAULT_DEPOT_URL;
+use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
+use toml;
+
+use super::{DesiredState, LocalDependency, MaintenanceWindow, ServiceBind, ServiceSpec,
+            StartStyle, Topology, UpdateStrategy};
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "CS";
+static DEFAULT_GROUP: &'static str = "default";
+const COMPOSITE_FILE_EXT: &'static str = "composite";
+
+/// Settings shared by every service of a composite. Binds given here are added to each service's
+/// own binds, and the update strategy, channel and maintenance window make the services pick up
+/// new releases at the same time. The Supervisor also holds each downloaded release until the
+/// composite's other services have had a chance to find theirs, so they restart together.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct CompositeSpec {
+    pub name: String,
+    pub group: String,
+    pub depot_url: String,
+    pub channel: String,
+    pub update_strategy: UpdateStrategy,
+    pub binds: Vec<ServiceBind>,
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub desired_state: DesiredState,
+    /// Services stopped on their own, which stay down while the rest of the composite runs.
+    pub stopped: Vec<String>,
+    // Tables must be represented last in a serializable struct for the current version of the
+    // toml crate.
+    pub maintenance_window: Option<MaintenanceWindow>,
+    pub services: Vec<CompositeService>,
+}
+
+/// One service of a composite.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct CompositeService {
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub ident: PackageIdent,
+    pub topology: Topology,
+    /// Binds of this service alone. A bind with the same name as a composite bind replaces it.
+    pub binds: Vec<ServiceBind>,
+    pub depends_on: Vec<LocalDependency>,
+}
+
+impl CompositeSpec {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut buf = String::new();
+        File::open(&path)
+            .and_then(|mut file| file.read_to_string(&mut buf))
+            .map_err(|err| {
+                sup_error!(Error::ServiceSpecFileIO(path.as_ref().to_path_buf(), err))
+            })?;
+        Self::from_str(&buf)
+    }
+
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let toml = toml::to_string(self).map_err(|err| {
+            sup_error!(Error::ServiceSpecRender(err))
+        })?;
+        let tmpfile = path.with_extension("tmp");
+        let result = path.parent()
+            .map_or(Ok(()), |dir| fs::create_dir_all(dir))
+            .and_then(|_| File::create(&tmpfile))
+            .and_then(|mut file| file.write_all(toml.as_bytes()))
+            .and_then(|_| fs::rename(&tmpfile, path));
+        result.map_err(|err| {
+            sup_error!(Error::ServiceSpecFileIO(path.to_path_buf(), err))
+        })
+    }
+
+    pub fn file_name(&self) -> String {
+        Self::file_name_for(&self.name)
+    }
+
+    pub fn file_name_for(name: &str) -> String {
+        format!("{}.{}", name, COMPOSITE_FILE_EXT)
+    }
+
+    /// Returns the path and spec of the composite in the given directory which runs the named
+    /// service, if there is one.
+    pub fn find_for_service<P: AsRef<Path>>(specs_path: P, name: &str) -> Option<(PathBuf, Self)> {
+        let entries = match fs::read_dir(specs_path) {
+            Ok(entries) => entries,
+            Err(_) => return None,
+        };
+        entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.extension().map_or(false, |ext| ext == COMPOSITE_FILE_EXT)
+            })
+            .filter_map(|path| Self::from_file(&path).ok().map(|spec| (path, spec)))
+            .find(|&(_, ref spec)| spec.services.iter().any(|s| s.ident.name == name))
+    }
+
+    /// Returns the spec of each of the composite's services, as the Supervisor runs them.
+    pub fn service_specs(&self) -> Vec<ServiceSpec> {
+        self.services
+            .iter()
+            .map(|service| {
+                let mut spec = ServiceSpec::default_for(service.ident.clone());
+                spec.group = self.group.clone();
+                spec.depot_url = self.depot_url.clone();
+                spec.channel = self.channel.clone();
+                spec.topology = service.topology;
+                spec.update_strategy = self.update_strategy;
+                spec.binds = self.binds
+                    .iter()
+                    .filter(|b| service.binds.iter().all(|own| own.name != b.name))
+                    .chain(service.binds.iter())
+                    .cloned()
+                    .collect();
+                spec.depends_on = service.depends_on.clone();
+                spec.desired_state = if self.stopped.contains(&service.ident.name) {
+                    DesiredState::Down
+                } else {
+                    self.desired_state.clone()
+                };
+                spec.start_style = StartStyle::Persistent;
+                spec.maintenance_window = self.maintenance_window.clone();
+                spec.composite = Some(self.name.clone());
+                spec
+            })
+            .collect()
+    }
+
+    /// Returns the names of the composite's services, which name their service specs.
+    pub fn service_names(&self) -> Vec<String> {
+        self.services.iter().map(|s| s.ident.name.clone()).collect()
+    }
+
+    /// Starts or stops one of the composite's services, leaving the others as they are.
+    pub fn set_service_desired_state(&mut self, name: &str, desired_state: DesiredState) {
+        match desired_state {
+            DesiredState::Down => {
+                if !self.stopped.iter().any(|s| s == name) {
+                    self.stopped.push(name.to_string());
+                }
+            }
+            DesiredState::Up if self.desired_state == DesiredState::Down => {
+                self.desired_state = DesiredState::Up;
+                self.stopped = self.service_names()
+                    .into_iter()
+                    .filter(|s| s != name)
+                    .collect();
+            }
+            DesiredState::Up => self.stopped.retain(|s| s != name),
+        }
+    }
+}
+
+impl Default for CompositeSpec {
+    fn default() -> Self {
+        CompositeSpec {
+            name: String::new(),
+            group: DEFAULT_GROUP.to_string(),
+            depot_url: DEFAULT_DEPOT_URL.to_string(),
+            channel: STABLE_CHANNEL.to_string(),
+            update_strategy: UpdateStrategy::default(),
+            binds: Vec::new(),
+            desired_state: DesiredState::default(),
+            stopped: Vec::new(),
+            maintenance_window: None,
+            services: Vec::new(),
+        }
+    }
+}
+
+impl Default for CompositeService {
+    fn default() -> Self {
+        CompositeService {
+            ident: PackageIdent::default(),
+            topology: Topology::default(),
+            binds: Vec::new(),
+            depends_on: Vec::new(),
+        }
+    }
+}
+
+impl FromStr for CompositeSpec {
+    type Err = SupError;
+
+    fn from_str(toml: &str) -> result::Result<Self, Self::Err> {
+        let spec: CompositeSpec = toml::from_str(toml).map_err(|e| {
+            sup_error!(Error::ServiceSpecParse(e))
+        })?;
+        if spec.name.is_empty() {
+            return Err(sup_error!(
+                Error::InvalidCompositeSpec("a name is required".to_string())
+            ));
+        }
+        // The name also names the composite's file, so it is held to the characters of a package
+        // name.
+        if !spec.name.chars().all(is_name_char) {
+            return Err(sup_error!(Error::InvalidCompositeSpec(format!(
+                "'{}' is not a valid name, use only letters, numbers, '_' and '-'",
+                spec.name
+            ))));
+        }
+        if spec.services.is_empty() {
+            return Err(sup_error!(Error::InvalidCompositeSpec(
+                format!("composite {} has no services", spec.name),
+            )));
+        }
+        let mut names = HashSet::new();
+        for service in spec.services.iter() {
+            if service.ident == PackageIdent::default() {
+                return Err(sup_error!(Error::MissingRequiredIdent));
+            }
+            if !names.insert(service.ident.name.clone()) {
+                return Err(sup_error!(Error::InvalidCompositeSpec(format!(
+                    "composite {} runs {} more than once",
+                    spec.name,
+                    service.ident.name
+                ))));
+            }
+        }
+        Ok(spec)
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    match c {
+        'a'...'z' | 'A'...'Z' | '0'...'9' | '_' | '-' => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use tempdir::TempDir;
+
+    use super::*;
+    use error::Error::*;
+
+    const COMPOSITE: &'static str = r#"
+        name = "shop"
+        group = "prod"
+        channel = "unstable"
+        update_strategy = "at-once"
+        binds = ["database:postgres.prod", "cache:redis.prod"]
+
+        [[services]]
+        ident = "acme/storefront"
+        binds = ["cache:memcached.prod"]
+
+        [[services]]
+        ident = "acme/log-shipper"
+        depends_on = ["storefront:healthy"]
+        "#;
+
+    #[test]
+    fn composite_spec_from_str() {
+        let spec = CompositeSpec::from_str(COMPOSITE).unwrap();
+
+        assert_eq!(spec.name, "shop");
+        assert_eq!(spec.update_strategy, UpdateStrategy::AtOnce);
+        assert_eq!(
+            spec.service_names(),
+            vec!["storefront".to_string(), "log-shipper".to_string()]
+        );
+    }
+
+    #[test]
+    fn composite_spec_from_str_invalid() {
+        let no_services = r#"name = "shop""#;
+        match CompositeSpec::from_str(no_services).unwrap_err().err {
+            InvalidCompositeSpec(_) => assert!(true),
+            e => panic!("Unexpected error returned: {:?}", e),
+        }
+
+        let duplicate = r#"
+            name = "shop"
+
+            [[services]]
+            ident = "acme/storefront"
+
+            [[services]]
+            ident = "other/storefront"
+            "#;
+        match CompositeSpec::from_str(duplicate).unwrap_err().err {
+            InvalidCompositeSpec(_) => assert!(true),
+            e => panic!("Unexpected error returned: {:?}", e),
+        }
+
+        for name in &["../shop", "shop/front", "shop.prod", ""] {
+            let toml = format!("name = {:?}\n[[services]]\nident = \"acme/storefront\"", name);
+            match CompositeSpec::from_str(&toml).unwrap_err().err {
+                InvalidCompositeSpec(_) => assert!(true),
+                e => panic!("Unexpected error returned: {:?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn composite_spec_set_service_desired_state() {
+        let mut spec = CompositeSpec::from_str(COMPOSITE).unwrap();
+        spec.set_service_desired_state("storefront", DesiredState::Down);
+        let states: Vec<DesiredState> =
+            spec.service_specs().into_iter().map(|s| s.desired_state).collect();
+        assert_eq!(states, vec![DesiredState::Down, DesiredState::Up]);
+
+        spec.set_service_desired_state("storefront", DesiredState::Up);
+        assert!(spec.stopped.is_empty());
+
+        // Starting one service of a stopped composite starts only that service.
+        spec.desired_state = DesiredState::Down;
+        spec.set_service_desired_state("log-shipper", DesiredState::Up);
+        let states: Vec<DesiredState> =
+            spec.service_specs().into_iter().map(|s| s.desired_state).collect();
+        assert_eq!(states, vec![DesiredState::Down, DesiredState::Up]);
+    }
+
+    #[test]
+    fn composite_spec_service_specs() {
+        let specs = CompositeSpec::from_str(COMPOSITE).unwrap().service_specs();
+
+        let storefront = &specs[0];
+        assert_eq!(
+            storefront.ident,
+            PackageIdent::from_str("acme/storefront").unwrap()
+        );
+        assert_eq!(storefront.group, "prod");
+        assert_eq!(storefront.channel, "unstable");
+        assert_eq!(storefront.start_style, StartStyle::Persistent);
+        assert_eq!(storefront.composite, Some("shop".to_string()));
+        assert_eq!(
+            storefront.binds,
+            vec![
+                ServiceBind::from_str("database:postgres.prod").unwrap(),
+                ServiceBind::from_str("cache:memcached.prod").unwrap(),
+            ]
+        );
+
+        let shipper = &specs[1];
+        assert_eq!(shipper.binds.len(), 2);
+        assert_eq!(
+            shipper.depends_on,
+            vec![LocalDependency::from_str("storefront:healthy").unwrap()]
+        );
+    }
+
+    #[test]
+    fn composite_spec_to_file_round_trip() {
+        let tmpdir = TempDir::new("compositespec").unwrap();
+        let spec = CompositeSpec::from_str(COMPOSITE).unwrap();
+        let path = tmpdir.path().join(spec.file_name());
+        spec.to_file(&path).unwrap();
+
+        assert_eq!(CompositeSpec::from_file(&path).unwrap(), spec);
+    }
+}
@@ -16,6 +16,7 @@
 
 use std;
 use std::ascii::AsciiExt;
+use std::collections::BTreeMap;
 use std::env;
 use std::fs::File;
 use std::io::prelude::*;
@@ -30,6 +31,7 @@ use serde_json;
 use toml;
 
 use super::Pkg;
+use super::schema::ConfigSchema;
 use census::CensusGroup;
 use error::{Error, Result};
 use templating::{TemplateRenderer, RenderContext};
@@ -53,9 +55,13 @@ pub struct Cfg {
     pub gossip: Option<toml::Value>,
     /// Environment level configuration loaded by the Supervisor's process environment
     pub environment: Option<toml::Value>,
+    /// Schema shipped beside `default.toml` which the other levels must match
+    pub schema: Option<ConfigSchema>,
 
     /// Last known incarnation number of the census group's service config
     gossip_incarnation: u64,
+    /// Why the levels which didn't match the schema were rejected, by level
+    rejected: BTreeMap<&'static str, String>,
 }
 
 impl Cfg {
@@ -64,14 +70,16 @@ impl Cfg {
             &package.path,
         );
         let mut cfg = Cfg::default();
+        cfg.schema = ConfigSchema::load(&pkg_root)?;
         cfg.load_default(&pkg_root)?;
-        cfg.load_user(&package)?;
+        cfg.load_user_file(package.svc_path.join("user.toml"))?;
         cfg.load_environment(&package)?;
         Ok(cfg)
     }
 
     /// Updates the service configuration with data from a census group if the census group has
-    /// newer data than the current configuration.
+    /// newer data than the current configuration. Data which doesn't match the package's config
+    /// schema is rejected, and the current configuration kept.
     ///
     /// Returns true if the configuration was updated.
     pub fn update(&mut self, census_group: &CensusGroup) -> bool {
@@ -81,13 +89,54 @@ impl Cfg {
                     return false;
                 }
                 self.gossip_incarnation = config.incarnation;
-                self.gossip = Some(config.value.clone());
+                let gossip = Some(config.value.clone());
+                if !self.accept("gossip", &gossip) {
+                    return false;
+                }
+                self.gossip = gossip;
                 true
             }
             None => false,
         }
     }
 
+    /// Returns why configuration was rejected for not matching the package's config schema,
+    /// and which required keys no level of the configuration sets.
+    pub fn errors(&self) -> Vec<String> {
+        let mut errors: Vec<String> = self.rejected
+            .iter()
+            .map(|(level, reason)| format!("{} config rejected, {}", level, reason))
+            .collect();
+        if let Some(ref schema) = self.schema {
+            if let Ok(toml::Value::Table(merged)) = toml::Value::try_from(&self) {
+                let missing = schema.missing_keys(&merged);
+                if !missing.is_empty() {
+                    errors.push(format!("missing required config: {}", missing.join(", ")));
+                }
+            }
+        }
+        errors
+    }
+
+    /// Checks a level of configuration against the package's config schema, recording why it
+    /// was rejected if it doesn't match.
+    ///
+    /// Returns true if the level may be applied.
+    fn accept(&mut self, level: &'static str, value: &Option<toml::Value>) -> bool {
+        let errors = match (self.schema.as_ref(), value.as_ref()) {
+            (Some(schema), Some(&toml::Value::Table(ref table))) => schema.check_layer(table),
+            (Some(_), Some(value)) => vec![format!("expected a table, got {}", value.type_str())],
+            _ => vec![],
+        };
+        if errors.is_empty() {
+            self.rejected.remove(level);
+            true
+        } else {
+            self.rejected.insert(level, errors.join("; "));
+            false
+        }
+    }
+
     /// Returns a subset of the overall configuration whitelisted by the given package's exports.
     pub fn to_exported(&self, pkg: &Pkg) -> Result<toml::value::Table> {
         let mut map = toml::value::Table::default();
@@ -134,6 +183,15 @@ impl Cfg {
                 let toml = toml::de::from_str(&config).map_err(|e| {
                     sup_error!(Error::TomlParser(e))
                 })?;
+                if let Some(ref schema) = self.schema {
+                    let errors = schema.check_layer(&toml);
+                    if !errors.is_empty() {
+                        return Err(sup_error!(Error::InvalidConfigSchema(format!(
+                            "'default.toml' does not match it, {}",
+                            errors.join("; ")
+                        ))));
+                    }
+                }
                 self.default = Some(toml::Value::Table(toml));
             }
             Err(e) => {
@@ -144,8 +202,10 @@ impl Cfg {
         Ok(())
     }
 
-    fn load_user(&mut self, package: &Pkg) -> Result<()> {
-        let path = package.svc_path.join("user.toml");
+    /// Loads the user level of configuration from a `user.toml`, replacing the current one. A
+    /// missing file leaves the level unset.
+    pub fn load_user_file<T: AsRef<Path>>(&mut self, path: T) -> Result<()> {
+        let path = path.as_ref();
         let mut file = match File::open(&path) {
             Ok(file) => file,
             Err(e) => {
@@ -160,7 +220,12 @@ impl Cfg {
                 let toml = toml::de::from_str(&config).map_err(|e| {
                     sup_error!(Error::TomlParser(e))
                 })?;
-                self.user = Some(toml::Value::Table(toml));
+                let user = Some(toml::Value::Table(toml));
+                self.user = if self.accept("user", &user) {
+                    user
+                } else {
+                    None
+                };
             }
             Err(e) => {
                 outputln!("Failed to load 'user.toml', {}, {}", path.display(), e);
@@ -178,14 +243,14 @@ impl Cfg {
             Ok(config) => {
                 match toml::de::from_str(&config) {
                     Ok(toml) => {
-                        self.environment = Some(toml::Value::Table(toml));
+                        self.set_environment(toml);
                         return Ok(());
                     }
                     Err(err) => debug!("Attempted to parse env config as toml and failed {}", err),
                 }
                 match serde_json::from_str(&config) {
                     Ok(json) => {
-                        self.environment = Some(toml::Value::Table(json));
+                        self.set_environment(json);
                         return Ok(());
                     }
                     Err(err) => debug!("Attempted to parse env config as json and failed {}", err),
@@ -204,6 +269,15 @@ impl Cfg {
             }
         }
     }
+
+    fn set_environment(&mut self, table: toml::value::Table) {
+        let environment = Some(toml::Value::Table(table));
+        self.environment = if self.accept("environment", &environment) {
+            environment
+        } else {
+            None
+        };
+    }
 }
 
 impl Serialize for Cfg {
@@ -408,6 +482,8 @@ fn is_toml_value_a_table(key: &str, table: &toml::value::Table) -> bool {
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
+
     use toml;
     use tempdir::TempDir;
 
@@ -598,6 +674,28 @@ mod test {
         }
     }
 
+    #[test]
+    fn config_not_matching_schema_is_rejected() {
+        let mut cfg = Cfg::default();
+        cfg.schema = Some(
+            ConfigSchema::from_str("[port]\ntype = \"integer\"\nrequired = true").unwrap(),
+        );
+        let bad = Some(toml::Value::Table(toml_from_str("port = \"80\"")));
+        assert!(!cfg.accept("gossip", &bad));
+        assert_eq!(
+            cfg.errors(),
+            vec![
+                "gossip config rejected, port: expected integer, got string",
+                "missing required config: port",
+            ]
+        );
+
+        let good = Some(toml::Value::Table(toml_from_str("port = 80")));
+        assert!(cfg.accept("gossip", &good));
+        cfg.gossip = good;
+        assert!(cfg.errors().is_empty());
+    }
+
     #[test]
     fn serialize_config() {
         let pkg_id = PackageIdent::new("testing", "testing", Some("1.0.0"), Some("20170712000000"));
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::fmt;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+use super::spec::RestartPolicy;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum HealthCheck {
     Ok,
     Warning,
@@ -75,3 +78,170 @@ impl fmt::Display for SmokeCheck {
         write!(f, "{}", msg)
     }
 }
+
+/// Outcome of evaluating a health check result against a service's `RestartPolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthRestart {
+    /// Nothing to do; the service is healthy, the policy is disabled, or the threshold has not
+    /// been reached yet.
+    None,
+    /// The service should be restarted now.
+    Restart,
+    /// The threshold has been reached but the service was restarted too recently. Holds the
+    /// number of seconds left before another restart is allowed.
+    Backoff(u64),
+    /// The maximum number of restarts within the policy window has been reached.
+    Exhausted,
+}
+
+/// Record of the restarts the Supervisor performed because of failing health checks.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RestartHistory {
+    /// Number of consecutive `Critical` health checks since the last restart.
+    pub consecutive_critical: u32,
+    /// Total number of health-check driven restarts since the service was loaded.
+    pub count: u64,
+    /// Time, in seconds since the epoch, of the most recent health-check driven restart.
+    pub last: Option<i64>,
+    /// Times, in seconds since the epoch, of the restarts within the current policy window.
+    pub recent: Vec<i64>,
+}
+
+impl RestartHistory {
+    /// Feed a health check result into the history and decide whether the service must be
+    /// restarted according to the given policy.
+    pub fn check(
+        &mut self,
+        policy: &RestartPolicy,
+        result: HealthCheck,
+        now: i64,
+    ) -> HealthRestart {
+        if !policy.is_enabled() || result != HealthCheck::Critical {
+            self.consecutive_critical = 0;
+            return HealthRestart::None;
+        }
+        self.consecutive_critical = self.consecutive_critical.saturating_add(1);
+        if self.consecutive_critical < policy.critical_threshold {
+            return HealthRestart::None;
+        }
+        let window_start = now - policy.window as i64;
+        self.recent.retain(|t| *t > window_start);
+        if policy.max_restarts > 0 && self.recent.len() >= policy.max_restarts as usize {
+            return HealthRestart::Exhausted;
+        }
+        if let Some(last) = self.recent.last() {
+            let exponent = cmp::min(self.recent.len() - 1, 16) as u32;
+            let delay = policy.backoff.saturating_mul(2u64.pow(exponent));
+            let elapsed = cmp::max(now - last, 0) as u64;
+            if elapsed < delay {
+                return HealthRestart::Backoff(delay - elapsed);
+            }
+        }
+        HealthRestart::Restart
+    }
+
+    /// Mark that the service was restarted at the given time.
+    pub fn record(&mut self, now: i64) {
+        self.consecutive_critical = 0;
+        self.count += 1;
+        self.last = Some(now);
+        self.recent.push(now);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn policy() -> RestartPolicy {
+        RestartPolicy {
+            critical_threshold: 2,
+            backoff: 10,
+            max_restarts: 3,
+            window: 100,
+        }
+    }
+
+    #[test]
+    fn restart_disabled_by_default() {
+        let mut history = RestartHistory::default();
+        let policy = RestartPolicy::default();
+
+        for now in 0..10 {
+            assert_eq!(
+                history.check(&policy, HealthCheck::Critical, now),
+                HealthRestart::None
+            );
+        }
+    }
+
+    #[test]
+    fn restart_after_consecutive_critical() {
+        let mut history = RestartHistory::default();
+        let policy = policy();
+
+        assert_eq!(history.check(&policy, HealthCheck::Critical, 0), HealthRestart::None);
+        assert_eq!(history.check(&policy, HealthCheck::Ok, 1), HealthRestart::None);
+        assert_eq!(history.check(&policy, HealthCheck::Critical, 2), HealthRestart::None);
+        assert_eq!(
+            history.check(&policy, HealthCheck::Critical, 3),
+            HealthRestart::Restart
+        );
+        history.record(3);
+        assert_eq!(history.count, 1);
+        assert_eq!(history.last, Some(3));
+        assert_eq!(history.consecutive_critical, 0);
+    }
+
+    #[test]
+    fn restart_backoff_doubles() {
+        let mut history = RestartHistory::default();
+        let policy = RestartPolicy {
+            critical_threshold: 1,
+            max_restarts: 0,
+            ..policy()
+        };
+
+        assert_eq!(history.check(&policy, HealthCheck::Critical, 0), HealthRestart::Restart);
+        history.record(0);
+        assert_eq!(
+            history.check(&policy, HealthCheck::Critical, 4),
+            HealthRestart::Backoff(6)
+        );
+        assert_eq!(history.check(&policy, HealthCheck::Critical, 10), HealthRestart::Restart);
+        history.record(10);
+        assert_eq!(
+            history.check(&policy, HealthCheck::Critical, 25),
+            HealthRestart::Backoff(5)
+        );
+        assert_eq!(history.check(&policy, HealthCheck::Critical, 30), HealthRestart::Restart);
+    }
+
+    #[test]
+    fn restart_limited_within_window() {
+        let mut history = RestartHistory::default();
+        let policy = RestartPolicy {
+            critical_threshold: 1,
+            backoff: 0,
+            ..policy()
+        };
+
+        for now in 0..3 {
+            assert_eq!(
+                history.check(&policy, HealthCheck::Critical, now),
+                HealthRestart::Restart
+            );
+            history.record(now);
+        }
+        assert_eq!(
+            history.check(&policy, HealthCheck::Critical, 50),
+            HealthRestart::Exhausted
+        );
+        assert_eq!(
+            history.check(&policy, HealthCheck::Critical, 101),
+            HealthRestart::Restart
+        );
+        assert_eq!(history.count, 3);
+    }
+}
@@ -23,20 +23,29 @@ use std::process::{Child, ExitStatus};
 #[cfg(windows)]
 use hcore::os::process::windows_child::{Child, ExitStatus};
 use std::result;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use ansi_term::Colour;
 use hcore;
 use hcore::crypto;
 use hcore::service::ServiceGroup;
+use hcore::util::log_file::{LogLine, RotatingLog, RotationPolicy};
 use serde::{Serialize, Serializer};
 
-use super::{health, Pkg};
+use super::{health, HookTimeouts, LogPolicy, Pkg};
 use error::{Result, SupError};
 use fs;
+use manager::metrics;
 use templating::{RenderContext, TemplateRenderer};
 use util::exec;
 
 pub const HOOK_PERMISSIONS: u32 = 0o755;
+/// Seconds a hook may run before it is killed, unless the hook, its package or its service
+/// declares otherwise.
+pub const DEFAULT_HOOK_TIMEOUT: u64 = 300;
+/// Seconds the hooks which are run on every tick of the service may run before they are killed.
+pub const DEFAULT_CHECK_HOOK_TIMEOUT: u64 = 30;
 static LOGKEY: &'static str = "HK";
 
 pub fn stdout_log_path<T>(service_group: &ServiceGroup) -> PathBuf
@@ -67,7 +76,19 @@ pub trait Hook: fmt::Debug + Sized {
 
     fn file_name() -> &'static str;
 
-    fn load<C, T>(service_group: &ServiceGroup, concrete_path: C, template_path: T) -> Option<Self>
+    /// Seconds the hook may run before it is killed, unless its package or service declares
+    /// otherwise.
+    fn default_timeout() -> u64 {
+        DEFAULT_HOOK_TIMEOUT
+    }
+
+    fn load<C, T>(
+        service_group: &ServiceGroup,
+        concrete_path: C,
+        template_path: T,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Option<Self>
     where
         C: AsRef<Path>,
         T: AsRef<Path>,
@@ -83,7 +104,7 @@ pub trait Hook: fmt::Debug + Sized {
                         return None;
                     }
                 };
-                Some(Self::new(service_group, pair))
+                Some(Self::new(service_group, pair, timeouts, log_policy))
             }
             Err(_) => {
                 warn!(
@@ -96,7 +117,12 @@ pub trait Hook: fmt::Debug + Sized {
         }
     }
 
-    fn new(service_group: &ServiceGroup, render_pair: RenderPair) -> Self;
+    fn new(
+        service_group: &ServiceGroup,
+        render_pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self;
 
     /// Compile a hook into its destination service directory.
     fn compile(&self, service_group: &ServiceGroup, ctx: &RenderContext) -> Result<bool> {
@@ -116,7 +142,8 @@ pub trait Hook: fmt::Debug + Sized {
         }
     }
 
-    /// Run a compiled hook.
+    /// Run a compiled hook. A hook which runs for longer than its timeout is killed, along with
+    /// any processes it started.
     fn run<T>(
         &self,
         service_group: &ServiceGroup,
@@ -126,6 +153,7 @@ pub trait Hook: fmt::Debug + Sized {
     where
         T: ToString,
     {
+        let started = Instant::now();
         let mut child = match exec::run(self.path(), &pkg, svc_encrypted_password) {
             Ok(child) => child,
             Err(err) => {
@@ -134,13 +162,31 @@ pub trait Hook: fmt::Debug + Sized {
                 return Self::ExitValue::default();
             }
         };
-        let mut hook_output = HookOutput::new(self.stdout_log_path(), self.stderr_log_path());
-        hook_output.stream_output::<Self>(service_group, &mut child);
-        match child.wait() {
-            Ok(status) => self.handle_exit(service_group, &hook_output, &status),
+        let hook_output = HookOutput::new(self.stdout_log_path(), self.stderr_log_path())
+            .with_hooks_log(fs::svc_hooks_log(service_group.service()), self.log_policy());
+        let streamer = hook_output.stream_output_in_background::<Self>(service_group, &mut child);
+        match exec::wait_timeout(&mut child, Duration::from_secs(self.timeout())) {
+            Ok(Some(status)) => {
+                if let Some(streamer) = streamer {
+                    streamer.join().ok();
+                }
+                metrics::observe_hook(service_group, Self::file_name(), started, status.code());
+                self.handle_exit(service_group, &hook_output, &status)
+            }
+            Ok(None) => {
+                outputln!(preamble service_group,
+                    "Hook timed out after {}s, {}, killing it", self.timeout(), Self::file_name());
+                if let Err(err) = exec::kill_tree(&mut child) {
+                    outputln!(preamble service_group,
+                        "Failed to kill hook, {}, {}", Self::file_name(), err);
+                }
+                metrics::observe_hook_timeout(service_group, Self::file_name(), started);
+                self.handle_timeout(service_group)
+            }
             Err(err) => {
                 outputln!(preamble service_group,
                     "Hook failed to run, {}, {}", Self::file_name(), err);
+                metrics::observe_hook(service_group, Self::file_name(), started, None);
                 Self::ExitValue::default()
             }
         }
@@ -153,6 +199,11 @@ pub trait Hook: fmt::Debug + Sized {
         status: &ExitStatus,
     ) -> Self::ExitValue;
 
+    /// The result of a run which was killed for exceeding the hook's timeout.
+    fn handle_timeout(&self, _: &ServiceGroup) -> Self::ExitValue {
+        Self::ExitValue::default()
+    }
+
     fn path(&self) -> &Path;
 
     fn renderer(&self) -> &TemplateRenderer;
@@ -160,6 +211,19 @@ pub trait Hook: fmt::Debug + Sized {
     fn stdout_log_path(&self) -> &Path;
 
     fn stderr_log_path(&self) -> &Path;
+
+    fn settings(&self) -> &HookSettings;
+
+    /// Seconds the hook may run before it is killed.
+    fn timeout(&self) -> u64 {
+        self.settings().timeout
+    }
+
+    /// How the service's combined hook log, which the hook's output is also appended to, is
+    /// rotated.
+    fn log_policy(&self) -> &RotationPolicy {
+        &self.settings().log_policy
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -167,6 +231,8 @@ pub struct FileUpdatedHook {
     render_pair: RenderPair,
     stdout_log_path: PathBuf,
     stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
 }
 
 impl Hook for FileUpdatedHook {
@@ -176,11 +242,17 @@ impl Hook for FileUpdatedHook {
         "file_updated"
     }
 
-    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
         FileUpdatedHook {
             render_pair: pair,
             stdout_log_path: stdout_log_path::<Self>(service_group),
             stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
         }
     }
 
@@ -208,6 +280,10 @@ impl Hook for FileUpdatedHook {
     fn stderr_log_path(&self) -> &Path {
         &self.stderr_log_path
     }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -215,6 +291,9 @@ pub struct HealthCheckHook {
     render_pair: RenderPair,
     stdout_log_path: PathBuf,
     stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
+    timed_out_health: health::HealthCheck,
 }
 
 impl Hook for HealthCheckHook {
@@ -224,11 +303,22 @@ impl Hook for HealthCheckHook {
         "health_check"
     }
 
-    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+    fn default_timeout() -> u64 {
+        DEFAULT_CHECK_HOOK_TIMEOUT
+    }
+
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
         HealthCheckHook {
             render_pair: pair,
             stdout_log_path: stdout_log_path::<Self>(service_group),
             stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
+            timed_out_health: timeouts.timed_out_health(),
         }
     }
 
@@ -256,6 +346,10 @@ impl Hook for HealthCheckHook {
         }
     }
 
+    fn handle_timeout(&self, _: &ServiceGroup) -> Self::ExitValue {
+        self.timed_out_health
+    }
+
     fn path(&self) -> &Path {
         &self.render_pair.path
     }
@@ -271,6 +365,10 @@ impl Hook for HealthCheckHook {
     fn stderr_log_path(&self) -> &Path {
         &self.stderr_log_path
     }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -278,6 +376,8 @@ pub struct InitHook {
     render_pair: RenderPair,
     stdout_log_path: PathBuf,
     stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
 }
 
 impl Hook for InitHook {
@@ -287,11 +387,17 @@ impl Hook for InitHook {
         "init"
     }
 
-    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
         InitHook {
             render_pair: pair,
             stdout_log_path: stdout_log_path::<Self>(service_group),
             stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
         }
     }
 
@@ -331,6 +437,10 @@ impl Hook for InitHook {
     fn stderr_log_path(&self) -> &Path {
         &self.stderr_log_path
     }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -338,6 +448,8 @@ pub struct RunHook {
     render_pair: RenderPair,
     stdout_log_path: PathBuf,
     stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
 }
 
 impl Hook for RunHook {
@@ -347,11 +459,17 @@ impl Hook for RunHook {
         "run"
     }
 
-    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
         RunHook {
             render_pair: pair,
             stdout_log_path: stdout_log_path::<Self>(service_group),
             stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
         }
     }
 
@@ -396,6 +514,10 @@ impl Hook for RunHook {
     fn stderr_log_path(&self) -> &Path {
         &self.stderr_log_path
     }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -403,6 +525,8 @@ pub struct PostRunHook {
     render_pair: RenderPair,
     stdout_log_path: PathBuf,
     stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
 }
 
 impl Hook for PostRunHook {
@@ -412,11 +536,17 @@ impl Hook for PostRunHook {
         "post-run"
     }
 
-    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
         PostRunHook {
             render_pair: pair,
             stdout_log_path: stdout_log_path::<Self>(service_group),
             stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
         }
     }
 
@@ -451,6 +581,146 @@ impl Hook for PostRunHook {
     fn stderr_log_path(&self) -> &Path {
         &self.stderr_log_path
     }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
+}
+
+/// Runs before the service's process is stopped, such as to drain its connections.
+#[derive(Debug, Serialize)]
+pub struct PreStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
+}
+
+impl Hook for PreStopHook {
+    type ExitValue = ExitCode;
+
+    fn file_name() -> &'static str {
+        "pre-stop"
+    }
+
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
+        PreStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
+        }
+    }
+
+    fn handle_exit<'a>(
+        &self,
+        service_group: &ServiceGroup,
+        _: &'a HookOutput,
+        status: &ExitStatus,
+    ) -> Self::ExitValue {
+        match status.code() {
+            Some(code) => ExitCode(code),
+            None => {
+                outputln!(preamble service_group,
+                    "{} exited without a status code", Self::file_name());
+                ExitCode::default()
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
+}
+
+/// Runs after the service's process has stopped.
+#[derive(Debug, Serialize)]
+pub struct PostStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
+}
+
+impl Hook for PostStopHook {
+    type ExitValue = ExitCode;
+
+    fn file_name() -> &'static str {
+        "post-stop"
+    }
+
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
+        PostStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
+        }
+    }
+
+    fn handle_exit<'a>(
+        &self,
+        service_group: &ServiceGroup,
+        _: &'a HookOutput,
+        status: &ExitStatus,
+    ) -> Self::ExitValue {
+        match status.code() {
+            Some(code) => ExitCode(code),
+            None => {
+                outputln!(preamble service_group,
+                    "{} exited without a status code", Self::file_name());
+                ExitCode::default()
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -458,6 +728,8 @@ pub struct ReloadHook {
     render_pair: RenderPair,
     stdout_log_path: PathBuf,
     stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
 }
 
 impl Hook for ReloadHook {
@@ -467,11 +739,17 @@ impl Hook for ReloadHook {
         "reload"
     }
 
-    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
         ReloadHook {
             render_pair: pair,
             stdout_log_path: stdout_log_path::<Self>(service_group),
             stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
         }
     }
 
@@ -511,6 +789,10 @@ impl Hook for ReloadHook {
     fn stderr_log_path(&self) -> &Path {
         &self.stderr_log_path
     }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -518,6 +800,8 @@ pub struct ReconfigureHook {
     render_pair: RenderPair,
     stdout_log_path: PathBuf,
     stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
 }
 
 impl Hook for ReconfigureHook {
@@ -527,11 +811,17 @@ impl Hook for ReconfigureHook {
         "reconfigure"
     }
 
-    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
         ReconfigureHook {
             render_pair: pair,
             stdout_log_path: stdout_log_path::<Self>(service_group),
             stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
         }
     }
 
@@ -566,6 +856,10 @@ impl Hook for ReconfigureHook {
     fn stderr_log_path(&self) -> &Path {
         &self.stderr_log_path
     }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -573,6 +867,8 @@ pub struct SmokeTestHook {
     render_pair: RenderPair,
     stdout_log_path: PathBuf,
     stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
 }
 
 impl Hook for SmokeTestHook {
@@ -582,11 +878,17 @@ impl Hook for SmokeTestHook {
         "smoke_test"
     }
 
-    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
         SmokeTestHook {
             render_pair: pair,
             stdout_log_path: stdout_log_path::<Self>(service_group),
             stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
         }
     }
 
@@ -622,6 +924,10 @@ impl Hook for SmokeTestHook {
     fn stderr_log_path(&self) -> &Path {
         &self.stderr_log_path
     }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -629,6 +935,8 @@ pub struct SuitabilityHook {
     render_pair: RenderPair,
     stdout_log_path: PathBuf,
     stderr_log_path: PathBuf,
+    #[serde(rename = "timeout")]
+    settings: HookSettings,
 }
 
 impl Hook for SuitabilityHook {
@@ -638,11 +946,21 @@ impl Hook for SuitabilityHook {
         "suitability"
     }
 
-    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+    fn default_timeout() -> u64 {
+        DEFAULT_CHECK_HOOK_TIMEOUT
+    }
+
+    fn new(
+        service_group: &ServiceGroup,
+        pair: RenderPair,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self {
         SuitabilityHook {
             render_pair: pair,
             stdout_log_path: stdout_log_path::<Self>(service_group),
             stderr_log_path: stderr_log_path::<Self>(service_group),
+            settings: HookSettings::new::<Self>(timeouts, log_policy),
         }
     }
 
@@ -708,6 +1026,10 @@ impl Hook for SuitabilityHook {
     fn stderr_log_path(&self) -> &Path {
         &self.stderr_log_path
     }
+
+    fn settings(&self) -> &HookSettings {
+        &self.settings
+    }
 }
 
 /// Cryptographically hash the contents of the compiled hook
@@ -751,12 +1073,20 @@ pub struct HookTable {
     pub suitability: Option<SuitabilityHook>,
     pub run: Option<RunHook>,
     pub post_run: Option<PostRunHook>,
+    pub pre_stop: Option<PreStopHook>,
+    pub post_stop: Option<PostStopHook>,
     pub smoke_test: Option<SmokeTestHook>,
 }
 
 impl HookTable {
     /// Read all available hook templates from the table's package directory into the table.
-    pub fn load<P, T>(service_group: &ServiceGroup, templates: T, hooks_path: P) -> Self
+    pub fn load<P, T>(
+        service_group: &ServiceGroup,
+        templates: T,
+        hooks_path: P,
+        timeouts: &HookTimeouts,
+        log_policy: &LogPolicy,
+    ) -> Self
     where
         P: AsRef<Path>,
         T: AsRef<Path>,
@@ -764,15 +1094,83 @@ impl HookTable {
         let mut table = HookTable::default();
         if let Some(meta) = std::fs::metadata(templates.as_ref()).ok() {
             if meta.is_dir() {
-                table.file_updated = FileUpdatedHook::load(service_group, &hooks_path, &templates);
-                table.health_check = HealthCheckHook::load(service_group, &hooks_path, &templates);
-                table.suitability = SuitabilityHook::load(service_group, &hooks_path, &templates);
-                table.init = InitHook::load(service_group, &hooks_path, &templates);
-                table.reload = ReloadHook::load(service_group, &hooks_path, &templates);
-                table.reconfigure = ReconfigureHook::load(service_group, &hooks_path, &templates);
-                table.run = RunHook::load(service_group, &hooks_path, &templates);
-                table.post_run = PostRunHook::load(service_group, &hooks_path, &templates);
-                table.smoke_test = SmokeTestHook::load(service_group, &hooks_path, &templates);
+                table.file_updated = FileUpdatedHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.health_check = HealthCheckHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.suitability = SuitabilityHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.init = InitHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.reload = ReloadHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.reconfigure = ReconfigureHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.run = RunHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.post_run = PostRunHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.pre_stop = PreStopHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.post_stop = PostStopHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
+                table.smoke_test = SmokeTestHook::load(
+                    service_group,
+                    &hooks_path,
+                    &templates,
+                    timeouts,
+                    log_policy,
+                );
             }
         }
         debug!(
@@ -815,6 +1213,12 @@ impl HookTable {
         if let Some(ref hook) = self.post_run {
             changed = self.compile_one(hook, service_group, ctx) || changed;
         }
+        if let Some(ref hook) = self.pre_stop {
+            changed = self.compile_one(hook, service_group, ctx) || changed;
+        }
+        if let Some(ref hook) = self.post_stop {
+            changed = self.compile_one(hook, service_group, ctx) || changed;
+        }
         if let Some(ref hook) = self.smoke_test {
             changed = self.compile_one(hook, service_group, ctx) || changed;
         }
@@ -842,6 +1246,35 @@ pub struct RenderPair {
     pub renderer: TemplateRenderer,
 }
 
+/// How a hook is run, which is the same for every kind of hook.
+#[derive(Debug)]
+pub struct HookSettings {
+    timeout: u64,
+    log_policy: RotationPolicy,
+}
+
+impl HookSettings {
+    pub fn new<T>(timeouts: &HookTimeouts, log_policy: &LogPolicy) -> Self
+    where
+        T: Hook,
+    {
+        HookSettings {
+            timeout: timeouts.get(T::file_name()).unwrap_or(T::default_timeout()),
+            log_policy: log_policy.rotation(),
+        }
+    }
+}
+
+/// Only the timeout is reported, in place of the hook's own `timeout` field.
+impl Serialize for HookSettings {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.timeout)
+    }
+}
+
 impl RenderPair {
     pub fn new<C, T>(concrete_path: C, template_path: T) -> Result<Self>
     where
@@ -884,6 +1317,7 @@ impl Serialize for RenderPair {
 pub struct HookOutput<'a> {
     stdout_log_file: &'a Path,
     stderr_log_file: &'a Path,
+    hooks_log: Option<(PathBuf, RotationPolicy)>,
 }
 
 impl<'a> HookOutput<'a> {
@@ -891,9 +1325,17 @@ impl<'a> HookOutput<'a> {
         HookOutput {
             stdout_log_file: stdout_log,
             stderr_log_file: stderr_log,
+            hooks_log: None,
         }
     }
 
+    /// Also append the output to the service's combined hook log, which unlike the logs of a
+    /// single hook is kept across runs and rotated according to the given policy.
+    fn with_hooks_log(mut self, path: PathBuf, policy: &RotationPolicy) -> Self {
+        self.hooks_log = Some((path, policy.clone()));
+        self
+    }
+
     fn stdout(&self) -> Option<BufReader<File>> {
         match File::open(&self.stdout_log_file) {
             Ok(f) => Some(BufReader::new(f)),
@@ -909,32 +1351,99 @@ impl<'a> HookOutput<'a> {
         }
     }
 
-    fn stream_output<H: Hook>(&mut self, service_group: &ServiceGroup, process: &mut Child) {
+    /// Stream the output of a process from a background thread, so that the process can be
+    /// timed out while its output is still being read.
+    fn stream_output_in_background<H: Hook>(
+        &self,
+        service_group: &ServiceGroup,
+        process: &mut Child,
+    ) -> Option<thread::JoinHandle<()>> {
+        let stdout_log = self.stdout_log_file.to_path_buf();
+        let stderr_log = self.stderr_log_file.to_path_buf();
+        let hooks_log = self.hooks_log.clone();
+        let hook = H::file_name();
+        let preamble_str = self.stream_preamble::<H>(service_group);
+        let stdout = process.stdout.take();
+        let stderr = process.stderr.take();
+        thread::Builder::new()
+            .name(format!("{}-{}", hook, service_group))
+            .spawn(move || {
+                let mut output = HookOutput::new(&stdout_log, &stderr_log);
+                output.hooks_log = hooks_log;
+                output.stream(hook, &preamble_str, stdout, stderr)
+            })
+            .ok()
+    }
+
+    fn stream<O, E>(&self, hook: &str, preamble_str: &str, stdout: Option<O>, stderr: Option<E>)
+    where
+        O: Read,
+        E: Read,
+    {
         let mut stdout_log =
             File::create(&self.stdout_log_file).expect("couldn't create log output file");
         let mut stderr_log =
             File::create(&self.stderr_log_file).expect("couldn't create log output file");
+        let mut hooks_log = self.open_hooks_log(preamble_str);
+        let stdout_source = format!("{}.stdout", hook);
+        let stderr_source = format!("{}.stderr", hook);
 
-        let preamble_str = self.stream_preamble::<H>(service_group);
-        if let Some(ref mut stdout) = process.stdout {
+        if let Some(stdout) = stdout {
             for line in BufReader::new(stdout).lines() {
                 if let Some(ref l) = line.ok() {
                     outputln!(preamble preamble_str, l);
                     stdout_log.write_fmt(format_args!("{}\n", l)).expect(
                         "couldn't write line",
                     );
+                    Self::append(&mut hooks_log, preamble_str, &stdout_source, l);
                 }
             }
         }
-        if let Some(ref mut stderr) = process.stderr {
+        if let Some(stderr) = stderr {
             for line in BufReader::new(stderr).lines() {
                 if let Some(ref l) = line.ok() {
                     outputln!(preamble preamble_str, l);
                     stderr_log.write_fmt(format_args!("{}\n", l)).expect(
                         "couldn't write line",
                     );
+                    Self::append(&mut hooks_log, preamble_str, &stderr_source, l);
+                }
+            }
+        }
+    }
+
+    fn open_hooks_log(&self, preamble_str: &str) -> Option<RotatingLog> {
+        let (path, policy) = match self.hooks_log {
+            Some((ref path, ref policy)) => (path, policy),
+            None => return None,
+        };
+        match RotatingLog::open(path.clone(), policy.clone()) {
+            Ok(log) => Some(log),
+            Err(err) => {
+                outputln!(preamble preamble_str,
+                    "Failed to open hook log {}, {}", path.display(), err);
+                None
+            }
+        }
+    }
+
+    /// Append a line to the combined hook log, giving up on the log if it can't be written.
+    fn append(log: &mut Option<RotatingLog>, preamble_str: &str, source: &str, line: &str) {
+        let failed = match *log {
+            Some(ref mut log) => {
+                match log.write(&LogLine::new(source, line)) {
+                    Ok(()) => false,
+                    Err(err) => {
+                        outputln!(preamble preamble_str,
+                            "Failed to write hook log {}, {}", log.path().display(), err);
+                        true
+                    }
                 }
             }
+            None => false,
+        };
+        if failed {
+            *log = None;
         }
     }
 
@@ -957,6 +1466,7 @@ mod tests {
     use config::GossipListenAddr;
     use http_gateway::ListenAddr;
     use hcore::package::{PackageIdent, PackageInstall};
+    use hcore::util::log_file::read_lines;
     use manager::service::{Pkg, Cfg};
     use manager::service::spec::ServiceBind;
     use census::CensusRing;
@@ -1051,8 +1561,13 @@ mod tests {
         let concrete_path = rendered_hooks_path();
         let template_path = hook_templates_path();
 
-        let hook = InitHook::load(&service_group, &concrete_path, &template_path)
-            .expect("Could not create testing init hook");
+        let hook = InitHook::load(
+            &service_group,
+            &concrete_path,
+            &template_path,
+            &HookTimeouts::default(),
+            &LogPolicy::default(),
+        ).expect("Could not create testing init hook");
 
         let content = r#"
 #!/bin/bash
@@ -1072,8 +1587,13 @@ echo "The message is Hello World"
         let service_group = service_group();
         let concrete_path = rendered_hooks_path();
         let template_path = hook_templates_path();
-        let hook = InitHook::load(&service_group, &concrete_path, &template_path)
-            .expect("Could not create testing init hook");
+        let hook = InitHook::load(
+            &service_group,
+            &concrete_path,
+            &template_path,
+            &HookTimeouts::default(),
+            &LogPolicy::default(),
+        ).expect("Could not create testing init hook");
 
         assert_eq!(hash_content(hook.path()).unwrap(), "");
     }
@@ -1084,8 +1604,13 @@ echo "The message is Hello World"
         let concrete_path = rendered_hooks_path();
         let template_path = hook_templates_path();
 
-        let hook = InitHook::load(&service_group, &concrete_path, &template_path)
-            .expect("Could not create testing init hook");
+        let hook = InitHook::load(
+            &service_group,
+            &concrete_path,
+            &template_path,
+            &HookTimeouts::default(),
+            &LogPolicy::default(),
+        ).expect("Could not create testing init hook");
 
         // Since we're trying to update a file that should already
         // exist, we need to actually create it :P
@@ -1114,8 +1639,13 @@ echo "The message is Hello World"
         let concrete_path = rendered_hooks_path();
         let template_path = hook_templates_path();
 
-        let hook = InitHook::load(&service_group, &concrete_path, &template_path)
-            .expect("Could not create testing init hook");
+        let hook = InitHook::load(
+            &service_group,
+            &concrete_path,
+            &template_path,
+            &HookTimeouts::default(),
+            &LogPolicy::default(),
+        ).expect("Could not create testing init hook");
 
         // In this test, we'll start with *no* rendered content.
         assert_eq!(hook.as_ref().exists(), false);
@@ -1141,8 +1671,13 @@ echo "The message is Hello World"
         let concrete_path = rendered_hooks_path();
         let template_path = hook_templates_path();
 
-        let hook = InitHook::load(&service_group, &concrete_path, &template_path)
-            .expect("Could not create testing init hook");
+        let hook = InitHook::load(
+            &service_group,
+            &concrete_path,
+            &template_path,
+            &HookTimeouts::default(),
+            &LogPolicy::default(),
+        ).expect("Could not create testing init hook");
 
         let initial_content = r#"
 #!/bin/bash
@@ -1198,8 +1733,13 @@ echo "The message is Hola Mundo"
         let concrete_path = rendered_hooks_path();
         let template_path = hook_templates_path();
 
-        let hook = InitHook::load(&service_group, &concrete_path, &template_path)
-            .expect("Could not create testing init hook");
+        let hook = InitHook::load(
+            &service_group,
+            &concrete_path,
+            &template_path,
+            &HookTimeouts::default(),
+            &LogPolicy::default(),
+        ).expect("Could not create testing init hook");
 
         ////////////////////////////////////////////////////////////////////////
         // BEGIN RENDER CONTEXT SETUP
@@ -1375,7 +1915,13 @@ echo "The message is Hello"
         // END RENDER CONTEXT SETUP
         ////////////////////////////////////////////////////////////////////////
 
-        let hook_table = HookTable::load(&service_group, &template_path, &hooks_path);
+        let hook_table = HookTable::load(
+            &service_group,
+            &template_path,
+            &hooks_path,
+            &HookTimeouts::default(),
+            &LogPolicy::default(),
+        );
         assert_eq!(hook_table.compile(&service_group, &ctx), true);
 
         // Verify init hook
@@ -1408,6 +1954,48 @@ echo "The message is Hello"
         );
     }
 
+    #[test]
+    fn hook_timeouts_are_overridden_or_default() {
+        let service_group = service_group();
+        let concrete_path = rendered_hooks_path();
+        let template_path = hook_templates_path();
+
+        let hook = InitHook::load(
+            &service_group,
+            &concrete_path,
+            &template_path,
+            &HookTimeouts::default(),
+            &LogPolicy::default(),
+        ).expect("Could not create testing init hook");
+        assert_eq!(hook.timeout(), DEFAULT_HOOK_TIMEOUT);
+
+        let timeouts = HookTimeouts {
+            init: Some(5),
+            ..HookTimeouts::default()
+        };
+        let hook = InitHook::load(
+            &service_group,
+            &concrete_path,
+            &template_path,
+            &timeouts,
+            &LogPolicy::default(),
+        ).expect("Could not create testing init hook");
+        assert_eq!(hook.timeout(), 5);
+        assert_eq!(HealthCheckHook::default_timeout(), DEFAULT_CHECK_HOOK_TIMEOUT);
+    }
+
+    #[test]
+    fn waiting_for_a_hook_stops_at_its_timeout() {
+        let mut child = Command::new("sleep").arg("10").spawn().expect(
+            "couldn't run sleep",
+        );
+        let status = exec::wait_timeout(&mut child, Duration::from_millis(100))
+            .expect("couldn't wait for sleep");
+        assert!(status.is_none());
+        child.kill().expect("couldn't kill sleep");
+        child.wait().expect("couldn't wait for sleep");
+    }
+
     ////////////////////////////////////////////////////////////////////////
 
     #[test]
@@ -1430,12 +2018,16 @@ echo "The message is Hello"
             "{}.stderr.log",
             InitHook::file_name()
         ));
-        let mut hook_output = HookOutput::new(&stdout_log, &stderr_log);
+        let hook_output = HookOutput::new(&stdout_log, &stderr_log);
         let service_group = ServiceGroup::new(None, "dummy", "service", None).expect(
             "couldn't create ServiceGroup",
         );
 
-        hook_output.stream_output::<InitHook>(&service_group, &mut child);
+        hook_output
+            .stream_output_in_background::<InitHook>(&service_group, &mut child)
+            .expect("couldn't stream hook output")
+            .join()
+            .expect("couldn't stream hook output");
 
         let mut stdout = String::new();
         hook_output
@@ -1455,4 +2047,40 @@ echo "The message is Hello"
 
         fs::remove_dir_all(tmp_dir).expect("remove temp dir");
     }
+
+    #[test]
+    fn hook_output_is_appended_to_hooks_log() {
+        let tmp_dir = TempDir::new("habitat_hooks_test").expect("create temp dir");
+        let stdout_log = tmp_dir.path().join("init.stdout.log");
+        let stderr_log = tmp_dir.path().join("init.stderr.log");
+        let hooks_log = tmp_dir.path().join("hooks.log");
+        let service_group = ServiceGroup::new(None, "dummy", "service", None).expect(
+            "couldn't create ServiceGroup",
+        );
+        for _ in 0..2 {
+            let mut cmd = Command::new(hook_fixtures_path().join(InitHook::file_name()));
+            cmd.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(
+                Stdio::piped(),
+            );
+            let mut child = cmd.spawn().expect("couldn't run hook");
+            let hook_output = HookOutput::new(&stdout_log, &stderr_log)
+                .with_hooks_log(hooks_log.clone(), &RotationPolicy::default());
+            hook_output
+                .stream_output_in_background::<InitHook>(&service_group, &mut child)
+                .expect("couldn't stream hook output")
+                .join()
+                .expect("couldn't stream hook output");
+        }
+
+        let lines: Vec<(String, String)> = read_lines(&hooks_log)
+            .expect("couldn't read hooks log")
+            .into_iter()
+            .map(|l| (l.source, l.text))
+            .collect();
+        let run = vec![
+            ("init.stdout".to_string(), "This is stdout".to_string()),
+            ("init.stderr".to_string(), "This is stderr".to_string()),
+        ];
+        assert_eq!(lines, [run.clone(), run].concat());
+    }
 }
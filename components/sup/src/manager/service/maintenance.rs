@@ -0,0 +1,423 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Maintenance windows, the times at which a service may be restarted into an updated package.
+//!
+//! A window is either a cron-like `schedule`, where every minute matched by the schedule is inside
+//! the window, or a daily range from `start` to `end` on the given `days`. Both are evaluated in
+//! the window's `timezone`.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+use time::{self, Timespec, Tm};
+
+use error::{Error, Result, SupError};
+use super::timezone::TimeZone;
+
+static LOGKEY: &'static str = "MW";
+const DAYS: [&'static str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct MaintenanceWindow {
+    /// Cron-like schedule of minute, hour, day of month, month and day of week fields.
+    pub schedule: Option<String>,
+    /// Days of the week the range applies to, such as `mon`; every day when empty.
+    pub days: Vec<String>,
+    /// Start of the daily range, as `HH:MM`.
+    pub start: Option<String>,
+    /// End of the daily range, as `HH:MM`. A range ending before it starts runs past midnight.
+    pub end: Option<String>,
+    /// `UTC`, `local`, a fixed offset from UTC such as `+02:00`, or a zone of the system's
+    /// zoneinfo database such as `Europe/Berlin`, which follows its daylight saving time.
+    pub timezone: String,
+    /// The zone loaded for `timezone`, so that its file is only read once.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub zoneinfo: ZoneCache,
+}
+
+impl MaintenanceWindow {
+    pub fn validate(&self) -> Result<()> {
+        self.zone()?;
+        match self.schedule {
+            Some(ref schedule) => {
+                Schedule::parse(schedule)?;
+            }
+            None => {
+                self.range()?;
+                self.weekdays()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns true if the window is open at the current time. An invalid window is always
+    /// open, so that it cannot hold back updates forever.
+    pub fn is_open(&self) -> bool {
+        match self.is_open_at(time::get_time()) {
+            Ok(open) => open,
+            Err(e) => {
+                warn!("Ignoring invalid maintenance window: {}", e);
+                true
+            }
+        }
+    }
+
+    pub fn is_open_at(&self, now: Timespec) -> Result<bool> {
+        let tm = match self.zone()? {
+            Zone::Fixed(offset) => time::at_utc(now + time::Duration::minutes(offset)),
+            Zone::Local => time::at(now),
+            Zone::Named(tz) => {
+                time::at_utc(now + time::Duration::seconds(tz.offset_at(now.sec) as i64))
+            }
+        };
+        match self.schedule {
+            Some(ref schedule) => Ok(Schedule::parse(schedule)?.matches(&tm)),
+            None => {
+                let (start, end) = self.range()?;
+                let days = self.weekdays()?;
+                let minute = (tm.tm_hour * 60 + tm.tm_min) as u32;
+                let today = days & (1 << tm.tm_wday) != 0;
+                let yesterday = days & (1 << ((tm.tm_wday + 6) % 7)) != 0;
+                Ok(if start == end {
+                    today
+                } else if start < end {
+                    today && minute >= start && minute < end
+                } else {
+                    (today && minute >= start) || (yesterday && minute < end)
+                })
+            }
+        }
+    }
+
+    fn zone(&self) -> Result<Zone> {
+        let tz = self.timezone.trim();
+        if tz.eq_ignore_ascii_case("utc") || tz.is_empty() {
+            return Ok(Zone::Fixed(0));
+        }
+        if tz.eq_ignore_ascii_case("local") {
+            return Ok(Zone::Local);
+        }
+        let sign = match tz.chars().next() {
+            Some('+') => 1,
+            Some('-') => -1,
+            _ => return self.zoneinfo.load(tz).map(Zone::Named),
+        };
+        let minutes = parse_time(&tz[1..])?;
+        Ok(Zone::Fixed(sign * minutes as i64))
+    }
+
+    fn range(&self) -> Result<(u32, u32)> {
+        match (self.start.as_ref(), self.end.as_ref()) {
+            (Some(start), Some(end)) => Ok((parse_time(start)?, parse_time(end)?)),
+            _ => Err(invalid(
+                "either a schedule or both a start and an end are required"
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// Returns the days of the week as a bitmask, with Sunday as bit 0.
+    fn weekdays(&self) -> Result<u64> {
+        if self.days.is_empty() {
+            return Ok(0b111_1111);
+        }
+        let mut mask = 0;
+        for day in self.days.iter() {
+            let name = day.to_lowercase();
+            match DAYS.iter().position(|d| name.starts_with(d)) {
+                Some(idx) => mask |= 1 << idx,
+                None => return Err(invalid(format!("unknown day '{}'", day))),
+            }
+        }
+        Ok(mask)
+    }
+}
+
+impl Default for MaintenanceWindow {
+    fn default() -> Self {
+        MaintenanceWindow {
+            schedule: None,
+            days: Vec::new(),
+            start: None,
+            end: None,
+            timezone: "UTC".to_string(),
+            zoneinfo: ZoneCache::default(),
+        }
+    }
+}
+
+enum Zone {
+    /// Offset from UTC in minutes.
+    Fixed(i64),
+    Local,
+    Named(Arc<TimeZone>),
+}
+
+/// The zone last loaded from the zoneinfo database for a window, by name, so that checking
+/// whether the window is open doesn't read and parse the zone's file every time. It takes no part
+/// in comparing or hashing windows.
+#[derive(Default)]
+pub struct ZoneCache(Mutex<Option<(String, Arc<TimeZone>)>>);
+
+impl ZoneCache {
+    fn load(&self, name: &str) -> Result<Arc<TimeZone>> {
+        let mut cached = self.0.lock().expect("Zone cache lock poisoned");
+        if let Some((ref loaded, ref zone)) = *cached {
+            if loaded == name {
+                return Ok(zone.clone());
+            }
+        }
+        let zone = Arc::new(TimeZone::load(name).map_err(invalid)?);
+        *cached = Some((name.to_string(), zone.clone()));
+        Ok(zone)
+    }
+}
+
+impl Clone for ZoneCache {
+    fn clone(&self) -> Self {
+        ZoneCache(Mutex::new(
+            self.0.lock().expect("Zone cache lock poisoned").clone(),
+        ))
+    }
+}
+
+impl fmt::Debug for ZoneCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ZoneCache")
+    }
+}
+
+impl PartialEq for ZoneCache {
+    fn eq(&self, _other: &ZoneCache) -> bool {
+        true
+    }
+}
+
+impl Eq for ZoneCache {}
+
+impl Hash for ZoneCache {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// A parsed cron-like schedule. Each field is a bitmask of the values it matches.
+struct Schedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    // As with cron, a restricted day of month or day of week matches if either of them does.
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl Schedule {
+    fn parse(schedule: &str) -> Result<Self> {
+        let fields: Vec<&str> = schedule.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(invalid(format!(
+                "schedule '{}' must have 5 fields: minute, hour, day of month, month and day \
+                 of week",
+                schedule
+            )));
+        }
+        let mut days_of_week = parse_field(fields[4], 0, 7)?;
+        // Both 0 and 7 are Sunday.
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week |= 1;
+        }
+        Ok(Schedule {
+            minutes: parse_field(fields[0], 0, 59)?,
+            hours: parse_field(fields[1], 0, 23)?,
+            days_of_month: parse_field(fields[2], 1, 31)?,
+            months: parse_field(fields[3], 1, 12)?,
+            days_of_week: days_of_week,
+            any_day_of_month: fields[2] == "*",
+            any_day_of_week: fields[4] == "*",
+        })
+    }
+
+    fn matches(&self, tm: &Tm) -> bool {
+        let dom = self.days_of_month & (1 << tm.tm_mday) != 0;
+        let dow = self.days_of_week & (1 << tm.tm_wday) != 0;
+        let day = match (self.any_day_of_month, self.any_day_of_week) {
+            (true, true) => true,
+            (true, false) => dow,
+            (false, true) => dom,
+            (false, false) => dom || dow,
+        };
+        self.minutes & (1 << tm.tm_min) != 0 && self.hours & (1 << tm.tm_hour) != 0 &&
+            self.months & (1 << (tm.tm_mon + 1)) != 0 && day
+    }
+}
+
+/// Parses a comma separated list of `*`, `N`, `N-M` and their `/STEP` variants into a bitmask.
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64> {
+    let mut mask = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.find('/') {
+            Some(idx) => (&part[..idx], parse_number(&part[idx + 1..])?),
+            None => (part, 1),
+        };
+        let (first, last) = if range == "*" {
+            (min, max)
+        } else {
+            match range.find('-') {
+                Some(idx) => (
+                    parse_number(&range[..idx])?,
+                    parse_number(&range[idx + 1..])?,
+                ),
+                None => {
+                    let value = parse_number(range)?;
+                    (value, value)
+                }
+            }
+        };
+        if step == 0 || first < min || last > max || first > last {
+            return Err(invalid(format!("schedule field '{}' is out of range", field)));
+        }
+        let mut value = first;
+        while value <= last {
+            mask |= 1 << value;
+            value += step;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_number(value: &str) -> Result<u32> {
+    value.parse().map_err(|_| {
+        invalid(format!("'{}' is not a number", value))
+    })
+}
+
+/// Parses an `HH:MM` time into minutes since midnight.
+fn parse_time(value: &str) -> Result<u32> {
+    let parts: Vec<&str> = value.trim().split(':').collect();
+    if parts.len() == 2 {
+        if let (Ok(hours), Ok(minutes)) = (parts[0].parse::<u32>(), parts[1].parse::<u32>()) {
+            if hours < 24 && minutes < 60 {
+                return Ok(hours * 60 + minutes);
+            }
+        }
+    }
+    Err(invalid(format!("'{}' is not a time of day as HH:MM", value)))
+}
+
+fn invalid(reason: String) -> SupError {
+    sup_error!(Error::InvalidMaintenanceWindow(reason))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use time::Timespec;
+
+    use super::MaintenanceWindow;
+
+    // Saturday, 2017-06-03 02:30:00 UTC
+    const SATURDAY_0230: i64 = 1496457000;
+
+    fn at(offset_minutes: i64) -> Timespec {
+        Timespec::new(SATURDAY_0230 + offset_minutes * 60, 0)
+    }
+
+    fn range(days: Vec<&str>, start: &str, end: &str) -> MaintenanceWindow {
+        MaintenanceWindow {
+            days: days.into_iter().map(|d| d.to_string()).collect(),
+            start: Some(start.to_string()),
+            end: Some(end.to_string()),
+            ..MaintenanceWindow::default()
+        }
+    }
+
+    fn schedule(schedule: &str) -> MaintenanceWindow {
+        MaintenanceWindow {
+            schedule: Some(schedule.to_string()),
+            ..MaintenanceWindow::default()
+        }
+    }
+
+    #[test]
+    fn range_is_open_between_start_and_end() {
+        let window = range(vec!["sat", "sun"], "02:00", "04:00");
+        assert!(window.is_open_at(at(0)).unwrap());
+        assert!(!window.is_open_at(at(-31)).unwrap());
+        assert!(!window.is_open_at(at(90)).unwrap());
+        // Friday
+        assert!(!window.is_open_at(at(-24 * 60)).unwrap());
+    }
+
+    #[test]
+    fn range_past_midnight_belongs_to_the_day_it_starts() {
+        let window = range(vec!["friday"], "23:00", "03:00");
+        assert!(window.is_open_at(at(0)).unwrap());
+        assert!(!window.is_open_at(at(24 * 60)).unwrap());
+    }
+
+    #[test]
+    fn range_honors_timezone_offset() {
+        let mut window = range(vec![], "04:00", "05:00");
+        assert!(!window.is_open_at(at(0)).unwrap());
+        window.timezone = "+02:00".to_string();
+        assert!(window.is_open_at(at(0)).unwrap());
+    }
+
+    #[test]
+    fn schedule_matches_minutes_hours_and_days() {
+        assert!(schedule("*/15 2-3 * * 6").is_open_at(at(0)).unwrap());
+        assert!(!schedule("*/15 2-3 * * 6").is_open_at(at(1)).unwrap());
+        assert!(schedule("* 2 * * 0,7").is_open_at(at(24 * 60)).unwrap());
+        assert!(!schedule("* 2 * * 1-5").is_open_at(at(0)).unwrap());
+        // Day of month or day of week, as with cron.
+        assert!(schedule("* 2 3 * 1").is_open_at(at(0)).unwrap());
+        assert!(!schedule("* 2 * 7 *").is_open_at(at(0)).unwrap());
+    }
+
+    #[test]
+    fn validate_rejects_invalid_windows() {
+        assert!(schedule("* * * *").validate().is_err());
+        assert!(schedule("60 * * * *").validate().is_err());
+        assert!(range(vec!["someday"], "02:00", "04:00").validate().is_err());
+        assert!(range(vec![], "25:00", "04:00").validate().is_err());
+        assert!(MaintenanceWindow::default().validate().is_err());
+        let mut window = range(vec![], "02:00", "04:00");
+        window.timezone = "Mars/Olympus".to_string();
+        assert!(window.validate().is_err());
+        window.timezone = "-05:30".to_string();
+        assert!(window.validate().is_ok());
+    }
+
+    #[test]
+    fn named_zone_is_loaded_once() {
+        let mut window = range(vec![], "02:00", "04:00");
+        window.timezone = "Mars/Olympus".to_string();
+        assert!(window.zoneinfo.load(&window.timezone).is_err());
+        assert!(window.zoneinfo.0.lock().unwrap().is_none());
+
+        window.timezone = "UTC0".to_string();
+        if window.zoneinfo.load(&window.timezone).is_err() {
+            // No zoneinfo database on this system
+            return;
+        }
+        let first = window.zoneinfo.load(&window.timezone).unwrap();
+        let second = window.zoneinfo.load(&window.timezone).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(window.clone(), window);
+    }
+}
@@ -13,13 +13,20 @@
 // limitations under the License.
 
 pub mod hooks;
+mod composite_spec;
 mod config;
 mod health;
+mod maintenance;
 mod package;
+mod probe;
+mod rollback;
+mod schema;
 mod spec;
 mod supervisor;
+mod timezone;
 
 use std;
+use std::collections::HashMap;
 use std::fmt;
 use std::fs::File;
 use std::io::BufWriter;
@@ -34,16 +41,19 @@ use ansi_term::Colour::{Yellow, Red, Green};
 use butterfly::rumor::service::Service as ServiceRumor;
 use common::ui::UI;
 use hcore::crypto::hash;
+use hcore::fs::FS_ROOT_PATH;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use hcore::util::deserialize_using_from_str;
 use hcore::util::perm::{set_owner, set_permissions};
 use launcher_client::LauncherCli;
 use serde;
-use time::Timespec;
+use time::{self, Timespec};
 
 use super::Sys;
 use self::config::CfgRenderer;
+use self::health::HealthRestart;
+use self::rollback::LastUpdate;
 use self::hooks::{HOOK_PERMISSIONS, Hook, HookTable};
 use self::supervisor::Supervisor;
 use error::{Error, Result, SupError};
@@ -53,10 +63,17 @@ use census::{ServiceFile, CensusRing, ElectionStatus};
 use templating::RenderContext;
 use util;
 
+pub use self::composite_spec::{CompositeService, CompositeSpec};
 pub use self::config::Cfg;
-pub use self::health::{HealthCheck, SmokeCheck};
+pub use self::health::{HealthCheck, RestartHistory, SmokeCheck};
+pub use self::probe::HealthProbe;
+pub use self::maintenance::MaintenanceWindow;
 pub use self::package::Pkg;
-pub use self::spec::{DesiredState, ServiceBind, ServiceSpec, StartStyle};
+pub use self::rollback::{Rollback, RollbackReason};
+pub use self::schema::ConfigSchema;
+pub use self::spec::{CanaryPolicy, DependencyCondition, DesiredState, HookTimeouts,
+                     LocalDependency, LogPolicy, ResourceLimits, RestartPolicy, RollbackPolicy,
+                     ServiceBind, ServiceSpec, ShutdownPolicy, StartStyle};
 pub use self::supervisor::ProcessState;
 
 static LOGKEY: &'static str = "SR";
@@ -67,6 +84,15 @@ lazy_static! {
     };
 }
 
+/// The state of a service, as seen by the services on the same Supervisor which depend on it.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DependencyState {
+    pub up: bool,
+    pub health: HealthCheck,
+    /// The number of times the service's process was restarted.
+    pub restarts: u64,
+}
+
 #[derive(Debug, Serialize)]
 pub struct Service {
     pub service_group: ServiceGroup,
@@ -76,7 +102,20 @@ pub struct Service {
     pub spec_ident: PackageIdent,
     pub start_style: StartStyle,
     pub topology: Topology,
+    pub quorum: Option<u32>,
     pub update_strategy: UpdateStrategy,
+    pub restart_policy: RestartPolicy,
+    pub canary_policy: CanaryPolicy,
+    pub rollback_policy: RollbackPolicy,
+    pub resource_limits: ResourceLimits,
+    pub hook_timeouts: HookTimeouts,
+    pub shutdown_policy: ShutdownPolicy,
+    pub log_policy: LogPolicy,
+    pub health_probe: Option<HealthProbe>,
+    pub maintenance_window: Option<MaintenanceWindow>,
+    pub depends_on: Vec<LocalDependency>,
+    /// The composite the service was loaded as part of, if any.
+    pub composite: Option<String>,
     pub cfg: Cfg,
     pub pkg: Pkg,
     pub sys: Arc<Sys>,
@@ -85,6 +124,7 @@ pub struct Service {
     #[serde(skip_serializing)]
     config_renderer: CfgRenderer,
     health_check: HealthCheck,
+    health_restarts: RestartHistory,
     last_election_status: ElectionStatus,
     needs_reload: bool,
     needs_reconfiguration: bool,
@@ -94,10 +134,21 @@ pub struct Service {
     config_from: Option<PathBuf>,
     #[serde(skip_serializing)]
     last_health_check: Instant,
+    #[serde(skip_serializing)]
+    last_update: Option<LastUpdate>,
+    last_rollback: Option<Rollback>,
+    /// Why configuration was rejected for not matching the package's config schema.
+    config_errors: Vec<String>,
     manager_fs_cfg: Arc<manager::FsCfg>,
     #[serde(rename = "process")]
     supervisor: Supervisor,
     svc_encrypted_password: Option<String>,
+    /// The restart count of each dependency as of the last tick, to notice when one restarts.
+    #[serde(skip_serializing)]
+    dependency_restarts: HashMap<String, u64>,
+    /// The dependencies the service was last reported waiting for.
+    #[serde(skip_serializing)]
+    unmet_dependencies: Vec<String>,
 }
 
 impl Service {
@@ -119,17 +170,21 @@ impl Service {
         )?;
         let config_root = Self::config_root(&pkg, spec.config_from.as_ref());
         let hooks_root = Self::hooks_root(&pkg, spec.config_from.as_ref());
-        Ok(Service {
+        let hook_timeouts = spec.hook_timeouts.or(&pkg.hook_timeouts);
+        let mut service = Service {
             sys: sys,
             cfg: Cfg::new(&pkg, spec.config_from.as_ref())?,
             config_renderer: CfgRenderer::new(&config_root)?,
             depot_url: spec.depot_url,
             channel: spec.channel,
             health_check: HealthCheck::default(),
+            health_restarts: RestartHistory::default(),
             hooks: HookTable::load(
                 &service_group,
                 &hooks_root,
                 fs::svc_hooks_path(&service_group.service()),
+                &hook_timeouts,
+                &spec.log_policy,
             ),
             initialized: false,
             last_election_status: ElectionStatus::None,
@@ -145,11 +200,30 @@ impl Service {
             spec_file: spec_file,
             start_style: spec.start_style,
             topology: spec.topology,
+            quorum: spec.quorum,
             update_strategy: spec.update_strategy,
+            restart_policy: spec.restart_policy,
+            canary_policy: spec.canary_policy,
+            rollback_policy: spec.rollback_policy,
+            resource_limits: spec.resource_limits,
+            hook_timeouts: spec.hook_timeouts,
+            shutdown_policy: spec.shutdown_policy,
+            log_policy: spec.log_policy,
+            health_probe: spec.health_probe,
+            maintenance_window: spec.maintenance_window,
+            depends_on: spec.depends_on,
+            composite: spec.composite,
             config_from: spec.config_from,
             last_health_check: Instant::now() - *HEALTH_CHECK_INTERVAL,
+            last_update: None,
+            last_rollback: None,
             svc_encrypted_password: spec.svc_encrypted_password,
-        })
+            config_errors: Vec::new(),
+            dependency_restarts: HashMap::new(),
+            unmet_dependencies: Vec::new(),
+        };
+        service.refresh_config_errors();
+        Ok(service)
     }
 
     /// Returns the config root given the package and optional config-from path.
@@ -230,12 +304,16 @@ impl Service {
     }
 
     fn start(&mut self, launcher: &LauncherCli) {
+        let limits = self.effective_limits();
         if let Some(err) = self.supervisor
             .start(
                 &self.pkg,
                 &self.service_group,
                 launcher,
                 self.svc_encrypted_password.as_ref(),
+                &limits,
+                &self.shutdown_policy,
+                &self.log_policy,
             )
             .err()
         {
@@ -246,21 +324,34 @@ impl Service {
         }
     }
 
+    /// Stop the service's process, running its `pre-stop` hook before and its `post-stop` hook
+    /// after.
     pub fn stop(&mut self, launcher: &LauncherCli) {
-        if let Err(err) = self.supervisor.stop(launcher) {
+        let running = self.process_up();
+        if running {
+            self.pre_stop();
+        }
+        if let Err(err) = self.supervisor.stop(launcher, &self.shutdown_policy) {
             outputln!(preamble self.service_group, "Service stop failed: {}", err);
         }
+        if running {
+            self.post_stop();
+        }
     }
 
     fn reload(&mut self, launcher: &LauncherCli) {
         self.needs_reload = false;
         if self.process_down() || self.hooks.reload.is_none() {
+            let limits = self.effective_limits();
             if let Some(err) = self.supervisor
                 .restart(
                     &self.pkg,
                     &self.service_group,
                     launcher,
                     self.svc_encrypted_password.as_ref(),
+                    &limits,
+                    &self.shutdown_policy,
+                    &self.log_policy,
                 )
                 .err()
             {
@@ -276,28 +367,89 @@ impl Service {
         }
     }
 
+    pub fn health_check(&self) -> HealthCheck {
+        self.health_check
+    }
+
     pub fn last_state_change(&self) -> Timespec {
         self.supervisor.state_entered
     }
 
-    pub fn tick(&mut self, census_ring: &CensusRing, launcher: &LauncherCli) -> bool {
+    pub fn process_up(&self) -> bool {
+        !self.process_down()
+    }
+
+    /// Returns the number of times the service's process was restarted.
+    pub fn restarts(&self) -> u64 {
+        self.supervisor.restarts
+    }
+
+    /// Returns the resource limits to run the service with: those of its spec, falling back to
+    /// the defaults of its package.
+    pub fn effective_limits(&self) -> ResourceLimits {
+        self.resource_limits.or(&self.pkg.svc_limits)
+    }
+
+    /// Returns true if the service may be restarted into an updated package now, which is always
+    /// the case for a service without a maintenance window.
+    pub fn in_maintenance_window(&self) -> bool {
+        self.maintenance_window.as_ref().map_or(
+            true,
+            |window| window.is_open(),
+        )
+    }
+
+    /// Returns the state of the service for the services which depend on it.
+    pub fn dependency_state(&self) -> DependencyState {
+        DependencyState {
+            up: self.process_up(),
+            health: self.health_check,
+            restarts: self.restarts(),
+        }
+    }
+
+    /// Advances the service, given the census and the state of each service on this Supervisor
+    /// by name.
+    ///
+    /// Returns true if the service's rumor should be gossiped.
+    pub fn tick(
+        &mut self,
+        census_ring: &CensusRing,
+        launcher: &LauncherCli,
+        dependencies: &HashMap<String, DependencyState>,
+    ) -> bool {
         if !self.initialized {
             if !self.all_binds_satisfied(census_ring) {
                 outputln!(preamble self.service_group, "Waiting for service binds...");
                 return false;
             }
         }
+        if !self.check_dependencies(dependencies, launcher) {
+            return false;
+        }
 
         let svc_updated = self.update_templates(census_ring);
         if self.update_service_files(census_ring) {
             self.file_updated();
         }
 
-        match self.topology {
-            Topology::Standalone => {
-                self.execute_hooks(launcher);
+        let hooks_changed = match self.topology {
+            Topology::Standalone => self.execute_hooks(launcher),
+            // Replicas which are already running keep running while a new primary is elected
+            Topology::PrimaryReplica if self.initialized && self.electing(census_ring) => {
+                let status = census_ring
+                    .census_group_for(&self.service_group)
+                    .map(|cg| cg.election_status)
+                    .unwrap_or(ElectionStatus::None);
+                if self.last_election_status != status {
+                    outputln!(preamble self.service_group,
+                              "Executing hooks; {}",
+                              Yellow.bold().paint("electing a new primary"));
+                    self.last_election_status = status;
+                }
+                self.execute_hooks(launcher)
             }
-            Topology::Leader => {
+            Topology::Leader | Topology::PrimaryReplica | Topology::Quorum => {
                 let census_group = census_ring.census_group_for(&self.service_group).expect(
                     "Service Group's census entry missing from list!",
                 );
@@ -309,6 +461,7 @@ impl Service {
                                       Yellow.bold().paint("election hasn't started"));
                             self.last_election_status = census_group.election_status;
                         }
+                        false
                     }
                     ElectionStatus::ElectionInProgress => {
                         if self.last_election_status != census_group.election_status {
@@ -317,6 +470,7 @@ impl Service {
                                       Yellow.bold().paint("election in progress."));
                             self.last_election_status = census_group.election_status;
                         }
+                        false
                     }
                     ElectionStatus::ElectionNoQuorum => {
                         if self.last_election_status != census_group.election_status {
@@ -326,6 +480,7 @@ impl Service {
                                       Red.bold().paint("and we have no quorum"));
                             self.last_election_status = census_group.election_status
                         }
+                        false
                     }
                     ElectionStatus::ElectionFinished => {
                         let leader_id = census_group.leader_id.as_ref().expect(
@@ -341,8 +496,8 @@ impl Service {
                     }
                 }
             }
-        }
-        svc_updated
+        };
+        svc_updated || hooks_changed
     }
 
     pub fn to_spec(&self) -> ServiceSpec {
@@ -354,13 +509,33 @@ impl Service {
         spec.depot_url = self.depot_url.clone();
         spec.channel = self.channel.clone();
         spec.topology = self.topology;
+        spec.quorum = self.quorum;
         spec.update_strategy = self.update_strategy;
         spec.binds = self.binds.clone();
+        spec.depends_on = self.depends_on.clone();
+        spec.composite = self.composite.clone();
         spec.start_style = self.start_style;
         spec.config_from = self.config_from.clone();
+        spec.restart_policy = self.restart_policy.clone();
+        spec.canary_policy = self.canary_policy.clone();
+        spec.rollback_policy = self.rollback_policy.clone();
+        spec.resource_limits = self.resource_limits.clone();
+        spec.hook_timeouts = self.hook_timeouts.clone();
+        spec.shutdown_policy = self.shutdown_policy.clone();
+        spec.log_policy = self.log_policy.clone();
+        spec.health_probe = self.health_probe.clone();
+        spec.maintenance_window = self.maintenance_window.clone();
         spec
     }
 
+    /// Returns true if the service group's election hasn't finished.
+    fn electing(&self, census_ring: &CensusRing) -> bool {
+        match census_ring.census_group_for(&self.service_group) {
+            Some(census_group) => census_group.election_status != ElectionStatus::ElectionFinished,
+            None => true,
+        }
+    }
+
     fn all_binds_satisfied(&self, census_ring: &CensusRing) -> bool {
         let mut ret = true;
         for ref bind in self.binds.iter() {
@@ -376,6 +551,76 @@ impl Service {
         ret
     }
 
+    /// Keeps the service's process from starting until its local dependencies are ready, stopping
+    /// it when a dependency goes down, and restarts it when a dependency was restarted while it
+    /// ran. A running service isn't stopped because a dependency stopped passing its health
+    /// check.
+    ///
+    /// Returns false if the service is waiting for its dependencies.
+    fn check_dependencies(
+        &mut self,
+        dependencies: &HashMap<String, DependencyState>,
+        launcher: &LauncherCli,
+    ) -> bool {
+        let mut restarted = false;
+        let mut down = false;
+        let mut unmet = vec![];
+        for dependency in self.depends_on.iter() {
+            let state = dependencies.get(&dependency.name);
+            if state.is_none() {
+                unmet.push(format!("{} (not loaded on this Supervisor)", dependency));
+            } else if !dependency.is_met(state) {
+                unmet.push(dependency.to_string());
+            }
+            match state {
+                Some(state) => {
+                    down = down || !state.up;
+                    let last = self.dependency_restarts.insert(
+                        dependency.name.clone(),
+                        state.restarts,
+                    );
+                    restarted = restarted || last.map_or(false, |last| state.restarts > last);
+                }
+                None => down = true,
+            }
+        }
+        if unmet != self.unmet_dependencies {
+            if !unmet.is_empty() {
+                outputln!(preamble self.service_group,
+                          "Waiting for local dependencies: {}",
+                          unmet.join(", "));
+            }
+            self.unmet_dependencies = unmet;
+        }
+        if !self.initialized || self.process_down() {
+            return self.unmet_dependencies.is_empty();
+        }
+        if down {
+            outputln!(preamble self.service_group,
+                      "Stopping service until its local dependencies are up");
+            self.stop(launcher);
+            return false;
+        }
+        if restarted {
+            outputln!(preamble self.service_group,
+                      "Restarting service after a local dependency restarted");
+            let limits = self.effective_limits();
+            if let Err(err) = self.supervisor.restart(
+                &self.pkg,
+                &self.service_group,
+                launcher,
+                self.svc_encrypted_password.as_ref(),
+                &limits,
+                &self.shutdown_policy,
+                &self.log_policy,
+            )
+            {
+                outputln!(preamble self.service_group, "Service restart failed: {}", err);
+            }
+        }
+        true
+    }
+
     /// Updates the process state of the service's supervisor
     fn check_process(&mut self) -> bool {
         self.supervisor.check_process()
@@ -385,6 +630,15 @@ impl Service {
         self.supervisor.state == ProcessState::Down
     }
 
+    /// Refreshes the config errors shown in the service's status, reporting any new ones.
+    fn refresh_config_errors(&mut self) {
+        let errors = self.cfg.errors();
+        for error in errors.iter().filter(|e| !self.config_errors.contains(e)) {
+            outputln!(preamble self.service_group, "Config error, {}", error);
+        }
+        self.config_errors = errors;
+    }
+
     /// Compares the current state of the service to the current state of the census ring and
     /// re-renders all templatable content to disk.
     ///
@@ -395,6 +649,7 @@ impl Service {
         );
         let cfg_updated = self.cfg.update(census_group);
         if cfg_updated || census_ring.changed {
+            self.refresh_config_errors();
             let (reload, reconfigure) = {
                 let ctx = self.render_context(census_ring);
                 let reload = self.compile_hooks(&ctx);
@@ -409,6 +664,7 @@ impl Service {
 
     /// Replace the package of the running service and restart it's system process.
     pub fn update_package(&mut self, package: PackageInstall, launcher: &LauncherCli) {
+        let running;
         match Pkg::from_install(package) {
             Ok(pkg) => {
                 outputln!(preamble self.service_group,
@@ -421,12 +677,22 @@ impl Service {
                         return;
                     }
                 }
+                // Drain the running release with its own hook before its hooks are replaced
+                running = self.process_up();
+                if running {
+                    self.pre_stop();
+                }
                 self.hooks = HookTable::load(
                     &self.service_group,
                     &Self::hooks_root(&pkg, self.config_from.as_ref()),
                     fs::svc_hooks_path(self.service_group.service()),
+                    &self.hook_timeouts.or(&pkg.hook_timeouts),
+                    &self.log_policy,
                 );
+                self.last_update = Some(LastUpdate::new(self.pkg.ident.clone()));
                 self.pkg = pkg;
+                // Health of the previous release says nothing about the new one
+                self.health_check = HealthCheck::default();
             }
             Err(err) => {
                 outputln!(preamble self.service_group,
@@ -434,13 +700,69 @@ impl Service {
                 return;
             }
         }
-        if let Err(err) = self.supervisor.stop(launcher) {
+        if let Err(err) = self.supervisor.stop(launcher, &self.shutdown_policy) {
             outputln!(preamble self.service_group,
                       "Error stopping process while updating package: {}", err);
         }
+        if running {
+            self.post_stop();
+        }
         self.initialized = false;
     }
 
+    /// Return the service to the release it ran before its last package update, if its rollback
+    /// policy is enabled.
+    ///
+    /// Returns true if the service was rolled back.
+    pub fn roll_back(&mut self, reason: RollbackReason, launcher: &LauncherCli) -> bool {
+        if !self.rollback_policy.enabled {
+            return false;
+        }
+        self.revert_update(reason, launcher)
+    }
+
+    /// Return the service to the release it ran before its last package update, regardless of
+    /// its rollback policy. The canary strategy reverts unhealthy canaries this way, as doing so
+    /// is part of the strategy itself.
+    ///
+    /// Returns true if the service was rolled back.
+    pub fn revert_update(&mut self, reason: RollbackReason, launcher: &LauncherCli) -> bool {
+        let previous = match self.last_update.take() {
+            Some(update) => update.previous,
+            None => return false,
+        };
+        let failed = self.pkg.ident.clone();
+        outputln!(preamble self.service_group,
+                  "Update to {} failed, {}; rolling back to {}",
+                  failed,
+                  Red.bold().paint(reason.to_string()),
+                  previous);
+        match PackageInstall::load(&previous, Some(&*FS_ROOT_PATH)) {
+            Ok(package) => {
+                self.update_package(package, launcher);
+                // Rolling back is not an update we would want to roll back again
+                self.last_update = None;
+                self.last_rollback = Some(Rollback {
+                    from: failed,
+                    to: previous,
+                    reason: reason,
+                    time: time::get_time().sec,
+                });
+                true
+            }
+            Err(err) => {
+                outputln!(preamble self.service_group,
+                          "Unable to load {} for rollback, {}", previous, err);
+                false
+            }
+        }
+    }
+
+    /// Returns the release the service was last rolled back from, if any.
+    pub fn failed_update(&self) -> Option<&PackageIdent> {
+        self.last_rollback.as_ref().map(|rollback| &rollback.from)
+    }
+
     pub fn to_rumor(&self, incarnation: u64) -> ServiceRumor {
         let exported = match self.cfg.to_exported(&self.pkg) {
             Ok(exported) => Some(exported),
@@ -459,6 +781,11 @@ impl Service {
             exported.as_ref(),
         );
         rumor.set_incarnation(incarnation);
+        rumor.set_health(self.health_check as i32);
+        rumor.set_health_restarts(self.health_restarts.count);
+        if let Some(last) = self.health_restarts.last {
+            rumor.set_last_health_restart(last);
+        }
         rumor
     }
 
@@ -501,6 +828,26 @@ impl Service {
         }
     }
 
+    fn pre_stop(&self) {
+        if let Some(ref hook) = self.hooks.pre_stop {
+            hook.run(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.as_ref(),
+            );
+        }
+    }
+
+    fn post_stop(&self) {
+        if let Some(ref hook) = self.hooks.post_stop {
+            hook.run(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.as_ref(),
+            );
+        }
+    }
+
     pub fn suitability(&self) -> Option<u64> {
         if !self.initialized {
             return None;
@@ -616,22 +963,53 @@ impl Service {
         Ok(())
     }
 
-    fn execute_hooks(&mut self, launcher: &LauncherCli) {
+    /// Runs the hooks appropriate to the service's current state.
+    ///
+    /// Returns true if the service's health changed, or the service was restarted because of its
+    /// restart policy or rolled back after a failed update, any of which should be gossiped.
+    fn execute_hooks(&mut self, launcher: &LauncherCli) -> bool {
+        let mut changed = false;
         if !self.initialized {
             if self.check_process() {
                 outputln!("Reattached to {}", self.service_group);
                 self.initialized = true;
-                return;
+                return false;
             }
             self.initialize();
             if self.initialized {
                 self.start(launcher);
                 self.post_run();
+            } else if self.update_in_grace() {
+                return self.roll_back(RollbackReason::InitFailed, launcher);
             }
         } else {
+            let was_up = !self.process_down();
             self.check_process();
-            if Instant::now().duration_since(self.last_health_check) >= *HEALTH_CHECK_INTERVAL {
-                self.run_health_check_hook();
+            if was_up && self.process_down() && self.rollback_policy.enabled {
+                let failed = match self.last_update {
+                    Some(ref mut update) => {
+                        if update.record_exit(&self.rollback_policy) {
+                            Some(update.exits())
+                        } else {
+                            None
+                        }
+                    }
+                    None => None,
+                };
+                if let Some(exits) = failed {
+                    return self.roll_back(RollbackReason::Exited(exits), launcher);
+                }
+            }
+            if Instant::now().duration_since(self.last_health_check) >=
+                self.health_check_interval()
+            {
+                let last_result = self.health_check;
+                let check_result = self.run_health_check_hook();
+                if check_result == HealthCheck::Critical && self.update_in_grace() {
+                    return self.roll_back(RollbackReason::Unhealthy, launcher);
+                }
+                let restarted = self.enforce_restart_policy(check_result, launcher);
+                changed = restarted || check_result != last_result;
             }
 
             // NOTE: if you need reconfiguration and you DON'T have a
@@ -643,6 +1021,70 @@ impl Service {
                 }
             }
         }
+        changed
+    }
+
+    /// Returns true if the last package update is recent enough for failures to be blamed on it,
+    /// and rolling back is enabled.
+    fn update_in_grace(&self) -> bool {
+        if !self.rollback_policy.enabled {
+            return false;
+        }
+        match self.last_update {
+            Some(ref update) => update.in_grace(&self.rollback_policy),
+            None => false,
+        }
+    }
+
+    /// Restarts the service if its health check has been critical for as long as its restart
+    /// policy tolerates.
+    ///
+    /// Returns true if the service was restarted.
+    fn enforce_restart_policy(
+        &mut self,
+        check_result: HealthCheck,
+        launcher: &LauncherCli,
+    ) -> bool {
+        let now = time::get_time().sec;
+        match self.health_restarts.check(&self.restart_policy, check_result, now) {
+            HealthRestart::None => false,
+            HealthRestart::Backoff(remaining) => {
+                outputln!(preamble self.service_group,
+                          "Health check {}; next restart allowed in {}s",
+                          Red.bold().paint(check_result.to_string()),
+                          remaining);
+                false
+            }
+            HealthRestart::Exhausted => {
+                outputln!(preamble self.service_group,
+                          "Health check {}; restart limit of {} within {}s reached",
+                          Red.bold().paint(check_result.to_string()),
+                          self.restart_policy.max_restarts,
+                          self.restart_policy.window);
+                false
+            }
+            HealthRestart::Restart => {
+                outputln!(preamble self.service_group,
+                          "Restarting service after {} consecutive {} health checks",
+                          self.health_restarts.consecutive_critical,
+                          Red.bold().paint(check_result.to_string()));
+                let limits = self.effective_limits();
+                if let Err(err) = self.supervisor.restart(
+                    &self.pkg,
+                    &self.service_group,
+                    launcher,
+                    self.svc_encrypted_password.as_ref(),
+                    &limits,
+                    &self.shutdown_policy,
+                    &self.log_policy,
+                )
+                {
+                    outputln!(preamble self.service_group, "Service restart failed: {}", err);
+                }
+                self.health_restarts.record(now);
+                true
+            }
+        }
     }
 
     /// Run file_updated hook if present
@@ -668,7 +1110,13 @@ impl Service {
         );
         let mut updated = false;
         for service_file in census_group.changed_service_files() {
-            if self.cache_service_file(&service_file) {
+            if service_file.removed {
+                if self.remove_service_file(&service_file) {
+                    outputln!(preamble self.service_group, "Service file removed, {}",
+                        service_file.filename);
+                    updated = true;
+                }
+            } else if self.cache_service_file(&service_file) {
                 outputln!(preamble self.service_group, "Service file updated, {}",
                     service_file.filename);
                 updated = true;
@@ -705,8 +1153,28 @@ impl Service {
         )
     }
 
-    fn run_health_check_hook(&mut self) {
-        let check_result = if let Some(ref hook) = self.hooks.health_check {
+    /// The health probe of the service's spec, or else of its package's plan.
+    fn effective_health_probe(&self) -> Option<&HealthProbe> {
+        self.health_probe.as_ref().or(self.pkg.health_probe.as_ref())
+    }
+
+    fn health_check_interval(&self) -> Duration {
+        match self.effective_health_probe() {
+            Some(probe) => probe.interval(),
+            None => *HEALTH_CHECK_INTERVAL,
+        }
+    }
+
+    /// Check the health of the service with its health probe if it has one, or else its
+    /// `health_check` hook, or else by whether its process is running.
+    fn run_health_check_hook(&mut self) -> HealthCheck {
+        let check_result = if let Some(probe) = self.effective_health_probe() {
+            probe.run(
+                &self.service_group,
+                &self.pkg,
+                self.svc_encrypted_password.as_ref(),
+            )
+        } else if let Some(ref hook) = self.hooks.health_check {
             hook.run(
                 &self.service_group,
                 &self.pkg,
@@ -719,7 +1187,9 @@ impl Service {
             }
         };
         self.last_health_check = Instant::now();
+        self.health_check = check_result;
         self.cache_health_check(check_result);
+        check_result
     }
 
     fn cache_service_file(&mut self, service_file: &ServiceFile) -> bool {
@@ -727,6 +1197,20 @@ impl Service {
         self.write_cache_file(file, &service_file.body)
     }
 
+    fn remove_service_file(&mut self, service_file: &ServiceFile) -> bool {
+        let file = self.pkg.svc_files_path.join(&service_file.filename);
+        if !file.exists() {
+            return false;
+        }
+        if let Err(e) = std::fs::remove_file(&file) {
+            outputln!(preamble self.service_group,
+                      "Failed to remove service file {}",
+                      Red.bold().paint(format!("{}, {}", file.display(), e)));
+            return false;
+        }
+        true
+    }
+
     fn write_cache_file<T>(&self, file: T, contents: &[u8]) -> bool
     where
         T: AsRef<Path>,
@@ -792,15 +1276,28 @@ impl fmt::Display for Service {
 pub enum Topology {
     Standalone,
     Leader,
+    PrimaryReplica,
+    Quorum,
 }
 
 impl Topology {
     fn as_str(&self) -> &str {
         match *self {
             Topology::Leader => "leader",
+            Topology::PrimaryReplica => "primary-replica",
+            Topology::Quorum => "quorum",
             Topology::Standalone => "standalone",
         }
     }
+
+    /// Returns true if the members of a service group with this topology hold an election before
+    /// running their hooks.
+    pub fn elects_leader(&self) -> bool {
+        match *self {
+            Topology::Leader | Topology::PrimaryReplica | Topology::Quorum => true,
+            Topology::Standalone => false,
+        }
+    }
 }
 
 impl FromStr for Topology {
@@ -809,6 +1306,8 @@ impl FromStr for Topology {
     fn from_str(topology: &str) -> result::Result<Self, Self::Err> {
         match topology {
             "leader" => Ok(Topology::Leader),
+            "primary-replica" => Ok(Topology::PrimaryReplica),
+            "quorum" => Ok(Topology::Quorum),
             "standalone" => Ok(Topology::Standalone),
             _ => Err(sup_error!(Error::InvalidTopology(String::from(topology)))),
         }
@@ -850,6 +1349,7 @@ pub enum UpdateStrategy {
     None,
     AtOnce,
     Rolling,
+    Canary,
 }
 
 impl UpdateStrategy {
@@ -858,6 +1358,7 @@ impl UpdateStrategy {
             UpdateStrategy::None => "none",
             UpdateStrategy::AtOnce => "at-once",
             UpdateStrategy::Rolling => "rolling",
+            UpdateStrategy::Canary => "canary",
         }
     }
 }
@@ -870,6 +1371,7 @@ impl FromStr for UpdateStrategy {
             "none" => Ok(UpdateStrategy::None),
             "at-once" => Ok(UpdateStrategy::AtOnce),
             "rolling" => Ok(UpdateStrategy::Rolling),
+            "canary" => Ok(UpdateStrategy::Canary),
             _ => Err(sup_error!(
                 Error::InvalidUpdateStrategy(String::from(strategy))
             )),
@@ -931,6 +1433,23 @@ mod test {
         assert_eq!(topology, Topology::Leader);
     }
 
+    #[test]
+    fn topology_from_str_primary_replica_and_quorum() {
+        assert_eq!(
+            Topology::from_str("primary-replica").unwrap(),
+            Topology::PrimaryReplica
+        );
+        assert_eq!(Topology::from_str("quorum").unwrap(), Topology::Quorum);
+    }
+
+    #[test]
+    fn topology_elects_leader() {
+        assert!(!Topology::Standalone.elects_leader());
+        assert!(Topology::Leader.elects_leader());
+        assert!(Topology::PrimaryReplica.elects_leader());
+        assert!(Topology::Quorum.elects_leader());
+    }
+
     #[test]
     fn topology_from_str_invalid() {
         let topology_str = "dope";
@@ -995,6 +1514,14 @@ mod test {
         assert_eq!(strategy, UpdateStrategy::AtOnce);
     }
 
+    #[test]
+    fn update_strategy_from_str_canary() {
+        let strategy = UpdateStrategy::from_str("canary").unwrap();
+
+        assert_eq!(strategy, UpdateStrategy::Canary);
+        assert_eq!("canary", strategy.to_string());
+    }
+
     #[test]
     fn update_strategy_from_str_invalid() {
         let strategy_str = "dope";
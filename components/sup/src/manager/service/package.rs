@@ -21,6 +21,7 @@ use hcore::fs::FS_ROOT_PATH;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
 
+use super::{HealthProbe, HookTimeouts, ResourceLimits};
 use error::{Error, Result};
 use fs;
 use util;
@@ -101,6 +102,15 @@ pub struct Pkg {
     pub svc_run: PathBuf,
     pub svc_user: String,
     pub svc_group: String,
+    /// Default resource limits declared by the package's plan.
+    #[serde(default)]
+    pub svc_limits: ResourceLimits,
+    /// Default hook timeouts declared by the package's plan.
+    #[serde(default)]
+    pub hook_timeouts: HookTimeouts,
+    /// Default health probe declared by the package's plan.
+    #[serde(default)]
+    pub health_probe: Option<HealthProbe>,
 }
 
 impl Pkg {
@@ -127,6 +137,15 @@ impl Pkg {
             exports: package.exports().map_err(|e| {
                 sup_error!(Error::BadPackage(package.clone(), e))
             })?,
+            svc_limits: ResourceLimits::from_metadata(&package.svc_limits().map_err(|e| {
+                sup_error!(Error::BadPackage(package.clone(), e))
+            })?)?,
+            hook_timeouts: HookTimeouts::from_metadata(&package.hook_timeouts().map_err(|e| {
+                sup_error!(Error::BadPackage(package.clone(), e))
+            })?)?,
+            health_probe: HealthProbe::from_metadata(&package.health_probe().map_err(|e| {
+                sup_error!(Error::BadPackage(package.clone(), e))
+            })?)?,
             path: package.installed_path,
             ident: package.ident.clone(),
             origin: package.ident.origin.clone(),
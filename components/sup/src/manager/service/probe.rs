@@ -0,0 +1,452 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Health checks which the Supervisor runs itself, so that a package does not need a
+//! `health_check` hook to report its health.
+//!
+//! A probe either requests an HTTP endpoint, opens a TCP connection, or runs an executable whose
+//! exit code is read like that of the `health_check` hook. Its result is cached and gossiped
+//! exactly like the hook's.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::result;
+use std::thread;
+use std::time::Duration;
+
+use hcore::service::ServiceGroup;
+use url::Url;
+
+use super::{HealthCheck, Pkg};
+use error::{Error, Result, SupError};
+use util::exec;
+
+static LOGKEY: &'static str = "HP";
+/// Seconds between checks when a probe does not set its own interval.
+pub const DEFAULT_PROBE_INTERVAL: u64 = 30;
+/// Seconds a check may take when a probe does not set its own timeout.
+pub const DEFAULT_PROBE_TIMEOUT: u64 = 5;
+/// Most bytes of an HTTP response which are read.
+const MAX_RESPONSE_BYTES: u64 = 64 * 1024;
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct HealthProbe {
+    /// URL of an endpoint to `GET`, which must use the `http` scheme.
+    pub http: Option<String>,
+    /// Address, as `host:port`, to open a TCP connection to.
+    pub tcp: Option<String>,
+    /// Executable to run as the service's user, relative to the package's path unless absolute.
+    pub exec: Option<String>,
+    /// Status the HTTP endpoint must answer with. Any 2xx status when not set.
+    pub status: Option<u16>,
+    /// Text the body of the HTTP response must contain.
+    pub body: Option<String>,
+    /// Seconds between checks.
+    pub interval: Option<u64>,
+    /// Seconds a check may take before the service is considered critical.
+    pub timeout: Option<u64>,
+}
+
+impl HealthProbe {
+    /// Parses the `HEALTH_PROBE` metadata of a package, which has no probe if it is empty.
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Result<Option<Self>> {
+        if metadata.is_empty() {
+            return Ok(None);
+        }
+        let mut probe = HealthProbe::default();
+        for (key, value) in metadata.iter() {
+            let value = value.trim().to_string();
+            match key.as_str() {
+                "http" => probe.http = Some(value),
+                "tcp" => probe.tcp = Some(value),
+                "exec" => probe.exec = Some(value),
+                "status" => probe.status = Some(parse_status(key, &value)?),
+                "body" => probe.body = Some(value),
+                "interval" => probe.interval = Some(parse_number(key, &value)?),
+                "timeout" => probe.timeout = Some(parse_number(key, &value)?),
+                _ => return Err(invalid(format!("unknown setting '{}'", key))),
+            }
+        }
+        probe.validate()?;
+        Ok(Some(probe))
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval.unwrap_or(DEFAULT_PROBE_INTERVAL))
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout.unwrap_or(DEFAULT_PROBE_TIMEOUT))
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let kinds = [&self.http, &self.tcp, &self.exec];
+        if kinds.iter().filter(|k| k.is_some()).count() != 1 {
+            return Err(invalid(
+                "exactly one of http, tcp or exec is required".to_string(),
+            ));
+        }
+        if let Some(ref url) = self.http {
+            let url = Url::parse(url).map_err(|e| {
+                invalid(format!("'{}' is not a valid URL, {}", url, e))
+            })?;
+            if url.scheme() != "http" || url.host_str().is_none() {
+                return Err(invalid(format!("'{}' is not an http URL", url)));
+            }
+            if let Some(status) = self.status {
+                if !valid_status(status as u64) {
+                    return Err(invalid(format!("{} is not an HTTP status", status)));
+                }
+            }
+        } else if self.status.is_some() || self.body.is_some() {
+            return Err(invalid(
+                "status and body only apply to http probes".to_string(),
+            ));
+        }
+        if let Some(ref address) = self.tcp {
+            if address.rfind(':').is_none() {
+                return Err(invalid(
+                    format!("'{}' is not an address as host:port", address),
+                ));
+            }
+        }
+        if self.interval == Some(0) || self.timeout == Some(0) {
+            return Err(invalid(
+                "interval and timeout must be at least 1 second".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Run the probe once and return the health it found.
+    pub fn run<T>(
+        &self,
+        service_group: &ServiceGroup,
+        pkg: &Pkg,
+        svc_encrypted_password: Option<T>,
+    ) -> HealthCheck
+    where
+        T: ToString,
+    {
+        let result = if let Some(ref url) = self.http {
+            self.check_http(url)
+        } else if let Some(ref address) = self.tcp {
+            check_tcp(address, self.timeout())
+        } else if let Some(ref path) = self.exec {
+            self.check_exec(path, pkg, svc_encrypted_password)
+        } else {
+            Err("no probe configured".to_string())
+        };
+        match result {
+            Ok(health) => health,
+            Err(reason) => {
+                outputln!(preamble service_group, "Health probe {} failed, {}", self, reason);
+                HealthCheck::Critical
+            }
+        }
+    }
+
+    fn check_http(&self, url: &str) -> result::Result<HealthCheck, String> {
+        let (status, body) = http_get(url, self.timeout()).map_err(|e| e.to_string())?;
+        let status_ok = match self.status {
+            Some(expected) => status == expected,
+            None => status >= 200 && status < 300,
+        };
+        if !status_ok {
+            return Err(format!("unexpected status {}", status));
+        }
+        if let Some(ref expected) = self.body {
+            if !body.contains(expected.as_str()) {
+                return Err(format!("response body does not contain '{}'", expected));
+            }
+        }
+        Ok(HealthCheck::Ok)
+    }
+
+    fn check_exec<T>(
+        &self,
+        path: &str,
+        pkg: &Pkg,
+        svc_encrypted_password: Option<T>,
+    ) -> result::Result<HealthCheck, String>
+    where
+        T: ToString,
+    {
+        let path = if Path::new(path).is_absolute() {
+            Path::new(path).to_path_buf()
+        } else {
+            pkg.path.join(path)
+        };
+        let mut child = exec::run(&path, pkg, svc_encrypted_password).map_err(
+            |e| e.to_string(),
+        )?;
+        drain(child.stdout.take());
+        drain(child.stderr.take());
+        match exec::wait_timeout(&mut child, self.timeout()) {
+            Ok(Some(status)) => {
+                match status.code() {
+                    Some(0) => Ok(HealthCheck::Ok),
+                    Some(1) => Ok(HealthCheck::Warning),
+                    Some(2) => Ok(HealthCheck::Critical),
+                    Some(3) => Ok(HealthCheck::Unknown),
+                    Some(code) => Err(format!("exited with status code {}", code)),
+                    None => Err("exited without a status code".to_string()),
+                }
+            }
+            Ok(None) => {
+                exec::kill_tree(&mut child).ok();
+                Err(format!("timed out after {}s", self.timeout().as_secs()))
+            }
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for HealthProbe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref url) = self.http {
+            write!(f, "http {}", url)
+        } else if let Some(ref address) = self.tcp {
+            write!(f, "tcp {}", address)
+        } else if let Some(ref path) = self.exec {
+            write!(f, "exec {}", path)
+        } else {
+            write!(f, "none")
+        }
+    }
+}
+
+fn check_tcp(address: &str, timeout: Duration) -> result::Result<HealthCheck, String> {
+    connect(address, timeout).map_err(|e| e.to_string())?;
+    Ok(HealthCheck::Ok)
+}
+
+fn connect(address: &str, timeout: Duration) -> io::Result<TcpStream> {
+    let mut last_err = io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("{} did not resolve to any address", address),
+    );
+    for addr in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(err) => last_err = err,
+        }
+    }
+    Err(last_err)
+}
+
+/// Request a URL with HTTP/1.0, which keeps the response free of chunked encoding, and return
+/// its status and body.
+fn http_get(url: &str, timeout: Duration) -> io::Result<(u16, String)> {
+    let url = Url::parse(url).map_err(|e| {
+        io::Error::new(io::ErrorKind::InvalidInput, e.to_string())
+    })?;
+    let host = url.host_str().unwrap_or("localhost");
+    let port = url.port_or_known_default().unwrap_or(80);
+    let mut stream = connect(&format!("{}:{}", host, port), timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+    write!(
+        stream,
+        "GET {} HTTP/1.0\r\nHost: {}:{}\r\nUser-Agent: hab-sup\r\nConnection: close\r\n\r\n",
+        path,
+        host,
+        port
+    )?;
+    let mut response = Vec::new();
+    stream.take(MAX_RESPONSE_BYTES).read_to_end(&mut response)?;
+    parse_response(&response)
+}
+
+fn parse_response(response: &[u8]) -> io::Result<(u16, String)> {
+    let text = String::from_utf8_lossy(response);
+    let mut parts = text.splitn(2, "\r\n\r\n");
+    let head = parts.next().unwrap_or("");
+    let body = parts.next().unwrap_or("").to_string();
+    let status = head.lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "response has no HTTP status line",
+        ))?;
+    Ok((status, body))
+}
+
+/// Discard a child's output in the background, so that it never blocks on a full pipe.
+fn drain<R>(output: Option<R>)
+where
+    R: Read + Send + 'static,
+{
+    if let Some(mut output) = output {
+        thread::Builder::new()
+            .name("health-probe-output".to_string())
+            .spawn(move || io::copy(&mut output, &mut io::sink()))
+            .ok();
+    }
+}
+
+fn parse_number(key: &str, value: &str) -> Result<u64> {
+    value.parse().map_err(|_| {
+        invalid(format!("{} must be a number, got '{}'", key, value))
+    })
+}
+
+/// Parses an HTTP status, which is checked before narrowing it so that a large number is not
+/// truncated into a valid status.
+fn parse_status(key: &str, value: &str) -> Result<u16> {
+    let status = parse_number(key, value)?;
+    if valid_status(status) {
+        Ok(status as u16)
+    } else {
+        Err(invalid(format!("{} must be from 100 to 599, got {}", key, status)))
+    }
+}
+
+fn valid_status(status: u64) -> bool {
+    status >= 100 && status <= 599
+}
+
+fn invalid(reason: String) -> SupError {
+    sup_error!(Error::InvalidHealthProbe(reason))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    fn probe(http: &str) -> HealthProbe {
+        HealthProbe {
+            http: Some(http.to_string()),
+            timeout: Some(2),
+            ..HealthProbe::default()
+        }
+    }
+
+    /// Serve a single HTTP request with the given response.
+    fn serve(response: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            stream.read(&mut request).unwrap();
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        format!("http://{}/health?full=true", address)
+    }
+
+    #[test]
+    fn from_metadata() {
+        let mut metadata = HashMap::new();
+        assert_eq!(HealthProbe::from_metadata(&metadata).unwrap(), None);
+        metadata.insert("tcp".to_string(), "localhost:6379".to_string());
+        metadata.insert("interval".to_string(), "10".to_string());
+        let probe = HealthProbe::from_metadata(&metadata).unwrap().unwrap();
+        assert_eq!(probe.tcp, Some("localhost:6379".to_string()));
+        assert_eq!(probe.interval(), Duration::from_secs(10));
+        assert_eq!(probe.timeout(), Duration::from_secs(DEFAULT_PROBE_TIMEOUT));
+
+        metadata.insert("method".to_string(), "POST".to_string());
+        assert!(HealthProbe::from_metadata(&metadata).is_err());
+    }
+
+    #[test]
+    fn from_metadata_rejects_out_of_range_status() {
+        let mut metadata = HashMap::new();
+        metadata.insert("http".to_string(), "http://localhost/health".to_string());
+        metadata.insert("status".to_string(), "204".to_string());
+        let probe = HealthProbe::from_metadata(&metadata).unwrap().unwrap();
+        assert_eq!(probe.status, Some(204));
+
+        // 65736 would wrap around to a status of 200 if truncated.
+        for status in &["65736", "99", "600"] {
+            metadata.insert("status".to_string(), status.to_string());
+            assert!(HealthProbe::from_metadata(&metadata).is_err());
+        }
+    }
+
+    #[test]
+    fn validate_requires_exactly_one_kind() {
+        assert!(HealthProbe::default().validate().is_err());
+        let mut probe = probe("http://localhost:9631/health");
+        assert!(probe.validate().is_ok());
+        probe.tcp = Some("localhost:9631".to_string());
+        assert!(probe.validate().is_err());
+        probe.http = None;
+        probe.status = Some(200);
+        assert!(probe.validate().is_err());
+        probe.status = None;
+        assert!(probe.validate().is_ok());
+        probe.tcp = Some("localhost".to_string());
+        assert!(probe.validate().is_err());
+        assert!(self::probe("https://localhost/health").validate().is_err());
+        assert!(self::probe("localhost:9631").validate().is_err());
+    }
+
+    #[test]
+    fn parse_response_reads_status_and_body() {
+        let (status, body) = parse_response(b"HTTP/1.0 503 Unavailable\r\nA: b\r\n\r\ndown")
+            .unwrap();
+        assert_eq!(status, 503);
+        assert_eq!(body, "down");
+        assert!(parse_response(b"garbage").is_err());
+    }
+
+    #[test]
+    fn http_probe_checks_status_and_body() {
+        let url = serve("HTTP/1.0 200 OK\r\n\r\n{\"status\": \"green\"}");
+        let mut check = probe(&url);
+        check.body = Some("green".to_string());
+        assert_eq!(check.check_http(&url), Ok(HealthCheck::Ok));
+
+        let url = serve("HTTP/1.0 200 OK\r\n\r\n{\"status\": \"red\"}");
+        assert!(check.check_http(&url).is_err());
+
+        let url = serve("HTTP/1.0 500 Internal Server Error\r\n\r\n");
+        assert!(probe(&url).check_http(&url).is_err());
+
+        let url = serve("HTTP/1.0 404 Not Found\r\n\r\n");
+        let mut check = probe(&url);
+        check.status = Some(404);
+        assert_eq!(check.check_http(&url), Ok(HealthCheck::Ok));
+    }
+
+    #[test]
+    fn tcp_probe_requires_a_listener() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        assert_eq!(
+            check_tcp(&address, Duration::from_secs(1)),
+            Ok(HealthCheck::Ok)
+        );
+        drop(listener);
+        assert!(check_tcp(&address, Duration::from_secs(1)).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracking of package updates so that a service can return to its last known-good release.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use hcore::package::PackageIdent;
+use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
+
+use super::spec::RollbackPolicy;
+
+/// The release a service ran before its most recent package update.
+#[derive(Debug)]
+pub struct LastUpdate {
+    pub previous: PackageIdent,
+    updated_at: Instant,
+    exits: u32,
+}
+
+impl LastUpdate {
+    pub fn new(previous: PackageIdent) -> Self {
+        LastUpdate {
+            previous: previous,
+            updated_at: Instant::now(),
+            exits: 0,
+        }
+    }
+
+    /// Returns true if the update happened recently enough that a failure of the service should
+    /// be blamed on it.
+    pub fn in_grace(&self, policy: &RollbackPolicy) -> bool {
+        policy.enabled && self.updated_at.elapsed() < Duration::from_secs(policy.grace)
+    }
+
+    /// Records an unexpected exit of the service's process and returns true if the process
+    /// exited often enough within the grace period to consider the update failed.
+    pub fn record_exit(&mut self, policy: &RollbackPolicy) -> bool {
+        if !self.in_grace(policy) {
+            return false;
+        }
+        self.exits += 1;
+        self.exits >= policy.max_exits
+    }
+
+    pub fn exits(&self) -> u32 {
+        self.exits
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum RollbackReason {
+    /// The new release's init hook failed.
+    InitFailed,
+    /// The new release's process kept exiting.
+    Exited(u32),
+    /// The new release's health check reported `Critical`.
+    Unhealthy,
+}
+
+impl fmt::Display for RollbackReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RollbackReason::InitFailed => write!(f, "init hook failed"),
+            RollbackReason::Exited(count) => write!(f, "process exited {} times", count),
+            RollbackReason::Unhealthy => write!(f, "health check critical"),
+        }
+    }
+}
+
+/// A rollback from a failed release to the release which ran before it.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Rollback {
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub from: PackageIdent,
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub to: PackageIdent,
+    pub reason: RollbackReason,
+    /// Time of the rollback in seconds since the epoch.
+    pub time: i64,
+}
+
+impl fmt::Display for Rollback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "rolled back from {} to {} ({})",
+            self.from,
+            self.to,
+            self.reason
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use serde_json;
+
+    use super::*;
+
+    fn policy() -> RollbackPolicy {
+        RollbackPolicy {
+            enabled: true,
+            grace: 300,
+            max_exits: 3,
+        }
+    }
+
+    fn last_update() -> LastUpdate {
+        LastUpdate::new(PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap())
+    }
+
+    #[test]
+    fn last_update_in_grace() {
+        let update = last_update();
+
+        assert!(update.in_grace(&policy()));
+        assert!(!update.in_grace(&RollbackPolicy {
+            grace: 0,
+            ..policy()
+        }));
+        assert!(!update.in_grace(&RollbackPolicy {
+            enabled: false,
+            ..policy()
+        }));
+    }
+
+    #[test]
+    fn last_update_fails_after_max_exits() {
+        let mut update = last_update();
+        let policy = policy();
+
+        assert!(!update.record_exit(&policy));
+        assert!(!update.record_exit(&policy));
+        assert!(update.record_exit(&policy));
+        assert_eq!(update.exits(), 3);
+    }
+
+    #[test]
+    fn last_update_ignores_exits_when_disabled() {
+        let mut update = last_update();
+        let policy = RollbackPolicy {
+            enabled: false,
+            ..policy()
+        };
+
+        for _ in 0..5 {
+            assert!(!update.record_exit(&policy));
+        }
+        assert_eq!(update.exits(), 0);
+    }
+
+    #[test]
+    fn rollback_json_round_trip() {
+        let rollback = Rollback {
+            from: PackageIdent::from_str("core/redis/3.2.5/20170601150022").unwrap(),
+            to: PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap(),
+            reason: RollbackReason::Exited(3),
+            time: 1496329222,
+        };
+        let json = serde_json::to_string(&rollback).unwrap();
+
+        assert!(json.contains(r#""from":"core/redis/3.2.5/20170601150022""#));
+        assert_eq!(serde_json::from_str::<Rollback>(&json).unwrap(), rollback);
+        assert_eq!(
+            "rolled back from core/redis/3.2.5/20170601150022 to \
+             core/redis/3.2.4/20170514150022 (process exited 3 times)",
+            rollback.to_string()
+        );
+    }
+}
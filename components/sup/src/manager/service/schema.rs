@@ -0,0 +1,437 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Schemas describing the configuration a package accepts, shipped as `schema.toml` beside the
+//! package's `default.toml`.
+//!
+//! A schema mirrors the layout of the configuration it describes. A table with a `type` key
+//! describes a single configuration key, and any other table describes a nested table of the
+//! configuration:
+//!
+//! ```toml
+//! [port]
+//! type = "integer"
+//! required = true
+//! min = 1
+//! max = 65535
+//!
+//! [log.level]
+//! type = "string"
+//! enum = ["debug", "info", "warn", "error"]
+//! ```
+//!
+//! Keys which a schema does not describe are rejected, so that a misspelled key is reported
+//! rather than silently ignored by the package's templates. A key of type `table` accepts any
+//! keys within it.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::result;
+use std::str::FromStr;
+
+use toml;
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "SC";
+/// The file a package's config schema is read from, beside its `default.toml`.
+pub const SCHEMA_FILE: &'static str = "schema.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ValueType {
+    Array,
+    Boolean,
+    Datetime,
+    Float,
+    Integer,
+    String,
+    Table,
+}
+
+impl ValueType {
+    fn matches(&self, value: &toml::Value) -> bool {
+        match (*self, value) {
+            (ValueType::Array, &toml::Value::Array(_)) |
+            (ValueType::Boolean, &toml::Value::Boolean(_)) |
+            (ValueType::Datetime, &toml::Value::Datetime(_)) |
+            (ValueType::Float, &toml::Value::Float(_)) |
+            (ValueType::Float, &toml::Value::Integer(_)) |
+            (ValueType::Integer, &toml::Value::Integer(_)) |
+            (ValueType::String, &toml::Value::String(_)) |
+            (ValueType::Table, &toml::Value::Table(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for ValueType {
+    type Err = String;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "array" => Ok(ValueType::Array),
+            "boolean" => Ok(ValueType::Boolean),
+            "datetime" => Ok(ValueType::Datetime),
+            "float" => Ok(ValueType::Float),
+            "integer" => Ok(ValueType::Integer),
+            "string" => Ok(ValueType::String),
+            "table" => Ok(ValueType::Table),
+            _ => Err(format!("unknown type '{}'", value)),
+        }
+    }
+}
+
+/// The constraints on a single configuration key.
+#[derive(Clone, Debug, PartialEq)]
+struct Rule {
+    value_type: ValueType,
+    required: bool,
+    choices: Option<Vec<toml::Value>>,
+    /// Least value of a number, or least length of a string or array.
+    min: Option<f64>,
+    /// Greatest value of a number, or greatest length of a string or array.
+    max: Option<f64>,
+}
+
+impl Rule {
+    fn parse(path: &str, table: &toml::value::Table) -> result::Result<Self, String> {
+        let mut rule = Rule {
+            value_type: ValueType::String,
+            required: false,
+            choices: None,
+            min: None,
+            max: None,
+        };
+        for (key, value) in table {
+            match (key.as_str(), value) {
+                ("type", &toml::Value::String(ref t)) => {
+                    rule.value_type = ValueType::from_str(t).map_err(
+                        |e| format!("{}: {}", path, e),
+                    )?
+                }
+                ("required", &toml::Value::Boolean(required)) => rule.required = required,
+                ("enum", &toml::Value::Array(ref choices)) => rule.choices = Some(choices.clone()),
+                ("min", value) => rule.min = Some(number(path, key, value)?),
+                ("max", value) => rule.max = Some(number(path, key, value)?),
+                _ => {
+                    return Err(format!(
+                        "{}: unknown or invalid setting '{}' = {}",
+                        path,
+                        key,
+                        value
+                    ))
+                }
+            }
+        }
+        if let Some(ref choices) = rule.choices {
+            if let Some(choice) = choices.iter().find(|c| !rule.value_type.matches(c)) {
+                return Err(format!(
+                    "{}: enum value {} is not of type {:?}",
+                    path,
+                    choice,
+                    rule.value_type
+                ));
+            }
+        }
+        Ok(rule)
+    }
+
+    fn check(&self, path: &str, value: &toml::Value, errors: &mut Vec<String>) {
+        if !self.value_type.matches(value) {
+            errors.push(format!(
+                "{}: expected {}, got {}",
+                path,
+                format!("{:?}", self.value_type).to_lowercase(),
+                value.type_str()
+            ));
+            return;
+        }
+        if let Some(ref choices) = self.choices {
+            if !choices.contains(value) {
+                let choices: Vec<String> = choices.iter().map(|c| c.to_string()).collect();
+                errors.push(format!(
+                    "{}: {} is not one of {}",
+                    path,
+                    value,
+                    choices.join(", ")
+                ));
+                return;
+            }
+        }
+        let (measure, what) = match *value {
+            toml::Value::Integer(n) => (n as f64, "value"),
+            toml::Value::Float(n) => (n, "value"),
+            toml::Value::String(ref s) => (s.chars().count() as f64, "length"),
+            toml::Value::Array(ref a) => (a.len() as f64, "length"),
+            _ => return,
+        };
+        if let Some(min) = self.min {
+            if measure < min {
+                errors.push(format!("{}: {} {} is less than {}", path, what, measure, min));
+            }
+        }
+        if let Some(max) = self.max {
+            if measure > max {
+                errors.push(format!("{}: {} {} is more than {}", path, what, measure, max));
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Key(Rule),
+    Table(BTreeMap<String, Node>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfigSchema {
+    keys: BTreeMap<String, Node>,
+}
+
+impl ConfigSchema {
+    /// Loads the schema shipped in the given package or config directory, if there is one.
+    pub fn load<P: AsRef<Path>>(dir: P) -> Result<Option<Self>> {
+        let path = dir.as_ref().join(SCHEMA_FILE);
+        let mut file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                debug!("Failed to open '{}', {}, {}", SCHEMA_FILE, path.display(), e);
+                return Ok(None);
+            }
+        };
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        Ok(Some(Self::from_str(&content)?))
+    }
+
+    /// Checks the keys a single configuration layer sets, returning why each key which does not
+    /// match the schema is invalid.
+    pub fn check_layer(&self, layer: &toml::value::Table) -> Vec<String> {
+        let mut errors = vec![];
+        check_table("", &self.keys, layer, &mut errors);
+        errors
+    }
+
+    /// Returns the required keys which the merged configuration does not set.
+    pub fn missing_keys(&self, config: &toml::value::Table) -> Vec<String> {
+        let mut missing = vec![];
+        find_missing("", &self.keys, config, &mut missing);
+        missing
+    }
+}
+
+impl FromStr for ConfigSchema {
+    type Err = SupError;
+
+    fn from_str(content: &str) -> result::Result<Self, Self::Err> {
+        let table: toml::value::Table = toml::from_str(content).map_err(|e| {
+            sup_error!(Error::InvalidConfigSchema(e.to_string()))
+        })?;
+        let keys = parse_table("", &table).map_err(|e| {
+            sup_error!(Error::InvalidConfigSchema(e))
+        })?;
+        Ok(ConfigSchema { keys: keys })
+    }
+}
+
+fn parse_table(
+    prefix: &str,
+    table: &toml::value::Table,
+) -> result::Result<BTreeMap<String, Node>, String> {
+    let mut keys = BTreeMap::new();
+    for (key, value) in table {
+        let path = join(prefix, key);
+        let node = match value.as_table() {
+            Some(t) if t.get("type").map_or(false, |t| t.is_str()) => {
+                Node::Key(Rule::parse(&path, t)?)
+            }
+            Some(t) => Node::Table(parse_table(&path, t)?),
+            None => return Err(format!("{}: expected a table describing the key", path)),
+        };
+        keys.insert(key.clone(), node);
+    }
+    Ok(keys)
+}
+
+fn check_table(
+    prefix: &str,
+    keys: &BTreeMap<String, Node>,
+    table: &toml::value::Table,
+    errors: &mut Vec<String>,
+) {
+    for (key, value) in table {
+        let path = join(prefix, key);
+        match keys.get(key) {
+            Some(&Node::Key(ref rule)) => rule.check(&path, value, errors),
+            Some(&Node::Table(ref children)) => {
+                match value.as_table() {
+                    Some(t) => check_table(&path, children, t, errors),
+                    None => {
+                        errors.push(format!("{}: expected table, got {}", path, value.type_str()))
+                    }
+                }
+            }
+            None => errors.push(format!("{}: unknown key", path)),
+        }
+    }
+}
+
+fn find_missing(
+    prefix: &str,
+    keys: &BTreeMap<String, Node>,
+    table: &toml::value::Table,
+    missing: &mut Vec<String>,
+) {
+    let empty = toml::value::Table::new();
+    for (key, node) in keys {
+        let path = join(prefix, key);
+        match *node {
+            Node::Key(ref rule) => {
+                if rule.required && !table.contains_key(key) {
+                    missing.push(path);
+                }
+            }
+            Node::Table(ref children) => {
+                let child = table.get(key).and_then(|v| v.as_table()).unwrap_or(&empty);
+                find_missing(&path, children, child, missing);
+            }
+        }
+    }
+}
+
+fn number(path: &str, key: &str, value: &toml::Value) -> result::Result<f64, String> {
+    match *value {
+        toml::Value::Integer(n) => Ok(n as f64),
+        toml::Value::Float(n) => Ok(n),
+        _ => Err(format!("{}: '{}' must be a number", path, key)),
+    }
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use toml;
+
+    use super::*;
+
+    fn schema() -> ConfigSchema {
+        ConfigSchema::from_str(
+            r#"
+            [port]
+            type = "integer"
+            required = true
+            min = 1
+            max = 65535
+
+            [name]
+            type = "string"
+            min = 1
+
+            [log.level]
+            type = "string"
+            enum = ["debug", "info"]
+
+            [extra]
+            type = "table"
+            "#,
+        ).unwrap()
+    }
+
+    fn table(content: &str) -> toml::value::Table {
+        toml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn valid_layer() {
+        let layer = table(
+            r#"
+            port = 8080
+            [log]
+            level = "info"
+            [extra]
+            anything = true
+            "#,
+        );
+        assert!(schema().check_layer(&layer).is_empty());
+    }
+
+    #[test]
+    fn invalid_layer() {
+        let layer = table(
+            r#"
+            port = "8080"
+            name = ""
+            prot = 80
+            log = "debug"
+            "#,
+        );
+        let errors = schema().check_layer(&layer);
+        assert_eq!(
+            errors,
+            vec![
+                "log: expected table, got string",
+                "name: length 0 is less than 1",
+                "port: expected integer, got string",
+                "prot: unknown key",
+            ]
+        );
+    }
+
+    #[test]
+    fn enums_and_ranges() {
+        let layer = table(
+            r#"
+            port = 70000
+            [log]
+            level = "trace"
+            "#,
+        );
+        let errors = schema().check_layer(&layer);
+        assert_eq!(
+            errors,
+            vec![
+                r#"log.level: "trace" is not one of "debug", "info""#,
+                "port: value 70000 is more than 65535",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_required_keys() {
+        assert_eq!(schema().missing_keys(&table("name = \"x\"")), vec!["port"]);
+        assert!(schema().missing_keys(&table("port = 80")).is_empty());
+    }
+
+    #[test]
+    fn invalid_schemas() {
+        assert!(ConfigSchema::from_str("port = 80").is_err());
+        assert!(ConfigSchema::from_str("[port]\ntype = \"number\"").is_err());
+        assert!(ConfigSchema::from_str("[port]\ntype = \"integer\"\nrequird = true").is_err());
+        assert!(
+            ConfigSchema::from_str("[port]\ntype = \"integer\"\nenum = [\"80\"]").is_err()
+        );
+    }
+}
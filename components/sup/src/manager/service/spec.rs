@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fs::{self, File};
 use std::io::{BufReader, Read, Write};
@@ -22,20 +22,37 @@ use std::result;
 use std::str::FromStr;
 
 use hcore::channel::STABLE_CHANNEL;
+use hcore::os::process::Signal;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::{ApplicationEnvironment, ServiceGroup};
 use hcore::url::DEFAULT_DEPOT_URL;
 use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
+use hcore::util::log_file::RotationPolicy;
 use rand::{Rng, thread_rng};
 use serde::{self, Deserialize};
 use toml;
 
-use super::{Topology, UpdateStrategy};
+use super::{DependencyState, HealthCheck, HealthProbe, MaintenanceWindow, Topology,
+            UpdateStrategy};
 use error::{Error, Result, SupError};
 
 static LOGKEY: &'static str = "SS";
 static DEFAULT_GROUP: &'static str = "default";
 const SPEC_FILE_EXT: &'static str = "spec";
+/// Default number of seconds to wait between two health-check driven restarts.
+const DEFAULT_RESTART_BACKOFF: u64 = 30;
+/// Default number of health-check driven restarts allowed within a restart window.
+const DEFAULT_MAX_RESTARTS: u32 = 3;
+/// Default length of a restart window in seconds.
+const DEFAULT_RESTART_WINDOW: u64 = 600;
+/// Default number of seconds after a package update during which failures trigger a rollback.
+const DEFAULT_ROLLBACK_GRACE: u64 = 300;
+/// Default number of process exits within the grace period which trigger a rollback.
+const DEFAULT_ROLLBACK_MAX_EXITS: u32 = 3;
+/// Default number of members updated first by the canary update strategy.
+const DEFAULT_CANARY_COUNT: u32 = 1;
+/// Default number of seconds canaries must stay healthy before the rest of the group updates.
+const DEFAULT_CANARY_SOAK: u64 = 300;
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum DesiredState {
@@ -100,8 +117,11 @@ pub struct ServiceSpec {
     pub depot_url: String,
     pub channel: String,
     pub topology: Topology,
+    pub quorum: Option<u32>,
     pub update_strategy: UpdateStrategy,
     pub binds: Vec<ServiceBind>,
+    /// Services on this Supervisor which must be ready before this service starts.
+    pub depends_on: Vec<LocalDependency>,
     pub config_from: Option<PathBuf>,
     #[serde(deserialize_with = "deserialize_using_from_str",
             serialize_with = "serialize_using_to_string")]
@@ -110,6 +130,21 @@ pub struct ServiceSpec {
             serialize_with = "serialize_using_to_string")]
     pub start_style: StartStyle,
     pub svc_encrypted_password: Option<String>,
+    /// The composite this spec was expanded from. Composite services have no spec file of their
+    /// own.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub composite: Option<String>,
+    // Tables must be represented last in a serializable struct for the current version of the
+    // toml crate.
+    pub restart_policy: RestartPolicy,
+    pub canary_policy: CanaryPolicy,
+    pub rollback_policy: RollbackPolicy,
+    pub resource_limits: ResourceLimits,
+    pub hook_timeouts: HookTimeouts,
+    pub shutdown_policy: ShutdownPolicy,
+    pub log_policy: LogPolicy,
+    pub health_probe: Option<HealthProbe>,
+    pub maintenance_window: Option<MaintenanceWindow>,
 }
 
 impl ServiceSpec {
@@ -179,9 +214,43 @@ impl ServiceSpec {
 
     pub fn validate(&self, package: &PackageInstall) -> Result<()> {
         self.validate_binds(package)?;
+        self.validate_quorum()?;
+        self.resource_limits.validate()?;
+        self.hook_timeouts.validate()?;
+        self.shutdown_policy.validate()?;
+        self.log_policy.validate()?;
+        if let Some(ref probe) = self.health_probe {
+            probe.validate()?;
+        }
+        if let Some(ref window) = self.maintenance_window {
+            window.validate()?;
+        }
         Ok(())
     }
 
+    /// Validates that a quorum size is only given for a topology which holds elections, and that
+    /// the quorum topology is always given one.
+    ///
+    /// # Errors
+    ///
+    /// * If the quorum size is 0
+    /// * If a quorum size is given for the standalone topology
+    /// * If no quorum size is given for the quorum topology
+    fn validate_quorum(&self) -> Result<()> {
+        match self.quorum {
+            Some(0) => Err(sup_error!(Error::InvalidQuorum(
+                "quorum must be at least 1".to_string(),
+            ))),
+            Some(_) if !self.topology.elects_leader() => Err(sup_error!(Error::InvalidQuorum(
+                format!("the {} topology does not hold elections", self.topology),
+            ))),
+            None if self.topology == Topology::Quorum => Err(sup_error!(Error::InvalidQuorum(
+                "the quorum topology requires a quorum size".to_string(),
+            ))),
+            _ => Ok(()),
+        }
+    }
+
     /// Validates that all required package binds are present in service binds and all remaining
     /// service binds are optional package binds.
     ///
@@ -215,8 +284,9 @@ impl ServiceSpec {
             }
         }
         // If we have remaining service binds then they are neither required nor optional package
-        // binds. In this case, return an `Err`.
-        if !svc_binds.is_empty() {
+        // binds. In this case, return an `Err`, unless they are binds a composite gives all of its
+        // services.
+        if !svc_binds.is_empty() && self.composite.is_none() {
             return Err(sup_error!(
                 Error::InvalidBinds(svc_binds.into_iter().collect())
             ));
@@ -235,12 +305,24 @@ impl Default for ServiceSpec {
             depot_url: DEFAULT_DEPOT_URL.to_string(),
             channel: STABLE_CHANNEL.to_string(),
             topology: Topology::default(),
+            quorum: None,
             update_strategy: UpdateStrategy::default(),
             binds: Vec::default(),
+            depends_on: Vec::default(),
             config_from: None,
             desired_state: DesiredState::default(),
             start_style: StartStyle::default(),
             svc_encrypted_password: None,
+            composite: None,
+            restart_policy: RestartPolicy::default(),
+            canary_policy: CanaryPolicy::default(),
+            rollback_policy: RollbackPolicy::default(),
+            resource_limits: ResourceLimits::default(),
+            hook_timeouts: HookTimeouts::default(),
+            shutdown_policy: ShutdownPolicy::default(),
+            log_policy: LogPolicy::default(),
+            health_probe: None,
+            maintenance_window: None,
         }
     }
 }
@@ -305,6 +387,107 @@ impl serde::Serialize for ServiceBind {
     }
 }
 
+/// What a service waits for from a service it depends on before it starts.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DependencyCondition {
+    /// The dependency's process is running.
+    Up,
+    /// The dependency's process is running and its last health check was `Ok`.
+    Healthy,
+}
+
+impl Default for DependencyCondition {
+    fn default() -> DependencyCondition {
+        DependencyCondition::Up
+    }
+}
+
+impl fmt::Display for DependencyCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            DependencyCondition::Up => "up",
+            DependencyCondition::Healthy => "healthy",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+/// A service on the same Supervisor which a service starts after, given as `<NAME>` or
+/// `<NAME>:<CONDITION>`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LocalDependency {
+    /// The name of the service's package, which names its spec file.
+    pub name: String,
+    pub condition: DependencyCondition,
+}
+
+impl LocalDependency {
+    /// Returns true if a dependency in the given state satisfies the condition. A dependency
+    /// which isn't loaded on this Supervisor at all never does, so the service waits for it.
+    pub fn is_met(&self, state: Option<&DependencyState>) -> bool {
+        match state {
+            Some(state) => {
+                match self.condition {
+                    DependencyCondition::Up => state.up,
+                    DependencyCondition::Healthy => state.up && state.health == HealthCheck::Ok,
+                }
+            }
+            None => false,
+        }
+    }
+}
+
+impl FromStr for LocalDependency {
+    type Err = SupError;
+
+    fn from_str(dependency_str: &str) -> result::Result<Self, Self::Err> {
+        let values: Vec<&str> = dependency_str.splitn(2, ':').collect();
+        let condition = match values.get(1).map(|c| c.to_lowercase()) {
+            None => DependencyCondition::default(),
+            Some(ref c) if c == "up" => DependencyCondition::Up,
+            Some(ref c) if c == "healthy" => DependencyCondition::Healthy,
+            Some(_) => {
+                return Err(sup_error!(
+                    Error::InvalidDependency(dependency_str.to_string())
+                ))
+            }
+        };
+        if values[0].is_empty() {
+            return Err(sup_error!(
+                Error::InvalidDependency(dependency_str.to_string())
+            ));
+        }
+        Ok(LocalDependency {
+            name: values[0].to_string(),
+            condition: condition,
+        })
+    }
+}
+
+impl fmt::Display for LocalDependency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.name, self.condition)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for LocalDependency {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for LocalDependency {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum StartStyle {
     Persistent,
@@ -339,6 +522,391 @@ impl FromStr for StartStyle {
     }
 }
 
+/// Governs how the Supervisor reacts to a service whose health check keeps reporting `Critical`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct RestartPolicy {
+    /// Number of consecutive `Critical` health checks after which the service is restarted. A
+    /// value of `0` disables health-check driven restarts.
+    pub critical_threshold: u32,
+    /// Seconds to wait after a restart before the service may be restarted again. The delay
+    /// doubles for every further restart within the same window.
+    pub backoff: u64,
+    /// Maximum number of restarts allowed within `window`. A value of `0` means no limit.
+    pub max_restarts: u32,
+    /// Length of the sliding window, in seconds, used to count restarts.
+    pub window: u64,
+}
+
+impl RestartPolicy {
+    pub fn is_enabled(&self) -> bool {
+        self.critical_threshold > 0
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        RestartPolicy {
+            critical_threshold: 0,
+            backoff: DEFAULT_RESTART_BACKOFF,
+            max_restarts: DEFAULT_MAX_RESTARTS,
+            window: DEFAULT_RESTART_WINDOW,
+        }
+    }
+}
+
+/// Governs when the Supervisor returns a service to its previous release after a package update.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct RollbackPolicy {
+    /// Whether failed updates are rolled back automatically.
+    pub enabled: bool,
+    /// Seconds after an update during which a failing service is blamed on the update.
+    pub grace: u64,
+    /// Number of process exits within the grace period after which the update is considered
+    /// failed.
+    pub max_exits: u32,
+}
+
+impl Default for RollbackPolicy {
+    fn default() -> Self {
+        RollbackPolicy {
+            enabled: false,
+            grace: DEFAULT_ROLLBACK_GRACE,
+            max_exits: DEFAULT_ROLLBACK_MAX_EXITS,
+        }
+    }
+}
+
+/// Settings for the `canary` update strategy.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct CanaryPolicy {
+    /// Number of members of the service group which are updated ahead of the others.
+    pub count: u32,
+    /// Seconds the canaries' health checks must stay `Ok` before the remaining members update.
+    pub soak: u64,
+}
+
+impl Default for CanaryPolicy {
+    fn default() -> Self {
+        CanaryPolicy {
+            count: DEFAULT_CANARY_COUNT,
+            soak: DEFAULT_CANARY_SOAK,
+        }
+    }
+}
+
+/// Limits on the resources a service's processes may use, applied by the Launcher through cgroups
+/// on Linux. A limit which is not set falls back to the one declared by the package's plan.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct ResourceLimits {
+    /// Relative share of CPU time, from 2 to 262144. Processes receive 1024 by default.
+    pub cpu_shares: Option<u64>,
+    /// Maximum memory, in bytes.
+    pub memory_bytes: Option<u64>,
+    /// Maximum number of processes and threads.
+    pub pids_max: Option<u64>,
+    /// Relative share of block IO, from 1 to 10000. Processes receive 100 by default.
+    pub io_weight: Option<u32>,
+}
+
+impl ResourceLimits {
+    /// Parses the `SVC_LIMITS` metadata of a package.
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Result<Self> {
+        let mut limits = ResourceLimits::default();
+        for (key, value) in metadata.iter() {
+            let number = value.trim().parse::<u64>().map_err(|_| {
+                sup_error!(Error::InvalidResourceLimits(
+                    format!("{} must be a number, got '{}'", key, value),
+                ))
+            })?;
+            match key.as_str() {
+                "cpu_shares" => limits.cpu_shares = Some(number),
+                "memory_bytes" => limits.memory_bytes = Some(number),
+                "pids_max" => limits.pids_max = Some(number),
+                // Checked here rather than by `validate` so a value too large for a u32 is not
+                // truncated into the valid range.
+                "io_weight" if number > 10000 => {
+                    return Err(sup_error!(Error::InvalidResourceLimits(
+                        "io_weight must be between 1 and 10000".to_string(),
+                    )))
+                }
+                "io_weight" => limits.io_weight = Some(number as u32),
+                _ => {
+                    return Err(sup_error!(Error::InvalidResourceLimits(
+                        format!("unknown limit '{}'", key),
+                    )))
+                }
+            }
+        }
+        limits.validate()?;
+        Ok(limits)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == ResourceLimits::default()
+    }
+
+    /// Returns these limits with any which are not set taken from `defaults`.
+    pub fn or(&self, defaults: &ResourceLimits) -> ResourceLimits {
+        ResourceLimits {
+            cpu_shares: self.cpu_shares.or(defaults.cpu_shares),
+            memory_bytes: self.memory_bytes.or(defaults.memory_bytes),
+            pids_max: self.pids_max.or(defaults.pids_max),
+            io_weight: self.io_weight.or(defaults.io_weight),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let Some(shares) = self.cpu_shares {
+            if shares < 2 || shares > 262144 {
+                return Err(sup_error!(Error::InvalidResourceLimits(
+                    "cpu_shares must be between 2 and 262144".to_string(),
+                )));
+            }
+        }
+        if let Some(weight) = self.io_weight {
+            if weight < 1 || weight > 10000 {
+                return Err(sup_error!(Error::InvalidResourceLimits(
+                    "io_weight must be between 1 and 10000".to_string(),
+                )));
+            }
+        }
+        if self.memory_bytes == Some(0) || self.pids_max == Some(0) {
+            return Err(sup_error!(Error::InvalidResourceLimits(
+                "memory_bytes and pids_max must be greater than 0".to_string(),
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ResourceLimits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut limits = Vec::new();
+        if let Some(shares) = self.cpu_shares {
+            limits.push(format!("cpu_shares={}", shares));
+        }
+        if let Some(bytes) = self.memory_bytes {
+            limits.push(format!("memory_bytes={}", bytes));
+        }
+        if let Some(pids) = self.pids_max {
+            limits.push(format!("pids_max={}", pids));
+        }
+        if let Some(weight) = self.io_weight {
+            limits.push(format!("io_weight={}", weight));
+        }
+        if limits.is_empty() {
+            write!(f, "none")
+        } else {
+            write!(f, "{}", limits.join(", "))
+        }
+    }
+}
+
+/// Seconds each of a service's hooks may run before it is killed. A timeout which is not set
+/// falls back to the one declared by the package's plan, and then to the hook's default.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct HookTimeouts {
+    pub file_updated: Option<u64>,
+    pub health_check: Option<u64>,
+    pub init: Option<u64>,
+    pub post_run: Option<u64>,
+    pub post_stop: Option<u64>,
+    pub pre_stop: Option<u64>,
+    pub reconfigure: Option<u64>,
+    pub reload: Option<u64>,
+    pub smoke_test: Option<u64>,
+    pub suitability: Option<u64>,
+    /// Health reported when the `health_check` hook times out, `Unknown` unless set to
+    /// `Critical`.
+    pub health_check_result: Option<HealthCheck>,
+}
+
+impl HookTimeouts {
+    /// Parses the `HOOK_TIMEOUTS` metadata of a package.
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Result<Self> {
+        let mut timeouts = HookTimeouts::default();
+        for (key, value) in metadata.iter() {
+            let seconds = value.trim().parse::<u64>().map_err(|_| {
+                sup_error!(Error::InvalidHookTimeouts(
+                    format!("{} must be a number of seconds, got '{}'", key, value),
+                ))
+            })?;
+            match timeouts.get_mut(key) {
+                Some(timeout) => *timeout = Some(seconds),
+                None => {
+                    return Err(sup_error!(Error::InvalidHookTimeouts(
+                        format!("unknown hook '{}'", key),
+                    )))
+                }
+            }
+        }
+        timeouts.validate()?;
+        Ok(timeouts)
+    }
+
+    /// Returns the timeout, in seconds, of the hook with the given file name.
+    pub fn get(&self, hook: &str) -> Option<u64> {
+        match hook {
+            "file_updated" => self.file_updated,
+            "health_check" => self.health_check,
+            "init" => self.init,
+            "post-run" | "post_run" => self.post_run,
+            "post-stop" | "post_stop" => self.post_stop,
+            "pre-stop" | "pre_stop" => self.pre_stop,
+            "reconfigure" => self.reconfigure,
+            "reload" => self.reload,
+            "smoke_test" => self.smoke_test,
+            "suitability" => self.suitability,
+            _ => None,
+        }
+    }
+
+    fn get_mut(&mut self, hook: &str) -> Option<&mut Option<u64>> {
+        match hook {
+            "file_updated" => Some(&mut self.file_updated),
+            "health_check" => Some(&mut self.health_check),
+            "init" => Some(&mut self.init),
+            "post-run" | "post_run" => Some(&mut self.post_run),
+            "post-stop" | "post_stop" => Some(&mut self.post_stop),
+            "pre-stop" | "pre_stop" => Some(&mut self.pre_stop),
+            "reconfigure" => Some(&mut self.reconfigure),
+            "reload" => Some(&mut self.reload),
+            "smoke_test" => Some(&mut self.smoke_test),
+            "suitability" => Some(&mut self.suitability),
+            _ => None,
+        }
+    }
+
+    /// Returns the health reported when the `health_check` hook times out.
+    pub fn timed_out_health(&self) -> HealthCheck {
+        self.health_check_result.unwrap_or(HealthCheck::Unknown)
+    }
+
+    /// Returns these timeouts with any which are not set taken from `defaults`.
+    pub fn or(&self, defaults: &HookTimeouts) -> HookTimeouts {
+        HookTimeouts {
+            file_updated: self.file_updated.or(defaults.file_updated),
+            health_check: self.health_check.or(defaults.health_check),
+            init: self.init.or(defaults.init),
+            post_run: self.post_run.or(defaults.post_run),
+            post_stop: self.post_stop.or(defaults.post_stop),
+            pre_stop: self.pre_stop.or(defaults.pre_stop),
+            reconfigure: self.reconfigure.or(defaults.reconfigure),
+            reload: self.reload.or(defaults.reload),
+            smoke_test: self.smoke_test.or(defaults.smoke_test),
+            suitability: self.suitability.or(defaults.suitability),
+            health_check_result: self.health_check_result.or(defaults.health_check_result),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let timeouts = [
+            self.file_updated,
+            self.health_check,
+            self.init,
+            self.post_run,
+            self.post_stop,
+            self.pre_stop,
+            self.reconfigure,
+            self.reload,
+            self.smoke_test,
+            self.suitability,
+        ];
+        if timeouts.iter().any(|t| *t == Some(0)) {
+            return Err(sup_error!(Error::InvalidHookTimeouts(
+                "timeouts must be at least 1 second".to_string(),
+            )));
+        }
+        match self.health_check_result {
+            None |
+            Some(HealthCheck::Unknown) |
+            Some(HealthCheck::Critical) => Ok(()),
+            Some(health) => Err(sup_error!(Error::InvalidHookTimeouts(format!(
+                "health_check_result must be Unknown or Critical, got {:?}",
+                health
+            )))),
+        }
+    }
+}
+
+/// How a service's process is stopped. The Launcher sends it `signal` and then kills it if it
+/// has not exited after `timeout` seconds.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct ShutdownPolicy {
+    /// Name of the signal, such as `TERM` or `SIGINT`. The Launcher sends `TERM` when not set.
+    /// Ignored on Windows, where processes are sent a ctrl-break event.
+    pub signal: Option<String>,
+    /// Seconds the process is given to exit. The Launcher waits 8 seconds when not set.
+    pub timeout: Option<u32>,
+}
+
+impl ShutdownPolicy {
+    pub fn is_empty(&self) -> bool {
+        *self == ShutdownPolicy::default()
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let Some(ref signal) = self.signal {
+            Signal::from_str(signal).map_err(|e| {
+                sup_error!(Error::InvalidShutdownPolicy(e.to_string()))
+            })?;
+        }
+        if self.timeout == Some(0) {
+            return Err(sup_error!(Error::InvalidShutdownPolicy(
+                "timeout must be at least 1 second".to_string(),
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// How the logs a service's output and hook output are captured to are rotated. Unset settings
+/// take their defaults from `hcore::util::log_file::RotationPolicy`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct LogPolicy {
+    /// Bytes a log may grow to before it is rotated.
+    pub max_bytes: Option<u64>,
+    /// Seconds after its first line that a log is rotated, regardless of its size.
+    pub max_age: Option<u64>,
+    /// Number of rotated logs to keep.
+    pub keep: Option<u32>,
+    /// Whether rotated logs are compressed with gzip.
+    pub compress: Option<bool>,
+}
+
+impl LogPolicy {
+    pub fn rotation(&self) -> RotationPolicy {
+        let default = RotationPolicy::default();
+        RotationPolicy {
+            max_bytes: self.max_bytes.unwrap_or(default.max_bytes),
+            max_age: self.max_age.or(default.max_age),
+            keep: self.keep.unwrap_or(default.keep),
+            compress: self.compress.unwrap_or(default.compress),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.max_bytes == Some(0) {
+            return Err(sup_error!(Error::InvalidLogPolicy(
+                "max_bytes must be at least 1".to_string(),
+            )));
+        }
+        if self.max_age == Some(0) {
+            return Err(sup_error!(Error::InvalidLogPolicy(
+                "max_age must be at least 1 second".to_string(),
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::fs::{self, File};
@@ -385,6 +953,7 @@ mod test {
             topology = "leader"
             update_strategy = "rolling"
             binds = ["cache:redis.cache@acmecorp", "db:postgres.app@acmecorp"]
+            depends_on = ["postgres:healthy", "redis"]
             start_style = "persistent"
             config_from = "/only/for/development"
 
@@ -413,6 +982,19 @@ mod test {
                 ServiceBind::from_str("db:postgres.app@acmecorp").unwrap(),
             ]
         );
+        assert_eq!(
+            spec.depends_on,
+            vec![
+                LocalDependency {
+                    name: String::from("postgres"),
+                    condition: DependencyCondition::Healthy,
+                },
+                LocalDependency {
+                    name: String::from("redis"),
+                    condition: DependencyCondition::Up,
+                },
+            ]
+        );
         assert_eq!(
             spec.config_from,
             Some(PathBuf::from("/only/for/development"))
@@ -420,6 +1002,289 @@ mod test {
         assert_eq!(spec.start_style, StartStyle::Persistent);
     }
 
+    #[test]
+    fn service_spec_from_str_quorum() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            topology = "primary-replica"
+            quorum = 2
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.topology, Topology::PrimaryReplica);
+        assert_eq!(spec.quorum, Some(2));
+        assert!(spec.validate_quorum().is_ok());
+    }
+
+    #[test]
+    fn service_spec_validate_quorum_invalid() {
+        let mut spec = ServiceSpec::default();
+        spec.quorum = Some(2);
+        assert!(spec.validate_quorum().is_err());
+
+        spec.topology = Topology::Quorum;
+        spec.quorum = None;
+        assert!(spec.validate_quorum().is_err());
+
+        spec.quorum = Some(0);
+        assert!(spec.validate_quorum().is_err());
+
+        spec.quorum = Some(3);
+        assert!(spec.validate_quorum().is_ok());
+    }
+
+    #[test]
+    fn service_spec_from_str_maintenance_window() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+
+            [maintenance_window]
+            schedule = "0-59 2-3 * * 6,0"
+            timezone = "+01:00"
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+        let window = spec.maintenance_window.unwrap();
+
+        assert_eq!(window.schedule, Some("0-59 2-3 * * 6,0".to_string()));
+        assert_eq!(window.timezone, "+01:00");
+        assert!(window.days.is_empty());
+    }
+
+    #[test]
+    fn service_spec_from_str_resource_limits() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+
+            [resource_limits]
+            cpu_shares = 512
+            memory_bytes = 268435456
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.resource_limits.cpu_shares, Some(512));
+        assert_eq!(spec.resource_limits.memory_bytes, Some(268435456));
+        assert_eq!(spec.resource_limits.pids_max, None);
+        assert_eq!(spec.resource_limits.io_weight, None);
+    }
+
+    #[test]
+    fn resource_limits_fall_back_to_defaults() {
+        let mut metadata = HashMap::new();
+        metadata.insert("cpu_shares".to_string(), "256".to_string());
+        metadata.insert("pids_max".to_string(), "100".to_string());
+        let defaults = ResourceLimits::from_metadata(&metadata).unwrap();
+        let limits = ResourceLimits {
+            cpu_shares: Some(512),
+            ..ResourceLimits::default()
+        };
+        let effective = limits.or(&defaults);
+
+        assert_eq!(effective.cpu_shares, Some(512));
+        assert_eq!(effective.pids_max, Some(100));
+        assert_eq!(effective.memory_bytes, None);
+        assert_eq!(effective.to_string(), "cpu_shares=512, pids_max=100");
+    }
+
+    #[test]
+    fn resource_limits_invalid() {
+        let mut metadata = HashMap::new();
+        metadata.insert("swap_bytes".to_string(), "1024".to_string());
+        assert!(ResourceLimits::from_metadata(&metadata).is_err());
+
+        // 2^32 + 100 would wrap around to a valid weight of 100 if truncated.
+        let mut metadata = HashMap::new();
+        metadata.insert("io_weight".to_string(), "4294967396".to_string());
+        assert!(ResourceLimits::from_metadata(&metadata).is_err());
+
+        let mut limits = ResourceLimits::default();
+        limits.cpu_shares = Some(1);
+        assert!(limits.validate().is_err());
+        limits.cpu_shares = Some(1024);
+        limits.io_weight = Some(0);
+        assert!(limits.validate().is_err());
+        limits.io_weight = None;
+        limits.pids_max = Some(0);
+        assert!(limits.validate().is_err());
+        limits.pids_max = Some(64);
+        assert!(limits.validate().is_ok());
+    }
+
+    #[test]
+    fn service_spec_from_str_shutdown_policy() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+
+            [shutdown_policy]
+            signal = "QUIT"
+            timeout = 30
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.shutdown_policy.signal, Some("QUIT".to_string()));
+        assert_eq!(spec.shutdown_policy.timeout, Some(30));
+        assert!(spec.shutdown_policy.validate().is_ok());
+    }
+
+    #[test]
+    fn shutdown_policy_invalid() {
+        let mut policy = ShutdownPolicy::default();
+        assert!(policy.is_empty());
+        assert!(policy.validate().is_ok());
+        policy.signal = Some("SIGSTOP".to_string());
+        assert!(policy.validate().is_err());
+        policy.signal = Some("sigint".to_string());
+        assert!(policy.validate().is_ok());
+        policy.timeout = Some(0);
+        assert!(policy.validate().is_err());
+    }
+
+    #[test]
+    fn log_policy_rotation_defaults_unset_settings() {
+        let policy = LogPolicy {
+            max_age: Some(86400),
+            compress: Some(false),
+            ..LogPolicy::default()
+        };
+        assert!(policy.validate().is_ok());
+        let rotation = policy.rotation();
+        assert_eq!(rotation.max_bytes, RotationPolicy::default().max_bytes);
+        assert_eq!(rotation.max_age, Some(86400));
+        assert_eq!(rotation.keep, RotationPolicy::default().keep);
+        assert!(!rotation.compress);
+
+        let policy = LogPolicy {
+            max_bytes: Some(0),
+            ..LogPolicy::default()
+        };
+        assert!(policy.validate().is_err());
+    }
+
+    #[test]
+    fn service_spec_from_str_health_probe() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+
+            [health_probe]
+            http = "http://localhost:8080/status"
+            status = 204
+            interval = 10
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+        let probe = spec.health_probe.unwrap();
+
+        assert_eq!(probe.http, Some("http://localhost:8080/status".to_string()));
+        assert_eq!(probe.status, Some(204));
+        assert_eq!(probe.interval, Some(10));
+        assert!(probe.validate().is_ok());
+    }
+
+    #[test]
+    fn service_spec_from_str_hook_timeouts() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+
+            [hook_timeouts]
+            health_check = 10
+            health_check_result = "Critical"
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.hook_timeouts.get("health_check"), Some(10));
+        assert_eq!(spec.hook_timeouts.get("init"), None);
+        assert_eq!(spec.hook_timeouts.timed_out_health(), HealthCheck::Critical);
+    }
+
+    #[test]
+    fn hook_timeouts_fall_back_to_defaults() {
+        let mut metadata = HashMap::new();
+        metadata.insert("init".to_string(), "600".to_string());
+        metadata.insert("post_run".to_string(), "120".to_string());
+        let defaults = HookTimeouts::from_metadata(&metadata).unwrap();
+        let timeouts = HookTimeouts {
+            init: Some(30),
+            ..HookTimeouts::default()
+        };
+        let effective = timeouts.or(&defaults);
+
+        assert_eq!(effective.get("init"), Some(30));
+        assert_eq!(effective.get("post-run"), Some(120));
+        assert_eq!(effective.get("reload"), None);
+        assert_eq!(effective.timed_out_health(), HealthCheck::Unknown);
+    }
+
+    #[test]
+    fn hook_timeouts_invalid() {
+        let mut metadata = HashMap::new();
+        metadata.insert("run".to_string(), "60".to_string());
+        assert!(HookTimeouts::from_metadata(&metadata).is_err());
+
+        let mut timeouts = HookTimeouts::default();
+        timeouts.reload = Some(0);
+        assert!(timeouts.validate().is_err());
+        timeouts.reload = Some(5);
+        timeouts.health_check_result = Some(HealthCheck::Ok);
+        assert!(timeouts.validate().is_err());
+        timeouts.health_check_result = Some(HealthCheck::Critical);
+        assert!(timeouts.validate().is_ok());
+    }
+
+    #[test]
+    fn service_spec_from_str_restart_policy() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+
+            [restart_policy]
+            critical_threshold = 5
+            window = 120
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.restart_policy.critical_threshold, 5);
+        assert_eq!(spec.restart_policy.window, 120);
+        assert_eq!(spec.restart_policy.backoff, DEFAULT_RESTART_BACKOFF);
+        assert_eq!(spec.restart_policy.max_restarts, DEFAULT_MAX_RESTARTS);
+        assert!(spec.restart_policy.is_enabled());
+    }
+
+    #[test]
+    fn service_spec_from_str_canary_policy() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            update_strategy = "canary"
+
+            [canary_policy]
+            count = 3
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.update_strategy, UpdateStrategy::Canary);
+        assert_eq!(spec.canary_policy.count, 3);
+        assert_eq!(spec.canary_policy.soak, DEFAULT_CANARY_SOAK);
+    }
+
+    #[test]
+    fn service_spec_from_str_rollback_policy() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+
+            [rollback_policy]
+            enabled = true
+            grace = 30
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert!(spec.rollback_policy.enabled);
+        assert_eq!(spec.rollback_policy.grace, 30);
+        assert_eq!(spec.rollback_policy.max_exits, DEFAULT_ROLLBACK_MAX_EXITS);
+    }
+
+    #[test]
+    fn service_spec_default_restart_policy_is_disabled() {
+        let spec = ServiceSpec::default_for(PackageIdent::from_str("origin/name").unwrap());
+
+        assert!(!spec.restart_policy.is_enabled());
+    }
+
     #[test]
     fn service_spec_from_str_missing_ident() {
         let toml = r#""#;
@@ -484,15 +1349,61 @@ mod test {
             depot_url: String::from("http://example.com/depot"),
             channel: String::from("unstable"),
             topology: Topology::Leader,
+            quorum: Some(3),
             update_strategy: UpdateStrategy::AtOnce,
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                 ServiceBind::from_str("db:postgres.app@acmecorp").unwrap(),
             ],
+            depends_on: vec![LocalDependency::from_str("postgres:healthy").unwrap()],
             config_from: Some(PathBuf::from("/only/for/development")),
             desired_state: DesiredState::Down,
             start_style: StartStyle::Persistent,
             svc_encrypted_password: None,
+            composite: None,
+            restart_policy: RestartPolicy {
+                critical_threshold: 3,
+                backoff: 10,
+                max_restarts: 5,
+                window: 300,
+            },
+            canary_policy: CanaryPolicy {
+                count: 2,
+                soak: 600,
+            },
+            rollback_policy: RollbackPolicy {
+                enabled: false,
+                grace: 60,
+                max_exits: 3,
+            },
+            resource_limits: ResourceLimits {
+                cpu_shares: Some(512),
+                memory_bytes: Some(268435456),
+                ..ResourceLimits::default()
+            },
+            hook_timeouts: HookTimeouts {
+                health_check: Some(10),
+                ..HookTimeouts::default()
+            },
+            shutdown_policy: ShutdownPolicy {
+                signal: Some(String::from("INT")),
+                timeout: Some(30),
+            },
+            log_policy: LogPolicy {
+                max_bytes: Some(1048576),
+                keep: Some(3),
+                ..LogPolicy::default()
+            },
+            health_probe: Some(HealthProbe {
+                tcp: Some(String::from("localhost:6379")),
+                ..HealthProbe::default()
+            }),
+            maintenance_window: Some(MaintenanceWindow {
+                days: vec!["sat".to_string()],
+                start: Some("02:00".to_string()),
+                end: Some("04:00".to_string()),
+                ..MaintenanceWindow::default()
+            }),
         };
         let toml = spec.to_toml_string().unwrap();
 
@@ -506,12 +1417,33 @@ mod test {
         assert!(toml.contains(r#"depot_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"channel = "unstable""#));
         assert!(toml.contains(r#"topology = "leader""#));
+        assert!(toml.contains("quorum = 3"));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
+        assert!(toml.contains(r#"depends_on = ["postgres:healthy"]"#));
         assert!(toml.contains(r#"desired_state = "down""#));
         assert!(toml.contains(r#"start_style = "persistent""#));
         assert!(toml.contains(r#"config_from = "/only/for/development""#));
+        assert!(toml.contains("[restart_policy]"));
+        assert!(toml.contains("critical_threshold = 3"));
+        assert!(toml.contains("max_restarts = 5"));
+        assert!(toml.contains("[canary_policy]"));
+        assert!(toml.contains("soak = 600"));
+        assert!(toml.contains("[rollback_policy]"));
+        assert!(toml.contains("enabled = false"));
+        assert!(toml.contains("[resource_limits]"));
+        assert!(toml.contains("memory_bytes = 268435456"));
+        assert!(toml.contains("[hook_timeouts]"));
+        assert!(toml.contains("health_check = 10"));
+        assert!(toml.contains("[shutdown_policy]"));
+        assert!(toml.contains(r#"signal = "INT""#));
+        assert!(toml.contains("[log_policy]"));
+        assert!(toml.contains("max_bytes = 1048576"));
+        assert!(toml.contains("[health_probe]"));
+        assert!(toml.contains(r#"tcp = "localhost:6379""#));
+        assert!(toml.contains("[maintenance_window]"));
+        assert!(toml.contains(r#"start = "02:00""#));
     }
 
     #[test]
@@ -642,15 +1574,61 @@ mod test {
             depot_url: String::from("http://example.com/depot"),
             channel: String::from("unstable"),
             topology: Topology::Leader,
+            quorum: Some(3),
             update_strategy: UpdateStrategy::AtOnce,
             binds: vec![
                 ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                 ServiceBind::from_str("db:postgres.app@acmecorp").unwrap(),
             ],
+            depends_on: vec![LocalDependency::from_str("postgres:healthy").unwrap()],
             config_from: Some(PathBuf::from("/only/for/development")),
             desired_state: DesiredState::Down,
             start_style: StartStyle::Persistent,
             svc_encrypted_password: None,
+            composite: None,
+            restart_policy: RestartPolicy {
+                critical_threshold: 3,
+                backoff: 10,
+                max_restarts: 5,
+                window: 300,
+            },
+            canary_policy: CanaryPolicy {
+                count: 2,
+                soak: 600,
+            },
+            rollback_policy: RollbackPolicy {
+                enabled: false,
+                grace: 60,
+                max_exits: 3,
+            },
+            resource_limits: ResourceLimits {
+                cpu_shares: Some(512),
+                memory_bytes: Some(268435456),
+                ..ResourceLimits::default()
+            },
+            hook_timeouts: HookTimeouts {
+                health_check: Some(10),
+                ..HookTimeouts::default()
+            },
+            shutdown_policy: ShutdownPolicy {
+                signal: Some(String::from("INT")),
+                timeout: Some(30),
+            },
+            log_policy: LogPolicy {
+                max_bytes: Some(1048576),
+                keep: Some(3),
+                ..LogPolicy::default()
+            },
+            health_probe: Some(HealthProbe {
+                tcp: Some(String::from("localhost:6379")),
+                ..HealthProbe::default()
+            }),
+            maintenance_window: Some(MaintenanceWindow {
+                days: vec!["sat".to_string()],
+                start: Some("02:00".to_string()),
+                end: Some("04:00".to_string()),
+                ..MaintenanceWindow::default()
+            }),
         };
         spec.to_file(&path).unwrap();
         let toml = string_from_file(path);
@@ -665,12 +1643,33 @@ mod test {
         assert!(toml.contains(r#"depot_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"channel = "unstable""#));
         assert!(toml.contains(r#"topology = "leader""#));
+        assert!(toml.contains("quorum = 3"));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
+        assert!(toml.contains(r#"depends_on = ["postgres:healthy"]"#));
         assert!(toml.contains(r#"desired_state = "down""#));
         assert!(toml.contains(r#"start_style = "persistent""#));
         assert!(toml.contains(r#"config_from = "/only/for/development""#));
+        assert!(toml.contains("[restart_policy]"));
+        assert!(toml.contains("critical_threshold = 3"));
+        assert!(toml.contains("max_restarts = 5"));
+        assert!(toml.contains("[canary_policy]"));
+        assert!(toml.contains("soak = 600"));
+        assert!(toml.contains("[rollback_policy]"));
+        assert!(toml.contains("enabled = false"));
+        assert!(toml.contains("[resource_limits]"));
+        assert!(toml.contains("memory_bytes = 268435456"));
+        assert!(toml.contains("[hook_timeouts]"));
+        assert!(toml.contains("health_check = 10"));
+        assert!(toml.contains("[shutdown_policy]"));
+        assert!(toml.contains(r#"signal = "INT""#));
+        assert!(toml.contains("[log_policy]"));
+        assert!(toml.contains("max_bytes = 1048576"));
+        assert!(toml.contains("[health_probe]"));
+        assert!(toml.contains(r#"tcp = "localhost:6379""#));
+        assert!(toml.contains("[maintenance_window]"));
+        assert!(toml.contains(r#"start = "02:00""#));
     }
 
     #[test]
@@ -813,4 +1812,45 @@ mod test {
 
         assert!(toml.starts_with(r#"key = "name:service.group""#));
     }
+
+    #[test]
+    fn local_dependency_from_str_invalid() {
+        for dependency_str in vec!["", ":up", "postgres:running"] {
+            match LocalDependency::from_str(dependency_str) {
+                Err(e) => {
+                    match e.err {
+                        InvalidDependency(val) => assert_eq!(dependency_str, val),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("String should fail to parse"),
+            }
+        }
+    }
+
+    #[test]
+    fn local_dependency_is_met() {
+        let up = LocalDependency::from_str("postgres:up").unwrap();
+        let healthy = LocalDependency::from_str("postgres:healthy").unwrap();
+        let mut state = DependencyState {
+            up: true,
+            health: HealthCheck::Warning,
+            restarts: 0,
+        };
+        assert!(up.is_met(Some(&state)));
+        assert!(!healthy.is_met(Some(&state)));
+        state.health = HealthCheck::Ok;
+        assert!(healthy.is_met(Some(&state)));
+        state.up = false;
+        assert!(!up.is_met(Some(&state)));
+        assert!(!healthy.is_met(Some(&state)));
+    }
+
+    #[test]
+    fn local_dependency_not_loaded_is_not_met() {
+        let up = LocalDependency::from_str("postgres:up").unwrap();
+        let healthy = LocalDependency::from_str("postgres:healthy").unwrap();
+        assert!(!up.is_met(None));
+        assert!(!healthy.is_met(None));
+    }
 }
@@ -28,14 +28,14 @@ use hcore::os::process;
 use std::result;
 
 use hcore::service::ServiceGroup;
-use launcher_client::LauncherCli;
+use launcher_client::{self, LauncherCli};
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 use time::{self, Timespec};
 
 use error::{Result, Error};
 use fs;
-use manager::service::Pkg;
+use manager::service::{LogPolicy, Pkg, ResourceLimits, ShutdownPolicy};
 
 static LOGKEY: &'static str = "SV";
 
@@ -60,6 +60,9 @@ pub struct Supervisor {
     pub preamble: String,
     pub state: ProcessState,
     pub state_entered: Timespec,
+    pub restarts: u64,
+    /// The resource limits the Launcher applied to the running process.
+    pub limits: ResourceLimits,
     pid: Option<u32>,
     pid_file: PathBuf,
 }
@@ -70,6 +73,8 @@ impl Supervisor {
             preamble: service_group.to_string(),
             state: ProcessState::Down,
             state_entered: time::get_time(),
+            restarts: 0,
+            limits: ResourceLimits::default(),
             pid: None,
             pid_file: fs::svc_pid_file(service_group.service()),
         }
@@ -106,21 +111,28 @@ impl Supervisor {
         group: &ServiceGroup,
         launcher: &LauncherCli,
         svc_password: Option<T>,
+        limits: &ResourceLimits,
+        shutdown: &ShutdownPolicy,
+        logs: &LogPolicy,
     ) -> Result<()>
     where
         T: ToString,
     {
         outputln!(preamble self.preamble,
             "Starting service as user={}, group={}", &pkg.svc_user, &pkg.svc_group);
-        let pid = launcher.spawn(
+        let reply = launcher.spawn(
             group.to_string(),
             &pkg.svc_run,
             &pkg.svc_user,
             &pkg.svc_group,
             svc_password,
             (*pkg.env).clone(),
+            to_launcher_limits(limits),
+            to_launcher_shutdown(shutdown),
+            Some(to_launcher_logs(group, logs)),
         )?;
-        self.pid = Some(pid);
+        self.pid = Some(reply.get_pid());
+        self.set_limits(limits, reply.get_limits());
         self.create_pidfile()?;
         self.change_state(ProcessState::Up);
         Ok(())
@@ -140,11 +152,11 @@ impl Supervisor {
         (healthy, status)
     }
 
-    pub fn stop(&mut self, launcher: &LauncherCli) -> Result<()> {
+    pub fn stop(&mut self, launcher: &LauncherCli, shutdown: &ShutdownPolicy) -> Result<()> {
         if self.pid.is_none() {
             return Ok(());
         }
-        launcher.terminate(self.pid.unwrap())?;
+        launcher.terminate(self.pid.unwrap(), to_launcher_shutdown(shutdown))?;
         self.cleanup_pidfile();
         self.change_state(ProcessState::Down);
         Ok(())
@@ -156,15 +168,24 @@ impl Supervisor {
         group: &ServiceGroup,
         launcher: &LauncherCli,
         svc_password: Option<T>,
+        limits: &ResourceLimits,
+        shutdown: &ShutdownPolicy,
+        logs: &LogPolicy,
     ) -> Result<()>
     where
         T: ToString,
     {
-        match self.pid {
+        let result = match self.pid {
             Some(pid) => {
-                match launcher.restart(pid) {
-                    Ok(pid) => {
-                        self.pid = Some(pid);
+                let restarted = launcher.restart(
+                    pid,
+                    to_launcher_limits(limits),
+                    to_launcher_shutdown(shutdown),
+                );
+                match restarted {
+                    Ok(reply) => {
+                        self.pid = Some(reply.get_pid());
+                        self.set_limits(limits, reply.get_limits());
                         self.create_pidfile()?;
                         self.change_state(ProcessState::Up);
                         Ok(())
@@ -176,8 +197,12 @@ impl Supervisor {
                     }
                 }
             }
-            None => self.start(pkg, group, launcher, svc_password),
+            None => self.start(pkg, group, launcher, svc_password, limits, shutdown, logs),
+        };
+        if result.is_ok() {
+            self.restarts += 1;
         }
+        result
     }
 
     /// Create a PID file for a running service
@@ -210,6 +235,20 @@ impl Supervisor {
         }
     }
 
+    /// Records the limits the Launcher applied, warning about any it could not.
+    fn set_limits(
+        &mut self,
+        requested: &ResourceLimits,
+        applied: &launcher_client::ResourceLimits,
+    ) {
+        self.limits = from_launcher_limits(applied);
+        if self.limits != *requested {
+            outputln!(preamble self.preamble,
+                "Unable to apply all resource limits; requested {}, applied {}",
+                requested, self.limits);
+        }
+    }
+
     fn change_state(&mut self, state: ProcessState) {
         if self.state == state {
             return;
@@ -224,17 +263,95 @@ impl Serialize for Supervisor {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("supervisor", 5)?;
+        let mut strukt = serializer.serialize_struct("supervisor", 6)?;
         strukt.serialize_field("pid", &self.pid)?;
         strukt.serialize_field("state", &self.state)?;
         strukt.serialize_field(
             "state_entered",
             &self.state_entered.sec,
         )?;
+        strukt.serialize_field("restarts", &self.restarts)?;
+        strukt.serialize_field("limits", &self.limits)?;
         strukt.end()
     }
 }
 
+fn to_launcher_limits(limits: &ResourceLimits) -> Option<launcher_client::ResourceLimits> {
+    if limits.is_empty() {
+        return None;
+    }
+    let mut msg = launcher_client::ResourceLimits::new();
+    if let Some(shares) = limits.cpu_shares {
+        msg.set_cpu_shares(shares);
+    }
+    if let Some(bytes) = limits.memory_bytes {
+        msg.set_memory_bytes(bytes);
+    }
+    if let Some(pids) = limits.pids_max {
+        msg.set_pids_max(pids);
+    }
+    if let Some(weight) = limits.io_weight {
+        msg.set_io_weight(weight);
+    }
+    Some(msg)
+}
+
+fn to_launcher_shutdown(shutdown: &ShutdownPolicy) -> Option<launcher_client::ShutdownPolicy> {
+    if shutdown.is_empty() {
+        return None;
+    }
+    let mut msg = launcher_client::ShutdownPolicy::new();
+    if let Some(ref signal) = shutdown.signal {
+        msg.set_signal(signal.clone());
+    }
+    if let Some(timeout) = shutdown.timeout {
+        msg.set_timeout(timeout);
+    }
+    Some(msg)
+}
+
+fn to_launcher_logs(group: &ServiceGroup, logs: &LogPolicy) -> launcher_client::LogFiles {
+    let rotation = logs.rotation();
+    let mut msg = launcher_client::LogFiles::new();
+    msg.set_path(
+        fs::svc_output_log(group.service())
+            .to_string_lossy()
+            .into_owned(),
+    );
+    msg.set_max_bytes(rotation.max_bytes);
+    if let Some(max_age) = rotation.max_age {
+        msg.set_max_age(max_age);
+    }
+    msg.set_keep(rotation.keep);
+    msg.set_compress(rotation.compress);
+    msg
+}
+
+fn from_launcher_limits(msg: &launcher_client::ResourceLimits) -> ResourceLimits {
+    ResourceLimits {
+        cpu_shares: if msg.has_cpu_shares() {
+            Some(msg.get_cpu_shares())
+        } else {
+            None
+        },
+        memory_bytes: if msg.has_memory_bytes() {
+            Some(msg.get_memory_bytes())
+        } else {
+            None
+        },
+        pids_max: if msg.has_pids_max() {
+            Some(msg.get_pids_max())
+        } else {
+            None
+        },
+        io_weight: if msg.has_io_weight() {
+            Some(msg.get_io_weight())
+        } else {
+            None
+        },
+    }
+}
+
 fn read_pid<T>(pid_file: T) -> Result<u32>
 where
     T: AsRef<Path>,
@@ -0,0 +1,466 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Named timezones, such as `Europe/Berlin`, read from the system's zoneinfo database.
+//!
+//! Zoneinfo files list the zone's past transitions between standard and daylight saving time,
+//! and end with a POSIX `TZ` rule for the transitions after the last listed one.
+
+use std::env;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+use std::result;
+
+const ZONEINFO_DIR: &'static str = "/usr/share/zoneinfo";
+const SECS_PER_DAY: i64 = 86400;
+
+type Result<T> = result::Result<T, String>;
+
+#[derive(Debug)]
+pub struct TimeZone {
+    /// Seconds since the epoch of each transition, with the UTC offset in effect from then on.
+    transitions: Vec<(i64, i32)>,
+    /// UTC offset before the first transition.
+    initial: i32,
+    /// Rule for the times after the last transition.
+    rule: Option<Rule>,
+}
+
+impl TimeZone {
+    /// Loads a zone from `$TZDIR`, or `/usr/share/zoneinfo` when it isn't set.
+    pub fn load(name: &str) -> Result<Self> {
+        let valid = !name.is_empty() && !name.starts_with('/') &&
+            name.split('/').all(|part| part != ".." && part != ".") &&
+            name.chars().all(|c| match c {
+                'a'...'z' | 'A'...'Z' | '0'...'9' | '/' | '_' | '-' | '+' => true,
+                _ => false,
+            });
+        if !valid {
+            return Err(format!("unknown timezone '{}'", name));
+        }
+        let dir = env::var("TZDIR").unwrap_or(ZONEINFO_DIR.to_string());
+        let path = PathBuf::from(dir).join(name);
+        let mut bytes = Vec::new();
+        File::open(&path)
+            .and_then(|mut file| file.read_to_end(&mut bytes))
+            .map_err(|e| {
+                format!("cannot read timezone '{}' from {}, {}", name, path.display(), e)
+            })?;
+        Self::from_bytes(&bytes).map_err(|e| format!("timezone '{}' {}", name, e))
+    }
+
+    /// Parses a zoneinfo (TZif) file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut header = Header::parse(bytes)?;
+        let mut data = &bytes[44..];
+        let mut time_size = 4;
+        if header.version >= b'2' {
+            // Skip the 32-bit data for the 64-bit data which follows it.
+            let skip = header.data_len(4);
+            if data.len() < skip {
+                return Err("is truncated".to_string());
+            }
+            header = Header::parse(&data[skip..])?;
+            data = &data[skip + 44..];
+            time_size = 8;
+        }
+        if data.len() < header.data_len(time_size) {
+            return Err("is truncated".to_string());
+        }
+        let times = &data[..header.timecnt * time_size];
+        let indices = &data[header.timecnt * time_size..header.timecnt * (time_size + 1)];
+        let types = &data[header.timecnt * (time_size + 1)..];
+        let mut offsets = Vec::with_capacity(header.typecnt);
+        for i in 0..header.typecnt {
+            offsets.push(be_i32(&types[i * 6..]));
+        }
+        if offsets.is_empty() {
+            return Err("has no local time types".to_string());
+        }
+        let mut transitions = Vec::with_capacity(header.timecnt);
+        for i in 0..header.timecnt {
+            let time = if time_size == 8 {
+                be_i64(&times[i * 8..])
+            } else {
+                be_i32(&times[i * 4..]) as i64
+            };
+            match offsets.get(indices[i] as usize) {
+                Some(offset) => transitions.push((time, *offset)),
+                None => return Err("has an invalid transition".to_string()),
+            }
+        }
+        let rule = if time_size == 8 {
+            let footer = &data[header.data_len(8)..];
+            let footer = String::from_utf8_lossy(footer);
+            let tz = footer.trim_matches('\n');
+            if tz.is_empty() {
+                None
+            } else {
+                Some(Rule::parse(tz)?)
+            }
+        } else {
+            None
+        };
+        Ok(TimeZone {
+            transitions: transitions,
+            initial: offsets[0],
+            rule: rule,
+        })
+    }
+
+    /// Returns the zone's offset from UTC, in seconds, at a time in seconds since the epoch.
+    pub fn offset_at(&self, time: i64) -> i32 {
+        match self.transitions.last() {
+            Some(&(last, _)) if time >= last && self.rule.is_some() => {
+                self.rule.as_ref().unwrap().offset_at(time)
+            }
+            None if self.rule.is_some() => self.rule.as_ref().unwrap().offset_at(time),
+            _ => {
+                match self.transitions.iter().rev().find(|&&(at, _)| at <= time) {
+                    Some(&(_, offset)) => offset,
+                    None => self.initial,
+                }
+            }
+        }
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn parse(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 44 || &bytes[..4] != b"TZif" {
+            return Err("is not a zoneinfo file".to_string());
+        }
+        let count = |i: usize| be_i32(&bytes[20 + i * 4..]) as u32 as usize;
+        Ok(Header {
+            version: bytes[4],
+            isutcnt: count(0),
+            isstdcnt: count(1),
+            leapcnt: count(2),
+            timecnt: count(3),
+            typecnt: count(4),
+            charcnt: count(5),
+        })
+    }
+
+    /// Length of the data following the header, with times of `time_size` bytes.
+    fn data_len(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1) + self.typecnt * 6 + self.charcnt +
+            self.leapcnt * (time_size + 4) + self.isstdcnt + self.isutcnt
+    }
+}
+
+/// A POSIX `TZ` rule, such as `CET-1CEST,M3.5.0,M10.5.0/3`.
+#[derive(Debug)]
+struct Rule {
+    /// Offsets from UTC, in seconds, east of Greenwich.
+    std_offset: i32,
+    dst: Option<(i32, Transition, Transition)>,
+}
+
+/// The day and local time of a transition.
+#[derive(Debug)]
+enum Transition {
+    /// Month, week of the month (5 being the last) and day of the week, Sunday being 0.
+    MonthWeekDay(u32, u32, u32, i32),
+    /// Day of the year from 1 to 365, not counting February 29th.
+    Julian(u32, i32),
+    /// Day of the year from 0 to 365, counting February 29th.
+    Day(u32, i32),
+}
+
+impl Rule {
+    fn parse(tz: &str) -> Result<Self> {
+        let invalid = || format!("has an invalid rule '{}'", tz);
+        let mut rest = skip_name(tz).ok_or_else(&invalid)?;
+        let (std, len) = parse_offset(rest).ok_or_else(&invalid)?;
+        rest = &rest[len..];
+        if rest.is_empty() {
+            return Ok(Rule {
+                std_offset: -std,
+                dst: None,
+            });
+        }
+        rest = skip_name(rest).ok_or_else(&invalid)?;
+        let dst = match parse_offset(rest) {
+            Some((dst, len)) => {
+                rest = &rest[len..];
+                dst
+            }
+            None => std - 3600,
+        };
+        let mut parts = rest.split(',').skip(1);
+        let start = parts.next().and_then(Transition::parse).ok_or_else(
+            &invalid,
+        )?;
+        let end = parts.next().and_then(Transition::parse).ok_or_else(
+            &invalid,
+        )?;
+        if !rest.starts_with(',') || parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(Rule {
+            std_offset: -std,
+            dst: Some((-dst, start, end)),
+        })
+    }
+
+    fn offset_at(&self, time: i64) -> i32 {
+        let (dst_offset, ref start, ref end) = match self.dst {
+            Some(ref dst) => (dst.0, &dst.1, &dst.2),
+            None => return self.std_offset,
+        };
+        let year = civil_from_days(div_floor(time + self.std_offset as i64, SECS_PER_DAY)).0;
+        // Daylight saving time starts in standard time, and ends in daylight saving time.
+        let starts = start.local_time(year) - self.std_offset as i64;
+        let ends = end.local_time(year) - dst_offset as i64;
+        let in_dst = if starts < ends {
+            time >= starts && time < ends
+        } else {
+            !(time >= ends && time < starts)
+        };
+        if in_dst { dst_offset } else { self.std_offset }
+    }
+}
+
+impl Transition {
+    fn parse(rule: &str) -> Option<Self> {
+        let (date, time) = match rule.find('/') {
+            Some(idx) => {
+                match parse_offset(&rule[idx + 1..]) {
+                    Some((time, len)) if len == rule.len() - idx - 1 => (&rule[..idx], time),
+                    _ => return None,
+                }
+            }
+            None => (rule, 7200),
+        };
+        if date.starts_with('M') {
+            let fields: Vec<u32> = date[1..].split('.').filter_map(|f| f.parse().ok()).collect();
+            if fields.len() != 3 || date[1..].split('.').count() != 3 {
+                return None;
+            }
+            let (m, w, d) = (fields[0], fields[1], fields[2]);
+            if m >= 1 && m <= 12 && w >= 1 && w <= 5 && d <= 6 {
+                Some(Transition::MonthWeekDay(m, w, d, time))
+            } else {
+                None
+            }
+        } else if date.starts_with('J') {
+            match date[1..].parse() {
+                Ok(n) if n >= 1 && n <= 365 => Some(Transition::Julian(n, time)),
+                _ => None,
+            }
+        } else {
+            match date.parse() {
+                Ok(n) if n <= 365 => Some(Transition::Day(n, time)),
+                _ => None,
+            }
+        }
+    }
+
+    /// Returns the local time of the transition in a year, in seconds since the epoch.
+    fn local_time(&self, year: i64) -> i64 {
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let (day, time) = match *self {
+            Transition::MonthWeekDay(month, week, weekday, time) => {
+                let first = days_from_civil(year, month, 1);
+                // 1970-01-01 was a Thursday
+                let first_weekday = (first + 4 - div_floor(first + 4, 7) * 7) as u32;
+                let mut day = 1 + (weekday + 7 - first_weekday) % 7 + (week - 1) * 7;
+                let month_len = (days_from_civil(
+                    year + month as i64 / 12,
+                    month % 12 + 1,
+                    1,
+                ) - first) as u32;
+                while day > month_len {
+                    day -= 7;
+                }
+                (first + day as i64 - 1, time)
+            }
+            Transition::Julian(n, time) => {
+                let extra = if leap && n >= 60 { 1 } else { 0 };
+                (days_from_civil(year, 1, 1) + n as i64 - 1 + extra, time)
+            }
+            Transition::Day(n, time) => (days_from_civil(year, 1, 1) + n as i64, time),
+        };
+        day * SECS_PER_DAY + time as i64
+    }
+}
+
+/// Skips a zone abbreviation, either alphabetic or quoted in angle brackets.
+fn skip_name(tz: &str) -> Option<&str> {
+    if tz.starts_with('<') {
+        return tz.find('>').map(|idx| &tz[idx + 1..]);
+    }
+    let len = tz.find(|c: char| match c {
+        'a'...'z' | 'A'...'Z' => false,
+        _ => true,
+    }).unwrap_or(tz.len());
+    if len < 3 { None } else { Some(&tz[len..]) }
+}
+
+/// Parses a `[+-]hh[:mm[:ss]]` offset into seconds, returning it with the length parsed.
+fn parse_offset(value: &str) -> Option<(i32, usize)> {
+    let (sign, digits) = match value.chars().next() {
+        Some('-') => (-1, &value[1..]),
+        Some('+') => (1, &value[1..]),
+        _ => (1, value),
+    };
+    let len = digits
+        .find(|c: char| match c {
+            '0'...'9' | ':' => false,
+            _ => true,
+        })
+        .unwrap_or(digits.len());
+    if len == 0 {
+        return None;
+    }
+    let mut seconds = 0;
+    let mut unit = 3600;
+    for part in digits[..len].split(':') {
+        if unit == 0 {
+            return None;
+        }
+        match part.parse::<i32>() {
+            Ok(value) => seconds += value * unit,
+            Err(_) => return None,
+        }
+        unit /= 60;
+    }
+    Some((sign * seconds, value.len() - digits.len() + len))
+}
+
+/// Returns the number of days since the epoch of a date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = div_floor(year, 400);
+    let yoe = year - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Returns the year, month and day of a number of days since the epoch.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = div_floor(days, 146097);
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Division rounding towards negative infinity.
+fn div_floor(value: i64, divisor: i64) -> i64 {
+    let quotient = value / divisor;
+    if value % divisor < 0 { quotient - 1 } else { quotient }
+}
+
+fn be_i32(bytes: &[u8]) -> i32 {
+    ((bytes[0] as i32) << 24) | ((bytes[1] as i32) << 16) | ((bytes[2] as i32) << 8) |
+        bytes[3] as i32
+}
+
+fn be_i64(bytes: &[u8]) -> i64 {
+    ((be_i32(bytes) as i64) << 32) | (be_i32(&bytes[4..]) as u32 as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{civil_from_days, days_from_civil, Rule, TimeZone};
+
+    // 2017-03-26 00:59:59 UTC, a second before Central European Summer Time starts
+    const BEFORE_CEST: i64 = 1490489999;
+
+    /// Builds a version 2 zoneinfo file holding only a rule.
+    fn zoneinfo(rule: &str) -> Vec<u8> {
+        let mut header = b"TZif2".to_vec();
+        header.extend_from_slice(&[0; 15]);
+        // One local time type, with a one letter abbreviation
+        for count in [0u8, 0, 0, 0, 1, 2].iter() {
+            header.extend_from_slice(&[0, 0, 0, *count]);
+        }
+        let mut bytes = header.clone();
+        bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, b'Z', 0]);
+        bytes.extend_from_slice(&header);
+        bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, b'Z', 0]);
+        bytes.push(b'\n');
+        bytes.extend_from_slice(rule.as_bytes());
+        bytes.push(b'\n');
+        bytes
+    }
+
+    #[test]
+    fn civil_dates_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2017, 3, 26), 17251);
+        assert_eq!(civil_from_days(17251), (2017, 3, 26));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn rule_switches_to_daylight_saving_time() {
+        let rule = Rule::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        assert_eq!(rule.offset_at(BEFORE_CEST), 3600);
+        assert_eq!(rule.offset_at(BEFORE_CEST + 1), 7200);
+        // 2017-10-29 00:59:59 and 01:00:00 UTC
+        assert_eq!(rule.offset_at(1509238799), 7200);
+        assert_eq!(rule.offset_at(1509238800), 3600);
+    }
+
+    #[test]
+    fn rule_in_the_southern_hemisphere() {
+        let rule = Rule::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        // January and July 2017
+        assert_eq!(rule.offset_at(1484000000), 39600);
+        assert_eq!(rule.offset_at(1500000000), 36000);
+    }
+
+    #[test]
+    fn rule_without_daylight_saving_time() {
+        assert_eq!(Rule::parse("<+0330>-3:30").unwrap().offset_at(0), 12600);
+        assert_eq!(Rule::parse("EST5").unwrap().offset_at(0), -18000);
+        assert!(Rule::parse("EST").is_err());
+        assert!(Rule::parse("CET-1CEST,M3.5.0").is_err());
+    }
+
+    #[test]
+    fn timezone_from_zoneinfo_uses_its_rule() {
+        let tz = TimeZone::from_bytes(&zoneinfo("CET-1CEST,M3.5.0,M10.5.0/3")).unwrap();
+        assert_eq!(tz.offset_at(BEFORE_CEST), 3600);
+        assert_eq!(tz.offset_at(BEFORE_CEST + 1), 7200);
+        assert!(TimeZone::from_bytes(b"not a zoneinfo file").is_err());
+    }
+
+    #[test]
+    fn load_rejects_paths() {
+        assert!(TimeZone::load("../../etc/passwd").is_err());
+        assert!(TimeZone::load("/etc/passwd").is_err());
+    }
+}
@@ -12,15 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::collections::HashMap;
 use std::path::Path;
+use std::result;
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
 use std::time::Duration;
 
 use butterfly;
 use common::ui::{Coloring, Status, UI};
-use depot_client;
 use env;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
@@ -30,10 +31,10 @@ use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
 use launcher_client::LauncherCli;
 use time::{SteadyTime, Duration as TimeDuration};
 
-use {PRODUCT, VERSION};
 use error::Result;
-use census::CensusRing;
-use manager::service::{Service, Topology, UpdateStrategy};
+use census::{CensusGroup, CensusMember, CensusRing};
+use manager::service::{HealthCheck, RollbackReason, Service, UpdateStrategy};
+use util::artifact_source::ArtifactSource;
 
 static LOGKEY: &'static str = "SU";
 const FREQUENCY_ENVVAR: &'static str = "HAB_UPDATE_STRATEGY_FREQUENCY_MS";
@@ -44,6 +45,7 @@ type UpdaterStateList = HashMap<ServiceGroup, UpdaterState>;
 enum UpdaterState {
     AtOnce(Receiver<PackageInstall>),
     Rolling(RollingState),
+    Canary(CanaryState),
 }
 
 enum RollingState {
@@ -63,15 +65,63 @@ enum FollowerState {
     Updating(Receiver<PackageInstall>),
 }
 
+enum CanaryState {
+    /// A canary polling for a newer package.
+    Polling(Receiver<PackageInstall>),
+    /// A canary running a new package. Holds the package to roll back to and the time since which
+    /// the health check has been `Ok`.
+    Soaking(PackageIdent, SteadyTime),
+    /// A regular member waiting for the canaries to prove a release. Holds the release the
+    /// canaries run and the time since which they have all been healthy.
+    Waiting(Option<(PackageIdent, SteadyTime)>),
+    /// A regular member installing the release proven by the canaries.
+    Updating(Receiver<PackageInstall>),
+}
+
 pub struct ServiceUpdater {
     states: UpdaterStateList,
+    pending: Pending,
     butterfly: butterfly::Server,
 }
 
+/// Packages which were downloaded but may not be restarted into yet.
+struct Pending {
+    packages: HashMap<ServiceGroup, PackageInstall>,
+    /// When a package was last downloaded for a service of each composite.
+    composites: HashMap<String, SteadyTime>,
+    /// How long a composite's services wait after a package was downloaded for any of them.
+    frequency: TimeDuration,
+}
+
+impl Pending {
+    fn new(frequency: TimeDuration) -> Self {
+        Pending {
+            packages: HashMap::new(),
+            composites: HashMap::new(),
+            frequency: frequency,
+        }
+    }
+
+    /// A package is held outside of its service's maintenance window. A service of a composite
+    /// also waits until no package has been downloaded for any of the composite's services for a
+    /// full update period, so that the others have found their releases too and all of them
+    /// restart together.
+    fn may_restart(&self, service: &Service) -> bool {
+        if !service.in_maintenance_window() {
+            return false;
+        }
+        match service.composite.as_ref().and_then(|c| self.composites.get(c)) {
+            Some(received) => SteadyTime::now() - *received >= self.frequency,
+            None => true,
+        }
+    }
+}
+
 impl ServiceUpdater {
     pub fn new(butterfly: butterfly::Server) -> Self {
         ServiceUpdater {
             states: UpdaterStateList::default(),
+            pending: Pending::new(TimeDuration::milliseconds(update_frequency())),
             butterfly: butterfly,
         }
     }
@@ -94,9 +144,22 @@ impl ServiceUpdater {
                 );
                 true
             }
+            UpdateStrategy::Canary => {
+                self.states.entry(service.service_group.clone()).or_insert(
+                    UpdaterState::Canary(CanaryState::Waiting(None)),
+                );
+                true
+            }
         }
     }
 
+    /// Stops tracking updates for a service which is no longer supervised, dropping any package
+    /// it had pending.
+    pub fn remove(&mut self, service_group: &ServiceGroup) {
+        self.states.remove(service_group);
+        self.pending.packages.remove(service_group);
+    }
+
     pub fn check_for_updated_package(
         &mut self,
         service: &mut Service,
@@ -106,7 +169,7 @@ impl ServiceUpdater {
         let mut updated = false;
         match self.states.get_mut(&service.service_group) {
             Some(&mut UpdaterState::AtOnce(ref mut rx)) => {
-                match rx.try_recv() {
+                match receive_package(&mut self.pending, service, rx) {
                     Ok(package) => {
                         service.update_package(package, launcher);
                         return true;
@@ -119,7 +182,7 @@ impl ServiceUpdater {
             }
             Some(&mut UpdaterState::Rolling(ref mut st @ RollingState::AwaitingElection)) => {
                 if let Some(census_group) = census_ring.census_group_for(&service.service_group) {
-                    if service.topology == Topology::Leader {
+                    if service.topology.elects_leader() {
                         debug!(
                             "Rolling Update, determining proper suitability because we're in \
                                 a leader topology"
@@ -135,6 +198,7 @@ impl ServiceUpdater {
                                     service.service_group.clone(),
                                     suitability,
                                     0,
+                                    service.quorum.unwrap_or(0),
                                 );
                                 *st = RollingState::InElection
                             }
@@ -146,6 +210,7 @@ impl ServiceUpdater {
                             service.service_group.clone(),
                             0,
                             0,
+                            service.quorum.unwrap_or(0),
                         );
                         *st = RollingState::InElection;
                     }
@@ -173,7 +238,7 @@ impl ServiceUpdater {
             Some(&mut UpdaterState::Rolling(RollingState::Leader(ref mut state))) => {
                 match *state {
                     LeaderState::Polling(ref mut rx) => {
-                        match rx.try_recv() {
+                        match receive_package(&mut self.pending, service, rx) {
                             Ok(package) => {
                                 debug!("Rolling Update, polling found a new package");
                                 service.update_package(package, launcher);
@@ -228,6 +293,10 @@ impl ServiceUpdater {
                                             debug!("We're not in an update");
                                             return false;
                                         }
+                                        if leader.pkg.as_ref() == service.failed_update() {
+                                            debug!("We already rolled back from this update");
+                                            return false;
+                                        }
                                         if leader.pkg != peer.pkg {
                                             debug!("We're in an update but it's not our turn");
                                             return false;
@@ -253,7 +322,7 @@ impl ServiceUpdater {
                     FollowerState::Updating(ref mut rx) => {
                         match census_ring.census_group_for(&service.service_group) {
                             Some(census_group) => {
-                                match rx.try_recv() {
+                                match receive_package(&mut self.pending, service, rx) {
                                     Ok(package) => {
                                         service.update_package(package, launcher);
                                         updated = true
@@ -283,16 +352,227 @@ impl ServiceUpdater {
                     *state = FollowerState::Waiting;
                 }
             }
+            Some(&mut UpdaterState::Canary(ref mut state)) => {
+                let census_group = match census_ring.census_group_for(&service.service_group) {
+                    Some(census_group) => census_group,
+                    None => return false,
+                };
+                let canary_count = cmp::max(service.canary_policy.count, 1) as usize;
+                let soak = TimeDuration::seconds(service.canary_policy.soak as i64);
+                let canaries = census_group.canaries(canary_count);
+                let is_canary = match census_group.me() {
+                    Some(me) => canaries.iter().any(|cm| cm.member_id == me.member_id),
+                    None => return false,
+                };
+                let next = match *state {
+                    CanaryState::Polling(ref mut rx) => {
+                        if !is_canary {
+                            debug!("Canary update, no longer a canary");
+                            Some(CanaryState::Waiting(None))
+                        } else {
+                            match receive_package(&mut self.pending, service, rx) {
+                                Ok(package) => {
+                                    debug!("Canary update, polling found a new package");
+                                    let previous = service.pkg.ident.clone();
+                                    service.update_package(package, launcher);
+                                    updated = true;
+                                    Some(CanaryState::Soaking(previous, SteadyTime::now()))
+                                }
+                                Err(TryRecvError::Empty) => None,
+                                Err(TryRecvError::Disconnected) => {
+                                    debug!("Service Updater worker has died; restarting...");
+                                    *rx = Worker::new(service).start(&service.service_group, None);
+                                    None
+                                }
+                            }
+                        }
+                    }
+                    CanaryState::Soaking(ref previous, ref mut since) => {
+                        if service.pkg.ident == *previous {
+                            debug!("Canary update, the service already rolled back");
+                            let rx = Worker::new(service).start(&service.service_group, None);
+                            Some(CanaryState::Polling(rx))
+                        } else {
+                            match service.health_check() {
+                                HealthCheck::Critical => {
+                                    outputln!(preamble service.service_group,
+                                              "Canary {} is unhealthy",
+                                              service.pkg.ident);
+                                    updated =
+                                        service.revert_update(RollbackReason::Unhealthy, launcher);
+                                    let rx =
+                                        Worker::new(service).start(&service.service_group, None);
+                                    Some(CanaryState::Polling(rx))
+                                }
+                                HealthCheck::Ok => {
+                                    if SteadyTime::now() - *since < soak {
+                                        return false;
+                                    }
+                                    outputln!(preamble service.service_group,
+                                              "Canary {} stayed healthy for {}s",
+                                              service.pkg.ident,
+                                              service.canary_policy.soak);
+                                    let rx =
+                                        Worker::new(service).start(&service.service_group, None);
+                                    Some(CanaryState::Polling(rx))
+                                }
+                                _ => {
+                                    *since = SteadyTime::now();
+                                    None
+                                }
+                            }
+                        }
+                    }
+                    CanaryState::Waiting(ref mut proven) => {
+                        if is_canary {
+                            debug!("Canary update, we're a canary");
+                            let rx = Worker::new(service).start(&service.service_group, None);
+                            Some(CanaryState::Polling(rx))
+                        } else {
+                            match healthy_canary_release(census_group, &canaries) {
+                                Some(release) => {
+                                    let since = match *proven {
+                                        Some((ref ident, since)) if *ident == release => {
+                                            Some(since)
+                                        }
+                                        _ => None,
+                                    };
+                                    match since {
+                                        Some(since) if SteadyTime::now() - since >= soak => {
+                                            debug!("Canary update, canaries proved {}", release);
+                                            let rx = Worker::new(service).start(
+                                                &service.service_group,
+                                                Some(release),
+                                            );
+                                            Some(CanaryState::Updating(rx))
+                                        }
+                                        Some(_) => None,
+                                        None => {
+                                            *proven = Some((release, SteadyTime::now()));
+                                            None
+                                        }
+                                    }
+                                }
+                                None => {
+                                    *proven = None;
+                                    None
+                                }
+                            }
+                        }
+                    }
+                    CanaryState::Updating(ref mut rx) => {
+                        match receive_package(&mut self.pending, service, rx) {
+                            Ok(package) => {
+                                service.update_package(package, launcher);
+                                updated = true;
+                                Some(CanaryState::Waiting(None))
+                            }
+                            Err(TryRecvError::Empty) => None,
+                            Err(TryRecvError::Disconnected) => {
+                                debug!("Service Updater worker has died; restarting...");
+                                Some(CanaryState::Waiting(None))
+                            }
+                        }
+                    }
+                };
+                if let Some(next) = next {
+                    *state = next;
+                }
+            }
             None => {}
         }
         updated
     }
 }
 
+/// Returns the release the canaries of a service group agree on, if all of them run the same
+/// release, it is newer than our own, and all of them report a healthy service.
+fn healthy_canary_release(
+    census_group: &CensusGroup,
+    canaries: &[&CensusMember],
+) -> Option<PackageIdent> {
+    let current = match census_group.me().and_then(|me| me.pkg.as_ref()) {
+        Some(current) => current,
+        None => return None,
+    };
+    let release = match canaries.first().and_then(|cm| cm.pkg.as_ref()) {
+        Some(release) => release,
+        None => return None,
+    };
+    if release <= current {
+        return None;
+    }
+    if canaries.iter().all(|cm| {
+        cm.pkg.as_ref() == Some(release) && cm.health == HealthCheck::Ok
+    })
+    {
+        Some(release.clone())
+    } else {
+        None
+    }
+}
+
+/// Receives the package a worker downloaded, but only hands it over once the service may restart
+/// into it. A package received before then is held until it may.
+fn receive_package(
+    pending: &mut Pending,
+    service: &Service,
+    rx: &Receiver<PackageInstall>,
+) -> result::Result<PackageInstall, TryRecvError> {
+    if let Some(package) = pending.packages.remove(&service.service_group) {
+        if pending.may_restart(service) {
+            return Ok(package);
+        }
+        pending.packages.insert(service.service_group.clone(), package);
+        return Err(TryRecvError::Empty);
+    }
+    let package = rx.try_recv()?;
+    if let Some(ref composite) = service.composite {
+        pending.composites.insert(composite.clone(), SteadyTime::now());
+    }
+    if pending.may_restart(service) {
+        Ok(package)
+    } else {
+        if service.in_maintenance_window() {
+            outputln!(preamble service.service_group,
+                      "Downloaded {}; waiting for the rest of its composite to restart into it",
+                      package.ident());
+        } else {
+            outputln!(preamble service.service_group,
+                      "Downloaded {}; waiting for the maintenance window to restart into it",
+                      package.ident());
+        }
+        pending.packages.insert(service.service_group.clone(), package);
+        Err(TryRecvError::Empty)
+    }
+}
+
+/// Milliseconds between checks for a newer package.
+fn update_frequency() -> i64 {
+    match env::var(FREQUENCY_ENVVAR) {
+        Ok(val) => {
+            match val.parse::<i64>() {
+                Ok(num) => num,
+                Err(_) => {
+                    outputln!(
+                        "Unable to parse '{}' from {} as a valid integer. Falling back \
+                              to default {} MS frequency.",
+                        val,
+                        FREQUENCY_ENVVAR,
+                        DEFAULT_FREQUENCY
+                    );
+                    DEFAULT_FREQUENCY
+                }
+            }
+        }
+        Err(_) => DEFAULT_FREQUENCY,
+    }
+}
+
 struct Worker {
     current: PackageIdent,
     spec_ident: PackageIdent,
-    depot: depot_client::Client,
+    source: ArtifactSource,
     channel: String,
     update_strategy: UpdateStrategy,
     ui: UI,
@@ -300,21 +580,34 @@ struct Worker {
 
 impl Worker {
     fn new(service: &Service) -> Self {
-        Worker {
+        let worker = Worker {
             current: service.pkg.ident.clone(),
             spec_ident: service.spec_ident.clone(),
-            depot: depot_client::Client::new(&service.depot_url, PRODUCT, VERSION, None).unwrap(),
+            source: ArtifactSource::new(&service.depot_url).unwrap(),
             channel: service.channel.clone(),
             update_strategy: service.update_strategy.clone(),
             ui: UI::default_with(Coloring::Never, None),
+        };
+        // Never update to a release we already had to roll back from; wait for a newer one
+        match service.failed_update() {
+            Some(failed) if *failed > worker.current => worker.skipping(failed.clone()),
+            _ => worker,
         }
     }
 
+    /// Consider the given package as the one currently running, so only a newer release will be
+    /// picked up by a polling worker.
+    fn skipping(mut self, ident: PackageIdent) -> Self {
+        self.current = ident;
+        self
+    }
+
     /// Start a new update worker.
     ///
     /// Passing an optional package identifier will make the worker perform a run-once update to
-    /// retrieve a specific version from a remote Depot. If no package identifier is specified,
-    /// then the updater will poll until a newer more suitable package is found.
+    /// retrieve a specific version from the service's Depot or artifact directory. If no package
+    /// identifier is specified, then the updater will poll until a newer more suitable package is
+    /// found.
     fn start(mut self, sg: &ServiceGroup, ident: Option<PackageIdent>) -> Receiver<PackageInstall> {
         let (tx, rx) = sync_channel(0);
         thread::Builder::new()
@@ -331,7 +624,7 @@ impl Worker {
         outputln!("Updating from {} to {}", self.current, ident);
         loop {
             let next_check = SteadyTime::now() +
-                TimeDuration::milliseconds(self.update_frequency());
+                TimeDuration::milliseconds(update_frequency());
             match self.install(&ident, true) {
                 Ok(package) => {
                     self.current = package.ident().clone();
@@ -350,14 +643,10 @@ impl Worker {
     fn run_poll(&mut self, sender: SyncSender<PackageInstall>) {
         loop {
             let next_check = SteadyTime::now() +
-                TimeDuration::milliseconds(self.update_frequency());
+                TimeDuration::milliseconds(update_frequency());
             let mut package: Option<PackageInstall> = None;
-            match self.depot.show_package(
-                &self.spec_ident,
-                Some(&self.channel),
-            ) {
-                Ok(remote) => {
-                    let latest: PackageIdent = remote.get_ident().clone().into();
+            match self.source.latest(&self.spec_ident, &self.channel) {
+                Ok(latest) => {
                     if latest > self.current {
                         outputln!("Updating from {} to {}", self.current, latest);
                         match self.install(&latest, true) {
@@ -414,7 +703,7 @@ impl Worker {
 
     fn download(&mut self, package: &PackageIdent) -> Result<PackageInstall> {
         outputln!("Downloading {}", package);
-        let mut archive = self.depot.fetch_package(
+        let mut archive = self.source.fetch_package(
             package,
             &Path::new(&*FS_ROOT_PATH).join(
                 CACHE_ARTIFACT_PATH,
@@ -439,38 +728,13 @@ impl Worker {
         Ok(pkg)
     }
 
-    fn update_frequency(&self) -> i64 {
-        match env::var(FREQUENCY_ENVVAR) {
-            Ok(val) => {
-                match val.parse::<i64>() {
-                    Ok(num) => num,
-                    Err(_) => {
-                        outputln!(
-                            "Unable to parse '{}' from {} as a valid integer. Falling back \
-                                  to default {} MS frequency.",
-                            val,
-                            FREQUENCY_ENVVAR,
-                            DEFAULT_FREQUENCY
-                        );
-                        DEFAULT_FREQUENCY
-                    }
-                }
-            }
-            Err(_) => DEFAULT_FREQUENCY,
-        }
-    }
-
-    // TODO (CM): Copied (with modifications) from
-    // common::command::package::install; will be properly factored in
-    // an upcoming broad refactoring and consolidation of our
-    // installation logic.
     fn fetch_origin_key(&mut self, name_with_rev: &str) -> Result<()> {
         self.ui.status(
             Status::Downloading,
             format!("{} public origin key", &name_with_rev),
         )?;
         let (name, rev) = parse_name_with_rev(&name_with_rev)?;
-        self.depot.fetch_origin_key(
+        self.source.fetch_origin_key(
             &name,
             &rev,
             &default_cache_key_path(None),
@@ -26,12 +26,13 @@ use glob::glob;
 use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 
 use error::{Error, Result};
-use manager::service::ServiceSpec;
+use manager::service::{CompositeSpec, ServiceSpec};
 
 static LOGKEY: &'static str = "SW";
 const WATCHER_DELAY_MS: u64 = 2_000;
 const SPEC_FILE_EXT: &'static str = "spec";
 const SPEC_FILE_GLOB: &'static str = "*.spec";
+const COMPOSITE_FILE_GLOB: &'static str = "*.composite";
 
 #[derive(Debug, PartialEq)]
 pub enum SpecWatcherEvent {
@@ -269,8 +270,134 @@ impl SpecWatcher {
             }
             specs.insert(spec.ident.name.clone(), spec);
         }
+        self.expand_composites(&mut specs)?;
+
+        // Skip every spec which depends on itself, directly or through other specs, as none of
+        // the services in a cycle could ever start.
+        let cycles: Vec<(String, Vec<String>)> = specs
+            .keys()
+            .filter_map(|name| {
+                dependency_cycle(&specs, name).map(|cycle| (name.clone(), cycle))
+            })
+            .collect();
+        for (name, cycle) in cycles {
+            let spec = specs.remove(&name).expect("value should exist for key");
+            outputln!(
+                "Error when loading service spec file '{}' \
+                      (Local dependencies form a cycle, {}). \
+                      This file will be skipped.",
+                self.watch_path.join(spec.file_name()).display(),
+                cycle.join(" -> ")
+            );
+        }
         Ok(specs)
     }
+
+    /// Adds the specs of the services of each composite spec on disk. A composite is loaded as
+    /// a whole or not at all, so one sharing a service with another spec is skipped.
+    fn expand_composites(&self, specs: &mut HashMap<String, ServiceSpec>) -> Result<()> {
+        let composite_files: Vec<PathBuf> =
+            glob(&self.watch_path.join(COMPOSITE_FILE_GLOB).display().to_string())?
+                .filter_map(|p| p.ok())
+                .filter(|p| p.is_file())
+                .collect();
+
+        for composite_file in composite_files {
+            let composite = match CompositeSpec::from_file(&composite_file) {
+                Ok(c) => c,
+                Err(e) => {
+                    match e.err {
+                        Error::ServiceSpecParse(_) |
+                        Error::MissingRequiredIdent |
+                        Error::InvalidCompositeSpec(_) => {
+                            outputln!(
+                                "Error when loading composite spec file '{}' ({}). \
+                                      This file will be skipped.",
+                                composite_file.display(),
+                                e.description()
+                            );
+                            continue;
+                        }
+                        _ => return Err(e),
+                    }
+                }
+            };
+            if composite_file.file_name().and_then(OsStr::to_str) !=
+                Some(composite.file_name().as_str())
+            {
+                outputln!(
+                    "Error when loading composite spec file '{}' \
+                          (File name does not match composite name '{}', \
+                          it should be called '{}'). \
+                          This file will be skipped.",
+                    composite_file.display(),
+                    &composite.name,
+                    composite.file_name()
+                );
+                continue;
+            }
+            let taken: Vec<String> = composite
+                .service_names()
+                .into_iter()
+                .filter(|name| specs.contains_key(name))
+                .collect();
+            if !taken.is_empty() {
+                outputln!(
+                    "Error when loading composite spec file '{}' \
+                          (Services already loaded by another spec, {}). \
+                          This file will be skipped.",
+                    composite_file.display(),
+                    taken.join(", ")
+                );
+                continue;
+            }
+            for spec in composite.service_specs() {
+                specs.insert(spec.ident.name.clone(), spec);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the chain of local dependencies leading from the named spec back to itself, if there
+/// is one.
+fn dependency_cycle(specs: &HashMap<String, ServiceSpec>, name: &str) -> Option<Vec<String>> {
+    let mut path = vec![name.to_string()];
+    let mut visited = HashSet::new();
+    if find_dependency_path(specs, name, name, &mut path, &mut visited) {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+/// Searches depth first for a chain of local dependencies from one spec to another, extending
+/// `path` with it when found.
+fn find_dependency_path(
+    specs: &HashMap<String, ServiceSpec>,
+    from: &str,
+    to: &str,
+    path: &mut Vec<String>,
+    visited: &mut HashSet<String>,
+) -> bool {
+    let spec = match specs.get(from) {
+        Some(spec) => spec,
+        None => return false,
+    };
+    for dependency in spec.depends_on.iter() {
+        if dependency.name == to {
+            path.push(to.to_string());
+            return true;
+        }
+        if visited.insert(dependency.name.clone()) {
+            path.push(dependency.name.clone());
+            if find_dependency_path(specs, &dependency.name, to, path, visited) {
+                return true;
+            }
+            path.pop();
+        }
+    }
+    false
 }
 
 #[cfg(test)]
@@ -290,7 +417,7 @@ mod test {
 
     use super::{SpecWatcher, SpecWatcherEvent};
     use error::Error::*;
-    use manager::service::ServiceSpec;
+    use manager::service::{CompositeSpec, LocalDependency, ServiceSpec};
 
     #[test]
     fn run_watch_dir_not_created() {
@@ -532,6 +659,42 @@ mod test {
         assert!(events.contains(&SpecWatcherEvent::AddService(alpha)));
     }
 
+    #[test]
+    fn loading_specs_with_dependency_cycle_skips_cycle() {
+        let tmpdir = TempDir::new("specs").unwrap();
+        let alpha = new_saved_spec_depending_on(tmpdir.path(), "acme/alpha", vec![]);
+        let beta = new_saved_spec_depending_on(tmpdir.path(), "acme/beta", vec!["alpha"]);
+        new_saved_spec_depending_on(tmpdir.path(), "acme/gamma", vec!["beta", "delta"]);
+        new_saved_spec_depending_on(tmpdir.path(), "acme/delta", vec!["gamma:healthy"]);
+        new_saved_spec_depending_on(tmpdir.path(), "acme/epsilon", vec!["epsilon"]);
+
+        let mut watcher = SpecWatcher::run(tmpdir.path()).unwrap();
+
+        let events = watcher.initial_events().unwrap();
+
+        assert_eq!(2, events.len());
+        assert!(events.contains(&SpecWatcherEvent::AddService(alpha)));
+        assert!(events.contains(&SpecWatcherEvent::AddService(beta)));
+    }
+
+    #[test]
+    fn loading_composites_expands_services_and_skips_conflicts() {
+        let tmpdir = TempDir::new("specs").unwrap();
+        let alpha = new_saved_spec(tmpdir.path(), "acme/alpha");
+        let shop = new_saved_composite(tmpdir.path(), "shop", vec!["acme/beta", "acme/gamma"]);
+        new_saved_composite(tmpdir.path(), "clash", vec!["acme/alpha", "acme/delta"]);
+
+        let mut watcher = SpecWatcher::run(tmpdir.path()).unwrap();
+
+        let events = watcher.initial_events().unwrap();
+
+        assert_eq!(3, events.len());
+        assert!(events.contains(&SpecWatcherEvent::AddService(alpha)));
+        for spec in shop.service_specs() {
+            assert!(events.contains(&SpecWatcherEvent::AddService(spec)));
+        }
+    }
+
     #[test]
     fn loading_spec_ident_name_mismatch_doesnt_impact_others() {
         let tmpdir = TempDir::new("specs").unwrap();
@@ -645,6 +808,33 @@ mod test {
         spec
     }
 
+    fn new_saved_spec_depending_on(
+        tmpdir: &Path,
+        ident: &str,
+        dependencies: Vec<&str>,
+    ) -> ServiceSpec {
+        let mut spec = new_spec(ident);
+        spec.depends_on = dependencies
+            .into_iter()
+            .map(|d| LocalDependency::from_str(d).expect("couldn't parse dependency str"))
+            .collect();
+        spec.to_file(tmpdir.join(format!("{}.spec", &spec.ident.name)))
+            .expect("couldn't save spec to disk");
+        spec
+    }
+
+    fn new_saved_composite(tmpdir: &Path, name: &str, idents: Vec<&str>) -> CompositeSpec {
+        let mut toml = format!("name = \"{}\"\n", name);
+        for ident in idents {
+            toml.push_str(&format!("[[services]]\nident = \"{}\"\n", ident));
+        }
+        let composite = CompositeSpec::from_str(&toml).expect("couldn't parse composite str");
+        composite
+            .to_file(tmpdir.join(composite.file_name()))
+            .expect("couldn't save composite to disk");
+        composite
+    }
+
     fn map_for_specs(idents: Vec<&str>) -> HashMap<String, ServiceSpec> {
         let mut map = HashMap::new();
         for ident in idents {
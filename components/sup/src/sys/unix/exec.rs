@@ -14,14 +14,18 @@
 
 use std::ffi::OsStr;
 use std::os::unix::process::CommandExt;
-use std::process::{Child, Command, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use hcore::os;
+use libc;
 
 use error::{Error, Result};
 use manager::service::Pkg;
 
 static LOGKEY: &'static str = "EX";
+const WAIT_INTERVAL_MS: u64 = 50;
 
 pub fn run<T, S>(path: S, pkg: &Pkg, _: Option<T>) -> Result<Child>
 where
@@ -43,6 +47,14 @@ where
             ))
         ),
     )?;
+    // Run the hook in its own process group, so that it can be killed along with any processes
+    // it started.
+    cmd.before_exec(|| {
+        unsafe {
+            libc::setpgid(0, 0);
+        }
+        Ok(())
+    });
     cmd.stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -53,3 +65,26 @@ where
     }
     Ok(cmd.spawn()?)
 }
+
+/// Wait for a child process to exit for no longer than `timeout`. Returns `None` if it is still
+/// running.
+pub fn wait_timeout(child: &mut Child, timeout: Duration) -> Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(WAIT_INTERVAL_MS));
+    }
+}
+
+/// Kill a child process started by `run` and every process in its process group, then reap it.
+pub fn kill_tree(child: &mut Child) -> Result<ExitStatus> {
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+    }
+    Ok(child.wait()?)
+}
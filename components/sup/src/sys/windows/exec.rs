@@ -13,12 +13,18 @@
 // limitations under the License.
 
 use std::ffi::OsStr;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
 
-use hcore::os::process::windows_child::Child;
+use hcore::os::process;
+use hcore::os::process::windows_child::{Child, ExitStatus};
 
 use error::Result;
 use manager::service::Pkg;
 
+const WAIT_INTERVAL_MS: u64 = 50;
+
 pub fn run<T, S>(path: S, pkg: &Pkg, svc_encrypted_password: Option<T>) -> Result<Child>
 where
     T: ToString,
@@ -34,3 +40,25 @@ where
         svc_encrypted_password,
     )?)
 }
+
+/// Wait for a child process to exit for no longer than `timeout`. Returns `None` if it is still
+/// running.
+pub fn wait_timeout(child: &mut Child, timeout: Duration) -> Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    while process::is_alive(child.id()) {
+        if Instant::now() >= deadline {
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(WAIT_INTERVAL_MS));
+    }
+    Ok(Some(child.wait()?))
+}
+
+/// Kill a child process started by `run` and every process it started, then reap it.
+pub fn kill_tree(child: &mut Child) -> Result<ExitStatus> {
+    // `taskkill /T` terminates the process along with its descendants.
+    Command::new("taskkill")
+        .args(&["/F", "/T", "/PID", &child.id().to_string()])
+        .output()?;
+    Ok(child.wait()?)
+}
@@ -102,6 +102,8 @@ pub struct Svc<'a> {
     pub first: SvcMember<'a>,
     pub members: Vec<SvcMember<'a>>,
     pub leader: Option<SvcMember<'a>>,
+    pub standbys: Vec<SvcMember<'a>>,
+    pub quorum: Option<u32>,
     pub update_leader: Option<SvcMember<'a>>,
 }
 
@@ -127,6 +129,12 @@ impl<'a> Svc<'a> {
                 .map(|m| SvcMember(m))
                 .collect(),
             leader: census_group.leader().map(|m| SvcMember(m)),
+            standbys: census_group
+                .standbys()
+                .iter()
+                .map(|m| SvcMember(m))
+                .collect(),
+            quorum: census_group.quorum,
             first: select_first(census_group).expect("First should always be present on svc"),
             update_leader: census_group.update_leader().map(|m| SvcMember(m)),
         }
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 
 use handlebars::{Handlebars, Helper, HelperDef, Renderable, RenderContext, RenderError};
+use serde_json::Map;
 use serde_json::Value as Json;
 
 use super::super::RenderResult;
@@ -38,7 +39,8 @@ impl HelperDef for EachAliveHelper {
                     let alive_members: Vec<Json> = list.iter()
                         .filter_map(|m| {
                             m.as_object().and_then(|m| if m.contains_key("alive") &&
-                                m["alive"].as_bool().unwrap()
+                                m["alive"].as_bool().unwrap() &&
+                                has_labels(h, m)
                             {
                                 Some(to_json(&m))
                             } else {
@@ -127,4 +129,15 @@ impl HelperDef for EachAliveHelper {
     }
 }
 
+/// Returns true if the member carries every label given to the helper as a hash parameter, as in
+/// `{{#eachAlive bind.database.members datacenter="east"}}`.
+fn has_labels(h: &Helper, member: &Map<String, Json>) -> bool {
+    h.hash().iter().all(|(key, value)| {
+        member
+            .get("labels")
+            .and_then(|labels| labels.get(key))
+            .map_or(false, |label| label == value.value())
+    })
+}
+
 pub static EACH_ALIVE: EachAliveHelper = EachAliveHelper;
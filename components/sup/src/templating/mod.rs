@@ -310,4 +310,23 @@ test: something"#
 
         assert_eq!(each_alive_render, each_if_render);
     }
+
+    #[test]
+    fn each_alive_helper_filters_on_labels() {
+        let content = "{{#eachAlive members datacenter=\"east\"}}{{sys.ip}} {{/eachAlive}}"
+            .to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let data = json!({
+            "members": [
+                {"alive": true, "sys": {"ip": "10.0.0.1"}, "labels": {"datacenter": "east"}},
+                {"alive": true, "sys": {"ip": "10.0.0.2"}, "labels": {"datacenter": "west"}},
+                {"alive": false, "sys": {"ip": "10.0.0.3"}, "labels": {"datacenter": "east"}},
+                {"alive": true, "sys": {"ip": "10.0.0.4"}, "labels": {}}
+            ]
+        });
+        let rendered = renderer.render("t", &data).unwrap();
+        assert_eq!(rendered, "10.0.0.1 ");
+    }
 }
@@ -0,0 +1,325 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Where the Supervisor gets packages and public origin keys from. This is a Depot, or for sites
+//! without access to one, a local directory of `.hart` files and `.pub` origin keys, such as
+//! removable media.
+//!
+//! A directory source is given as a `file://` URL or an absolute path. The latest release of a
+//! package in a channel is the newest matching artifact in the directory's subdirectory named
+//! after the channel, or in the directory itself when there is no such subdirectory. Artifacts
+//! and keys are otherwise found in the directory and its immediate subdirectories.
+
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use common::ui::ProgressBar;
+use depot_client::{Client as DepotClient, DisplayProgress};
+use hcore::crypto::{artifact, default_cache_key_path, SigKeyPair};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
+use hcore::package::{Identifiable, PackageArchive, PackageIdent, PackageInstall};
+use url::Url;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "AS";
+const ARTIFACT_EXT: &'static str = "hart";
+const PUBLIC_KEY_EXT: &'static str = "pub";
+
+pub enum ArtifactSource {
+    Depot(DepotClient),
+    Directory(PathBuf),
+}
+
+impl ArtifactSource {
+    pub fn new(url: &str) -> Result<Self> {
+        match local_path(url) {
+            Some(path) => Ok(ArtifactSource::Directory(path)),
+            None => Ok(ArtifactSource::Depot(
+                DepotClient::new(url, PRODUCT, VERSION, None)?,
+            )),
+        }
+    }
+
+    /// Returns true if the URL names a local directory rather than a Depot.
+    pub fn is_directory(url: &str) -> bool {
+        local_path(url).is_some()
+    }
+
+    /// Returns the latest release of a package in a channel.
+    pub fn latest(&self, ident: &PackageIdent, channel: &str) -> Result<PackageIdent> {
+        match *self {
+            ArtifactSource::Depot(ref depot) => {
+                Ok(depot.show_package(ident, Some(channel))?.take_ident().into())
+            }
+            ArtifactSource::Directory(ref path) => {
+                let channel_path = path.join(channel);
+                let search_path = if channel_path.is_dir() {
+                    channel_path
+                } else {
+                    path.clone()
+                };
+                artifacts_for(&[search_path], ident)?
+                    .into_iter()
+                    .map(|(ident, _)| ident)
+                    .max()
+                    .ok_or_else(|| sup_error!(Error::PackageNotFound(ident.clone())))
+            }
+        }
+    }
+
+    /// Places the artifact of a fully qualified package in a directory.
+    pub fn fetch_package<D>(
+        &self,
+        ident: &PackageIdent,
+        dst_path: &Path,
+        progress: Option<D>,
+    ) -> Result<PackageArchive>
+    where
+        D: DisplayProgress + Sized,
+    {
+        match *self {
+            ArtifactSource::Depot(ref depot) => {
+                Ok(depot.fetch_package(ident, dst_path, progress)?)
+            }
+            ArtifactSource::Directory(ref path) => {
+                let artifact = artifacts_for(&search_paths(path)?, ident)?
+                    .into_iter()
+                    .find(|&(ref found, _)| found == ident)
+                    .map(|(_, artifact)| artifact)
+                    .ok_or_else(|| sup_error!(Error::PackageNotFound(ident.clone())))?;
+                let dst = dst_path.join(artifact.file_name().expect("artifact has a file name"));
+                fs::create_dir_all(dst_path)
+                    .and_then(|_| fs::copy(&artifact, &dst))
+                    .map_err(|err| sup_error!(Error::ArtifactSourceIO(dst.clone(), err)))?;
+                Ok(PackageArchive::new(dst))
+            }
+        }
+    }
+
+    /// Places the public origin key of the given name and revision in a directory.
+    pub fn fetch_origin_key<D>(
+        &self,
+        name: &str,
+        revision: &str,
+        dst_path: &Path,
+        progress: Option<D>,
+    ) -> Result<()>
+    where
+        D: DisplayProgress + Sized,
+    {
+        match *self {
+            ArtifactSource::Depot(ref depot) => {
+                depot.fetch_origin_key(name, revision, dst_path, progress)?;
+            }
+            ArtifactSource::Directory(ref path) => {
+                let file_name = format!("{}-{}.{}", name, revision, PUBLIC_KEY_EXT);
+                let key_file = search_paths(path)?
+                    .into_iter()
+                    .map(|p| p.join(&file_name))
+                    .find(|p| p.is_file())
+                    .ok_or_else(|| sup_error!(Error::FileNotFound(
+                        path.join(&file_name).display().to_string(),
+                    )))?;
+                let mut content = String::new();
+                File::open(&key_file)
+                    .and_then(|mut file| file.read_to_string(&mut content))
+                    .map_err(|err| sup_error!(Error::ArtifactSourceIO(key_file.clone(), err)))?;
+                SigKeyPair::write_file_from_str(&content, dst_path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Installs a package and, if asked, its dependencies, verifying each artifact's signature
+    /// against the public origin keys in the cache, fetched from this source when missing.
+    pub fn install(&self, package: &PackageIdent, recurse: bool) -> Result<PackageInstall> {
+        let package = match PackageInstall::load(package, Some(&*FS_ROOT_PATH)) {
+            Ok(pkg) => pkg,
+            Err(_) => self.download(package)?,
+        };
+        if recurse {
+            for ident in package.tdeps()?.iter() {
+                self.install(&ident, false)?;
+            }
+        }
+        Ok(package)
+    }
+
+    fn download(&self, package: &PackageIdent) -> Result<PackageInstall> {
+        let cache_key_path = default_cache_key_path(None);
+        let mut archive = self.fetch_package(
+            package,
+            &Path::new(&*FS_ROOT_PATH).join(CACHE_ARTIFACT_PATH),
+            None::<ProgressBar>,
+        )?;
+        let nwr = artifact::artifact_signer(&archive.path)?;
+        if let Err(_) = SigKeyPair::get_public_key_path(&nwr, &cache_key_path) {
+            let (name, rev) = parse_name_with_rev(&nwr)?;
+            self.fetch_origin_key(&name, &rev, &cache_key_path, None::<ProgressBar>)?;
+        }
+        archive.verify(&cache_key_path)?;
+        archive.unpack(None)?;
+        Ok(PackageInstall::load(
+            archive.ident().as_ref().unwrap(),
+            Some(&*FS_ROOT_PATH),
+        )?)
+    }
+}
+
+/// Returns the directory a `file://` URL or absolute path names, if it does.
+fn local_path(url: &str) -> Option<PathBuf> {
+    if Path::new(url).is_absolute() {
+        return Some(PathBuf::from(url));
+    }
+    match Url::parse(url) {
+        Ok(ref parsed) if parsed.scheme() == "file" => parsed.to_file_path().ok(),
+        _ => None,
+    }
+}
+
+/// Returns a directory and its immediate subdirectories.
+fn search_paths(path: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = vec![path.to_path_buf()];
+    let entries = fs::read_dir(path).map_err(|err| {
+        sup_error!(Error::ArtifactSourceIO(path.to_path_buf(), err))
+    })?;
+    for entry in entries.filter_map(|e| e.ok()) {
+        if entry.path().is_dir() {
+            paths.push(entry.path());
+        }
+    }
+    Ok(paths)
+}
+
+/// Returns the artifacts in the given directories which are releases of a package, with the
+/// identifier of the release each holds. Unreadable artifacts are skipped.
+fn artifacts_for(paths: &[PathBuf], ident: &PackageIdent) -> Result<Vec<(PackageIdent, PathBuf)>> {
+    // Artifact file names begin with the package's origin and name, which saves opening most
+    // artifacts of other packages.
+    let prefix = format!("{}-{}-", ident.origin, ident.name);
+    let mut artifacts = Vec::new();
+    for path in paths {
+        let entries = fs::read_dir(path).map_err(|err| {
+            sup_error!(Error::ArtifactSourceIO(path.to_path_buf(), err))
+        })?;
+        for entry in entries.filter_map(|e| e.ok()) {
+            let artifact = entry.path();
+            let candidate = artifact.extension().map_or(false, |e| e == ARTIFACT_EXT) &&
+                entry.file_name().to_string_lossy().starts_with(&prefix);
+            if !candidate {
+                continue;
+            }
+            match PackageArchive::new(artifact.clone()).ident() {
+                Ok(found) => {
+                    if found.satisfies(ident) {
+                        artifacts.push((found, artifact));
+                    }
+                }
+                Err(err) => {
+                    debug!("Skipping unreadable artifact {}, {}", artifact.display(), err)
+                }
+            }
+        }
+    }
+    Ok(artifacts)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use common::ui::ProgressBar;
+    use hcore::crypto::SigKeyPair;
+    use hcore::package::PackageIdent;
+    use tempdir::TempDir;
+
+    use super::*;
+    use error::Error::*;
+
+    const ARTIFACT: &'static str = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+    const ORIGIN_KEY: &'static str = "happyhumans-20160424223347.pub";
+
+    fn fixtures() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("artifacts")
+    }
+
+    fn copy_fixture(name: &str, dir: &Path) {
+        fs::create_dir_all(dir).unwrap();
+        fs::copy(fixtures().join(name), dir.join(name)).unwrap();
+    }
+
+    #[test]
+    fn new_from_url() {
+        match ArtifactSource::new("file:///media/habitat").unwrap() {
+            ArtifactSource::Directory(path) => assert_eq!(path, PathBuf::from("/media/habitat")),
+            ArtifactSource::Depot(_) => panic!("expected a directory source"),
+        }
+        assert!(ArtifactSource::is_directory("/media/habitat"));
+        assert!(!ArtifactSource::is_directory("https://bldr.habitat.sh/v1/depot"));
+    }
+
+    #[test]
+    fn latest_prefers_the_channel_directory() {
+        let tmpdir = TempDir::new("artifacts").unwrap();
+        let source = ArtifactSource::Directory(tmpdir.path().to_path_buf());
+        let possums = PackageIdent::from_str("happyhumans/possums").unwrap();
+        match source.latest(&possums, "stable").unwrap_err().err {
+            PackageNotFound(_) => assert!(true),
+            e => panic!("Unexpected error returned: {:?}", e),
+        }
+
+        copy_fixture(ARTIFACT, &tmpdir.path().join("unstable"));
+        match source.latest(&possums, "unstable") {
+            Ok(latest) => {
+                assert_eq!(
+                    latest,
+                    PackageIdent::from_str("happyhumans/possums/8.1.4/20160427165340").unwrap()
+                )
+            }
+            Err(e) => panic!("Unexpected error returned: {:?}", e),
+        }
+        assert!(source.latest(&possums, "stable").is_err());
+    }
+
+    #[test]
+    fn fetch_package_and_origin_key() {
+        let tmpdir = TempDir::new("artifacts").unwrap();
+        let source_path = tmpdir.path().join("source");
+        let cache_path = tmpdir.path().join("cache");
+        copy_fixture(ARTIFACT, &source_path.join("stable"));
+        copy_fixture(ORIGIN_KEY, &source_path);
+        let source = ArtifactSource::Directory(source_path);
+
+        let ident = PackageIdent::from_str("happyhumans/possums/8.1.4/20160427165340").unwrap();
+        let archive = source
+            .fetch_package(&ident, &cache_path, None::<ProgressBar>)
+            .unwrap();
+        assert_eq!(archive.path, cache_path.join(ARTIFACT));
+
+        source
+            .fetch_origin_key("happyhumans", "20160424223347", &cache_path, None::<ProgressBar>)
+            .unwrap();
+        assert!(SigKeyPair::get_public_key_path("happyhumans-20160424223347", &cache_path).is_ok());
+        assert!(archive.verify(&cache_path).is_ok());
+    }
+}
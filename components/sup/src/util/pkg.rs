@@ -17,14 +17,15 @@ use std::path::Path;
 use ansi_term::Colour::Yellow;
 use common;
 use common::ui::UI;
-use depot_client::Client;
+use hcore::channel::STABLE_CHANNEL;
 use hcore::fs::{self, FS_ROOT_PATH};
-use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
 
 use {PRODUCT, VERSION};
 use error::Result;
 use manager::ServiceSpec;
 use manager::service::UpdateStrategy;
+use util::artifact_source::ArtifactSource;
 
 static LOGKEY: &'static str = "PK";
 
@@ -34,6 +35,18 @@ pub fn install(
     ident: &PackageIdent,
     channel: Option<&str>,
 ) -> Result<PackageInstall> {
+    // The package installer only knows Depots, so resolve and install packages from an artifact
+    // directory here.
+    if ArtifactSource::is_directory(url) {
+        let source = ArtifactSource::new(url)?;
+        let ident = if ident.fully_qualified() {
+            ident.clone()
+        } else {
+            source.latest(ident, channel.unwrap_or(STABLE_CHANNEL))?
+        };
+        outputln!("Installing {} from {}", ident, url);
+        return source.install(&ident, true);
+    }
     let fs_root_path = Path::new(&*FS_ROOT_PATH);
     let installed_ident = common::command::package::install::start(
         ui,
@@ -55,9 +68,9 @@ pub fn maybe_install_newer(
     current: PackageInstall,
 ) -> Result<PackageInstall> {
     let latest_ident: PackageIdent = {
-        let depot_client = Client::new(&spec.depot_url, PRODUCT, VERSION, None)?;
-        match depot_client.show_package(&spec.ident, Some(&spec.channel)) {
-            Ok(pkg) => pkg.get_ident().clone().into(),
+        let source = ArtifactSource::new(&spec.depot_url)?;
+        match source.latest(&spec.ident, &spec.channel) {
+            Ok(ident) => ident,
             Err(_) => return Ok(current),
         }
     };
@@ -85,7 +98,9 @@ pub fn install_from_spec(ui: &mut UI, spec: &ServiceSpec) -> Result<PackageInsta
         Ok(package) => {
             match spec.update_strategy {
                 UpdateStrategy::AtOnce => Ok(maybe_install_newer(ui, spec, package)?),
-                UpdateStrategy::None | UpdateStrategy::Rolling => Ok(package),
+                UpdateStrategy::None | UpdateStrategy::Rolling | UpdateStrategy::Canary => {
+                    Ok(package)
+                }
             }
         }
         Err(_) => {